@@ -0,0 +1,280 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            client.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Thin HTTP client for talking to a Redfish service.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::collections::{BTreeMap, VecDeque};
+use std::error;
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use hyper::client::HttpConnector;
+use hyper::header::{self, HeaderValue};
+use hyper::{Body, Method, Request, StatusCode, Uri};
+use odata::Link;
+use serde::{Deserialize, de::DeserializeOwned};
+use serde_json::Value;
+
+///////////////////////////////////////////////////////////////////////////////
+// Error
+////
+
+#[derive(Debug)]
+pub enum Error {
+    InvalidUri(String),
+    Http(hyper::Error),
+    Json(serde_json::Error),
+    Status(StatusCode, Option<String>),
+    MissingLink(&'static str),
+    NotFound(String),
+    Nonconformant(usize),
+
+    // Local files, e.g. interoperability profiles
+    Io(PathBuf, io::Error),
+    Parse(PathBuf, serde_json::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidUri(uri) => write!(f, "invalid URI: {}", uri),
+            Error::Http(error) => write!(f, "{}", error),
+            Error::Json(error) => write!(f, "malformed response: {}", error),
+            Error::Status(status, Some(message)) =>
+                write!(f, "{}: {}", status, message),
+            Error::Status(status, None) => write!(f, "{}", status),
+            Error::MissingLink(name) =>
+                write!(f, "service does not provide {}", name),
+            Error::NotFound(what) => write!(f, "{} not found", what),
+            Error::Nonconformant(failures) => write!(
+                f, "service does not conform ({} failures)", failures),
+            Error::Io(path, error) =>
+                write!(f, "{}: {}", path.display(), error),
+            Error::Parse(path, error) =>
+                write!(f, "{}: malformed: {}", path.display(), error),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+impl From<hyper::Error> for Error {
+    fn from(value: hyper::Error) -> Self { Error::Http(value) }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(value: serde_json::Error) -> Self { Error::Json(value) }
+}
+
+// Pull the human-readable message out of a Redfish error response, if the
+// service sent one.
+fn error_message(body: &Value) -> Option<String> {
+    let error = body.get("error")?;
+    let extended = error.get("@Message.ExtendedInfo")
+        .and_then(|info| info.get(0))
+        .and_then(|info| info.get("Message"))
+        .and_then(Value::as_str);
+    extended.or_else(|| error.get("message").and_then(Value::as_str))
+        .map(str::to_string)
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reply
+////
+
+pub struct Reply {
+    pub status: StatusCode,
+    pub location: Option<String>,
+    pub body: Value,
+}
+
+impl Reply {
+    // Convert non-success replies into an Error
+    pub fn success(self) -> Result<Self, Error> {
+        if self.status.is_success() {
+            Ok(self)
+        } else {
+            Err(Error::Status(self.status, error_message(&self.body)))
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Collection
+////
+
+#[derive(Deserialize)]
+struct Collection {
+    #[serde(rename = "Members", default)]
+    members: Vec<Link>,
+
+    #[serde(rename = "Members@odata.nextLink")]
+    next_link: Option<String>,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Client
+////
+
+pub struct Client {
+    http: hyper::Client<HttpConnector>,
+    host: String,
+    authorization: Option<HeaderValue>,
+}
+
+impl Client {
+    pub fn new(host: &str, credentials: Option<(&str, &str)>) ->
+        Result<Self, Error>
+    {
+        let host = host.trim_end_matches('/').to_string();
+        host.parse::<Uri>()
+            .map_err(|_| Error::InvalidUri(host.clone()))?;
+        let authorization = credentials.map(|(user, password)| {
+            let token = STANDARD.encode(format!("{}:{}", user, password));
+            HeaderValue::from_str(&format!("Basic {}", token)).unwrap()
+        });
+        Ok(Client { http: hyper::Client::new(), host, authorization })
+    }
+
+    fn uri(&self, path: &str) -> Result<Uri, Error> {
+        let uri = if path.starts_with("http://") {
+            path.to_string()
+        } else if path.starts_with('/') {
+            format!("{}{}", self.host, path)
+        } else {
+            format!("{}/{}", self.host, path)
+        };
+        uri.parse().map_err(|_| Error::InvalidUri(uri))
+    }
+
+    // Issue a request and return whatever the service replied with.
+    pub async fn request(&self, method: Method, path: &str,
+                         body: Option<&Value>) -> Result<Reply, Error>
+    {
+        let mut request = Request::builder()
+            .method(method)
+            .uri(self.uri(path)?)
+            .header(header::ACCEPT, "application/json");
+        if let Some(authorization) = &self.authorization {
+            request = request.header(header::AUTHORIZATION, authorization);
+        }
+
+        let request = match body {
+            Some(body) => request
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(Body::from(serde_json::to_vec(body)?)),
+            None => request.body(Body::empty()),
+        }.unwrap();
+
+        let response = self.http.request(request).await?;
+        let status = response.status();
+        let location = response.headers().get(header::LOCATION)
+            .and_then(|location| location.to_str().ok())
+            .map(str::to_string);
+        let bytes = hyper::body::to_bytes(response.into_body()).await?;
+        let body = if bytes.is_empty() {
+            Value::Null
+        } else {
+            serde_json::from_slice(&bytes)
+                .unwrap_or_else(|_| Value::String(
+                    String::from_utf8_lossy(&bytes).into_owned()))
+        };
+        Ok(Reply { status, location, body })
+    }
+
+    pub async fn get<T: DeserializeOwned>(&self, path: &str) ->
+        Result<T, Error>
+    {
+        let reply = self.request(Method::GET, path, None).await?.success()?;
+        Ok(serde_json::from_value(reply.body)?)
+    }
+
+    pub async fn post(&self, path: &str, body: &Value) -> Result<Reply, Error>
+    {
+        self.request(Method::POST, path, Some(body)).await?.success()
+    }
+
+    // Fetch every member of a collection, following nextLink paging.
+    pub async fn members(&self, path: &str) -> Result<Vec<Value>, Error> {
+        let mut members = Vec::new();
+        let mut next = Some(path.to_string());
+        while let Some(page) = next {
+            let collection: Collection = self.get(&page).await?;
+            for member in collection.members {
+                members.push(self.get(&link_path(&member)).await?);
+            }
+            next = collection.next_link;
+        }
+        Ok(members)
+    }
+
+    // Find the member of a collection with the given Id.
+    pub async fn member(&self, path: &str, id: &str) -> Result<Value, Error> {
+        self.members(path).await?.into_iter()
+            .find(|member| {
+                member.get("Id").and_then(Value::as_str) == Some(id)
+            })
+            .ok_or_else(|| Error::NotFound(id.to_string()))
+    }
+}
+
+impl Client {
+    // Fetch every resource reachable from the given URI, keyed by URI.
+    // Resources that cannot be retrieved are left out.
+    pub async fn crawl(&self, root: &str) ->
+        Result<BTreeMap<String, Value>, Error>
+    {
+        let mut resources = BTreeMap::new();
+        let mut pending = VecDeque::from(vec![root.to_string()]);
+        while let Some(uri) = pending.pop_front() {
+            if resources.contains_key(&uri) {
+                continue;
+            }
+            let reply = self.request(Method::GET, &uri, None).await?;
+            if !reply.status.is_success() {
+                continue;
+            }
+            let mut found = Vec::new();
+            odata::links(&reply.body, &mut found);
+            pending.extend(found);
+            resources.insert(uri, reply.body);
+        }
+        Ok(resources)
+    }
+}
+
+pub fn link_path(link: &Link) -> String {
+    link.as_ref().to_string_lossy().into_owned()
+}
+
+///////////////////////////////////////////////////////////////////////////////
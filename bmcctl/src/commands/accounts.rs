@@ -0,0 +1,93 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            accounts.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Subcommands for managing AccountService accounts.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use clap::Subcommand;
+use odata::Link;
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::client::{Client, Error};
+use crate::output::{self, Format};
+use crate::resources::{self, ServiceRoot};
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct AccountService {
+    accounts: Option<Link>,
+}
+
+#[derive(Subcommand)]
+pub enum Accounts {
+    /// Create a new user account
+    Add {
+        /// Name of the new user
+        user_name: String,
+
+        /// Password of the new user
+        #[clap(long)]
+        password: String,
+
+        /// Role assigned to the user
+        #[clap(long, default_value = "ReadOnly")]
+        role: String,
+
+        /// Create the account disabled
+        #[clap(long)]
+        disabled: bool,
+    },
+}
+
+pub async fn run(client: &Client, format: Format, command: Accounts) ->
+    Result<(), Error>
+{
+    let root = ServiceRoot::fetch(client).await?;
+    let service: AccountService = client.get(&root.account_service()?).await?;
+    let accounts = resources::required(&service.accounts, "Accounts")?;
+
+    let Accounts::Add { user_name, password, role, disabled } = command;
+    let reply = client.post(&accounts, &json!({
+        "UserName": user_name,
+        "Password": password,
+        "RoleId": role,
+        "Enabled": !disabled,
+    })).await?;
+    match format {
+        Format::Json => output::print_json(&reply.body),
+        Format::Table => match reply.location {
+            Some(location) => println!("Created {}", location),
+            None => println!("Created account {}", user_name),
+        },
+    }
+    Ok(())
+}
+
+///////////////////////////////////////////////////////////////////////////////
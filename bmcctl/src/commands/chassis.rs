@@ -0,0 +1,106 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            chassis.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Subcommands operating on Chassis.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use clap::Subcommand;
+use serde_json::Value;
+
+use crate::client::{Client, Error};
+use crate::output::{self, Format, Table};
+use crate::resources::{self, Chassis as ChassisView, ServiceRoot};
+
+#[derive(Subcommand)]
+pub enum Chassis {
+    /// Show all chassis, or the properties of a single chassis
+    Show {
+        /// Id of the chassis to show
+        id: Option<String>,
+    },
+}
+
+// Render the scalar properties of a resource as a two-column table.
+fn properties(resource: &Value) -> Table {
+    let mut table = Table::new(vec!["Property", "Value"]);
+    if let Value::Object(properties) = resource {
+        for (name, value) in properties {
+            let value = match value {
+                Value::String(value) => value.clone(),
+                Value::Number(_) | Value::Bool(_) => value.to_string(),
+                _ => continue,
+            };
+            table.row(vec![name.clone(), value]);
+        }
+    }
+    table
+}
+
+pub async fn run(client: &Client, format: Format, command: Chassis) ->
+    Result<(), Error>
+{
+    let root = ServiceRoot::fetch(client).await?;
+    let collection = root.chassis()?;
+    match command {
+        Chassis::Show { id: Some(id) } => {
+            let chassis = client.member(&collection, &id).await?;
+            match format {
+                Format::Json => output::print_json(&chassis),
+                Format::Table => print!("{}", properties(&chassis)),
+            }
+        },
+
+        Chassis::Show { id: None } => {
+            let chassis = client.members(&collection).await?;
+            if format == Format::Json {
+                output::print_json(&chassis.into());
+                return Ok(());
+            }
+
+            let mut table = Table::new(vec![
+                "Id", "Name", "Type", "Manufacturer", "Model", "SerialNumber",
+                "State", "Health"]);
+            for (chassis, _) in resources::typed::<ChassisView>(chassis)? {
+                table.row(vec![
+                    chassis.id, output::text(&chassis.name),
+                    output::text(&chassis.chassis_type),
+                    output::text(&chassis.manufacturer),
+                    output::text(&chassis.model),
+                    output::text(&chassis.serial_number),
+                    output::cell(Some(chassis.status.state)),
+                    output::cell(Some(chassis.status.health)),
+                ]);
+            }
+            print!("{}", table);
+        },
+    }
+    Ok(())
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,145 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            logs.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Subcommands for reading LogService entries.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::collections::HashSet;
+use std::time::Duration;
+
+use clap::Subcommand;
+
+use crate::client::{Client, Error};
+use crate::output::{self, Format};
+use crate::resources::{self, ComputerSystem, LogEntry, LogService, Manager,
+                       ServiceRoot};
+
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+#[derive(Subcommand)]
+pub enum Logs {
+    /// Print the most recent entries of a log service
+    Tail {
+        /// Read the logs of the system with this Id
+        #[clap(long, conflicts_with = "manager")]
+        system: Option<String>,
+
+        /// Read the logs of the manager with this Id (default: the first)
+        #[clap(long)]
+        manager: Option<String>,
+
+        /// Id of the log service (default: the first)
+        #[clap(long)]
+        service: Option<String>,
+
+        /// Number of entries to print
+        #[clap(short = 'n', long, default_value_t = 10)]
+        lines: usize,
+
+        /// Keep polling for new entries
+        #[clap(short, long)]
+        follow: bool,
+    },
+}
+
+// Locate the LogServices collection of the requested system or manager.
+async fn log_services(client: &Client, system: Option<String>,
+                      manager: Option<String>) -> Result<String, Error>
+{
+    let root = ServiceRoot::fetch(client).await?;
+    if let Some(id) = system {
+        let system: ComputerSystem = serde_json::from_value(
+            client.member(&root.systems()?, &id).await?)?;
+        return resources::required(&system.log_services, "LogServices");
+    }
+
+    let managers = client.members(&root.managers()?).await?;
+    let manager = resources::typed::<Manager>(managers)?.into_iter()
+        .map(|(manager, _)| manager)
+        .find(|candidate| manager.as_ref()
+              .is_none_or(|id| id == &candidate.id))
+        .ok_or_else(|| Error::NotFound(
+            manager.unwrap_or_else(|| "Manager".to_string())))?;
+    resources::required(&manager.log_services, "LogServices")
+}
+
+async fn entries(client: &Client, system: Option<String>,
+                 manager: Option<String>, service: Option<String>) ->
+    Result<String, Error>
+{
+    let services = log_services(client, system, manager).await?;
+    let service = resources::typed::<LogService>(
+        client.members(&services).await?)?.into_iter()
+        .map(|(log_service, _)| log_service)
+        .find(|candidate| service.as_ref()
+              .is_none_or(|id| id == &candidate.id))
+        .ok_or_else(|| Error::NotFound(
+            service.unwrap_or_else(|| "LogService".to_string())))?;
+    resources::required(&service.entries, "Entries")
+}
+
+fn print_entry(format: Format, entry: &LogEntry, raw: &serde_json::Value) {
+    match format {
+        Format::Json => println!("{}", raw),
+        Format::Table => println!(
+            "{} {:<8} {} {}", output::text(&entry.created),
+            output::text(&entry.severity), output::text(&entry.message_id),
+            output::text(&entry.message)),
+    }
+}
+
+pub async fn run(client: &Client, format: Format, command: Logs) ->
+    Result<(), Error>
+{
+    let Logs::Tail { system, manager, service, lines, follow } = command;
+    let entries = entries(client, system, manager, service).await?;
+
+    let mut seen = HashSet::new();
+    let mut first = true;
+    loop {
+        let members = resources::typed::<LogEntry>(
+            client.members(&entries).await?)?;
+        // Only the last few entries are printed initially, but every entry
+        // is remembered so that following prints only new ones.
+        let skip = if first { members.len().saturating_sub(lines) } else { 0 };
+        for (index, (entry, raw)) in members.into_iter().enumerate() {
+            if seen.insert(entry.id.clone()) && index >= skip {
+                print_entry(format, &entry, &raw);
+            }
+        }
+
+        if !follow {
+            return Ok(());
+        }
+        first = false;
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,125 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            profile.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Subcommands for checking a service against a Redfish
+//                  Interoperability Profile.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use clap::Subcommand;
+
+use crate::client::{Client, Error};
+use crate::interop::{self, Outcome, Profile};
+use crate::output::{self, Format, Table};
+use crate::resources::SERVICE_ROOT;
+
+#[derive(Subcommand)]
+pub enum Profiles {
+    /// Check the service against an interoperability profile
+    Check {
+        /// Path to the profile JSON document
+        profile: PathBuf,
+
+        /// Also list the requirements that were met
+        #[clap(short, long)]
+        all: bool,
+    },
+}
+
+fn load(path: &PathBuf) -> Result<Profile, Error> {
+    let contents = fs::read(path).map_err(|error| match error.kind() {
+        io::ErrorKind::NotFound =>
+            Error::NotFound(path.display().to_string()),
+        _ => Error::Io(path.clone(), error),
+    })?;
+    serde_json::from_slice(&contents)
+        .map_err(|error| Error::Parse(path.clone(), error))
+}
+
+pub async fn run(client: &Client, format: Format, command: Profiles) ->
+    Result<(), Error>
+{
+    let Profiles::Check { profile, all } = command;
+    let profile = load(&profile)?;
+    let resources = client.crawl(SERVICE_ROOT).await?;
+    let report = interop::evaluate(&profile, &resources);
+
+    match format {
+        Format::Json => output::print_json(&serde_json::to_value(&report)?),
+        Format::Table => {
+            let mut table = Table::new(vec![
+                "Result", "Resource", "Requirement", "Detail"]);
+            for finding in &report.findings {
+                if all || finding.outcome != Outcome::Pass {
+                    table.row(vec![
+                        finding.outcome.to_string(), finding.resource.clone(),
+                        finding.requirement.clone(), finding.message.clone(),
+                    ]);
+                }
+            }
+            println!("{} {}: {} resources checked", report.profile_name,
+                     report.profile_version, resources.len());
+            print!("{}", table);
+            println!("{} passed, {} warnings, {} failed",
+                     report.count(Outcome::Pass), report.count(Outcome::Warn),
+                     report.count(Outcome::Fail));
+        },
+    }
+
+    match report.count(Outcome::Fail) {
+        0 => Ok(()),
+        failures => Err(Error::Nonconformant(failures)),
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Profile Test
+////
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use super::load;
+    use crate::client::Error;
+
+    #[test]
+    fn load_errors_are_distinguished() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        assert!(matches!(load(&root.join("missing.json")),
+                         Err(Error::NotFound(_))));
+        assert!(matches!(load(&root.join("src")), Err(Error::Io(..))));
+        assert!(matches!(load(&root.join("Cargo.toml")),
+                         Err(Error::Parse(..))));
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,96 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            raw.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Subcommands issuing arbitrary requests against the service.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::fs;
+
+use clap::Subcommand;
+use hyper::Method;
+use serde_json::Value;
+
+use crate::client::{Client, Error};
+use crate::output;
+
+#[derive(Subcommand)]
+pub enum Raw {
+    /// GET a URI
+    Get { uri: String },
+
+    /// POST a JSON body to a URI
+    Post {
+        uri: String,
+
+        /// JSON body, or @FILE to read it from a file
+        #[clap(short, long, value_parser = parse_body)]
+        data: Value,
+    },
+
+    /// PATCH a URI with a JSON body
+    Patch {
+        uri: String,
+
+        /// JSON body, or @FILE to read it from a file
+        #[clap(short, long, value_parser = parse_body)]
+        data: Value,
+    },
+
+    /// DELETE a URI
+    Delete { uri: String },
+}
+
+fn parse_body(argument: &str) -> Result<Value, String> {
+    let text = match argument.strip_prefix('@') {
+        Some(path) => fs::read_to_string(path)
+            .map_err(|error| format!("{}: {}", path, error))?,
+        None => argument.to_string(),
+    };
+    serde_json::from_str(&text).map_err(|error| error.to_string())
+}
+
+pub async fn run(client: &Client, command: Raw) -> Result<(), Error> {
+    let (method, uri, body) = match command {
+        Raw::Get { uri } => (Method::GET, uri, None),
+        Raw::Post { uri, data } => (Method::POST, uri, Some(data)),
+        Raw::Patch { uri, data } => (Method::PATCH, uri, Some(data)),
+        Raw::Delete { uri } => (Method::DELETE, uri, None),
+    };
+
+    let reply = client.request(method, &uri, body.as_ref()).await?;
+    if let Some(location) = &reply.location {
+        eprintln!("Location: {}", location);
+    }
+    if !reply.body.is_null() {
+        output::print_json(&reply.body);
+    }
+    reply.success().map(|_| ())
+}
+
+///////////////////////////////////////////////////////////////////////////////
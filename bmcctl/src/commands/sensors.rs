@@ -0,0 +1,90 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            sensors.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Subcommand listing sensor readings.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use clap::Args;
+use serde_json::Value;
+
+use crate::client::{self, Client, Error};
+use crate::output::{self, Format, Table};
+use crate::resources::{self, Chassis, Sensor, ServiceRoot};
+
+#[derive(Args)]
+pub struct Sensors {
+    /// Only show sensors of the chassis with this Id
+    #[clap(long)]
+    chassis: Option<String>,
+}
+
+pub async fn run(client: &Client, format: Format, command: Sensors) ->
+    Result<(), Error>
+{
+    let root = ServiceRoot::fetch(client).await?;
+    let chassis = resources::typed::<Chassis>(
+        client.members(&root.chassis()?).await?)?;
+
+    let mut sensors: Vec<(String, Value)> = Vec::new();
+    for (chassis, _) in chassis {
+        if command.chassis.as_ref().is_some_and(|id| id != &chassis.id) {
+            continue;
+        }
+        if let Some(link) = &chassis.sensors {
+            for sensor in client.members(&client::link_path(link)).await? {
+                sensors.push((chassis.id.clone(), sensor));
+            }
+        }
+    }
+
+    if format == Format::Json {
+        let sensors: Vec<Value> = sensors.into_iter()
+            .map(|(_, sensor)| sensor).collect();
+        output::print_json(&sensors.into());
+        return Ok(());
+    }
+
+    let mut table = Table::new(vec![
+        "Chassis", "Id", "Name", "Reading", "Units", "Type", "Health"]);
+    for (chassis, sensor) in sensors {
+        let sensor: Sensor = serde_json::from_value(sensor)?;
+        table.row(vec![
+            chassis, sensor.id, output::text(&sensor.name),
+            sensor.reading.map(|reading| reading.to_string())
+                .unwrap_or_else(|| "-".to_string()),
+            output::text(&sensor.reading_units),
+            output::text(&sensor.reading_type),
+            output::cell(Some(sensor.status.health)),
+        ]);
+    }
+    print!("{}", table);
+    Ok(())
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,107 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            systems.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Subcommands operating on ComputerSystems.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use clap::Subcommand;
+use librebmc::models::ResetType;
+use serde_json::json;
+
+use crate::client::{Client, Error};
+use crate::output::{self, Format, Table};
+use crate::resources::{self, ComputerSystem, ServiceRoot};
+
+const RESET_ACTION: &'static str = "#ComputerSystem.Reset";
+
+#[derive(Subcommand)]
+pub enum Systems {
+    /// List the computer systems managed by the service
+    List,
+
+    /// Reset a computer system
+    Reset {
+        /// Id of the system to reset
+        id: String,
+
+        /// The kind of reset to perform
+        #[clap(long = "type", value_parser,
+               default_value_t = ResetType::GracefulRestart)]
+        reset_type: ResetType,
+    },
+}
+
+pub async fn run(client: &Client, format: Format, command: Systems) ->
+    Result<(), Error>
+{
+    let root = ServiceRoot::fetch(client).await?;
+    let collection = root.systems()?;
+    match command {
+        Systems::List => {
+            let systems = client.members(&collection).await?;
+            if format == Format::Json {
+                output::print_json(&systems.into());
+                return Ok(());
+            }
+
+            let mut table = Table::new(vec![
+                "Id", "Name", "Type", "Power", "State", "Health"]);
+            for (system, _) in resources::typed::<ComputerSystem>(systems)? {
+                table.row(vec![
+                    system.id, output::text(&system.name),
+                    output::cell(system.system_type),
+                    output::cell(system.power_state),
+                    output::cell(Some(system.status.state)),
+                    output::cell(Some(system.status.health)),
+                ]);
+            }
+            print!("{}", table);
+        },
+
+        Systems::Reset { id, reset_type } => {
+            let system: ComputerSystem = serde_json::from_value(
+                client.member(&collection, &id).await?)?;
+            let action = system.actions.get(RESET_ACTION)
+                .ok_or(Error::MissingLink(RESET_ACTION))?;
+            let reply = client.post(
+                &action.target, &json!({"ResetType": reset_type})).await?;
+            if format == Format::Json && !reply.body.is_null() {
+                output::print_json(&reply.body);
+            } else {
+                println!("{} of system {}: {}", reset_type, id, reply.status);
+            }
+            if let Some(location) = reply.location {
+                println!("Task monitor: {}", location);
+            }
+        },
+    }
+    Ok(())
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,564 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            interop.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Evaluation of Redfish Interoperability Profiles (DSP0272)
+//                  against the resources of a running service.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+///////////////////////////////////////////////////////////////////////////////
+// Profile
+////
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq)]
+pub enum Requirement {
+    #[default]
+    Mandatory,
+    Supported,
+    Recommended,
+    IfImplemented,
+    IfPopulated,
+    Conditional,
+    None,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+pub enum Comparison {
+    AnyOf,
+    AllOf,
+    Equal,
+    NotEqual,
+    GreaterThan,
+    GreaterThanOrEqual,
+    LessThan,
+    LessThanOrEqual,
+    Absent,
+    Present,
+    LinkToResource,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct PropertyRequirement {
+    pub read_requirement: Requirement,
+    pub comparison: Option<Comparison>,
+    pub values: Vec<Value>,
+    pub min_count: Option<usize>,
+    pub property_requirements: BTreeMap<String, PropertyRequirement>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct ParameterRequirement {
+    pub read_requirement: Requirement,
+    pub parameter_values: Vec<String>,
+    pub recommended_values: Vec<String>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct ActionRequirement {
+    pub read_requirement: Requirement,
+    pub parameters: BTreeMap<String, ParameterRequirement>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct ResourceRequirement {
+    pub read_requirement: Requirement,
+    pub min_version: Option<String>,
+    #[serde(rename = "URIs")]
+    pub uris: Option<Vec<String>>,
+    pub property_requirements: BTreeMap<String, PropertyRequirement>,
+    pub action_requirements: BTreeMap<String, ActionRequirement>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct Protocol {
+    pub min_version: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Profile {
+    pub profile_name: String,
+    pub profile_version: String,
+    #[serde(default)]
+    pub protocol: Protocol,
+    #[serde(default)]
+    pub resources: BTreeMap<String, ResourceRequirement>,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Report
+////
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub enum Outcome {
+    Pass,
+    Warn,
+    Fail,
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Outcome::Pass => "PASS",
+            Outcome::Warn => "WARN",
+            Outcome::Fail => "FAIL",
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Finding {
+    pub outcome: Outcome,
+    pub resource: String,
+    pub requirement: String,
+    pub message: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Report {
+    pub profile_name: String,
+    pub profile_version: String,
+    pub findings: Vec<Finding>,
+}
+
+impl Report {
+    pub fn count(&self, outcome: Outcome) -> usize {
+        self.findings.iter()
+            .filter(|finding| finding.outcome == outcome)
+            .count()
+    }
+
+    fn record(&mut self, outcome: Outcome, resource: &str, requirement: &str,
+              message: String)
+    {
+        self.findings.push(Finding {
+            outcome, resource: resource.to_string(),
+            requirement: requirement.to_string(), message,
+        });
+    }
+
+    // Record the outcome of a check that did not find what it looked for.
+    fn missing(&mut self, requirement: Requirement, resource: &str,
+               name: &str, message: String)
+    {
+        match requirement {
+            Requirement::Mandatory => self.record(
+                Outcome::Fail, resource, name, message),
+            Requirement::Recommended => self.record(
+                Outcome::Warn, resource, name, message),
+            _ => {},
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Helpers
+////
+
+// Parse "1.6.0" or "v1_6_0" into its components.
+fn version(text: &str) -> Vec<u32> {
+    text.trim_start_matches('v')
+        .split(['.', '_'])
+        .map(|part| part.parse().unwrap_or(0))
+        .collect()
+}
+
+// The schema namespace and version of an @odata.type such as
+// #ComputerSystem.v1_16_1.ComputerSystem
+fn odata_type(resource: &Value) -> Option<(&str, Option<&str>)> {
+    let odata_type = resource["@odata.type"].as_str()?.strip_prefix('#')?;
+    let mut parts = odata_type.split('.');
+    let namespace = parts.next()?;
+    let version = parts.next().filter(|_| parts.next().is_some());
+    Some((namespace, version))
+}
+
+// Match a URI against a profile URI template such as
+// /redfish/v1/Systems/{ComputerSystemId}
+fn matches_template(uri: &str, template: &str) -> bool {
+    let uri: Vec<&str> = uri.trim_end_matches('/').split('/').collect();
+    let template: Vec<&str> = template.trim_end_matches('/').split('/')
+        .collect();
+    uri.len() == template.len() && uri.iter().zip(&template).all(
+        |(segment, pattern)| pattern.starts_with('{') || segment == pattern)
+}
+
+fn compare(left: &Value, right: &Value) -> Option<Ordering> {
+    match (left, right) {
+        (Value::Number(left), Value::Number(right)) =>
+            left.as_f64()?.partial_cmp(&right.as_f64()?),
+        (Value::String(left), Value::String(right)) =>
+            Some(version(left).cmp(&version(right))),
+        _ => None,
+    }
+}
+
+fn describe(values: &[Value]) -> String {
+    values.iter().map(Value::to_string).collect::<Vec<String>>().join(", ")
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Evaluation
+////
+
+struct Evaluator<'a> {
+    resources: &'a BTreeMap<String, Value>,
+    report: Report,
+}
+
+impl Evaluator<'_> {
+    fn comparison(&mut self, uri: &str, name: &str,
+                  requirement: &PropertyRequirement, value: &Value)
+    {
+        let comparison = match requirement.comparison {
+            Some(comparison) => comparison,
+            None => return,
+        };
+
+        let values = &requirement.values;
+        let elements: Vec<&Value> = match value {
+            Value::Array(elements) => elements.iter().collect(),
+            value => vec![value],
+        };
+        let ordering = values.first().and_then(|first| compare(value, first));
+        let satisfied = match comparison {
+            Comparison::AnyOf => elements.iter()
+                .any(|element| values.contains(element)),
+            Comparison::AllOf => values.iter()
+                .all(|expected| elements.contains(&expected)),
+            Comparison::Equal => values.first() == Some(value),
+            Comparison::NotEqual => values.first() != Some(value),
+            Comparison::GreaterThan => ordering == Some(Ordering::Greater),
+            Comparison::GreaterThanOrEqual =>
+                matches!(ordering, Some(Ordering::Greater | Ordering::Equal)),
+            Comparison::LessThan => ordering == Some(Ordering::Less),
+            Comparison::LessThanOrEqual =>
+                matches!(ordering, Some(Ordering::Less | Ordering::Equal)),
+            Comparison::Present => true,
+            Comparison::Absent => false,
+            Comparison::LinkToResource => value["@odata.id"].as_str()
+                .and_then(|target| self.resources.get(target))
+                .and_then(odata_type)
+                .is_some_and(|(namespace, _)| values.iter().any(
+                    |expected| expected.as_str() == Some(namespace))),
+        };
+
+        let outcome = if satisfied { Outcome::Pass } else { Outcome::Fail };
+        self.report.record(outcome, uri, name, format!(
+            "{} is {:?} [{}]", value, comparison, describe(values)));
+    }
+
+    fn property(&mut self, uri: &str, name: &str,
+                requirement: &PropertyRequirement, value: Option<&Value>)
+    {
+        let value = match value.filter(|value| !value.is_null()) {
+            Some(value) => value,
+            None => {
+                if requirement.comparison == Some(Comparison::Absent) {
+                    self.report.record(Outcome::Pass, uri, name,
+                                       "property is absent".to_string());
+                } else {
+                    self.report.missing(
+                        requirement.read_requirement, uri, name,
+                        "property is missing".to_string());
+                }
+                return;
+            },
+        };
+
+        if requirement.comparison == Some(Comparison::Absent) {
+            self.report.record(Outcome::Fail, uri, name,
+                               "property must be absent".to_string());
+            return;
+        }
+
+        if requirement.comparison.is_none()
+            && requirement.property_requirements.is_empty()
+        {
+            self.report.record(Outcome::Pass, uri, name,
+                               "property is present".to_string());
+        }
+
+        self.comparison(uri, name, requirement, value);
+        if let (Some(minimum), Value::Array(elements)) =
+            (requirement.min_count, value)
+        {
+            let outcome = if elements.len() >= minimum { Outcome::Pass }
+                          else { Outcome::Fail };
+            self.report.record(outcome, uri, name, format!(
+                "{} members, at least {} required", elements.len(), minimum));
+        }
+
+        // Nested requirements apply to objects, or to each object in an array
+        let objects: Vec<&Value> = match value {
+            Value::Array(elements) => elements.iter().collect(),
+            value => vec![value],
+        };
+        for object in objects {
+            self.properties(uri, name, &requirement.property_requirements,
+                            object);
+        }
+    }
+
+    fn properties(&mut self, uri: &str, prefix: &str,
+                  requirements: &BTreeMap<String, PropertyRequirement>,
+                  resource: &Value)
+    {
+        for (property, requirement) in requirements {
+            let name = format!("{}.{}", prefix, property);
+            self.property(uri, &name, requirement, resource.get(property));
+        }
+    }
+
+    fn action(&mut self, uri: &str, namespace: &str, action: &str,
+              requirement: &ActionRequirement, resource: &Value)
+    {
+        let name = format!("{}.{}", namespace, action);
+        let found = &resource["Actions"][format!("#{}", name)];
+        if found.is_null() {
+            self.report.missing(requirement.read_requirement, uri, &name,
+                                "action is not supported".to_string());
+            return;
+        }
+        self.report.record(Outcome::Pass, uri, &name,
+                           "action is supported".to_string());
+
+        for (parameter, requirement) in &requirement.parameters {
+            let name = format!("{}({})", name, parameter);
+            let allowable = &found[format!(
+                "{}@Redfish.AllowableValues", parameter)];
+            let allowable: Vec<&str> = match allowable.as_array() {
+                Some(values) => values.iter().filter_map(Value::as_str)
+                    .collect(),
+                None => {
+                    if !requirement.parameter_values.is_empty() {
+                        self.report.record(
+                            Outcome::Warn, uri, &name,
+                            "service does not list allowable values"
+                                .to_string());
+                    }
+                    continue;
+                },
+            };
+
+            for (values, outcome) in [
+                (&requirement.parameter_values, Outcome::Fail),
+                (&requirement.recommended_values, Outcome::Warn),
+            ] {
+                let absent: Vec<&str> = values.iter().map(String::as_str)
+                    .filter(|value| !allowable.contains(value))
+                    .collect();
+                if !absent.is_empty() {
+                    self.report.record(outcome, uri, &name, format!(
+                        "{} not allowed", absent.join(", ")));
+                } else if !values.is_empty() {
+                    self.report.record(Outcome::Pass, uri, &name, format!(
+                        "allows {}", values.join(", ")));
+                }
+            }
+        }
+    }
+
+    fn resource(&mut self, namespace: &str, requirement: &ResourceRequirement)
+    {
+        let resources = self.resources;
+        let instances: Vec<(&String, &Value)> = resources.iter()
+            .filter(|(_, resource)| odata_type(resource)
+                    .is_some_and(|(found, _)| found == namespace))
+            .filter(|(uri, _)| requirement.uris.as_ref().is_none_or(
+                |templates| templates.iter().any(
+                    |template| matches_template(uri, template))))
+            .collect();
+        if instances.is_empty() {
+            self.report.missing(requirement.read_requirement, namespace,
+                                namespace, "no instances found".to_string());
+            return;
+        }
+
+        for (uri, resource) in instances {
+            if let Some(minimum) = &requirement.min_version {
+                let found = odata_type(resource)
+                    .and_then(|(_, found)| found).unwrap_or("v1_0_0");
+                let outcome = if version(found) >= version(minimum) {
+                    Outcome::Pass
+                } else {
+                    Outcome::Fail
+                };
+                self.report.record(outcome, uri, namespace, format!(
+                    "schema version {}, at least {} required", found,
+                    minimum));
+            }
+
+            self.properties(uri, namespace, &requirement.property_requirements,
+                            resource);
+            for (action, action_requirement) in
+                &requirement.action_requirements
+            {
+                self.action(uri, namespace, action, action_requirement,
+                            resource);
+            }
+        }
+    }
+}
+
+// Evaluate a profile against the resources of a service, keyed by URI.
+pub fn evaluate(profile: &Profile, resources: &BTreeMap<String, Value>) ->
+    Report
+{
+    let mut evaluator = Evaluator {
+        resources,
+        report: Report {
+            profile_name: profile.profile_name.clone(),
+            profile_version: profile.profile_version.clone(),
+            findings: Vec::new(),
+        },
+    };
+
+    if let Some(minimum) = &profile.protocol.min_version {
+        let root = resources.values()
+            .find(|resource| odata_type(resource)
+                  .is_some_and(|(namespace, _)| namespace == "ServiceRoot"));
+        let found = root.and_then(|root| root["RedfishVersion"].as_str())
+            .unwrap_or("0.0.0");
+        let outcome = if version(found) >= version(minimum) {
+            Outcome::Pass
+        } else {
+            Outcome::Fail
+        };
+        evaluator.report.record(outcome, "/redfish/v1", "Protocol", format!(
+            "Redfish version {}, at least {} required", found, minimum));
+    }
+
+    for (namespace, requirement) in &profile.resources {
+        evaluator.resource(namespace, requirement);
+    }
+    evaluator.report
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Profile Test
+////
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use serde_json::{json, Value};
+
+    use super::{evaluate, Outcome, Profile};
+
+    fn service() -> BTreeMap<String, Value> {
+        let mut resources = BTreeMap::new();
+        resources.insert("/redfish/v1".to_string(), json!({
+            "@odata.type": "#ServiceRoot.v1_12_0.ServiceRoot",
+            "RedfishVersion": "1.6.0",
+        }));
+        resources.insert("/redfish/v1/Systems/system".to_string(), json!({
+            "@odata.type": "#ComputerSystem.v1_16_1.ComputerSystem",
+            "PowerState": "On",
+            "Status": {"State": "Enabled"},
+            "Actions": {"#ComputerSystem.Reset": {
+                "target": concat!("/redfish/v1/Systems/system/Actions/",
+                                  "ComputerSystem.Reset"),
+                "ResetType@Redfish.AllowableValues": ["On", "ForceOff"],
+            }},
+        }));
+        resources
+    }
+
+    fn profile(resources: Value) -> Profile {
+        serde_json::from_value(json!({
+            "ProfileName": "Test",
+            "ProfileVersion": "1.0.0",
+            "Protocol": {"MinVersion": "1.6"},
+            "Resources": resources,
+        })).unwrap()
+    }
+
+    #[test]
+    fn conforming_service_passes() {
+        let profile = profile(json!({
+            "ComputerSystem": {
+                "MinVersion": "1.1.0",
+                "PropertyRequirements": {
+                    "PowerState": {"Comparison": "AnyOf",
+                                   "Values": ["On", "Off"]},
+                    "Status": {"PropertyRequirements": {"State": {}}},
+                },
+                "ActionRequirements": {"Reset": {"Parameters": {
+                    "ResetType": {"ParameterValues": ["On", "ForceOff"]},
+                }}},
+            },
+        }));
+        let report = evaluate(&profile, &service());
+        assert_eq!(0, report.count(Outcome::Fail), "{:?}", report.findings);
+        assert_eq!(0, report.count(Outcome::Warn), "{:?}", report.findings);
+        assert_eq!(6, report.count(Outcome::Pass), "{:?}", report.findings);
+    }
+
+    #[test]
+    fn missing_requirements_are_reported() {
+        let profile = profile(json!({
+            "ComputerSystem": {
+                "PropertyRequirements": {
+                    "SerialNumber": {},
+                    "AssetTag": {"ReadRequirement": "Recommended"},
+                    "HostName": {"ReadRequirement": "IfImplemented"},
+                },
+                "ActionRequirements": {"Reset": {"Parameters": {
+                    "ResetType": {"ParameterValues": ["GracefulShutdown"]},
+                }}},
+            },
+            "Chassis": {},
+        }));
+        let report = evaluate(&profile, &service());
+        let failed: Vec<&str> = report.findings.iter()
+            .filter(|finding| finding.outcome == Outcome::Fail)
+            .map(|finding| finding.requirement.as_str())
+            .collect();
+        assert_eq!(vec!["Chassis", "ComputerSystem.SerialNumber",
+                        "ComputerSystem.Reset(ResetType)"], failed);
+        assert_eq!(1, report.count(Outcome::Warn), "{:?}", report.findings);
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
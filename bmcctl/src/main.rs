@@ -0,0 +1,140 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            main.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Entrypoint for bmcctl, a command-line client for Redfish
+//                  services.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
+
+mod client;
+mod commands;
+mod interop;
+mod output;
+mod resources;
+
+use crate::client::{Client, Error};
+use crate::commands::{accounts::Accounts, chassis::Chassis, logs::Logs,
+                      profile::Profiles, raw::Raw, sensors::Sensors,
+                      systems::Systems};
+use crate::output::Format;
+
+#[derive(Parser)]
+#[clap(name = "bmcctl", version,
+       about = "Command-line client for Redfish services")]
+struct Cli {
+    /// Base URL of the Redfish service
+    #[clap(long, env = "BMCCTL_HOST", default_value = "http://127.0.0.1:3000",
+           global = true)]
+    host: String,
+
+    /// User name for HTTP Basic authentication
+    #[clap(short, long, env = "BMCCTL_USER", global = true)]
+    user: Option<String>,
+
+    /// Password for HTTP Basic authentication
+    #[clap(short, long, env = "BMCCTL_PASSWORD", hide_env_values = true,
+           global = true)]
+    password: Option<String>,
+
+    /// Output format
+    #[clap(short, long, value_enum, default_value_t = Format::Table,
+           global = true)]
+    output: Format,
+
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Inspect and control computer systems
+    #[clap(subcommand)]
+    Systems(Systems),
+
+    /// Inspect chassis
+    #[clap(subcommand)]
+    Chassis(Chassis),
+
+    /// List sensor readings
+    Sensors(Sensors),
+
+    /// Read log services
+    #[clap(subcommand)]
+    Logs(Logs),
+
+    /// Manage user accounts
+    #[clap(subcommand)]
+    Accounts(Accounts),
+
+    /// Issue raw requests
+    #[clap(subcommand)]
+    Raw(Raw),
+
+    /// Check conformance to Redfish Interoperability Profiles
+    #[clap(subcommand)]
+    Profile(Profiles),
+}
+
+async fn run(cli: Cli) -> Result<(), Error> {
+    let credentials = cli.user.as_deref()
+        .map(|user| (user, cli.password.as_deref().unwrap_or("")));
+    let client = Client::new(&cli.host, credentials)?;
+    let format = cli.output;
+    match cli.command {
+        Command::Systems(command) =>
+            commands::systems::run(&client, format, command).await,
+        Command::Chassis(command) =>
+            commands::chassis::run(&client, format, command).await,
+        Command::Sensors(command) =>
+            commands::sensors::run(&client, format, command).await,
+        Command::Logs(command) =>
+            commands::logs::run(&client, format, command).await,
+        Command::Accounts(command) =>
+            commands::accounts::run(&client, format, command).await,
+        Command::Raw(command) => commands::raw::run(&client, command).await,
+        Command::Profile(command) =>
+            commands::profile::run(&client, format, command).await,
+    }
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    match run(Cli::parse()).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("bmcctl: {}", error);
+            ExitCode::FAILURE
+        },
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,138 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            output.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Rendering of command results as tables or JSON.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::fmt;
+
+use clap::ValueEnum;
+use serde_json::Value;
+
+///////////////////////////////////////////////////////////////////////////////
+// Format
+////
+
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum Format {
+    Table,
+    Json,
+}
+
+pub fn print_json(value: &Value) {
+    println!("{}", serde_json::to_string_pretty(value).unwrap());
+}
+
+// Render an optional cell, using a dash for missing values.
+pub fn cell<T: fmt::Debug>(value: Option<T>) -> String {
+    value.map(|value| format!("{:?}", value))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+pub fn text(value: &Option<String>) -> String {
+    value.clone().unwrap_or_else(|| "-".to_string())
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Table
+////
+
+pub struct Table {
+    headers: Vec<&'static str>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(headers: Vec<&'static str>) -> Self {
+        Table { headers, rows: Vec::new() }
+    }
+
+    pub fn row(&mut self, row: Vec<String>) {
+        self.rows.push(row);
+    }
+
+    fn widths(&self) -> Vec<usize> {
+        let mut widths: Vec<usize> = self.headers.iter()
+            .map(|header| header.chars().count()).collect();
+        for row in &self.rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+        widths
+    }
+}
+
+fn write_row<T: AsRef<str>>(f: &mut fmt::Formatter<'_>, widths: &[usize],
+                            row: &[T]) -> fmt::Result
+{
+    let line = widths.iter().zip(row)
+        .map(|(width, cell)| {
+            format!("{:width$}", cell.as_ref(), width = width)
+        })
+        .collect::<Vec<String>>()
+        .join("  ");
+    writeln!(f, "{}", line.trim_end())
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let widths = self.widths();
+        write_row(f, &widths, &self.headers)?;
+        let rules: Vec<String> = widths.iter().map(|width| "-".repeat(*width))
+            .collect();
+        write_row(f, &widths, &rules)?;
+        for row in &self.rows {
+            write_row(f, &widths, row)?;
+        }
+        Ok(())
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Table Test
+////
+
+#[cfg(test)]
+mod tests {
+    use super::Table;
+
+    #[test]
+    fn columns_are_aligned() {
+        let mut table = Table::new(vec!["Id", "Name"]);
+        table.row(vec!["1".to_string(), "Host".to_string()]);
+        table.row(vec!["system-2".to_string(), "-".to_string()]);
+        assert_eq!(
+            "Id        Name\n--------  ----\n1         Host\nsystem-2  -\n",
+            table.to_string()
+        );
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,185 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            resources.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Client-side views of the Redfish resources bmcctl
+//                  understands.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::collections::HashMap;
+
+use librebmc::models::{PowerState, Status, SystemType};
+use odata::Link;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::client::{self, Client, Error};
+
+pub const SERVICE_ROOT: &'static str = "/redfish/v1";
+
+///////////////////////////////////////////////////////////////////////////////
+// ServiceRoot
+////
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ServiceRoot {
+    pub systems: Option<Link>,
+    pub chassis: Option<Link>,
+    pub managers: Option<Link>,
+    pub account_service: Option<Link>,
+}
+
+impl ServiceRoot {
+    pub async fn fetch(client: &Client) -> Result<Self, Error> {
+        client.get(SERVICE_ROOT).await
+    }
+
+    pub fn systems(&self) -> Result<String, Error> {
+        required(&self.systems, "Systems")
+    }
+
+    pub fn chassis(&self) -> Result<String, Error> {
+        required(&self.chassis, "Chassis")
+    }
+
+    pub fn managers(&self) -> Result<String, Error> {
+        required(&self.managers, "Managers")
+    }
+
+    pub fn account_service(&self) -> Result<String, Error> {
+        required(&self.account_service, "AccountService")
+    }
+}
+
+pub fn required(link: &Option<Link>, name: &'static str) ->
+    Result<String, Error>
+{
+    link.as_ref().map(client::link_path).ok_or(Error::MissingLink(name))
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// ComputerSystem
+////
+
+#[derive(Deserialize)]
+pub struct ActionTarget {
+    pub target: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ComputerSystem {
+    pub id: String,
+    pub name: Option<String>,
+    pub system_type: Option<SystemType>,
+    pub power_state: Option<PowerState>,
+    #[serde(default)]
+    pub status: Status,
+    #[serde(default)]
+    pub actions: HashMap<String, ActionTarget>,
+    pub log_services: Option<Link>,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Chassis
+////
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Chassis {
+    pub id: String,
+    pub name: Option<String>,
+    pub chassis_type: Option<String>,
+    pub manufacturer: Option<String>,
+    pub model: Option<String>,
+    pub serial_number: Option<String>,
+    #[serde(default)]
+    pub status: Status,
+    pub sensors: Option<Link>,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Sensor
+////
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Sensor {
+    pub id: String,
+    pub name: Option<String>,
+    pub reading: Option<f64>,
+    pub reading_units: Option<String>,
+    pub reading_type: Option<String>,
+    #[serde(default)]
+    pub status: Status,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Manager, LogService and LogEntry
+////
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Manager {
+    pub id: String,
+    pub log_services: Option<Link>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct LogService {
+    pub id: String,
+    pub entries: Option<Link>,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct LogEntry {
+    pub id: String,
+    pub created: Option<String>,
+    pub severity: Option<String>,
+    pub message_id: Option<String>,
+    pub message: Option<String>,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Conversions
+////
+
+// Convert raw members into their typed views, keeping the raw value around
+// for JSON output.
+pub fn typed<T>(members: Vec<Value>) -> Result<Vec<(T, Value)>, Error>
+where T: serde::de::DeserializeOwned
+{
+    members.into_iter()
+        .map(|member| Ok((serde_json::from_value(member.clone())?, member)))
+        .collect()
+}
+
+///////////////////////////////////////////////////////////////////////////////
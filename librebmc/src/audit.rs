@@ -0,0 +1,276 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            audit.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Records every request that changes the state of the
+//                  service, in the audit log and optionally in a file.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::fs::{File, OpenOptions};
+use std::io::{self, Write};
+use std::net::{IpAddr, SocketAddr};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use chrono::{DateTime, Utc};
+use hyper::body::HttpBody;
+use hyper::{Body, Method, Request, Uri};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::logs::LogStore;
+use crate::models::Message;
+use crate::registries::audit;
+use crate::service;
+
+// The address of the client that sent a request, which is inserted into the
+// extensions of each request by the server.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RemoteAddress(pub SocketAddr);
+
+// Larger bodies aren't read into memory to be recorded
+const MAX_RECORDED_BODY: u64 = 64 * 1024;
+
+// Requests with these methods are recorded
+pub fn is_audited(method: &Method) -> bool {
+    matches!(*method, Method::POST | Method::PATCH | Method::PUT
+             | Method::DELETE)
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Redaction
+////
+
+// Properties whose values are credentials, e.g. Password, NewPassword,
+// Token, or the HttpHeaders of an event subscription.
+fn is_secret(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    ["password", "passphrase", "token", "secret", "privatekey",
+     "httpheaders", "authorization"]
+        .iter()
+        .any(|secret| name.contains(secret))
+}
+
+// Replace the values of secret properties with null, which is how Redfish
+// represents a password when it's read.
+pub fn redact(value: &mut Value) {
+    match value {
+        Value::Object(properties) => {
+            for (name, value) in properties.iter_mut() {
+                match is_secret(name) {
+                    true => *value = Value::Null,
+                    false => redact(value),
+                }
+            }
+        },
+        Value::Array(values) => values.iter_mut().for_each(redact),
+        _ => {},
+    }
+}
+
+// The URI of a request, with the values of secret query parameters removed
+pub fn redact_uri(uri: &Uri) -> String {
+    let Some(query) = uri.query() else {
+        return uri.path().to_string();
+    };
+    let query: Vec<String> = query.split('&')
+        .map(|parameter| match parameter.split_once('=') {
+            Some((name, _)) if is_secret(&service::percent_decode(name)) =>
+                format!("{}=", name),
+            _ => parameter.to_string(),
+        })
+        .collect();
+    format!("{}?{}", uri.path(), query.join("&"))
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// AuditRecord
+////
+
+// The user named in the Authorization header of the request. librebmc does
+// not authenticate requests, so this is only the user the client claims to
+// be.
+pub fn user<T>(request: &Request<T>) -> String {
+    request.headers().get("Authorization")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Basic "))
+        .and_then(|credentials| STANDARD.decode(credentials.trim()).ok())
+        .and_then(|credentials| String::from_utf8(credentials).ok())
+        .and_then(|credentials| credentials.split_once(':')
+                  .map(|(user, _)| user.to_string()))
+        .unwrap_or_else(|| "anonymous".to_string())
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AuditRecord {
+    pub created: DateTime<Utc>,
+    pub user: String,
+    pub address: Option<IpAddr>,
+    pub method: String,
+    #[serde(rename = "URI")]
+    pub uri: String,
+    pub body: Option<Value>,
+    pub status: u16,
+}
+
+impl AuditRecord {
+    // Read the body of the request, so that it can be recorded, and return a
+    // request that's equivalent to the original. Bodies that aren't JSON
+    // objects can't be redacted, so they're not recorded. Only bodies that
+    // are declared to be JSON, and whose length is known to be within the
+    // limit, are read.
+    pub async fn new(request: Request<Body>) -> (Request<Body>, AuditRecord) {
+        let is_json = request.headers().get("Content-Type")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.split(';').next())
+            .is_some_and(|value| value.trim()
+                         .eq_ignore_ascii_case("application/json"));
+        let is_small = request.body().size_hint().upper()
+            .is_some_and(|length| length <= MAX_RECORDED_BODY);
+        let mut record = AuditRecord {
+            created: Utc::now(),
+            user: user(&request),
+            address: request.extensions().get::<RemoteAddress>()
+                .map(|address| address.0.ip()),
+            method: request.method().to_string(),
+            uri: redact_uri(request.uri()),
+            body: None,
+            status: 0,
+        };
+        if !is_json || !is_small {
+            return (request, record);
+        }
+
+        let (parts, body) = request.into_parts();
+        let body = hyper::body::to_bytes(body).await.unwrap_or_default();
+        if let Ok(mut value @ Value::Object(_)) = serde_json::from_slice(&body)
+        {
+            redact(&mut value);
+            record.body = Some(value);
+        }
+        (Request::from_parts(parts, Body::from(body)), record)
+    }
+
+    pub fn message(&self) -> Message {
+        let address = self.address
+            .map(|address| address.to_string())
+            .unwrap_or_else(|| "an unknown address".to_string());
+        let body = self.body.as_ref()
+            .map(|body| body.to_string())
+            .unwrap_or_else(|| "(none)".to_string());
+        audit::request_completed(&self.user, &address, &self.method,
+                                 &self.uri, &body, &self.status.to_string())
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// AuditLog
+////
+
+pub struct AuditLog {
+    store: Arc<LogStore>,
+    file: Option<Mutex<File>>,
+}
+
+impl AuditLog {
+    // Records are kept in the store, and appended to the file as JSON lines
+    pub fn open(store: Arc<LogStore>, file: Option<&Path>) ->
+        io::Result<Arc<Self>>
+    {
+        let file = match file {
+            Some(path) => Some(Mutex::new(
+                OpenOptions::new().create(true).append(true).open(path)?)),
+            None => None,
+        };
+        Ok(Arc::new(AuditLog { store, file }))
+    }
+
+    pub fn store(&self) -> &Arc<LogStore> { &self.store }
+
+    pub fn record(&self, record: &AuditRecord) -> io::Result<()> {
+        self.store.append(record.created, record.message(), None)?;
+        if let Some(file) = &self.file {
+            let mut line = serde_json::to_vec(record)?;
+            line.push(b'\n');
+            file.lock().unwrap().write_all(&line)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn secrets_are_redacted() {
+        let mut body = json!({
+            "UserName": "admin", "Password": "hunter2",
+            "Oem": {"Vendor": {"NewPassword": "hunter3", "Keep": 1}},
+            "HttpHeaders": [{"Authorization": "Bearer abc"}],
+            "Tokens": [{"Token": "abc"}],
+        });
+        redact(&mut body);
+        assert_eq!(json!({
+            "UserName": "admin", "Password": null,
+            "Oem": {"Vendor": {"NewPassword": null, "Keep": 1}},
+            "HttpHeaders": null, "Tokens": null,
+        }), body);
+    }
+
+    #[test]
+    fn secret_query_parameters_are_redacted() {
+        let uri = |uri: &str| redact_uri(&uri.parse().unwrap());
+        assert_eq!("/redfish/v1/Systems", uri("/redfish/v1/Systems"));
+        assert_eq!("/redfish/v1/Systems?$top=2&Password=&Token=",
+                   uri("/redfish/v1/Systems?$top=2&Password=x&Token=abc"));
+        assert_eq!("/redfish/v1?%70assword=", uri("/redfish/v1?%70assword=x"));
+    }
+
+    #[test]
+    fn user_is_read_from_basic_credentials() {
+        let request = |authorization: Option<&str>| {
+            let mut request = Request::builder();
+            if let Some(authorization) = authorization {
+                request = request.header("Authorization", authorization);
+            }
+            request.body(()).unwrap()
+        };
+        let credentials = STANDARD.encode("operator:secret");
+        assert_eq!("operator", user(&request(
+            Some(&format!("Basic {}", credentials)))));
+        assert_eq!("anonymous", user(&request(Some("Bearer abc"))));
+        assert_eq!("anonymous", user(&request(None)));
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,144 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            bus.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     In-process publish/subscribe bus for changes in the state
+//                  of resources. The event service, logs and other consumers
+//                  subscribe to it.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use chrono::{DateTime, Utc};
+use odata::Link;
+use tokio::sync::broadcast;
+
+use crate::events::EventType;
+use crate::models::Message;
+use crate::registries::resource_event;
+
+// The number of changes a consumer may fall behind by before it starts to
+// miss them.
+const CAPACITY: usize = 1024;
+
+///////////////////////////////////////////////////////////////////////////////
+// StateChange
+////
+
+// A change in the state of a resource, e.g. a property written by a client, a
+// sensor crossing a threshold, or a system powering on.
+#[derive(Clone)]
+pub struct StateChange {
+    pub event_type: EventType,
+    pub origin: Link,
+    // The @odata.type of the origin, if known
+    pub odata_type: Option<String>,
+    pub message: Message,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl StateChange {
+    pub fn new(event_type: EventType, origin: Link, odata_type: Option<&str>,
+               message: Message) -> Self
+    {
+        StateChange {
+            event_type, origin, odata_type: odata_type.map(str::to_string),
+            message, timestamp: Utc::now(),
+        }
+    }
+
+    pub fn resource_changed(origin: Link, odata_type: &str) -> Self {
+        Self::new(EventType::ResourceUpdated, origin, Some(odata_type),
+                  resource_event::resource_changed())
+    }
+
+    pub fn resource_created(origin: Link, odata_type: Option<&str>) -> Self {
+        Self::new(EventType::ResourceAdded, origin, odata_type,
+                  resource_event::resource_created())
+    }
+
+    pub fn resource_removed(origin: Link, odata_type: &str) -> Self {
+        Self::new(EventType::ResourceRemoved, origin, Some(odata_type),
+                  resource_event::resource_removed())
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// EventBus
+////
+
+#[derive(Clone)]
+pub struct EventBus(broadcast::Sender<StateChange>);
+
+impl EventBus {
+    pub fn new() -> Self {
+        EventBus(broadcast::channel(CAPACITY).0)
+    }
+
+    // Publishing never blocks, so changes may be published from threads
+    // outside of the runtime, like the sensor monitor.
+    pub fn publish(&self, change: StateChange) {
+        let _ = self.0.send(change);
+    }
+
+    // Receive every change published from now on. A consumer that lags too
+    // far behind receives RecvError::Lagged, and loses the oldest changes.
+    pub fn subscribe(&self) -> broadcast::Receiver<StateChange> {
+        self.0.subscribe()
+    }
+}
+
+impl Default for EventBus {
+    fn default() -> Self { Self::new() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn fan() -> Link {
+        PathBuf::from("/redfish/v1/Chassis/chassis/ThermalSubsystem/Fans/0")
+            .into()
+    }
+
+    #[test]
+    fn changes_are_received_by_every_subscriber() {
+        let bus = EventBus::new();
+        bus.publish(StateChange::resource_changed(fan(), "#Fan.v1_5_0.Fan"));
+        let (mut first, mut second) = (bus.subscribe(), bus.subscribe());
+        bus.publish(StateChange::resource_removed(fan(), "#Fan.v1_5_0.Fan"));
+        for receiver in [&mut first, &mut second] {
+            let change = receiver.try_recv().unwrap();
+            assert_eq!(EventType::ResourceRemoved, change.event_type);
+            assert_eq!("ResourceEvent.1.3.0.ResourceRemoved",
+                       change.message.message_id);
+            assert!(receiver.try_recv().is_err());
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,761 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            config.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Configuration of the librebmc service, read from a TOML
+//                  file.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::net::{IpAddr, Ipv4Addr};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use crate::hardware::block::{NVME, SYS_BLOCK};
+use crate::hardware::hwmon::HWMON_ROOT;
+use crate::hardware::inventory::SystemInventory;
+use crate::hardware::ipmitool::IPMITOOL;
+use crate::hardware::networkd::{
+    IP, NETWORK_DIRECTORY, NETWORKCTL, RESOLV_CONF, SYS_CLASS_NET,
+};
+use crate::hardware::smbios::DMI_TABLE;
+use crate::hardware::timesyncd::{SYSTEMCTL, TIMEDATECTL, TIMESYNCD_CONF};
+use crate::logs::OverWritePolicy;
+use crate::logs::journald::JOURNALCTL;
+
+///////////////////////////////////////////////////////////////////////////////
+// ConfigError
+////
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(io::Error),
+    Parse(toml::de::Error),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Io(error) => write!(f, "{}", error),
+            ConfigError::Parse(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for ConfigError {}
+
+impl From<io::Error> for ConfigError {
+    fn from(value: io::Error) -> Self { ConfigError::Io(value) }
+}
+
+impl From<toml::de::Error> for ConfigError {
+    fn from(value: toml::de::Error) -> Self { ConfigError::Parse(value) }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Sensors
+////
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "kebab-case", deny_unknown_fields)]
+pub enum SensorProviderConfig {
+    #[default]
+    Simulator,
+    Hwmon {
+        #[serde(default = "hwmon_root")]
+        root: PathBuf,
+    },
+}
+
+fn hwmon_root() -> PathBuf { PathBuf::from(HWMON_ROOT) }
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct SensorConfig {
+    pub provider: SensorProviderConfig,
+
+    // How often every sensor is sampled
+    pub poll_interval_ms: u64,
+}
+
+impl SensorConfig {
+    pub fn poll_interval(&self) -> Duration {
+        Duration::from_millis(self.poll_interval_ms)
+    }
+}
+
+impl Default for SensorConfig {
+    fn default() -> Self {
+        SensorConfig {
+            provider: SensorProviderConfig::default(),
+            poll_interval_ms: 1000,
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Chassis
+////
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "kebab-case", deny_unknown_fields)]
+pub enum PowerSupplyProviderConfig {
+    #[default]
+    Simulator,
+
+    // Supplies driven by PMBus drivers, which are found through hwmon
+    Hwmon {
+        #[serde(default = "hwmon_root")]
+        root: PathBuf,
+    },
+
+    // The BMC can't see the supplies of the chassis
+    None,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "kebab-case", deny_unknown_fields)]
+pub enum FanProviderConfig {
+    #[default]
+    Simulator,
+
+    // The tachometers of hwmon chips, and the PWM outputs that drive them
+    Hwmon {
+        #[serde(default = "hwmon_root")]
+        root: PathBuf,
+    },
+
+    // The BMC can't see the fans of the chassis
+    None,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct ChassisConfig {
+    pub power_supplies: PowerSupplyProviderConfig,
+    pub fans: FanProviderConfig,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Events
+////
+
+pub const SUBSCRIPTIONS_PATH: &'static str =
+    "/var/lib/librebmc/subscriptions.json";
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct EventConfig {
+    // Where event subscriptions are kept, so that they survive a restart
+    pub subscriptions: PathBuf,
+
+    // How many times delivery of an event is retried, and how long to wait
+    // between each attempt
+    pub delivery_retry_attempts: u32,
+    pub delivery_retry_interval_ms: u64,
+}
+
+impl EventConfig {
+    pub fn delivery_retry_interval(&self) -> Duration {
+        Duration::from_millis(self.delivery_retry_interval_ms)
+    }
+}
+
+impl Default for EventConfig {
+    fn default() -> Self {
+        EventConfig {
+            subscriptions: PathBuf::from(SUBSCRIPTIONS_PATH),
+            delivery_retry_attempts: 3,
+            delivery_retry_interval_ms: 30000,
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Logs
+////
+
+pub const LOG_DIRECTORY: &'static str = "/var/lib/librebmc/logs";
+
+// Where the log of the BMC's own services is read from, if anywhere
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "kebab-case", deny_unknown_fields)]
+pub enum JournalConfig {
+    #[default]
+    Disabled,
+    Journald {
+        #[serde(default = "journalctl")]
+        command: PathBuf,
+    },
+    Syslog {
+        path: PathBuf,
+    },
+}
+
+fn journalctl() -> PathBuf { PathBuf::from(JOURNALCTL) }
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct LogConfig {
+    // Each log service keeps its entries in a subdirectory of this one
+    pub directory: PathBuf,
+
+    // The capacity of each log, and what happens once it's reached
+    pub max_records: usize,
+    pub overwrite_policy: OverWritePolicy,
+
+    pub journal: JournalConfig,
+
+    // Mutating requests are recorded in the audit log, and in this file too
+    // if it's given, e.g. for collection by a remote syslog daemon.
+    pub audit_file: Option<PathBuf>,
+}
+
+impl Default for LogConfig {
+    fn default() -> Self {
+        LogConfig {
+            directory: PathBuf::from(LOG_DIRECTORY),
+            max_records: 1000,
+            overwrite_policy: OverWritePolicy::default(),
+            journal: JournalConfig::default(),
+            audit_file: None,
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Updates
+////
+
+pub const STAGING_DIRECTORY: &'static str = "/var/lib/librebmc/updates";
+pub const TRUSTED_KEYS_DIRECTORY: &'static str = "/etc/librebmc/trusted-keys";
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct UpdateConfig {
+    // Firmware images are written here until they're applied
+    pub staging: PathBuf,
+
+    // Images must be signed by one of the Ed25519 keys or certificates in
+    // the PEM files in this directory. If there are none, every image is
+    // refused.
+    pub trusted_keys: PathBuf,
+
+    // Whether images may contain older versions than are installed
+    pub allow_downgrade: bool,
+
+    // Larger images are refused while they're being received
+    pub max_image_size: u64,
+
+    // If given, librebmc supervises itself, running from one of two slots
+    // in this directory, into which updates of the BMC are installed.
+    pub slots: Option<PathBuf>,
+
+    // How long an updated BMC has to become healthy before it's rolled back
+    pub trial_timeout_ms: u64,
+
+    // Whatever updates the firmware of the other components of the platform
+    pub platform: PlatformUpdaterConfig,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "kebab-case", deny_unknown_fields)]
+pub enum PlatformUpdaterConfig {
+    #[default]
+    Simulator,
+
+    // Only the BMC can be updated, through its slots
+    None,
+}
+
+impl UpdateConfig {
+    pub fn trial_timeout(&self) -> Duration {
+        Duration::from_millis(self.trial_timeout_ms)
+    }
+}
+
+impl Default for UpdateConfig {
+    fn default() -> Self {
+        UpdateConfig {
+            staging: PathBuf::from(STAGING_DIRECTORY),
+            trusted_keys: PathBuf::from(TRUSTED_KEYS_DIRECTORY),
+            allow_downgrade: false,
+            max_image_size: 256 * 1024 * 1024,
+            slots: None,
+            trial_timeout_ms: 120000,
+            platform: PlatformUpdaterConfig::default(),
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Host
+////
+
+pub const HOST_SETTINGS_PATH: &'static str = "/var/lib/librebmc/host.json";
+
+// What controls the power and boot override of the host
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "kebab-case", deny_unknown_fields)]
+pub enum HostProviderConfig {
+    #[default]
+    Simulator,
+    Ipmitool {
+        #[serde(default = "ipmitool")]
+        command: PathBuf,
+
+        // Options that select the interface and host, e.g. -I lanplus
+        #[serde(default)]
+        arguments: Vec<String>,
+    },
+}
+
+fn ipmitool() -> PathBuf { PathBuf::from(IPMITOOL) }
+
+// Where the processors and memory of the host are described
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "kebab-case", deny_unknown_fields)]
+pub enum InventoryProviderConfig {
+    #[default]
+    Simulator,
+
+    // Given in the configuration, with the same fields as in a file
+    Static(Box<SystemInventory>),
+
+    // A JSON file, written by an agent on the host
+    File {
+        path: PathBuf,
+    },
+
+    // The SMBIOS table exported by the firmware, or uploaded by the host
+    Smbios {
+        #[serde(default = "dmi_table")]
+        path: PathBuf,
+    },
+}
+
+fn dmi_table() -> PathBuf { PathBuf::from(DMI_TABLE) }
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "kebab-case", deny_unknown_fields)]
+pub enum StorageProviderConfig {
+    #[default]
+    Simulator,
+
+    // The block devices in sysfs, and nvme-cli for NVMe controllers
+    Sysfs {
+        #[serde(default = "sys_block")]
+        root: PathBuf,
+        #[serde(default = "nvme")]
+        nvme: PathBuf,
+    },
+}
+
+fn sys_block() -> PathBuf { PathBuf::from(SYS_BLOCK) }
+fn nvme() -> PathBuf { PathBuf::from(NVME) }
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct HostConfig {
+    pub provider: HostProviderConfig,
+    pub inventory: InventoryProviderConfig,
+    pub storage: StorageProviderConfig,
+
+    // Where the power restore policy and delays of the host are kept
+    pub settings: PathBuf,
+}
+
+impl Default for HostConfig {
+    fn default() -> Self {
+        HostConfig {
+            provider: HostProviderConfig::default(),
+            inventory: InventoryProviderConfig::default(),
+            storage: StorageProviderConfig::default(),
+            settings: PathBuf::from(HOST_SETTINGS_PATH),
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Network
+////
+
+// What reports and configures the network interfaces of the BMC
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "kebab-case", deny_unknown_fields)]
+pub enum NetworkProviderConfig {
+    #[default]
+    Simulator,
+
+    // The interfaces in sysfs and iproute2, configured by systemd-networkd
+    Networkd {
+        #[serde(default = "sys_class_net")]
+        root: PathBuf,
+        #[serde(default = "ip")]
+        ip: PathBuf,
+
+        // Where the network files of each interface are written
+        #[serde(default = "network_directory")]
+        directory: PathBuf,
+        #[serde(default = "networkctl")]
+        networkctl: PathBuf,
+        #[serde(default = "resolv_conf")]
+        resolv_conf: PathBuf,
+    },
+}
+
+fn sys_class_net() -> PathBuf { PathBuf::from(SYS_CLASS_NET) }
+fn ip() -> PathBuf { PathBuf::from(IP) }
+fn network_directory() -> PathBuf { PathBuf::from(NETWORK_DIRECTORY) }
+fn networkctl() -> PathBuf { PathBuf::from(NETWORKCTL) }
+fn resolv_conf() -> PathBuf { PathBuf::from(RESOLV_CONF) }
+
+// What synchronizes the clock of the BMC
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "kebab-case", deny_unknown_fields)]
+pub enum TimeProviderConfig {
+    #[default]
+    Simulator,
+
+    Timesyncd {
+        // The drop-in where the NTP servers are written
+        #[serde(default = "timesyncd_conf")]
+        path: PathBuf,
+        #[serde(default = "timedatectl")]
+        timedatectl: PathBuf,
+        #[serde(default = "systemctl")]
+        systemctl: PathBuf,
+    },
+}
+
+fn timesyncd_conf() -> PathBuf { PathBuf::from(TIMESYNCD_CONF) }
+fn timedatectl() -> PathBuf { PathBuf::from(TIMEDATECTL) }
+fn systemctl() -> PathBuf { PathBuf::from(SYSTEMCTL) }
+
+pub const PROTOCOL_SETTINGS_PATH: &'static str =
+    "/var/lib/librebmc/protocols.json";
+
+// The Redfish service. These are only the defaults, which may be changed at
+// runtime by PATCH of the ManagerNetworkProtocol.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct HttpConfig {
+    pub enabled: bool,
+    pub address: IpAddr,
+    pub port: u16,
+}
+
+impl Default for HttpConfig {
+    fn default() -> Self {
+        HttpConfig {
+            enabled: true, address: Ipv4Addr::LOCALHOST.into(), port: 3000,
+        }
+    }
+}
+
+// Discovery of the Redfish service by SSDP, which always listens on every
+// IPv4 address of the BMC.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct SsdpConfig {
+    pub enabled: bool,
+    pub port: u16,
+}
+
+impl Default for SsdpConfig {
+    fn default() -> Self { SsdpConfig { enabled: false, port: 1900 } }
+}
+
+// A protocol served by some other daemon, e.g. sshd, which is described in
+// the ManagerNetworkProtocol but can't be changed by librebmc.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct ProtocolConfig {
+    #[serde(default = "enabled")]
+    pub enabled: bool,
+    pub port: u16,
+}
+
+fn enabled() -> bool { true }
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct NetworkConfig {
+    pub provider: NetworkProviderConfig,
+    pub time: TimeProviderConfig,
+    pub http: HttpConfig,
+    pub ssdp: SsdpConfig,
+
+    // Where changes made to the listeners at runtime are kept
+    pub settings: PathBuf,
+
+    pub https: Option<ProtocolConfig>,
+    pub ssh: Option<ProtocolConfig>,
+    pub ipmi: Option<ProtocolConfig>,
+    pub snmp: Option<ProtocolConfig>,
+}
+
+impl Default for NetworkConfig {
+    fn default() -> Self {
+        NetworkConfig {
+            provider: NetworkProviderConfig::default(),
+            time: TimeProviderConfig::default(),
+            http: HttpConfig::default(),
+            ssdp: SsdpConfig::default(),
+            settings: PathBuf::from(PROTOCOL_SETTINGS_PATH),
+            https: None,
+            ssh: None,
+            ipmi: None,
+            snmp: None,
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Config
+////
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct Config {
+    pub sensors: SensorConfig,
+    pub chassis: ChassisConfig,
+    pub events: EventConfig,
+    pub logs: LogConfig,
+    pub updates: UpdateConfig,
+    pub host: HostConfig,
+    pub network: NetworkConfig,
+}
+
+impl Config {
+    pub fn load(path: &Path) -> Result<Config, ConfigError> {
+        Ok(toml::from_str(&fs::read_to_string(path)?)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hardware::network::{Ipv4AddressOrigin, LinkStatus};
+
+    #[test]
+    fn empty_config_is_default() {
+        assert_eq!(Config::default(), toml::from_str::<Config>("").unwrap());
+    }
+
+    #[test]
+    fn sensor_provider() {
+        let config: Config = toml::from_str(r#"
+            [sensors]
+            poll-interval-ms = 250
+            provider = { type = "hwmon" }
+        "#).unwrap();
+        assert_eq!(Duration::from_millis(250), config.sensors.poll_interval());
+        assert_eq!(SensorProviderConfig::Hwmon { root: hwmon_root() },
+                   config.sensors.provider);
+
+        assert!(toml::from_str::<Config>(r#"
+            [sensors]
+            poll-interval = 250
+        "#).is_err());
+    }
+
+    #[test]
+    fn power_supply_provider() {
+        let config: Config = toml::from_str(r#"
+            [chassis]
+            power-supplies = { type = "hwmon" }
+        "#).unwrap();
+        assert_eq!(PowerSupplyProviderConfig::Hwmon { root: hwmon_root() },
+                   config.chassis.power_supplies);
+
+        let config: Config = toml::from_str(r#"
+            [chassis]
+            power-supplies = { type = "none" }
+        "#).unwrap();
+        assert_eq!(PowerSupplyProviderConfig::None,
+                   config.chassis.power_supplies);
+    }
+
+    #[test]
+    fn fan_provider() {
+        let config: Config = toml::from_str(r#"
+            [chassis]
+            fans = { type = "hwmon", root = "/tmp/hwmon" }
+        "#).unwrap();
+        assert_eq!(FanProviderConfig::Hwmon {
+            root: PathBuf::from("/tmp/hwmon"),
+        }, config.chassis.fans);
+        assert_eq!(PowerSupplyProviderConfig::Simulator,
+                   config.chassis.power_supplies);
+    }
+
+    #[test]
+    fn journal() {
+        let config: Config = toml::from_str(r#"
+            [logs]
+            journal = { type = "journald" }
+        "#).unwrap();
+        assert_eq!(JournalConfig::Journald { command: journalctl() },
+                   config.logs.journal);
+
+        assert!(toml::from_str::<Config>(r#"
+            [logs]
+            journal = { type = "syslog" }
+        "#).is_err());
+    }
+
+    #[test]
+    fn platform_updater() {
+        let config: Config = toml::from_str(r#"
+            [updates]
+            slots = "/var/lib/librebmc/slots"
+            platform = { type = "none" }
+        "#).unwrap();
+        assert_eq!(PlatformUpdaterConfig::None, config.updates.platform);
+        assert_eq!(Some(PathBuf::from("/var/lib/librebmc/slots")),
+                   config.updates.slots);
+    }
+
+    #[test]
+    fn host_provider() {
+        let config: Config = toml::from_str(r#"
+            [host]
+            provider = { type = "ipmitool", arguments = ["-I", "lanplus"] }
+        "#).unwrap();
+        assert_eq!(HostProviderConfig::Ipmitool {
+            command: ipmitool(),
+            arguments: vec!["-I".to_string(), "lanplus".to_string()],
+        }, config.host.provider);
+    }
+
+    #[test]
+    fn storage_provider() {
+        let config: Config = toml::from_str(r#"
+            [host]
+            storage = { type = "sysfs", nvme = "/usr/bin/nvme" }
+        "#).unwrap();
+        assert_eq!(StorageProviderConfig::Sysfs {
+            root: sys_block(),
+            nvme: PathBuf::from("/usr/bin/nvme"),
+        }, config.host.storage);
+    }
+
+    #[test]
+    fn network_provider() {
+        let config: Config = toml::from_str(r#"
+            [network]
+            provider = { type = "networkd", ip = "/sbin/ip" }
+        "#).unwrap();
+        assert_eq!(NetworkProviderConfig::Networkd {
+            root: sys_class_net(),
+            ip: PathBuf::from("/sbin/ip"),
+            directory: network_directory(),
+            networkctl: networkctl(),
+            resolv_conf: resolv_conf(),
+        }, config.network.provider);
+    }
+
+    #[test]
+    fn protocols() {
+        let config: Config = toml::from_str(r#"
+            [network]
+            time = { type = "timesyncd" }
+            http = { address = "0.0.0.0" }
+            ssh = { port = 22 }
+            ipmi = { enabled = false, port = 623 }
+        "#).unwrap();
+        assert_eq!(TimeProviderConfig::Timesyncd {
+            path: timesyncd_conf(),
+            timedatectl: timedatectl(),
+            systemctl: systemctl(),
+        }, config.network.time);
+        assert_eq!(HttpConfig {
+            enabled: true, address: Ipv4Addr::UNSPECIFIED.into(), port: 3000,
+        }, config.network.http);
+        assert_eq!(Some(ProtocolConfig { enabled: true, port: 22 }),
+                   config.network.ssh);
+        assert_eq!(Some(ProtocolConfig { enabled: false, port: 623 }),
+                   config.network.ipmi);
+        assert_eq!(None, config.network.snmp);
+
+        assert!(toml::from_str::<Config>(r#"
+            [network]
+            snmp = { enabled = true }
+        "#).is_err());
+    }
+
+    #[test]
+    fn inventory_providers() {
+        let config: Config = toml::from_str(r#"
+            [host.inventory]
+            type = "static"
+
+            [[host.inventory.processors]]
+            id = "0"
+            processor-architecture = "x86"
+            total-cores = 8
+
+            [[host.inventory.memory]]
+            id = "DIMM0"
+            capacity-mib = 8192
+
+            [[host.inventory.ethernet-interfaces]]
+            id = "eno1"
+            link-status = "LinkUp"
+            ipv4-addresses = [
+                { address = "10.0.0.5", subnet-mask = "255.255.255.0" },
+            ]
+        "#).unwrap();
+        let InventoryProviderConfig::Static(inventory) = config.host.inventory
+        else {
+            panic!("inventory is not static");
+        };
+        assert_eq!(Some(8), inventory.processors[0].total_cores);
+        assert_eq!(Some(8192), inventory.memory[0].capacity_mib);
+        let interface = &inventory.ethernet_interfaces[0];
+        assert_eq!(Some(LinkStatus::LinkUp), interface.link_status);
+        assert_eq!(Ipv4AddressOrigin::Static,
+                   interface.ipv4_addresses[0].origin);
+
+        let config: Config = toml::from_str(r#"
+            [host.inventory]
+            type = "smbios"
+        "#).unwrap();
+        assert_eq!(InventoryProviderConfig::Smbios {
+            path: PathBuf::from(DMI_TABLE),
+        }, config.host.inventory);
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
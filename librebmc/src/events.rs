@@ -0,0 +1,689 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            events.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Event subscriptions, and delivery of events to their
+//                  destinations.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::collections::{BTreeMap, VecDeque};
+use std::fs;
+use std::io;
+use std::sync::{Arc, Mutex, Weak};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use hyper::{Body, Client, Request, Uri};
+use hyper::body::Bytes;
+use hyper::client::HttpConnector;
+use serde::{Deserialize, Serialize};
+use tokio::sync::{broadcast, mpsc};
+
+use crate::bus::{EventBus, StateChange};
+use crate::config::EventConfig;
+use crate::models::Message;
+
+pub mod filter;
+use filter::Filter;
+
+// Registries whose messages are sent as events
+pub const REGISTRY_PREFIXES: &[&str] = &["Base", "ResourceEvent"];
+
+const DELIVERY_TIMEOUT: Duration = Duration::from_secs(10);
+const MAXIMUM_BACKOFF: Duration = Duration::from_secs(3600);
+
+// The number of recent events kept, so that clients of the event stream can
+// resume after a dropped connection.
+const HISTORY_LENGTH: usize = 256;
+const KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(30);
+
+// The number of events queued for a subscriber. Once its destination has
+// fallen this far behind, the subscription is suspended until it's resumed.
+const QUEUE_LENGTH: usize = 256;
+
+// Event ids are reserved this many at a time, so that they continue after a
+// restart without the broker's state being saved for every event.
+const EVENT_ID_BLOCK: u64 = 1024;
+
+///////////////////////////////////////////////////////////////////////////////
+// EventRecord
+////
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub enum EventType {
+    StatusChange,
+    ResourceUpdated,
+    ResourceAdded,
+    ResourceRemoved,
+    Alert,
+    MetricReport,
+    Other,
+}
+
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct EventRecord {
+    member_id: String,
+    pub event_type: EventType,
+    pub event_id: String,
+    pub event_timestamp: DateTime<Utc>,
+
+    #[serde(flatten)]
+    pub message: Message,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub origin_of_condition: Option<odata::Link>,
+
+    // The type of the origin, e.g. Chassis, for subscriptions that filter on
+    // ResourceTypes.
+    #[serde(skip)]
+    pub resource_type: Option<String>,
+}
+
+impl EventRecord {
+    pub fn new(event_type: EventType, message: Message) -> Self {
+        EventRecord {
+            member_id: String::new(), event_type, event_id: String::new(),
+            event_timestamp: Utc::now(), message, origin_of_condition: None,
+            resource_type: None,
+        }
+    }
+
+    // The resource that caused the event, and its @odata.type, if known.
+    pub fn with_origin(mut self, origin: odata::Link, odata_type: Option<&str>)
+        -> Self
+    {
+        self.origin_of_condition = Some(origin);
+        self.resource_type = odata_type
+            .and_then(|odata_type| odata_type.strip_prefix('#'))
+            .and_then(|odata_type| odata_type.split('.').next())
+            .map(str::to_string);
+        self
+    }
+
+    // The registry that defines the message, e.g. Base
+    fn registry_prefix(&self) -> &str {
+        self.message.message_id.split('.').next().unwrap_or_default()
+    }
+}
+
+impl From<StateChange> for EventRecord {
+    fn from(change: StateChange) -> Self {
+        let record = EventRecord {
+            event_timestamp: change.timestamp,
+            ..EventRecord::new(change.event_type, change.message)
+        };
+        record.with_origin(change.origin, change.odata_type.as_deref())
+    }
+}
+
+// The payload delivered to a subscriber
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct Event<'a> {
+    #[serde(rename = "@odata.type")]
+    odata_type: &'static str,
+    id: &'a str,
+    name: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    context: Option<&'a str>,
+    events: Vec<EventRecord>,
+}
+
+impl<'a> Event<'a> {
+    fn new(context: Option<&'a str>, record: &'a EventRecord) -> Self {
+        let member = EventRecord {
+            member_id: "0".to_string(), ..record.clone()
+        };
+        Event {
+            odata_type: "#Event.v1_7_0.Event", id: &record.event_id,
+            name: "Event", context, events: vec![member],
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Subscription
+////
+
+// What to do with a subscription once delivery of an event has failed
+// DeliveryRetryAttempts times.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub enum DeliveryRetryPolicy {
+    #[default]
+    TerminateAfterRetries,
+    SuspendRetries,
+    RetryForever,
+    RetryForeverWithBackoff,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct Subscription {
+    pub id: String,
+    pub destination: String,
+    pub context: Option<String>,
+    pub event_types: Vec<EventType>,
+    pub registry_prefixes: Vec<String>,
+    pub resource_types: Vec<String>,
+    pub delivery_retry_policy: DeliveryRetryPolicy,
+    pub suspended: bool,
+}
+
+impl Subscription {
+    // True if the record passes every filter of this subscription. Empty
+    // filters match every event.
+    pub fn matches(&self, record: &EventRecord) -> bool {
+        (self.event_types.is_empty()
+         || self.event_types.contains(&record.event_type))
+            && (self.registry_prefixes.is_empty()
+                || self.registry_prefixes.iter()
+                .any(|prefix| prefix == record.registry_prefix()))
+            && (self.resource_types.is_empty()
+                || record.resource_type.as_ref().is_some_and(|resource_type| {
+                    self.resource_types.contains(resource_type)
+                }))
+    }
+}
+
+// Events are pushed to destinations with an HTTP POST. TLS is not supported
+// yet.
+pub fn valid_destination(destination: &str) -> bool {
+    destination.parse::<Uri>().is_ok_and(|uri| {
+        uri.scheme_str() == Some("http") && uri.authority().is_some()
+    })
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// EventBroker
+////
+
+struct Subscriber {
+    subscription: Subscription,
+    queue: Option<mpsc::Sender<EventRecord>>,
+}
+
+// What's kept of the broker across restarts. Ids aren't reused, so that an
+// old id can't refer to a new subscription or event.
+#[derive(Default, Deserialize, Serialize)]
+#[serde(default, rename_all = "PascalCase")]
+struct SavedState {
+    next_subscription_id: u64,
+    reserved_event_ids: u64,
+    subscriptions: Vec<Subscription>,
+}
+
+// Keeps the subscriptions of the event service, and delivers each event to
+// the subscribers interested in it. Every subscriber has its own queue, so
+// that an unreachable destination doesn't delay delivery to the others.
+pub struct EventBroker {
+    config: EventConfig,
+    subscribers: Mutex<BTreeMap<u64, Subscriber>>,
+    next_subscription_id: AtomicU64,
+    next_event_id: AtomicU64,
+    reserved_event_ids: AtomicU64,
+    client: Client<HttpConnector>,
+    history: Mutex<VecDeque<EventRecord>>,
+    stream: broadcast::Sender<EventRecord>,
+}
+
+fn key(id: &str) -> Option<u64> { id.parse().ok() }
+
+impl EventBroker {
+    pub fn new(config: &EventConfig) -> io::Result<Arc<Self>> {
+        let state: SavedState = match fs::read(&config.subscriptions) {
+            Ok(contents) => serde_json::from_slice(&contents)
+                .map_err(|error| io::Error::new(
+                    io::ErrorKind::InvalidData, error))?,
+            Err(error) if error.kind() == io::ErrorKind::NotFound =>
+                SavedState::default(),
+            Err(error) => return Err(error),
+        };
+        let subscribers: BTreeMap<u64, Subscriber> = state.subscriptions
+            .into_iter()
+            .filter_map(|subscription| Some((key(&subscription.id)?,
+                                             Subscriber {
+                                                 subscription, queue: None,
+                                             })))
+            .collect();
+        let next_subscription_id = subscribers.keys().next_back()
+            .map_or(1, |key| key + 1)
+            .max(state.next_subscription_id);
+        Ok(Arc::new(EventBroker {
+            config: config.clone(),
+            subscribers: Mutex::new(subscribers),
+            next_subscription_id: AtomicU64::new(next_subscription_id),
+            next_event_id: AtomicU64::new(state.reserved_event_ids.max(1)),
+            reserved_event_ids: AtomicU64::new(state.reserved_event_ids),
+            client: Client::new(),
+            history: Mutex::new(VecDeque::with_capacity(HISTORY_LENGTH)),
+            stream: broadcast::channel(HISTORY_LENGTH).0,
+        }))
+    }
+
+    pub fn delivery_retry_attempts(&self) -> u32 {
+        self.config.delivery_retry_attempts
+    }
+
+    pub fn delivery_retry_interval(&self) -> Duration {
+        self.config.delivery_retry_interval()
+    }
+
+    pub fn subscriptions(&self) -> Vec<Subscription> {
+        self.subscribers.lock().unwrap().values()
+            .map(|subscriber| subscriber.subscription.clone())
+            .collect()
+    }
+
+    pub fn subscription(&self, id: &str) -> Option<Subscription> {
+        self.subscribers.lock().unwrap().get(&key(id)?)
+            .map(|subscriber| subscriber.subscription.clone())
+    }
+
+    // Add a subscription, returning its id
+    pub fn subscribe(&self, mut subscription: Subscription) ->
+        io::Result<String>
+    {
+        let mut subscribers = self.subscribers.lock().unwrap();
+        let key = self.next_subscription_id.fetch_add(1, Ordering::SeqCst);
+        subscription.id = key.to_string();
+        subscribers.insert(key, Subscriber { subscription, queue: None });
+        self.save(&subscribers)?;
+        Ok(key.to_string())
+    }
+
+    // Modify a subscription, returning false if it doesn't exist
+    pub fn update<F>(&self, id: &str, update: F) -> io::Result<bool>
+    where F: FnOnce(&mut Subscription)
+    {
+        let mut subscribers = self.subscribers.lock().unwrap();
+        let Some(subscriber) = key(id)
+            .and_then(|key| subscribers.get_mut(&key)) else {
+            return Ok(false);
+        };
+        update(&mut subscriber.subscription);
+        if subscriber.subscription.suspended {
+            // Events are discarded while a subscription is suspended
+            subscriber.queue = None;
+        }
+        self.save(&subscribers)?;
+        Ok(true)
+    }
+
+    pub fn unsubscribe(&self, id: &str) -> io::Result<bool> {
+        let mut subscribers = self.subscribers.lock().unwrap();
+        if key(id).and_then(|key| subscribers.remove(&key)).is_none() {
+            return Ok(false);
+        }
+        self.save(&subscribers)?;
+        Ok(true)
+    }
+
+    // Queue an event for delivery to every interested subscriber, under the
+    // next event id. Must be called from within the tokio runtime.
+    pub fn publish(self: &Arc<Self>, mut record: EventRecord) {
+        record.event_id = self.next_event_id().to_string();
+
+        {
+            // Listeners take a snapshot of the history and subscribe to the
+            // stream under this lock, so they never miss or repeat an event.
+            let mut history = self.history.lock().unwrap();
+            if history.len() == HISTORY_LENGTH {
+                history.pop_front();
+            }
+            history.push_back(record.clone());
+            let _ = self.stream.send(record.clone());
+        }
+
+        let mut subscribers = self.subscribers.lock().unwrap();
+        let mut suspended = false;
+        for (key, subscriber) in subscribers.iter_mut() {
+            if subscriber.subscription.suspended
+                || !subscriber.subscription.matches(&record) {
+                continue;
+            }
+            let queue = subscriber.queue.get_or_insert_with(|| {
+                let (sender, receiver) = mpsc::channel(QUEUE_LENGTH);
+                tokio::spawn(deliver(Arc::downgrade(self), *key, receiver));
+                sender
+            });
+            if let Err(mpsc::error::TrySendError::Full(_)) =
+                queue.try_send(record.clone()) {
+                subscriber.subscription.suspended = true;
+                subscriber.queue = None;
+                suspended = true;
+            }
+        }
+        if suspended {
+            if let Err(error) = self.save(&subscribers) {
+                eprintln!("failed to save subscriptions: {}", error);
+            }
+        }
+    }
+
+    // Publish every change on the bus as an event. Must be called from within
+    // the tokio runtime.
+    pub fn listen(self: &Arc<Self>, bus: &EventBus) {
+        let broker = Arc::downgrade(self);
+        let mut changes = bus.subscribe();
+        tokio::spawn(async move {
+            loop {
+                let change = match changes.recv().await {
+                    Ok(change) => change,
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => return,
+                };
+                match broker.upgrade() {
+                    Some(broker) => broker.publish(change.into()),
+                    None => return,
+                }
+            }
+        });
+    }
+
+    // A text/event-stream of the events that pass the filter. Events after
+    // last_event_id that are still in the history are sent first.
+    pub fn stream(&self, filter: Filter, last_event_id: Option<u64>) -> Body {
+        let (replay, mut events) = {
+            let history = self.history.lock().unwrap();
+            let replay: Vec<EventRecord> = match last_event_id {
+                Some(last) => history.iter()
+                    .filter(|record| record.event_id.parse::<u64>()
+                            .is_ok_and(|id| id > last))
+                    .cloned()
+                    .collect(),
+                None => Vec::new(),
+            };
+            (replay, self.stream.subscribe())
+        };
+
+        let (mut sender, body) = Body::channel();
+        tokio::spawn(async move {
+            let replay = replay.iter().filter(|record| filter.matches(record));
+            for record in replay {
+                if sender.send_data(server_sent_event(record)).await.is_err() {
+                    return;
+                }
+            }
+            let mut keep_alive = tokio::time::interval(KEEP_ALIVE_INTERVAL);
+            keep_alive.tick().await;
+            loop {
+                let data = tokio::select! {
+                    // A client that falls too far behind is disconnected, and
+                    // may resume from the last event it received.
+                    record = events.recv() => match record {
+                        Ok(record) if filter.matches(&record) =>
+                            server_sent_event(&record),
+                        Ok(_) => continue,
+                        Err(_) => return,
+                    },
+                    _ = keep_alive.tick() => Bytes::from(": keep-alive\n\n"),
+                };
+                if sender.send_data(data).await.is_err() {
+                    return;
+                }
+            }
+        });
+        body
+    }
+
+    fn next_event_id(&self) -> u64 {
+        let id = self.next_event_id.fetch_add(1, Ordering::SeqCst);
+        if id < self.reserved_event_ids.load(Ordering::SeqCst) {
+            return id;
+        }
+        let subscribers = self.subscribers.lock().unwrap();
+        let reserved = self.reserved_event_ids
+            .fetch_max(id + EVENT_ID_BLOCK, Ordering::SeqCst);
+        if id >= reserved {
+            if let Err(error) = self.save(&subscribers) {
+                eprintln!("failed to reserve event ids: {}", error);
+            }
+        }
+        id
+    }
+
+    fn save(&self, subscribers: &BTreeMap<u64, Subscriber>) -> io::Result<()> {
+        let state = SavedState {
+            next_subscription_id: self.next_subscription_id
+                .load(Ordering::SeqCst),
+            reserved_event_ids: self.reserved_event_ids.load(Ordering::SeqCst),
+            subscriptions: subscribers.values()
+                .map(|subscriber| subscriber.subscription.clone())
+                .collect(),
+        };
+        let path = &self.config.subscriptions;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        // Replace the file atomically, so a crash can't lose subscriptions
+        let temporary = path.with_extension("tmp");
+        fs::write(&temporary, serde_json::to_vec_pretty(&state)?)?;
+        fs::rename(&temporary, path)
+    }
+
+    async fn post(&self, subscription: &Subscription, record: &EventRecord) ->
+        bool
+    {
+        let event = Event::new(subscription.context.as_deref(), record);
+        let request = Request::post(subscription.destination.as_str())
+            .header("Content-Type", "application/json")
+            .body(Body::from(serde_json::to_vec(&event).unwrap()));
+        let Ok(request) = request else { return false; };
+        match tokio::time::timeout(
+            DELIVERY_TIMEOUT, self.client.request(request)).await {
+            Ok(Ok(response)) => response.status().is_success(),
+            _ => false,
+        }
+    }
+}
+
+fn server_sent_event(record: &EventRecord) -> Bytes {
+    let event = Event::new(None, record);
+    Bytes::from(format!("id: {}\ndata: {}\n\n", record.event_id,
+                        serde_json::to_string(&event).unwrap()))
+}
+
+// Deliver the events queued for a subscriber, in order, until the
+// subscription is removed or suspended.
+async fn deliver(
+    broker: Weak<EventBroker>, key: u64,
+    mut queue: mpsc::Receiver<EventRecord>,
+) {
+    while let Some(record) = queue.recv().await {
+        let mut failures = 0;
+        loop {
+            let Some(broker) = broker.upgrade() else { return; };
+            let id = key.to_string();
+            let subscription = match broker.subscription(&id) {
+                Some(subscription) if !subscription.suspended => subscription,
+                _ => return,
+            };
+            if broker.post(&subscription, &record).await {
+                break;
+            }
+
+            failures += 1;
+            let exhausted = failures > broker.delivery_retry_attempts();
+            let mut interval = broker.delivery_retry_interval();
+            match subscription.delivery_retry_policy {
+                DeliveryRetryPolicy::TerminateAfterRetries if exhausted => {
+                    let _ = broker.unsubscribe(&id);
+                    return;
+                },
+                DeliveryRetryPolicy::SuspendRetries if exhausted => {
+                    let _ = broker.update(&id, |subscription| {
+                        subscription.suspended = true;
+                    });
+                    return;
+                },
+                DeliveryRetryPolicy::RetryForeverWithBackoff => {
+                    interval = interval
+                        .saturating_mul(1 << (failures - 1).min(16))
+                        .min(MAXIMUM_BACKOFF);
+                },
+                _ => {},
+            }
+            drop(broker);
+            tokio::time::sleep(interval).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registries::base;
+
+    #[test]
+    fn filters() {
+        let record = EventRecord::new(EventType::Alert, base::general_error())
+            .with_origin(std::path::PathBuf::from(
+                "/redfish/v1/Chassis/chassis").into(),
+                         Some("#Chassis.v1_20_0.Chassis"));
+        assert!(Subscription::default().matches(&record));
+
+        let subscription = Subscription {
+            event_types: vec![EventType::Alert],
+            registry_prefixes: vec!["Base".to_string()],
+            resource_types: vec!["Chassis".to_string()],
+            ..Default::default()
+        };
+        assert!(subscription.matches(&record));
+        assert!(!Subscription {
+            event_types: vec![EventType::StatusChange], ..subscription.clone()
+        }.matches(&record));
+        assert!(!Subscription {
+            registry_prefixes: vec!["ResourceEvent".to_string()],
+            ..subscription.clone()
+        }.matches(&record));
+        assert!(!Subscription {
+            resource_types: vec!["Fan".to_string()], ..subscription.clone()
+        }.matches(&EventRecord::new(
+            EventType::Alert, base::general_error())));
+    }
+
+    #[test]
+    fn destinations() {
+        assert!(valid_destination("http://192.0.2.1:8080/events"));
+        assert!(!valid_destination("https://192.0.2.1/events"));
+        assert!(!valid_destination("/events"));
+        assert!(!valid_destination("not a uri"));
+    }
+
+    #[test]
+    fn subscriptions_are_persisted() {
+        let directory = tempfile::tempdir().unwrap();
+        let config = EventConfig {
+            subscriptions: directory.path().join("state/subscriptions.json"),
+            ..Default::default()
+        };
+        let broker = EventBroker::new(&config).unwrap();
+        let first = broker.subscribe(Subscription {
+            destination: "http://192.0.2.1/".to_string(), ..Default::default()
+        }).unwrap();
+        let second = broker.subscribe(Subscription {
+            destination: "http://192.0.2.2/".to_string(),
+            context: Some("second".to_string()), ..Default::default()
+        }).unwrap();
+        assert!(broker.unsubscribe(&first).unwrap());
+        assert!(!broker.unsubscribe(&first).unwrap());
+        drop(broker);
+
+        let broker = EventBroker::new(&config).unwrap();
+        let subscriptions = broker.subscriptions();
+        assert_eq!(1, subscriptions.len());
+        assert_eq!(second, subscriptions[0].id);
+        assert_eq!(Some("second"), subscriptions[0].context.as_deref());
+        // New subscriptions are numbered after the last one
+        assert_eq!("3", broker.subscribe(Subscription::default()).unwrap());
+
+        // Ids aren't reused, even once every subscription has been removed
+        assert!(broker.unsubscribe(&second).unwrap());
+        assert!(broker.unsubscribe("3").unwrap());
+        drop(broker);
+        let broker = EventBroker::new(&config).unwrap();
+        assert!(broker.subscriptions().is_empty());
+        assert_eq!("4", broker.subscribe(Subscription::default()).unwrap());
+    }
+
+    #[tokio::test]
+    async fn subscribers_that_fall_behind_are_suspended() {
+        let directory = tempfile::tempdir().unwrap();
+        let config = EventConfig {
+            subscriptions: directory.path().join("subscriptions.json"),
+            ..Default::default()
+        };
+        let broker = EventBroker::new(&config).unwrap();
+        let id = broker.subscribe(Subscription {
+            destination: "http://192.0.2.1/".to_string(), ..Default::default()
+        }).unwrap();
+
+        // Nothing is delivered until this task yields to the runtime
+        for _ in 0..QUEUE_LENGTH {
+            broker.publish(EventRecord::new(
+                EventType::Alert, base::general_error()));
+        }
+        assert!(!broker.subscription(&id).unwrap().suspended);
+        broker.publish(EventRecord::new(
+            EventType::Alert, base::general_error()));
+        assert!(broker.subscription(&id).unwrap().suspended);
+        drop(broker);
+        let broker = EventBroker::new(&config).unwrap();
+        assert!(broker.subscription(&id).unwrap().suspended);
+    }
+
+    #[tokio::test]
+    async fn event_ids_continue_after_a_restart() {
+        let directory = tempfile::tempdir().unwrap();
+        let config = EventConfig {
+            subscriptions: directory.path().join("subscriptions.json"),
+            ..Default::default()
+        };
+        let publish = |broker: &Arc<EventBroker>| {
+            broker.publish(EventRecord::new(
+                EventType::Alert, base::general_error()));
+            let history = broker.history.lock().unwrap();
+            history.back().unwrap().event_id.parse::<u64>().unwrap()
+        };
+        let broker = EventBroker::new(&config).unwrap();
+        assert_eq!(1, publish(&broker));
+        assert_eq!(2, publish(&broker));
+        drop(broker);
+
+        let broker = EventBroker::new(&config).unwrap();
+        let id = publish(&broker);
+        assert!(id > 2);
+        drop(broker);
+        let broker = EventBroker::new(&config).unwrap();
+        assert!(publish(&broker) > id);
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,346 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            filter.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Parser for the $filter query parameter of the Server-Sent
+//                  Events stream, e.g. EventType eq 'Alert' and RegistryPrefix
+//                  eq 'Base'.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::fmt;
+use std::iter::Peekable;
+use std::str::Chars;
+
+use crate::events::EventRecord;
+
+// Filters are given by clients, so they're limited before they're parsed
+const MAX_LENGTH: usize = 2048;
+const MAX_DEPTH: usize = 32;
+
+///////////////////////////////////////////////////////////////////////////////
+// Tokens
+////
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Identifier(String),
+    Literal(String),
+    Open,
+    Close,
+}
+
+fn literal(characters: &mut Peekable<Chars>) -> Result<String, FilterError> {
+    let mut value = String::new();
+    loop {
+        match characters.next() {
+            // Quotes within a literal are escaped by doubling them
+            Some('\'') if characters.peek() == Some(&'\'') => {
+                characters.next();
+                value.push('\'');
+            },
+            Some('\'') => return Ok(value),
+            Some(character) => value.push(character),
+            None => return Err(FilterError("unterminated string".to_string())),
+        }
+    }
+}
+
+fn tokenize(filter: &str) -> Result<Vec<Token>, FilterError> {
+    let mut tokens = Vec::new();
+    let mut characters = filter.chars().peekable();
+    while let Some(character) = characters.next() {
+        match character {
+            '(' => tokens.push(Token::Open),
+            ')' => tokens.push(Token::Close),
+            '\'' => tokens.push(Token::Literal(literal(&mut characters)?)),
+            character if character.is_whitespace() => {},
+            character if character.is_alphanumeric() => {
+                let mut identifier = character.to_string();
+                while let Some(character) = characters.peek()
+                    .filter(|character| character.is_alphanumeric()) {
+                    identifier.push(*character);
+                    characters.next();
+                }
+                tokens.push(Token::Identifier(identifier));
+            },
+            character => return Err(FilterError(
+                format!("unexpected character '{}'", character))),
+        }
+    }
+    Ok(tokens)
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Filter
+////
+
+#[derive(Debug, PartialEq)]
+pub struct FilterError(String);
+
+impl fmt::Display for FilterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+// The properties of an event that can be filtered on
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Property {
+    EventType,
+    MessageId,
+    OriginResource,
+    RegistryPrefix,
+    ResourceType,
+}
+
+impl Property {
+    fn parse(name: &str) -> Result<Self, FilterError> {
+        match name {
+            "EventType" => Ok(Property::EventType),
+            "MessageId" => Ok(Property::MessageId),
+            "OriginResource" => Ok(Property::OriginResource),
+            "RegistryPrefix" => Ok(Property::RegistryPrefix),
+            "ResourceType" => Ok(Property::ResourceType),
+            name => Err(FilterError(format!("unsupported property {}", name))),
+        }
+    }
+
+    fn value(&self, record: &EventRecord) -> Option<String> {
+        match self {
+            Property::EventType => serde_json::to_value(record.event_type)
+                .ok()
+                .and_then(|value| value.as_str().map(str::to_string)),
+            Property::MessageId => Some(record.message.message_id.clone()),
+            Property::OriginResource => record.origin_of_condition.as_ref()
+                .map(|origin| origin.as_ref().to_string_lossy().to_string()),
+            Property::RegistryPrefix =>
+                Some(record.registry_prefix().to_string()),
+            Property::ResourceType => record.resource_type.clone(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Expression {
+    All,
+    Equal(Property, String),
+    NotEqual(Property, String),
+    And(Box<Expression>, Box<Expression>),
+    Or(Box<Expression>, Box<Expression>),
+}
+
+impl Expression {
+    fn matches(&self, record: &EventRecord) -> bool {
+        match self {
+            Expression::All => true,
+            Expression::Equal(property, value) =>
+                property.value(record).as_ref() == Some(value),
+            Expression::NotEqual(property, value) =>
+                property.value(record).as_ref() != Some(value),
+            Expression::And(left, right) =>
+                left.matches(record) && right.matches(record),
+            Expression::Or(left, right) =>
+                left.matches(record) || right.matches(record),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Filter(Expression);
+
+impl Filter {
+    // A filter that matches every event
+    pub fn all() -> Self { Filter(Expression::All) }
+
+    // Parse a filter. "and" binds more tightly than "or", and parentheses
+    // may be used for grouping.
+    pub fn parse(filter: &str) -> Result<Self, FilterError> {
+        if filter.len() > MAX_LENGTH {
+            return Err(FilterError(format!(
+                "longer than {} characters", MAX_LENGTH)));
+        }
+        let mut parser = Parser {
+            tokens: tokenize(filter)?, position: 0, depth: 0,
+        };
+        let expression = parser.or()?;
+        match parser.next() {
+            None => Ok(Filter(expression)),
+            Some(token) => Err(FilterError(
+                format!("unexpected {:?}", token))),
+        }
+    }
+
+    pub fn matches(&self, record: &EventRecord) -> bool {
+        self.0.matches(record)
+    }
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+
+    // The number of parentheses that enclose the current position
+    depth: usize,
+}
+
+impl Parser {
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn keyword(&mut self, keyword: &str) -> bool {
+        match self.tokens.get(self.position) {
+            Some(Token::Identifier(identifier)) if identifier == keyword => {
+                self.position += 1;
+                true
+            },
+            _ => false,
+        }
+    }
+
+    fn or(&mut self) -> Result<Expression, FilterError> {
+        let mut expression = self.and()?;
+        while self.keyword("or") {
+            expression = Expression::Or(
+                Box::new(expression), Box::new(self.and()?));
+        }
+        Ok(expression)
+    }
+
+    fn and(&mut self) -> Result<Expression, FilterError> {
+        let mut expression = self.comparison()?;
+        while self.keyword("and") {
+            expression = Expression::And(
+                Box::new(expression), Box::new(self.comparison()?));
+        }
+        Ok(expression)
+    }
+
+    fn comparison(&mut self) -> Result<Expression, FilterError> {
+        let property = match self.next() {
+            Some(Token::Open) => {
+                self.depth += 1;
+                if self.depth > MAX_DEPTH {
+                    return Err(FilterError(format!(
+                        "nested more than {} deep", MAX_DEPTH)));
+                }
+                let expression = self.or()?;
+                self.depth -= 1;
+                return match self.next() {
+                    Some(Token::Close) => Ok(expression),
+                    _ => Err(FilterError("expected ')'".to_string())),
+                };
+            },
+            Some(Token::Identifier(name)) => Property::parse(&name)?,
+            token => return Err(FilterError(
+                format!("expected a property, found {:?}", token))),
+        };
+        let operator = match self.next() {
+            Some(Token::Identifier(operator)) => operator,
+            token => return Err(FilterError(
+                format!("expected an operator, found {:?}", token))),
+        };
+        let value = match self.next() {
+            Some(Token::Literal(value)) => value,
+            token => return Err(FilterError(
+                format!("expected a string, found {:?}", token))),
+        };
+        match operator.as_str() {
+            "eq" => Ok(Expression::Equal(property, value)),
+            "ne" => Ok(Expression::NotEqual(property, value)),
+            operator => Err(FilterError(
+                format!("unsupported operator {}", operator))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::events::EventType;
+    use crate::registries::base;
+
+    fn record() -> EventRecord {
+        EventRecord::new(EventType::Alert, base::general_error())
+            .with_origin(PathBuf::from("/redfish/v1/Chassis/chassis").into(),
+                         Some("#Chassis.v1_20_0.Chassis"))
+    }
+
+    #[test]
+    fn comparisons() {
+        let matches = |filter: &str| Filter::parse(filter).unwrap()
+            .matches(&record());
+        assert!(matches("EventType eq 'Alert'"));
+        assert!(!matches("EventType ne 'Alert'"));
+        assert!(matches("MessageId eq 'Base.1.15.0.GeneralError'"));
+        assert!(matches("OriginResource eq '/redfish/v1/Chassis/chassis'"));
+        assert!(matches("RegistryPrefix eq 'Base'"));
+        assert!(matches("ResourceType eq 'Chassis'"));
+        assert!(!matches("ResourceType eq 'Fan'"));
+    }
+
+    #[test]
+    fn precedence() {
+        let matches = |filter: &str| Filter::parse(filter).unwrap()
+            .matches(&record());
+        assert!(matches("ResourceType eq 'Fan' or EventType eq 'Alert' \
+                         and RegistryPrefix eq 'Base'"));
+        assert!(!matches("(ResourceType eq 'Fan' or EventType eq 'Alert') \
+                          and RegistryPrefix eq 'Contoso'"));
+        assert!(Filter::parse("MessageId eq 'It''s'").is_ok());
+    }
+
+    #[test]
+    fn invalid_filters() {
+        for filter in ["", "EventType", "EventType eq Alert",
+                       "EventType gt 'Alert'", "Color eq 'Blue'",
+                       "(EventType eq 'Alert'", "EventType eq 'Alert",
+                       "EventType eq 'Alert' 'Alert'"] {
+            assert!(Filter::parse(filter).is_err(), "{}", filter);
+        }
+    }
+
+    #[test]
+    fn nesting_is_limited() {
+        let nested = |depth| format!(
+            "{}EventType eq 'Alert'{}", "(".repeat(depth), ")".repeat(depth));
+        assert!(Filter::parse(&nested(MAX_DEPTH)).is_ok());
+        assert!(Filter::parse(&nested(MAX_DEPTH + 1)).is_err());
+        assert!(Filter::parse(&"(".repeat(5000)).is_err());
+        assert!(Filter::parse(&"(".repeat(MAX_LENGTH)).is_err());
+
+        let terms = vec!["EventType eq 'Alert'"; 1000];
+        assert!(Filter::parse(&terms.join(" or ")).is_err());
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,52 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            hardware.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Interfaces to the hardware managed by the BMC. Resources
+//                  read and control the platform through the traits defined
+//                  here, which are implemented by drivers for the various
+//                  hardware interfaces.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+pub mod block;
+pub mod boot;
+pub mod hwmon;
+pub mod inventory;
+pub mod ipmitool;
+pub mod network;
+pub mod networkd;
+pub mod power;
+pub mod sensor;
+pub mod simulator;
+pub mod smbios;
+pub mod storage;
+pub mod thermal;
+pub mod time;
+pub mod timesyncd;
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,509 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            block.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Storage provider for the block devices of a Linux host,
+//                  which reads sysfs and the identify data of NVMe
+//                  controllers.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use serde_json::Value;
+
+use crate::hardware::storage::{
+    ControllerInventory, DriveInventory, MediaType, Protocol,
+    StorageInventory, StorageProvider, VolumeInventory,
+};
+use crate::models::{Health, State};
+
+pub const SYS_BLOCK: &'static str = "/sys/block";
+pub const NVME: &'static str = "/usr/sbin/nvme";
+
+// The size attribute of a block device is in 512-byte sectors, regardless of
+// the logical block size of the device.
+const SECTOR_SIZE: u64 = 512;
+
+// Bits of the critical warning in the SMART / health log of an NVMe
+// controller which mean the media can no longer be trusted.
+const RELIABILITY_DEGRADED: u64 = 0x04;
+const READ_ONLY: u64 = 0x08;
+
+fn attribute(path: &Path) -> Option<String> {
+    let value = fs::read_to_string(path).ok()?;
+    Some(value.trim().to_string()).filter(|value| !value.is_empty())
+}
+
+fn capacity(block: &Path) -> Option<u64> {
+    attribute(&block.join("size"))?.parse::<u64>().ok()
+        .map(|sectors| sectors * SECTOR_SIZE)
+}
+
+// The state of a SCSI device or an NVMe controller
+fn device_state(device: &Path) -> (State, Health) {
+    match attribute(&device.join("state")).as_deref() {
+        None | Some("running" | "live") => (State::Enabled, Health::OK),
+        Some("offline" | "dead") =>
+            (State::UnavailableOffline, Health::Critical),
+        Some(_) => (State::Enabled, Health::Warning),
+    }
+}
+
+// The unit serial number, from the VPD page 0x80 of a SCSI device
+fn unit_serial_number(device: &Path) -> Option<String> {
+    let page = fs::read(device.join("vpd_pg80")).ok()?;
+    let length = *page.get(3)? as usize;
+    let serial = String::from_utf8_lossy(page.get(4..4 + length)?);
+    Some(serial.trim().to_string()).filter(|serial| !serial.is_empty())
+}
+
+fn is_numbered(name: &str, prefix: &str) -> bool {
+    name.strip_prefix(prefix).is_some_and(|number| {
+        !number.is_empty() && number.chars().all(|c| c.is_ascii_digit())
+    })
+}
+
+// The controller a SCSI device is attached to, and the protocol it's attached
+// by, from the path of the device in sysfs. For example, a disk at
+// /sys/devices/pci0000:00/0000:00:17.0/ata1/host0/... is a SATA disk on the
+// controller at /sys/devices/pci0000:00/0000:00:17.0.
+fn transport(device: &Path) -> Option<(PathBuf, Option<Protocol>)> {
+    let components: Vec<String> = device.iter()
+        .map(|component| component.to_string_lossy().into_owned())
+        .collect();
+    let position = components.iter().position(|component| {
+        ["ata", "usb", "host"].iter()
+            .any(|prefix| is_numbered(component, prefix))
+    })?;
+    let controller = components[..position].iter().collect();
+    let protocol = match &components[position] {
+        port if is_numbered(port, "ata") => Some(Protocol::SATA),
+        port if is_numbered(port, "usb") => Some(Protocol::USB),
+        _ if components.iter()
+            .any(|component| component.starts_with("end_device-")) =>
+            Some(Protocol::SAS),
+        _ => None,
+    };
+    Some((controller, protocol))
+}
+
+// The file name of the target of a symbolic link, like the driver of a device
+fn link_name(path: &Path) -> Option<String> {
+    Some(fs::read_link(path).ok()?.file_name()?.to_string_lossy().into_owned())
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// NVMe
+////
+
+// nvme-cli prints some fields as objects in its verbose output
+fn number(value: &Value) -> Option<u64> {
+    value.as_u64()
+        .or_else(|| value.as_f64().map(|value| value as u64))
+        .or_else(|| number(value.get("value")?))
+}
+
+fn text(value: &Value, name: &str) -> Option<String> {
+    let value = value.get(name)?.as_str()?.trim();
+    Some(value.to_string()).filter(|value| !value.is_empty())
+}
+
+// The health of the media, and the percentage of its life left, from the
+// SMART / health log of the controller.
+fn media_health(log: &Value) -> (Health, Option<f64>) {
+    let warning = log.get("critical_warning").and_then(number).unwrap_or(0);
+    let used = log.get("percent_used")
+        .or_else(|| log.get("percentage_used"))
+        .and_then(number);
+    let health = match warning {
+        0 if used.is_some_and(|used| used >= 100) => Health::Warning,
+        0 => Health::OK,
+        warning if warning & (RELIABILITY_DEGRADED | READ_ONLY) != 0 =>
+            Health::Critical,
+        _ => Health::Warning,
+    };
+    (health, used.map(|used| 100.0 - used.min(100) as f64))
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// BlockDevices
+////
+
+pub struct BlockDevices {
+    root: PathBuf,
+    nvme: PathBuf,
+}
+
+impl BlockDevices {
+    pub fn new(root: &Path, nvme: &Path) -> Self {
+        BlockDevices { root: root.to_owned(), nvme: nvme.to_owned() }
+    }
+
+    // Run nvme-cli against a controller. Without nvme-cli, what's in sysfs
+    // is all that's known about the controller.
+    fn nvme(&self, command: &str, controller: &str) -> Option<Value> {
+        let output = Command::new(&self.nvme)
+            .args([command, &format!("/dev/{}", controller),
+                   "--output-format=json"])
+            .stdin(Stdio::null())
+            .stderr(Stdio::null())
+            .output().ok()?;
+        match output.status.success() {
+            true => serde_json::from_slice(&output.stdout).ok(),
+            false => None,
+        }
+    }
+
+    // An NVMe controller is a storage subsystem of its own, with a single
+    // drive, and its namespaces are volumes on that drive.
+    fn controller(&self, name: &str, device: &Path) -> StorageInventory {
+        let identify = self.nvme("id-ctrl", name).unwrap_or(Value::Null);
+        let (media_health, life_left) = self.nvme("smart-log", name)
+            .map_or((Health::OK, None), |log| media_health(&log));
+        let (state, health) = device_state(device);
+        let model = text(&identify, "mn")
+            .or_else(|| attribute(&device.join("model")));
+        let serial_number = text(&identify, "sn")
+            .or_else(|| attribute(&device.join("serial")));
+        let firmware = text(&identify, "fr")
+            .or_else(|| attribute(&device.join("firmware_rev")));
+        StorageInventory {
+            id: name.to_string(),
+            name: Some(format!("NVMe Controller {}", name)),
+            controllers: vec![ControllerInventory {
+                name: Some(name.to_string()),
+                model: model.clone(),
+                serial_number: serial_number.clone(),
+                firmware_version: firmware.clone(),
+                controller_protocols: vec![Protocol::PCIe],
+                device_protocols: vec![Protocol::NVMe],
+                state,
+                health,
+                ..Default::default()
+            }],
+            drives: vec![DriveInventory {
+                id: name.to_string(),
+                name: model.clone(),
+                model,
+                serial_number,
+                revision: firmware,
+                capacity_bytes: identify.get("tnvmcap").and_then(number)
+                    .filter(|capacity| *capacity != 0),
+                media_type: Some(MediaType::SSD),
+                protocol: Some(Protocol::NVMe),
+                predicted_media_life_left_percent: life_left,
+                state,
+                health: health.worst(media_health),
+                ..Default::default()
+            }],
+            volumes: Vec::new(),
+        }
+    }
+
+    fn namespace(&self, subsystems: &mut BTreeMap<String, StorageInventory>,
+                 name: &str, block: &Path, device: &Path)
+    {
+        let Some(controller) = device.file_name()
+            .map(|name| name.to_string_lossy().into_owned()) else {
+            return;
+        };
+        let storage = subsystems.entry(controller.clone())
+            .or_insert_with(|| self.controller(&controller, device));
+        storage.volumes.push(VolumeInventory {
+            id: name.to_string(),
+            name: Some(format!("Namespace {}", name)),
+            capacity_bytes: capacity(block),
+            drives: vec![controller],
+            ..Default::default()
+        });
+    }
+
+    fn disk(&self, subsystems: &mut BTreeMap<String, StorageInventory>,
+            name: &str, block: &Path, device: &Path)
+    {
+        let Some((controller, protocol)) = transport(device) else {
+            return;
+        };
+        let Some(id) = controller.file_name()
+            .map(|name| name.to_string_lossy().into_owned()) else {
+            return;
+        };
+        let storage = subsystems.entry(id.clone())
+            .or_insert_with(|| StorageInventory {
+                id: id.clone(),
+                name: Some(format!("Storage Controller {}", id)),
+                controllers: vec![ControllerInventory {
+                    name: link_name(&controller.join("driver")),
+                    controller_protocols: match link_name(
+                        &controller.join("subsystem")).as_deref() {
+                        Some("pci") => vec![Protocol::PCIe],
+                        _ => Vec::new(),
+                    },
+                    ..Default::default()
+                }],
+                ..Default::default()
+            });
+        if let Some(protocol) = protocol {
+            let protocols = &mut storage.controllers[0].device_protocols;
+            if !protocols.contains(&protocol) {
+                protocols.push(protocol);
+            }
+        }
+
+        // SATA disks behind libata are all reported as made by "ATA"
+        let manufacturer = attribute(&device.join("vendor"))
+            .filter(|vendor| vendor != "ATA");
+        let (state, health) = device_state(device);
+        storage.drives.push(DriveInventory {
+            id: name.to_string(),
+            name: attribute(&device.join("model")),
+            manufacturer,
+            model: attribute(&device.join("model")),
+            serial_number: unit_serial_number(device),
+            revision: attribute(&device.join("rev")),
+            capacity_bytes: capacity(block),
+            media_type: match attribute(&block.join("queue/rotational"))
+                .as_deref() {
+                Some("1") => Some(MediaType::HDD),
+                Some("0") => Some(MediaType::SSD),
+                _ => None,
+            },
+            protocol,
+            state,
+            health,
+            ..Default::default()
+        });
+    }
+}
+
+impl Default for BlockDevices {
+    fn default() -> Self {
+        BlockDevices::new(Path::new(SYS_BLOCK), Path::new(NVME))
+    }
+}
+
+impl StorageProvider for BlockDevices {
+    fn storage(&self) -> io::Result<Vec<StorageInventory>> {
+        let mut names = fs::read_dir(&self.root)?
+            .map(|entry| {
+                entry.map(|entry| entry.file_name().to_string_lossy()
+                          .into_owned())
+            })
+            .collect::<io::Result<Vec<String>>>()?;
+        names.sort();
+
+        let mut subsystems = BTreeMap::new();
+        for name in names {
+            let block = self.root.join(&name);
+
+            // Virtual devices, like loop devices and device mappers, have no
+            // device, and removable media, like optical drives, aren't
+            // drives of the host.
+            let Ok(device) = fs::canonicalize(block.join("device")) else {
+                continue;
+            };
+            if attribute(&block.join("removable")).as_deref() == Some("1") {
+                continue;
+            }
+            if name.starts_with("nvme") {
+                self.namespace(&mut subsystems, &name, &block, &device);
+            } else {
+                self.disk(&mut subsystems, &name, &block, &device);
+            }
+        }
+
+        // Without the identify data, a drive is as large as its namespaces
+        let mut subsystems: Vec<StorageInventory> =
+            subsystems.into_values().collect();
+        for storage in &mut subsystems {
+            let namespaces = storage.volumes.iter()
+                .map(|volume| volume.capacity_bytes)
+                .sum::<Option<u64>>();
+            for drive in &mut storage.drives {
+                if drive.protocol == Some(Protocol::NVMe) {
+                    drive.capacity_bytes = drive.capacity_bytes.or(namespaces);
+                }
+            }
+        }
+        Ok(subsystems)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::{PermissionsExt, symlink};
+
+    const NVME_CONTROLLER: &'static str =
+        "devices/pci0000:00/0000:00:1d.0/0000:3d:00.0/nvme/nvme0";
+    const SATA_DISK: &'static str =
+        "devices/pci0000:00/0000:00:17.0/ata1/host0/target0:0:0/0:0:0:0";
+
+    fn write(directory: &Path, attributes: &[(&str, &[u8])]) {
+        fs::create_dir_all(directory).unwrap();
+        for (name, value) in attributes {
+            let path = directory.join(name);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, value).unwrap();
+        }
+    }
+
+    // Block devices are linked from /sys/block, and link to their devices
+    fn block(root: &Path, device: &Path, name: &str, sectors: &str,
+             rotational: &str) {
+        let path = device.join("block").join(name);
+        write(&path, &[
+            ("size", sectors.as_bytes()), ("removable", b"0\n"),
+            ("queue/rotational", rotational.as_bytes()),
+        ]);
+        symlink(device, path.join("device")).unwrap();
+        symlink(&path, root.join("block").join(name)).unwrap();
+    }
+
+    fn fake_sysfs() -> tempfile::TempDir {
+        let root = tempfile::tempdir().unwrap();
+        fs::create_dir_all(root.path().join("block")).unwrap();
+        fs::create_dir_all(root.path().join("bus/pci/drivers/ahci")).unwrap();
+
+        let controller = root.path().join(NVME_CONTROLLER);
+        write(&controller, &[
+            ("model", b"Samsung SSD 970 EVO Plus 1TB\n"),
+            ("serial", b"S4EWNX0R123456\n"), ("firmware_rev", b"2B2QEXM7\n"),
+            ("state", b"live\n"),
+        ]);
+        block(root.path(), &controller, "nvme0n1", "1953525168\n", "0\n");
+
+        let disk = root.path().join(SATA_DISK);
+        let mut serial = vec![0, 0x80, 0, 8];
+        serial.extend_from_slice(b"WD-12345");
+        write(&disk, &[
+            ("vendor", b"ATA     \n"), ("model", b"WDC WD40EFRX-68N\n"),
+            ("rev", b"0A82\n"), ("state", b"running\n"), ("vpd_pg80", &serial),
+        ]);
+        block(root.path(), &disk, "sda", "7814037168\n", "1\n");
+        let ahci = root.path().join("devices/pci0000:00/0000:00:17.0");
+        symlink(root.path().join("bus/pci"), ahci.join("subsystem")).unwrap();
+        symlink(root.path().join("bus/pci/drivers/ahci"), ahci.join("driver"))
+            .unwrap();
+
+        let virtual_block = root.path().join("devices/virtual/block/loop0");
+        write(&virtual_block, &[("size", b"0\n")]);
+        symlink(&virtual_block, root.path().join("block/loop0")).unwrap();
+        root
+    }
+
+    fn nvme(root: &Path, smart_log: &str) -> PathBuf {
+        let path = root.join("nvme");
+        fs::write(&path, format!(
+            "#!/bin/sh\n\
+             case \"$1\" in\n\
+             id-ctrl) echo '{{\"sn\": \"S4EWNX0R123456  \", \
+             \"mn\": \"Samsung SSD 970 EVO Plus 1TB\", \"fr\": \"2B2QEXM7\", \
+             \"tnvmcap\": 1000204886016}}' ;;\n\
+             smart-log) echo '{}' ;;\n\
+             esac\n", smart_log)).unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755))
+            .unwrap();
+        path
+    }
+
+    #[test]
+    fn block_devices() {
+        let root = fake_sysfs();
+        let provider = BlockDevices::new(
+            &root.path().join("block"), &root.path().join("missing"));
+        let storage = provider.storage().unwrap();
+        assert_eq!(vec!["0000:00:17.0", "nvme0"], storage.iter()
+                   .map(|storage| storage.id.as_str()).collect::<Vec<_>>());
+
+        let sata = &storage[0];
+        assert_eq!(Some("ahci".to_string()), sata.controllers[0].name);
+        assert_eq!(vec![Protocol::PCIe],
+                   sata.controllers[0].controller_protocols);
+        assert_eq!(vec![Protocol::SATA], sata.controllers[0].device_protocols);
+        assert_eq!(DriveInventory {
+            id: "sda".to_string(),
+            name: Some("WDC WD40EFRX-68N".to_string()),
+            model: Some("WDC WD40EFRX-68N".to_string()),
+            serial_number: Some("WD-12345".to_string()),
+            revision: Some("0A82".to_string()),
+            capacity_bytes: Some(4000787030016),
+            media_type: Some(MediaType::HDD),
+            protocol: Some(Protocol::SATA),
+            ..Default::default()
+        }, sata.drives[0]);
+
+        // Without nvme-cli, the drive is described by sysfs
+        let nvme = &storage[1];
+        let drive = &nvme.drives[0];
+        assert_eq!(Some("S4EWNX0R123456".to_string()), drive.serial_number);
+        assert_eq!(Some(1000204886016), drive.capacity_bytes);
+        assert_eq!(None, drive.predicted_media_life_left_percent);
+        assert_eq!(vec!["nvme0".to_string()], nvme.volumes[0].drives);
+    }
+
+    #[test]
+    fn nvme_identify_data() {
+        let root = fake_sysfs();
+        let smart_log = r#"{"critical_warning": 0, "percent_used": 3}"#;
+        let provider = BlockDevices::new(
+            &root.path().join("block"), &nvme(root.path(), smart_log));
+        let storage = provider.storage().unwrap();
+        let drive = &storage[1].drives[0];
+        assert_eq!(Some(1000204886016), drive.capacity_bytes);
+        assert_eq!(Some(97.0), drive.predicted_media_life_left_percent);
+        assert_eq!(Health::OK, drive.health);
+    }
+
+    #[test]
+    fn failing_media() {
+        let (health, left) = media_health(&serde_json::json!({
+            "critical_warning": {"value": 4}, "percent_used": 120,
+        }));
+        assert_eq!((Health::Critical, Some(0.0)), (health, left));
+        let (health, _) = media_health(&serde_json::json!({
+            "critical_warning": 0, "percent_used": 100,
+        }));
+        assert_eq!(Health::Warning, health);
+
+        let root = fake_sysfs();
+        fs::write(root.path().join(SATA_DISK).join("state"), "offline\n")
+            .unwrap();
+        let storage = BlockDevices::new(
+            &root.path().join("block"), &root.path().join("missing"))
+            .storage().unwrap();
+        assert_eq!(State::UnavailableOffline, storage[0].drives[0].state);
+        assert_eq!(Health::Critical, storage[0].health());
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
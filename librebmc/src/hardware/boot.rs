@@ -0,0 +1,191 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            boot.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Boot override of the host, and the trait of the backends
+//                  that apply it.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::io;
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+
+///////////////////////////////////////////////////////////////////////////////
+// Supporting Types
+////
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub enum BootSource {
+    #[default]
+    None,
+    Pxe,
+    Hdd,
+    Cd,
+    Usb,
+    BiosSetup,
+    UefiShell,
+    UefiHttp,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub enum BootSourceOverrideEnabled {
+    #[default]
+    Disabled,
+
+    // The override is cleared once the host has booted from the target
+    Once,
+    Continuous,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum BootSourceOverrideMode {
+    Legacy,
+    #[default]
+    UEFI,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct BootOverride {
+    pub target: BootSource,
+    pub enabled: BootSourceOverrideEnabled,
+    pub mode: BootSourceOverrideMode,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// BootControl
+////
+
+// Whatever decides where the host boots from, e.g. the IPMI chassis boot
+// options of its firmware, or the -boot option of a virtual machine.
+pub trait BootControl: Send + Sync {
+    // The targets that the host can be directed to boot from
+    fn targets(&self) -> Vec<BootSource>;
+
+    fn boot_override(&self) -> io::Result<BootOverride>;
+    fn set_boot_override(&self, value: &BootOverride) -> io::Result<()>;
+
+    // The order of the host's boot options, by reference, for backends
+    // that are able to change it.
+    fn boot_order(&self) -> io::Result<Option<Vec<String>>> { Ok(None) }
+    fn set_boot_order(&self, _order: &[String]) -> io::Result<()> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// CachedBoot
+////
+
+// Serves the boot override and order of a backend from what was last read
+// from it, since reading them may take a while (e.g. by running ipmitool).
+// The cache is refreshed by the monitor, and written through on changes.
+pub struct CachedBoot {
+    backend: Arc<dyn BootControl>,
+    cache: Mutex<Option<(BootOverride, Option<Vec<String>>)>>,
+}
+
+impl CachedBoot {
+    pub fn new(backend: Arc<dyn BootControl>) -> Self {
+        CachedBoot { backend, cache: Mutex::new(None) }
+    }
+
+    // Read the boot override and order from the backend
+    pub fn refresh(&self) -> io::Result<()> {
+        let boot_override = self.backend.boot_override()?;
+        let order = self.backend.boot_order()?;
+        *self.cache.lock().unwrap() = Some((boot_override, order));
+        Ok(())
+    }
+
+    fn cached(&self) -> io::Result<(BootOverride, Option<Vec<String>>)> {
+        self.cache.lock().unwrap().clone()
+            .ok_or_else(|| io::ErrorKind::WouldBlock.into())
+    }
+}
+
+impl BootControl for CachedBoot {
+    fn targets(&self) -> Vec<BootSource> { self.backend.targets() }
+
+    fn boot_override(&self) -> io::Result<BootOverride> {
+        self.cached().map(|(boot_override, _)| boot_override)
+    }
+
+    fn set_boot_override(&self, value: &BootOverride) -> io::Result<()> {
+        self.backend.set_boot_override(value)?;
+        if let Some((boot_override, _)) = self.cache.lock().unwrap().as_mut() {
+            *boot_override = *value;
+        }
+        Ok(())
+    }
+
+    fn boot_order(&self) -> io::Result<Option<Vec<String>>> {
+        self.cached().map(|(_, order)| order)
+    }
+
+    fn set_boot_order(&self, order: &[String]) -> io::Result<()> {
+        self.backend.set_boot_order(order)?;
+        if let Some((_, cached)) = self.cache.lock().unwrap().as_mut() {
+            *cached = Some(order.to_vec());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hardware::simulator::SimulatedBoot;
+
+    #[test]
+    fn boot_is_served_from_the_cache() {
+        let backend = Arc::new(SimulatedBoot::new());
+        let cached = CachedBoot::new(backend.clone());
+        assert!(cached.boot_override().is_err());
+        cached.refresh().unwrap();
+        assert_eq!(BootOverride::default(), cached.boot_override().unwrap());
+
+        let pxe = BootOverride {
+            target: BootSource::Pxe,
+            enabled: BootSourceOverrideEnabled::Once,
+            ..Default::default()
+        };
+        cached.set_boot_override(&pxe).unwrap();
+        assert_eq!(pxe, backend.boot_override().unwrap());
+        assert_eq!(pxe, cached.boot_override().unwrap());
+
+        // Changes made by the host are seen once the cache is refreshed
+        backend.set_boot_override(&BootOverride::default()).unwrap();
+        assert_eq!(pxe, cached.boot_override().unwrap());
+        cached.refresh().unwrap();
+        assert_eq!(BootOverride::default(), cached.boot_override().unwrap());
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
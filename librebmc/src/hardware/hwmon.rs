@@ -0,0 +1,651 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            hwmon.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Sensor provider for the Linux hwmon subsystem.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::hardware::power::{
+    LineInputStatus, PowerSupply, PowerSupplyInventory, PowerSupplyReading,
+};
+use crate::hardware::thermal::{Fan, FanControl, FanInventory, FanReading};
+use crate::hardware::sensor::{
+    PhysicalContext, ReadingType, Sensor, SensorInfo, SensorProvider,
+    Thresholds,
+};
+use crate::models::{Health, State};
+
+pub const HWMON_ROOT: &'static str = "/sys/class/hwmon";
+
+// The hwmon attribute classes that are exported as sensors, the reading type
+// of each, and the divisor which converts sysfs units into Redfish units.
+const CLASSES: &[(&str, ReadingType, f64)] = &[
+    ("temp", ReadingType::Temperature, 1e3),
+    ("fan", ReadingType::Rotational, 1.0),
+    ("in", ReadingType::Voltage, 1e3),
+    ("curr", ReadingType::Current, 1e3),
+    ("power", ReadingType::Power, 1e6),
+];
+
+fn read_value(path: &Path) -> io::Result<f64> {
+    fs::read_to_string(path)?.trim().parse::<f64>()
+        .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+}
+
+// Split an attribute name like "temp1_input" into ("temp", "1", "input")
+fn parse_attribute(name: &str) -> Option<(&str, &str, &str)> {
+    let (channel, item) = name.split_once('_')?;
+    let split = channel.find(|c: char| c.is_ascii_digit())?;
+    let (class, index) = channel.split_at(split);
+    match index.chars().all(|c| c.is_ascii_digit()) {
+        true => Some((class, index, item)),
+        false => None,
+    }
+}
+
+// True if an alarm attribute, e.g. "in1_lcrit_alarm", is raised
+fn alarm(path: &Path) -> bool {
+    read_value(path).is_ok_and(|value| value != 0.0)
+}
+
+// The channel of a device that's labelled label, e.g. "power1" for "pin"
+fn labelled(device: &Path, class: &str, label: &str) -> Option<String> {
+    let mut channels: Vec<String> = fs::read_dir(device).ok()?
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter_map(|name| match parse_attribute(&name) {
+            Some((prefix, index, "label")) if prefix == class =>
+                Some(format!("{}{}", prefix, index)),
+            _ => None,
+        })
+        .collect();
+    channels.sort();
+    channels.into_iter().find(|channel| {
+        fs::read_to_string(device.join(format!("{}_label", channel)))
+            .is_ok_and(|name| name.trim() == label)
+    })
+}
+
+fn physical_context(chip: &str, reading_type: ReadingType) ->
+    Option<PhysicalContext>
+{
+    match (chip, reading_type) {
+        (_, ReadingType::Rotational) => Some(PhysicalContext::Fan),
+        ("coretemp" | "k10temp" | "zenpower", _) => Some(PhysicalContext::CPU),
+        ("nvme" | "drivetemp", _) => Some(PhysicalContext::StorageDevice),
+        ("amdgpu" | "nouveau", _) => Some(PhysicalContext::GPU),
+        _ => None,
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// HwmonSensor
+////
+
+pub struct HwmonSensor {
+    info: SensorInfo,
+    input: PathBuf,
+    divisor: f64,
+}
+
+impl HwmonSensor {
+    fn new(
+        directory: &Path, chip: &str, channel: &str,
+        reading_type: ReadingType, divisor: f64,
+    ) -> Self {
+        let attribute = |item: &str| {
+            directory.join(format!("{}_{}", channel, item))
+        };
+        let threshold = |item: &str| {
+            read_value(&attribute(item)).ok().map(|value| value / divisor)
+        };
+        let mut thresholds = Thresholds {
+            lower_critical: threshold("lcrit"),
+            lower_caution: threshold("min"),
+            upper_caution: threshold("max"),
+            upper_critical: threshold("crit"),
+            hysteresis: 0.0,
+        };
+
+        // Some drivers report the point at which an alarm is cleared, which
+        // is the threshold less its hysteresis.
+        let hysteresis = |limit: Option<f64>, item: &str| {
+            limit.zip(threshold(item)).map(|(limit, clear)| limit - clear)
+        };
+        thresholds.hysteresis = [
+            hysteresis(thresholds.upper_critical, "crit_hyst"),
+            hysteresis(thresholds.upper_caution, "max_hyst"),
+        ].into_iter().flatten().fold(0.0, f64::max);
+        let name = fs::read_to_string(attribute("label"))
+            .map(|label| label.trim().to_string())
+            .unwrap_or_else(|_| format!("{} {}", chip, channel));
+        HwmonSensor {
+            info: SensorInfo {
+                id: format!("{}_{}", chip, channel),
+                name,
+                reading_type,
+                physical_context: physical_context(chip, reading_type),
+                thresholds,
+            },
+            input: attribute("input"),
+            divisor,
+        }
+    }
+}
+
+impl Sensor for HwmonSensor {
+    fn info(&self) -> &SensorInfo { &self.info }
+    fn read(&self) -> io::Result<f64> {
+        Ok(read_value(&self.input)? / self.divisor)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// HwmonPowerSupply
+////
+
+// PMBus drivers label the channels of a supply by what they measure
+const INPUT_POWER: &str = "pin";
+const INPUT_VOLTAGE: &str = "vin";
+
+pub struct HwmonPowerSupply {
+    inventory: PowerSupplyInventory,
+    device: PathBuf,
+
+    // The channels of the input power and voltage, e.g. "power1"
+    power: String,
+    voltage: Option<String>,
+}
+
+impl HwmonPowerSupply {
+    fn attribute(&self, channel: &str, item: &str) -> PathBuf {
+        self.device.join(format!("{}_{}", channel, item))
+    }
+}
+
+impl PowerSupply for HwmonPowerSupply {
+    fn inventory(&self) -> &PowerSupplyInventory { &self.inventory }
+    fn read(&self) -> io::Result<PowerSupplyReading> {
+        let input_watts = read_value(&self.attribute(&self.power, "input"))?
+            / 1e6;
+        let raised = |channel: &Option<String>, items: &[&str]| {
+            channel.as_ref().is_some_and(|channel| items.iter().any(|item| {
+                alarm(&self.attribute(channel, item))
+            }))
+        };
+        let line_input_status = if raised(&self.voltage, &["lcrit_alarm"]) {
+            LineInputStatus::LossOfInput
+        } else if raised(&self.voltage,
+                         &["min_alarm", "max_alarm", "crit_alarm"]) {
+            LineInputStatus::OutOfRange
+        } else {
+            LineInputStatus::Normal
+        };
+        let power = Some(self.power.clone());
+        let health = match line_input_status {
+            LineInputStatus::LossOfInput => Health::Critical,
+            LineInputStatus::OutOfRange => Health::Warning,
+            LineInputStatus::Normal => Health::OK,
+        }.worst(match (raised(&power, &["crit_alarm"]),
+                       raised(&power, &["max_alarm"])) {
+            (true, _) => Health::Critical,
+            (false, true) => Health::Warning,
+            (false, false) => Health::OK,
+        });
+        Ok(PowerSupplyReading {
+            state: State::Enabled, health, line_input_status,
+            input_watts: Some(input_watts),
+        })
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// HwmonFan
+////
+
+// The duty cycle of a PWM output ranges from 0 to this
+const PWM_MAX: f64 = 255.0;
+
+// The pwmN_enable setting that gives control of the output to userspace
+const PWM_MANUAL: &str = "1";
+
+pub struct HwmonFan {
+    inventory: FanInventory,
+    input: PathBuf,
+    alarm: PathBuf,
+    fault: PathBuf,
+
+    // The PWM output that drives the fan, e.g. "pwm1", if it has one
+    pwm: Option<PathBuf>,
+
+    // The setting of pwmN_enable that returns the fan to the control of
+    // its chip, which is whatever it was set to when librebmc started.
+    automatic: String,
+}
+
+impl HwmonFan {
+    fn enable(&self) -> Option<PathBuf> {
+        self.pwm.as_ref().map(|pwm| {
+            let mut enable = pwm.clone().into_os_string();
+            enable.push("_enable");
+            PathBuf::from(enable)
+        })
+    }
+
+    fn percent(&self) -> Option<f64> {
+        let pwm = self.pwm.as_ref()?;
+        read_value(pwm).ok().map(|duty| duty * 100.0 / PWM_MAX)
+    }
+}
+
+impl Fan for HwmonFan {
+    fn inventory(&self) -> &FanInventory { &self.inventory }
+    fn read(&self) -> io::Result<FanReading> {
+        let health = match (alarm(&self.fault), alarm(&self.alarm)) {
+            (true, _) => Health::Critical,
+            (false, true) => Health::Warning,
+            (false, false) => Health::OK,
+        };
+        Ok(FanReading {
+            state: State::Enabled, health,
+            speed_rpm: Some(read_value(&self.input)?),
+            speed_percent: self.percent(),
+        })
+    }
+
+    fn control(&self) -> FanControl {
+        let manual = self.enable()
+            .and_then(|enable| fs::read_to_string(enable).ok())
+            .is_some_and(|enable| enable.trim() == PWM_MANUAL);
+        match (manual, self.percent()) {
+            (true, Some(percent)) => FanControl::Manual(percent),
+            _ => FanControl::Automatic,
+        }
+    }
+
+    fn set_control(&self, control: FanControl) -> io::Result<()> {
+        let (Some(pwm), Some(enable)) = (self.pwm.as_ref(), self.enable())
+        else {
+            return Err(io::ErrorKind::Unsupported.into());
+        };
+        match control {
+            FanControl::Automatic => fs::write(enable, &self.automatic),
+            FanControl::Manual(percent) => {
+                fs::write(enable, PWM_MANUAL)?;
+                let duty = (percent.clamp(0.0, 100.0) * PWM_MAX / 100.0)
+                    .round();
+                fs::write(pwm, duty.to_string())
+            },
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Hwmon
+////
+
+pub struct Hwmon {
+    root: PathBuf,
+}
+
+impl Hwmon {
+    pub fn new(root: &Path) -> Self {
+        Hwmon { root: root.to_path_buf() }
+    }
+}
+
+impl Hwmon {
+    fn devices(&self) -> io::Result<Vec<PathBuf>> {
+        let mut devices = fs::read_dir(&self.root)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<io::Result<Vec<PathBuf>>>()?;
+        devices.sort();
+        Ok(devices)
+    }
+
+    // The supplies driven by PMBus drivers, which are the devices that
+    // report their input power.
+    pub fn power_supplies(&self) -> io::Result<Vec<Arc<dyn PowerSupply>>> {
+        let mut supplies: Vec<Arc<dyn PowerSupply>> = Vec::new();
+        for device in self.devices()? {
+            let Some(power) = labelled(&device, "power", INPUT_POWER) else {
+                continue;
+            };
+            let id = supplies.len().to_string();
+            let model = fs::read_to_string(device.join("name"))
+                .map(|name| name.trim().to_string())
+                .ok();
+            let capacity = read_value(
+                &device.join(format!("{}_rated_max", power)))
+                .ok()
+                .map(|capacity| capacity / 1e6);
+            let inventory = PowerSupplyInventory {
+                name: format!("Power Supply {}", id),
+                id, model, power_capacity_watts: capacity,
+                ..Default::default()
+            };
+            supplies.push(Arc::new(HwmonPowerSupply {
+                inventory, voltage: labelled(&device, "in", INPUT_VOLTAGE),
+                device, power,
+            }));
+        }
+        Ok(supplies)
+    }
+
+    // Every tachometer, and the PWM output of the same index, which drives
+    // the fan on most chips.
+    pub fn fans(&self) -> io::Result<Vec<Arc<dyn Fan>>> {
+        let mut fans: Vec<Arc<dyn Fan>> = Vec::new();
+        for device in self.devices()? {
+            let Ok(chip) = fs::read_to_string(device.join("name")) else {
+                continue;
+            };
+            let mut indices: Vec<u32> = fs::read_dir(&device)?
+                .filter_map(|entry| entry.ok()?.file_name().into_string()
+                            .ok())
+                .filter_map(|name| match parse_attribute(&name) {
+                    Some(("fan", index, "input")) => index.parse().ok(),
+                    _ => None,
+                })
+                .collect();
+            indices.sort();
+            for index in indices {
+                let attribute = |item: &str| {
+                    device.join(format!("fan{}_{}", index, item))
+                };
+                let id = fans.len().to_string();
+                let pwm = Some(device.join(format!("pwm{}", index)))
+                    .filter(|pwm| pwm.exists());
+                let automatic = fs::read_to_string(
+                    device.join(format!("pwm{}_enable", index)))
+                    .map(|enable| enable.trim().to_string())
+                    .ok();
+                let name = fs::read_to_string(attribute("label"))
+                    .map(|label| label.trim().to_string())
+                    .unwrap_or_else(|_| format!("Fan {}", id));
+                let inventory = FanInventory {
+                    id, name, model: Some(chip.trim().to_string()),
+                    controllable: pwm.is_some() && automatic.is_some(),
+                    ..Default::default()
+                };
+                fans.push(Arc::new(HwmonFan {
+                    inventory, input: attribute("input"),
+                    alarm: attribute("alarm"), fault: attribute("fault"), pwm,
+                    automatic: automatic
+                        .filter(|enable| enable != PWM_MANUAL)
+                        .unwrap_or_else(|| "2".to_string()),
+                }));
+            }
+        }
+        Ok(fans)
+    }
+}
+
+impl Default for Hwmon {
+    fn default() -> Self { Hwmon::new(Path::new(HWMON_ROOT)) }
+}
+
+impl SensorProvider for Hwmon {
+    fn sensors(&self) -> io::Result<Vec<Arc<dyn Sensor>>> {
+        let devices = self.devices()?;
+
+        // Chip names are not unique, e.g. one "nvme" device per drive, so
+        // subsequent devices with the same name are numbered.
+        let mut chips: HashMap<String, usize> = HashMap::new();
+        let mut sensors: Vec<Arc<dyn Sensor>> = Vec::new();
+        for device in devices {
+            let Ok(name) = fs::read_to_string(device.join("name")) else {
+                continue;
+            };
+            let name = name.trim().to_string();
+            let count = chips.entry(name.clone()).or_default();
+            let chip = match *count {
+                0 => name.clone(),
+                count => format!("{}{}", name, count),
+            };
+            *count += 1;
+
+            let mut inputs = Vec::new();
+            for entry in fs::read_dir(&device)? {
+                let file_name = entry?.file_name();
+                let Some(file_name) = file_name.to_str() else { continue };
+                let Some((class, index, "input")) = parse_attribute(file_name)
+                else { continue };
+                let Some((_, reading_type, divisor)) = CLASSES.iter()
+                    .find(|(prefix, _, _)| *prefix == class)
+                else { continue };
+                inputs.push((format!("{}{}", class, index), *reading_type,
+                             *divisor));
+            }
+            inputs.sort_by(|a, b| a.0.cmp(&b.0));
+            for (channel, reading_type, divisor) in inputs {
+                sensors.push(Arc::new(HwmonSensor::new(
+                    &device, &chip, &channel, reading_type, divisor)));
+            }
+        }
+        Ok(sensors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(directory: &Path, attributes: &[(&str, &str)]) {
+        fs::create_dir_all(directory).unwrap();
+        for (name, value) in attributes {
+            fs::write(directory.join(name), format!("{}\n", value)).unwrap();
+        }
+    }
+
+    fn fake_sysfs() -> tempfile::TempDir {
+        let root = tempfile::tempdir().unwrap();
+        write(&root.path().join("hwmon0"), &[
+            ("name", "coretemp"),
+            ("temp1_input", "45000"),
+            ("temp1_label", "Package id 0"),
+            ("temp1_max", "80000"),
+            ("temp1_crit", "100000"),
+            ("temp1_crit_hyst", "95000"),
+            ("temp1_crit_alarm", "0"),
+        ]);
+        write(&root.path().join("hwmon1"), &[
+            ("name", "nct6775"),
+            ("fan1_input", "1200"),
+            ("fan1_min", "300"),
+            ("in0_input", "1216"),
+            ("in0_min", "1000"),
+            ("in0_max", "1400"),
+            ("curr1_input", "2500"),
+            ("power1_input", "95000000"),
+            ("power1_crit", "200000000"),
+            ("pwm1", "128"),
+        ]);
+        write(&root.path().join("hwmon2"), &[
+            ("name", "nvme"), ("temp1_input", "38850"),
+        ]);
+        write(&root.path().join("hwmon3"), &[
+            ("name", "nvme"), ("temp1_input", "41850"),
+        ]);
+        root
+    }
+
+    fn find<'a>(sensors: &'a [Arc<dyn Sensor>], id: &str) -> &'a dyn Sensor {
+        sensors.iter().find(|sensor| sensor.info().id == id)
+            .unwrap_or_else(|| panic!("no sensor {}", id))
+            .as_ref()
+    }
+
+    #[test]
+    fn discovers_every_input() {
+        let root = fake_sysfs();
+        let sensors = Hwmon::new(root.path()).sensors().unwrap();
+        let ids: Vec<&str> = sensors.iter()
+            .map(|sensor| sensor.info().id.as_str())
+            .collect();
+        assert_eq!(vec![
+            "coretemp_temp1", "nct6775_curr1", "nct6775_fan1", "nct6775_in0",
+            "nct6775_power1", "nvme_temp1", "nvme1_temp1",
+        ], ids);
+    }
+
+    #[test]
+    fn readings_are_converted_to_redfish_units() {
+        let root = fake_sysfs();
+        let sensors = Hwmon::new(root.path()).sensors().unwrap();
+
+        let temperature = find(&sensors, "coretemp_temp1");
+        assert_eq!("Package id 0", temperature.info().name);
+        assert_eq!(ReadingType::Temperature, temperature.info().reading_type);
+        assert_eq!("Cel", temperature.info().reading_type.units());
+        assert_eq!(Some(PhysicalContext::CPU),
+                   temperature.info().physical_context);
+        assert_eq!(45.0, temperature.read().unwrap());
+        assert_eq!(Thresholds {
+            lower_critical: None, lower_caution: None,
+            upper_caution: Some(80.0), upper_critical: Some(100.0),
+            hysteresis: 5.0,
+        }, temperature.info().thresholds);
+
+        let fan = find(&sensors, "nct6775_fan1");
+        assert_eq!(ReadingType::Rotational, fan.info().reading_type);
+        assert_eq!("RPM", fan.info().reading_type.units());
+        assert_eq!(1200.0, fan.read().unwrap());
+        assert_eq!(Some(300.0), fan.info().thresholds.lower_caution);
+
+        let voltage = find(&sensors, "nct6775_in0");
+        assert_eq!("V", voltage.info().reading_type.units());
+        assert_eq!(1.216, voltage.read().unwrap());
+        assert_eq!(Some(1.0), voltage.info().thresholds.lower_caution);
+        assert_eq!(Some(1.4), voltage.info().thresholds.upper_caution);
+
+        let current = find(&sensors, "nct6775_curr1");
+        assert_eq!("A", current.info().reading_type.units());
+        assert_eq!(2.5, current.read().unwrap());
+
+        let power = find(&sensors, "nct6775_power1");
+        assert_eq!("W", power.info().reading_type.units());
+        assert_eq!(95.0, power.read().unwrap());
+        assert_eq!(Some(200.0), power.info().thresholds.upper_critical);
+        assert_eq!("nct6775 power1", power.info().name);
+    }
+
+    #[test]
+    fn readings_follow_the_hardware() {
+        let root = fake_sysfs();
+        let sensors = Hwmon::new(root.path()).sensors().unwrap();
+        let temperature = find(&sensors, "nvme1_temp1");
+        fs::write(root.path().join("hwmon3/temp1_input"), "50000\n").unwrap();
+        assert_eq!(50.0, temperature.read().unwrap());
+
+        fs::remove_file(root.path().join("hwmon3/temp1_input")).unwrap();
+        assert!(temperature.read().is_err());
+    }
+
+    #[test]
+    fn power_supplies_report_their_input() {
+        let root = fake_sysfs();
+        let device = root.path().join("hwmon4");
+        write(&device, &[
+            ("name", "pmbus"),
+            ("in1_label", "vin"),
+            ("in1_input", "230000"),
+            ("in1_lcrit_alarm", "0"),
+            ("in1_min_alarm", "0"),
+            ("in2_label", "vout1"),
+            ("in2_input", "12000"),
+            ("power1_label", "pout1"),
+            ("power1_input", "180000000"),
+            ("power2_label", "pin"),
+            ("power2_input", "200000000"),
+            ("power2_rated_max", "1600000000"),
+        ]);
+        let supplies = Hwmon::new(root.path()).power_supplies().unwrap();
+        assert_eq!(1, supplies.len());
+        let supply = &supplies[0];
+        assert_eq!("0", supply.inventory().id);
+        assert_eq!(Some("pmbus"), supply.inventory().model.as_deref());
+        assert_eq!(Some(1600.0), supply.inventory().power_capacity_watts);
+        let reading = supply.read().unwrap();
+        assert_eq!(Some(200.0), reading.input_watts);
+        assert_eq!(LineInputStatus::Normal, reading.line_input_status);
+        assert_eq!(Health::OK, reading.health);
+
+        fs::write(device.join("in1_min_alarm"), "1\n").unwrap();
+        let reading = supply.read().unwrap();
+        assert_eq!(LineInputStatus::OutOfRange, reading.line_input_status);
+        assert_eq!(Health::Warning, reading.health);
+        fs::write(device.join("in1_lcrit_alarm"), "1\n").unwrap();
+        let reading = supply.read().unwrap();
+        assert_eq!(LineInputStatus::LossOfInput, reading.line_input_status);
+        assert_eq!(Health::Critical, reading.health);
+    }
+
+    #[test]
+    fn fans_are_driven_by_their_pwm_output() {
+        let root = fake_sysfs();
+        let device = root.path().join("hwmon1");
+        write(&device, &[("pwm1_enable", "5"), ("fan2_input", "900")]);
+        let fans = Hwmon::new(root.path()).fans().unwrap();
+        assert_eq!(2, fans.len());
+        let (fan, fixed) = (&fans[0], &fans[1]);
+        assert_eq!("0", fan.inventory().id);
+        assert!(fan.inventory().controllable);
+        assert!(!fixed.inventory().controllable);
+        assert_eq!(Some(900.0), fixed.read().unwrap().speed_rpm);
+        assert!(fixed.set_control(FanControl::Manual(50.0)).is_err());
+
+        let reading = fan.read().unwrap();
+        assert_eq!(Some(1200.0), reading.speed_rpm);
+        assert_eq!(Some(128.0 * 100.0 / 255.0), reading.speed_percent);
+        assert_eq!(FanControl::Automatic, fan.control());
+
+        fan.set_control(FanControl::Manual(100.0)).unwrap();
+        let pwm = |item: &str| {
+            fs::read_to_string(device.join(item)).unwrap()
+        };
+        assert_eq!(("1".to_string(), "255".to_string()),
+                   (pwm("pwm1_enable"), pwm("pwm1")));
+        assert_eq!(FanControl::Manual(100.0), fan.control());
+
+        // Automatic control is returned to the chip as it was found
+        fan.set_control(FanControl::Automatic).unwrap();
+        assert_eq!("5", pwm("pwm1_enable"));
+        assert_eq!(FanControl::Automatic, fan.control());
+
+        fs::write(device.join("fan1_alarm"), "1\n").unwrap();
+        assert_eq!(Health::Warning, fan.read().unwrap().health);
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
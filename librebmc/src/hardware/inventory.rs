@@ -0,0 +1,279 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            inventory.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     The processors, memory and network interfaces of the host,
+//                  and the trait of the sources that describe them.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+
+use crate::hardware::network::InterfaceInventory;
+use crate::models::{Health, State};
+
+///////////////////////////////////////////////////////////////////////////////
+// Supporting Enums
+////
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum ProcessorType {
+    #[default]
+    CPU,
+    GPU,
+    FPGA,
+    DSP,
+    Accelerator,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum ProcessorArchitecture {
+    #[serde(rename = "x86")]
+    X86,
+    #[serde(rename = "IA-64")]
+    IA64,
+    ARM,
+    MIPS,
+    Power,
+    #[serde(rename = "RISC-V")]
+    RISCV,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum InstructionSet {
+    #[serde(rename = "x86")]
+    X86,
+    #[serde(rename = "x86-64")]
+    X86_64,
+    #[serde(rename = "IA-64")]
+    IA64,
+    #[serde(rename = "ARM-A32")]
+    ARMA32,
+    #[serde(rename = "ARM-A64")]
+    ARMA64,
+    MIPS32,
+    MIPS64,
+    PowerISA,
+    RV32,
+    RV64,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum MemoryDeviceType {
+    DDR,
+    DDR2,
+    DDR3,
+    DDR4,
+    DDR5,
+    #[serde(rename = "LPDDR3_SDRAM")]
+    LPDDR3,
+    #[serde(rename = "LPDDR4_SDRAM")]
+    LPDDR4,
+    #[serde(rename = "LPDDR5_SDRAM")]
+    LPDDR5,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Inventory
+////
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct ProcessorInventory {
+    pub id: String,
+    pub socket: Option<String>,
+    pub processor_type: ProcessorType,
+    pub processor_architecture: Option<ProcessorArchitecture>,
+    pub instruction_set: Option<InstructionSet>,
+    pub manufacturer: Option<String>,
+    pub model: Option<String>,
+    pub max_speed_mhz: Option<u32>,
+    pub total_cores: Option<u32>,
+    pub total_threads: Option<u32>,
+
+    // Empty sockets are reported as Absent
+    pub state: State,
+    pub health: Health,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct MemoryInventory {
+    pub id: String,
+    pub device_locator: Option<String>,
+    pub memory_device_type: Option<MemoryDeviceType>,
+    pub capacity_mib: Option<u32>,
+    pub operating_speed_mhz: Option<u32>,
+    pub manufacturer: Option<String>,
+    pub part_number: Option<String>,
+    pub serial_number: Option<String>,
+    pub state: State,
+    pub health: Health,
+}
+
+// Identifies the system, e.g. to a vendor's support
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct SystemInfo {
+    pub manufacturer: Option<String>,
+    pub model: Option<String>,
+    pub serial_number: Option<String>,
+    pub sku: Option<String>,
+    pub bios_version: Option<String>,
+}
+
+// Identifies the enclosure the system is installed in
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct ChassisInfo {
+    pub manufacturer: Option<String>,
+    pub model: Option<String>,
+    pub serial_number: Option<String>,
+    pub part_number: Option<String>,
+    pub sku: Option<String>,
+    pub asset_tag: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct SystemInventory {
+    pub system: SystemInfo,
+    pub chassis: ChassisInfo,
+    pub processors: Vec<ProcessorInventory>,
+    pub memory: Vec<MemoryInventory>,
+    pub ethernet_interfaces: Vec<InterfaceInventory>,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// InventorySource
+////
+
+// Whatever describes the hardware of the host, e.g. its SMBIOS tables, the
+// configuration of librebmc, or an agent running on the host. Sources are
+// read whenever the inventory is requested, so they may change.
+pub trait InventorySource: Send + Sync {
+    fn inventory(&self) -> io::Result<SystemInventory>;
+}
+
+// An inventory that never changes, e.g. one given in the configuration
+impl InventorySource for SystemInventory {
+    fn inventory(&self) -> io::Result<SystemInventory> { Ok(self.clone()) }
+}
+
+// The inventory in a file, which is only parsed again once the file has
+// been changed, since it's read for every request of the system.
+pub struct ParsedFile {
+    path: PathBuf,
+    parsed: Mutex<Option<(SystemTime, u64, SystemInventory)>>,
+}
+
+impl ParsedFile {
+    pub fn new(path: &Path) -> Self {
+        ParsedFile { path: path.to_owned(), parsed: Mutex::new(None) }
+    }
+
+    // Until the file has been written, the host has no inventory
+    pub fn read<F>(&self, parse: F) -> io::Result<SystemInventory>
+    where F: FnOnce(&[u8]) -> io::Result<SystemInventory>
+    {
+        let metadata = match fs::metadata(&self.path) {
+            Ok(metadata) => metadata,
+            Err(error) if error.kind() == io::ErrorKind::NotFound =>
+                return Ok(SystemInventory::default()),
+            Err(error) => return Err(error),
+        };
+        let (modified, length) = (metadata.modified()?, metadata.len());
+        let mut parsed = self.parsed.lock().unwrap();
+        match parsed.as_ref() {
+            Some((time, size, inventory))
+                if *time == modified && *size == length =>
+                Ok(inventory.clone()),
+            _ => {
+                let inventory = parse(&fs::read(&self.path)?)?;
+                *parsed = Some((modified, length, inventory.clone()));
+                Ok(inventory)
+            },
+        }
+    }
+}
+
+// An inventory in a JSON file, which is written by an agent on the host
+pub struct InventoryFile {
+    file: ParsedFile,
+}
+
+impl InventoryFile {
+    pub fn new(path: &Path) -> Self {
+        InventoryFile { file: ParsedFile::new(path) }
+    }
+}
+
+impl InventorySource for InventoryFile {
+    fn inventory(&self) -> io::Result<SystemInventory> {
+        self.file.read(|contents| Ok(serde_json::from_slice(contents)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn files_are_parsed_once_they_change() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("inventory.json");
+        let file = ParsedFile::new(&path);
+        let parse = |_: &[u8]| -> io::Result<SystemInventory> {
+            panic!("the file was parsed again")
+        };
+        assert_eq!(SystemInventory::default(), file.read(parse).unwrap());
+
+        fs::write(&path, r#"{"system": {"model": "A"}}"#).unwrap();
+        let inventory = InventoryFile { file };
+        let model = |inventory: &InventoryFile| {
+            inventory.inventory().unwrap().system.model.unwrap()
+        };
+        assert_eq!("A", model(&inventory));
+        assert_eq!("A", inventory.file.read(parse).unwrap().system.model
+                   .unwrap());
+
+        fs::write(&path, r#"{"system": {"model": "BB"}}"#).unwrap();
+        assert_eq!("BB", model(&inventory));
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
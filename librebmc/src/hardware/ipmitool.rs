@@ -0,0 +1,284 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            ipmitool.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Power and boot override of a host through its IPMI
+//                  chassis commands, using ipmitool.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use crate::hardware::boot::{
+    BootControl, BootOverride, BootSource, BootSourceOverrideEnabled,
+    BootSourceOverrideMode,
+};
+use crate::hardware::power::HostPower;
+use crate::models::{PowerState, ResetType};
+
+pub const IPMITOOL: &'static str = "/usr/bin/ipmitool";
+
+// Bits of the first byte of the boot flags, which are the fifth of the
+// system boot options.
+const FLAGS_VALID: u8 = 0x80;
+const FLAGS_PERSISTENT: u8 = 0x40;
+const FLAGS_EFI: u8 = 0x20;
+
+fn malformed(reason: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, reason.to_string())
+}
+
+// The argument of "chassis bootdev" for each target
+fn device(target: BootSource) -> Option<&'static str> {
+    match target {
+        BootSource::None => Some("none"),
+        BootSource::Pxe => Some("pxe"),
+        BootSource::Hdd => Some("disk"),
+        BootSource::Cd => Some("cdrom"),
+        BootSource::Usb => Some("floppy"),
+        BootSource::BiosSetup => Some("bios"),
+        BootSource::UefiShell | BootSource::UefiHttp => None,
+    }
+}
+
+fn bootdev(value: &BootOverride) -> io::Result<Vec<String>> {
+    let mut arguments = vec!["chassis".to_string(), "bootdev".to_string()];
+    if value.enabled == BootSourceOverrideEnabled::Disabled {
+        arguments.push("none".to_string());
+        return Ok(arguments);
+    }
+
+    let device = device(value.target)
+        .ok_or::<io::Error>(io::ErrorKind::Unsupported.into())?;
+    arguments.push(device.to_string());
+    let mut options = Vec::new();
+    if value.enabled == BootSourceOverrideEnabled::Continuous {
+        options.push("persistent");
+    }
+    if value.mode == BootSourceOverrideMode::UEFI {
+        options.push("efiboot");
+    }
+    if !options.is_empty() {
+        arguments.push(format!("options={}", options.join(",")));
+    }
+    Ok(arguments)
+}
+
+// The argument of "chassis power" for each reset
+fn power_command(reset_type: ResetType) -> Option<&'static str> {
+    match reset_type {
+        ResetType::On | ResetType::ForceOn => Some("on"),
+        ResetType::ForceOff => Some("off"),
+        ResetType::GracefulShutdown => Some("soft"),
+        ResetType::ForceRestart => Some("reset"),
+        ResetType::PowerCycle | ResetType::FullPowerCycle => Some("cycle"),
+        ResetType::Nmi => Some("diag"),
+        _ => None,
+    }
+}
+
+// The power state, from the output of "chassis power status"
+fn parse_power_state(output: &str) -> io::Result<PowerState> {
+    match output.trim() {
+        "Chassis Power is on" => Ok(PowerState::On),
+        "Chassis Power is off" => Ok(PowerState::Off),
+        _ => Err(malformed("power status is malformed")),
+    }
+}
+
+// The boot flags, from the output of "chassis bootparam get 5"
+fn parse_flags(output: &str) -> io::Result<BootOverride> {
+    let data = output.lines()
+        .find_map(|line| line.trim().strip_prefix("Boot parameter data:"))
+        .map(|data| data.trim())
+        .ok_or_else(|| malformed("boot flags are missing"))?;
+    let byte = |index: usize| data.get(index * 2..index * 2 + 2)
+        .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+        .ok_or_else(|| malformed("boot flags are malformed"));
+    let (flags, selector) = (byte(0)?, (byte(1)? >> 2) & 0x0f);
+
+    let mode = match flags & FLAGS_EFI {
+        0 => BootSourceOverrideMode::Legacy,
+        _ => BootSourceOverrideMode::UEFI,
+    };
+    let target = match selector {
+        0b0001 => BootSource::Pxe,
+        0b0010 | 0b0011 | 0b1011 => BootSource::Hdd,
+        0b0101 | 0b1000 => BootSource::Cd,
+        0b0110 => BootSource::BiosSetup,
+        0b0111 | 0b1001 | 0b1111 => BootSource::Usb,
+        _ => BootSource::None,
+    };
+    let enabled = match flags & (FLAGS_VALID | FLAGS_PERSISTENT) {
+        _ if target == BootSource::None => BootSourceOverrideEnabled::Disabled,
+        FLAGS_VALID => BootSourceOverrideEnabled::Once,
+        flags if flags & FLAGS_VALID != 0 =>
+            BootSourceOverrideEnabled::Continuous,
+        _ => BootSourceOverrideEnabled::Disabled,
+    };
+    Ok(BootOverride { target, enabled, mode })
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Ipmitool
+////
+
+pub struct Ipmitool {
+    command: PathBuf,
+
+    // Options that select the interface and host, e.g. -I lanplus -H ...
+    arguments: Vec<String>,
+}
+
+impl Ipmitool {
+    pub fn new(command: &Path, arguments: &[String]) -> Self {
+        Ipmitool {
+            command: command.to_owned(), arguments: arguments.to_vec(),
+        }
+    }
+
+    fn run<S: AsRef<str>>(&self, arguments: &[S]) -> io::Result<String> {
+        let output = Command::new(&self.command)
+            .args(&self.arguments)
+            .args(arguments.iter().map(|argument| argument.as_ref()))
+            .stdin(Stdio::null())
+            .output()?;
+        if !output.status.success() {
+            let error = String::from_utf8_lossy(&output.stderr);
+            return Err(io::Error::other(error.trim().to_string()));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+}
+
+impl BootControl for Ipmitool {
+    fn targets(&self) -> Vec<BootSource> {
+        vec![BootSource::None, BootSource::Pxe, BootSource::Hdd,
+             BootSource::Cd, BootSource::Usb, BootSource::BiosSetup]
+    }
+
+    fn boot_override(&self) -> io::Result<BootOverride> {
+        parse_flags(&self.run(&["chassis", "bootparam", "get", "5"])?)
+    }
+
+    fn set_boot_override(&self, value: &BootOverride) -> io::Result<()> {
+        self.run(&bootdev(value)?).map(|_| ())
+    }
+}
+
+impl HostPower for Ipmitool {
+    fn power_state(&self) -> io::Result<PowerState> {
+        parse_power_state(&self.run(&["chassis", "power", "status"])?)
+    }
+
+    fn reset_types(&self) -> Vec<ResetType> {
+        ResetType::ALL.into_iter()
+            .filter(|reset_type| power_command(*reset_type).is_some())
+            .collect()
+    }
+
+    fn reset(&self, reset_type: ResetType) -> io::Result<()> {
+        let command = power_command(reset_type)
+            .ok_or::<io::Error>(io::ErrorKind::Unsupported.into())?;
+        self.run(&["chassis", "power", command]).map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FLAGS: &'static str = concat!(
+        "Boot parameter version: 1\n",
+        "Boot parameter 5 is valid/unlocked\n",
+        "Boot parameter data: a004000000\n",
+        " Boot Flags :\n",
+        "   - Boot Flag Valid\n",
+        "   - Options apply to only next boot\n",
+        "   - BIOS EFI boot \n",
+        "   - Boot Device Selector : Force PXE\n",
+    );
+
+    #[test]
+    fn boot_flags_are_parsed() {
+        assert_eq!(BootOverride {
+            target: BootSource::Pxe,
+            enabled: BootSourceOverrideEnabled::Once,
+            mode: BootSourceOverrideMode::UEFI,
+        }, parse_flags(FLAGS).unwrap());
+
+        let persistent = FLAGS.replace("a004", "c018");
+        assert_eq!(BootOverride {
+            target: BootSource::BiosSetup,
+            enabled: BootSourceOverrideEnabled::Continuous,
+            mode: BootSourceOverrideMode::Legacy,
+        }, parse_flags(&persistent).unwrap());
+
+        let cleared = FLAGS.replace("a004", "0004");
+        assert_eq!(BootSourceOverrideEnabled::Disabled,
+                   parse_flags(&cleared).unwrap().enabled);
+        assert!(parse_flags("Boot parameter data: a").is_err());
+    }
+
+    #[test]
+    fn power_status_is_parsed() {
+        assert_eq!(PowerState::On,
+                   parse_power_state("Chassis Power is on\n").unwrap());
+        assert_eq!(PowerState::Off,
+                   parse_power_state("Chassis Power is off\n").unwrap());
+        assert!(parse_power_state("Unable to establish session").is_err());
+    }
+
+    #[test]
+    fn overrides_are_translated_to_bootdev() {
+        let mut value = BootOverride {
+            target: BootSource::Pxe,
+            enabled: BootSourceOverrideEnabled::Continuous,
+            mode: BootSourceOverrideMode::UEFI,
+        };
+        assert_eq!(vec!["chassis", "bootdev", "pxe",
+                        "options=persistent,efiboot"],
+                   bootdev(&value).unwrap());
+
+        value.mode = BootSourceOverrideMode::Legacy;
+        value.enabled = BootSourceOverrideEnabled::Once;
+        assert_eq!(vec!["chassis", "bootdev", "pxe"],
+                   bootdev(&value).unwrap());
+
+        value.enabled = BootSourceOverrideEnabled::Disabled;
+        assert_eq!(vec!["chassis", "bootdev", "none"],
+                   bootdev(&value).unwrap());
+
+        value.enabled = BootSourceOverrideEnabled::Once;
+        value.target = BootSource::UefiHttp;
+        assert!(bootdev(&value).is_err());
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
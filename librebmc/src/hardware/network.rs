@@ -0,0 +1,180 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            network.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     The network interfaces of the BMC and the host, and the
+//                  trait of the backends that configure them.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::io;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::{Health, State};
+
+///////////////////////////////////////////////////////////////////////////////
+// Supporting Enums
+////
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub enum LinkStatus {
+    LinkUp,
+    NoLink,
+    LinkDown,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum Ipv4AddressOrigin {
+    #[default]
+    Static,
+    DHCP,
+    BOOTP,
+    IPv4LinkLocal,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum Ipv6AddressOrigin {
+    #[default]
+    Static,
+    DHCPv6,
+    LinkLocal,
+    SLAAC,
+}
+
+// Whether addresses are leased from a DHCPv6 server, or only the other
+// configuration, e.g. name servers, with addresses from router advertisements.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub enum Dhcpv6Mode {
+    Stateful,
+    Stateless,
+    #[default]
+    Disabled,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Addresses
+////
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct Ipv4Address {
+    pub address: Ipv4Addr,
+    pub subnet_mask: Ipv4Addr,
+    #[serde(default)]
+    pub gateway: Option<Ipv4Addr>,
+    #[serde(default)]
+    pub origin: Ipv4AddressOrigin,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct Ipv6Address {
+    pub address: Ipv6Addr,
+    pub prefix_length: u8,
+    #[serde(default)]
+    pub origin: Ipv6AddressOrigin,
+}
+
+// The length of the prefix of a subnet mask, unless its bits aren't
+// contiguous.
+pub fn prefix_length(mask: Ipv4Addr) -> Option<u8> {
+    let bits = u32::from(mask);
+    match bits.leading_ones() + bits.trailing_zeros() == 32 {
+        true => Some(bits.leading_ones() as u8),
+        false => None,
+    }
+}
+
+pub fn subnet_mask(prefix_length: u8) -> Ipv4Addr {
+    match prefix_length {
+        0 => Ipv4Addr::UNSPECIFIED,
+        length => Ipv4Addr::from(u32::MAX << (32 - u32::from(length.min(32)))),
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Inventory
+////
+
+// How the addresses of an interface are assigned. Static addresses are used
+// alongside those leased from DHCP servers, if it's enabled.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct InterfaceSettings {
+    pub dhcpv4: bool,
+    pub dhcpv6: Dhcpv6Mode,
+    pub ipv4_static_addresses: Vec<Ipv4Address>,
+    pub ipv6_static_addresses: Vec<Ipv6Address>,
+    pub static_name_servers: Vec<IpAddr>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct InterfaceInventory {
+    pub id: String,
+    pub description: Option<String>,
+    pub mac_address: Option<String>,
+    pub interface_enabled: Option<bool>,
+    pub link_status: Option<LinkStatus>,
+    pub speed_mbps: Option<u32>,
+    pub mtu_size: Option<u32>,
+    pub host_name: Option<String>,
+    pub name_servers: Vec<IpAddr>,
+    pub ipv4_addresses: Vec<Ipv4Address>,
+    pub ipv6_addresses: Vec<Ipv6Address>,
+    pub ipv6_default_gateway: Option<Ipv6Addr>,
+
+    // Interfaces that tag their frames are VLANs of another interface
+    pub vlan_id: Option<u16>,
+
+    // How the addresses are assigned, if it's known
+    pub settings: Option<InterfaceSettings>,
+    pub state: State,
+    pub health: Health,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// NetworkControl
+////
+
+// Whatever reports and configures the network interfaces of the BMC, e.g.
+// iproute2 and systemd-networkd. Interfaces are read whenever they're
+// requested, because their addresses may be leased and renewed at any time.
+pub trait NetworkControl: Send + Sync {
+    fn interfaces(&self) -> io::Result<Vec<InterfaceInventory>>;
+
+    // Replace the settings of an interface. Its addresses may not change
+    // until some time after this returns.
+    fn configure(&self, id: &str, settings: &InterfaceSettings) ->
+        io::Result<()>;
+}
+
+///////////////////////////////////////////////////////////////////////////////
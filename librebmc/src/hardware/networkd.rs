@@ -0,0 +1,528 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            networkd.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Network interfaces reported by sysfs and iproute2, and
+//                  configured through systemd-networkd.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::fs;
+use std::io;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use serde_json::Value;
+
+use crate::hardware::network::{
+    self, Dhcpv6Mode, InterfaceInventory, InterfaceSettings, Ipv4Address,
+    Ipv4AddressOrigin, Ipv6Address, Ipv6AddressOrigin, LinkStatus,
+    NetworkControl,
+};
+use crate::models::State;
+
+pub const SYS_CLASS_NET: &'static str = "/sys/class/net";
+pub const IP: &'static str = "/usr/sbin/ip";
+pub const NETWORK_DIRECTORY: &'static str = "/etc/systemd/network";
+pub const NETWORKCTL: &'static str = "/usr/bin/networkctl";
+pub const RESOLV_CONF: &'static str = "/etc/resolv.conf";
+
+const HOSTNAME: &'static str = "/proc/sys/kernel/hostname";
+
+// Written in place of the network files of the distribution, which are
+// ignored once one earlier in lexical order matches the interface.
+const HEADER: &'static str =
+    "# Written by librebmc. Changes to this file will be overwritten.\n";
+
+fn network_file(directory: &Path, id: &str) -> PathBuf {
+    directory.join(format!("00-librebmc-{}.network", id))
+}
+
+fn attribute(path: &Path) -> Option<String> {
+    fs::read_to_string(path).ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+fn has_flag(link: &Value, flag: &str) -> bool {
+    link["flags"].as_array()
+        .is_some_and(|flags| flags.iter().any(|value| value == flag))
+}
+
+// The default gateway of each interface, from `ip route show default`
+fn gateway<T: std::str::FromStr>(routes: &Value, id: &str) -> Option<T> {
+    routes.as_array()?.iter()
+        .filter(|route| route["dev"] == id)
+        .find_map(|route| route["gateway"].as_str()?.parse().ok())
+}
+
+// The name servers in resolv.conf
+fn name_servers(path: &Path) -> Vec<IpAddr> {
+    fs::read_to_string(path).unwrap_or_default().lines()
+        .filter_map(|line| line.trim().strip_prefix("nameserver"))
+        .filter_map(|server| server.trim().parse().ok())
+        .collect()
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Network Files
+////
+
+// Read the settings from a network file written by configure(), which are
+// the only files that this parses.
+fn read_settings(contents: &str) -> InterfaceSettings {
+    let mut settings = InterfaceSettings::default();
+    let mut gateway = None;
+    let mut accept_ra = false;
+    for line in contents.lines() {
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        match key.trim() {
+            "DHCP" => {
+                let value = value.trim();
+                settings.dhcpv4 = matches!(value, "yes" | "ipv4");
+                if matches!(value, "yes" | "ipv6") {
+                    settings.dhcpv6 = Dhcpv6Mode::Stateful;
+                }
+            },
+            "IPv6AcceptRA" => accept_ra = value.trim() == "yes",
+            "Gateway" => gateway = value.trim().parse::<Ipv4Addr>().ok(),
+            "DNS" => settings.static_name_servers.extend(
+                value.trim().parse::<IpAddr>().ok()),
+            "Address" => {
+                let Some((address, length)) = value.trim().split_once('/')
+                else {
+                    continue;
+                };
+                let Ok(length) = length.parse::<u8>() else {
+                    continue;
+                };
+                match address.parse::<IpAddr>() {
+                    Ok(IpAddr::V4(address)) =>
+                        settings.ipv4_static_addresses.push(Ipv4Address {
+                            address, subnet_mask: network::subnet_mask(length),
+                            gateway: None, origin: Ipv4AddressOrigin::Static,
+                        }),
+                    Ok(IpAddr::V6(address)) =>
+                        settings.ipv6_static_addresses.push(Ipv6Address {
+                            address, prefix_length: length,
+                            origin: Ipv6AddressOrigin::Static,
+                        }),
+                    Err(_) => {},
+                }
+            },
+            _ => {},
+        }
+    }
+    if accept_ra && settings.dhcpv6 == Dhcpv6Mode::Disabled {
+        settings.dhcpv6 = Dhcpv6Mode::Stateless;
+    }
+    for address in &mut settings.ipv4_static_addresses {
+        address.gateway = gateway;
+    }
+    settings
+}
+
+fn write_settings(id: &str, settings: &InterfaceSettings, vlans: &[String])
+    -> String
+{
+    let dhcp = match (settings.dhcpv4, settings.dhcpv6) {
+        (true, Dhcpv6Mode::Stateful) => "yes",
+        (true, _) => "ipv4",
+        (false, Dhcpv6Mode::Stateful) => "ipv6",
+        (false, _) => "no",
+    };
+    let accept_ra = match settings.dhcpv6 {
+        Dhcpv6Mode::Disabled => "no",
+        _ => "yes",
+    };
+    let mut contents = format!(
+        "{}\n[Match]\nName={}\n\n[Network]\nDHCP={}\nIPv6AcceptRA={}\n",
+        HEADER, id, dhcp, accept_ra);
+    for address in &settings.ipv4_static_addresses {
+        let length = network::prefix_length(address.subnet_mask)
+            .unwrap_or(32);
+        contents += &format!("Address={}/{}\n", address.address, length);
+    }
+    for address in &settings.ipv6_static_addresses {
+        contents += &format!(
+            "Address={}/{}\n", address.address, address.prefix_length);
+    }
+
+    // The interface has a single default gateway
+    if let Some(gateway) = settings.ipv4_static_addresses.iter()
+        .find_map(|address| address.gateway) {
+        contents += &format!("Gateway={}\n", gateway);
+    }
+    for server in &settings.static_name_servers {
+        contents += &format!("DNS={}\n", server);
+    }
+    for vlan in vlans {
+        contents += &format!("VLAN={}\n", vlan);
+    }
+    contents
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Networkd
+////
+
+pub struct Networkd {
+    root: PathBuf,
+    ip: PathBuf,
+    directory: PathBuf,
+    networkctl: PathBuf,
+    resolv_conf: PathBuf,
+}
+
+impl Networkd {
+    pub fn new(root: &Path, ip: &Path, directory: &Path, networkctl: &Path,
+               resolv_conf: &Path) -> Self
+    {
+        Networkd {
+            root: root.to_owned(), ip: ip.to_owned(),
+            directory: directory.to_owned(), networkctl: networkctl.to_owned(),
+            resolv_conf: resolv_conf.to_owned(),
+        }
+    }
+
+    fn ip(&self, arguments: &[&str]) -> io::Result<Value> {
+        let output = Command::new(&self.ip)
+            .arg("-json")
+            .args(arguments)
+            .stdin(Stdio::null())
+            .stderr(Stdio::null())
+            .output()?;
+        match output.status.success() {
+            true => Ok(serde_json::from_slice(&output.stdout)?),
+            false => Err(io::Error::other(
+                format!("{} exited with {}", self.ip.display(),
+                        output.status))),
+        }
+    }
+
+    fn networkctl(&self, arguments: &[&str]) -> io::Result<()> {
+        let status = Command::new(&self.networkctl)
+            .args(arguments)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .status()?;
+        match status.success() {
+            true => Ok(()),
+            false => Err(io::Error::other(
+                format!("{} exited with {}", self.networkctl.display(),
+                        status))),
+        }
+    }
+
+    // The Ethernet interfaces of the BMC are those with a device, and the
+    // VLANs on top of them. Bridges, tunnels and the like are omitted.
+    fn links(&self) -> io::Result<Vec<Value>> {
+        let links = self.ip(&["-details", "address", "show"])?;
+        let links = links.as_array().cloned().unwrap_or_default();
+        let physical = |name: &str| {
+            self.root.join(name).join("device").exists()
+        };
+        Ok(links.into_iter()
+           .filter(|link| link["link_type"] == "ether")
+           .filter(|link| {
+               let name = link["ifname"].as_str().unwrap_or_default();
+               let parent = link["link"].as_str().unwrap_or_default();
+               physical(name) || (link["linkinfo"]["info_kind"] == "vlan"
+                                  && physical(parent))
+           })
+           .collect())
+    }
+
+    fn interface(&self, link: &Value, routes: (&Value, &Value),
+                 host_name: &Option<String>, name_servers: &[IpAddr]) ->
+        InterfaceInventory
+    {
+        let id = link["ifname"].as_str().unwrap_or_default().to_string();
+        let enabled = has_flag(link, "UP");
+        let link_status = match (enabled, has_flag(link, "LOWER_UP")) {
+            (true, true) => LinkStatus::LinkUp,
+            (true, false) => LinkStatus::NoLink,
+            (false, _) => LinkStatus::LinkDown,
+        };
+        let addresses = link["addr_info"].as_array().cloned()
+            .unwrap_or_default();
+        let ipv4_gateway = gateway::<Ipv4Addr>(routes.0, &id);
+        let ipv4_addresses = addresses.iter()
+            .filter(|address| address["family"] == "inet")
+            .filter_map(|address| {
+                let origin = match (address["scope"].as_str(),
+                                    address["dynamic"].as_bool()) {
+                    (Some("link"), _) => Ipv4AddressOrigin::IPv4LinkLocal,
+                    (_, Some(true)) => Ipv4AddressOrigin::DHCP,
+                    (_, _) => Ipv4AddressOrigin::Static,
+                };
+                Some(Ipv4Address {
+                    address: address["local"].as_str()?.parse().ok()?,
+                    subnet_mask: network::subnet_mask(
+                        address["prefixlen"].as_u64()? as u8),
+                    gateway: ipv4_gateway,
+                    origin,
+                })
+            })
+            .collect();
+
+        // Addresses leased from a DHCPv6 server are given without a prefix,
+        // and those from router advertisements with the prefix of the link.
+        let ipv6_addresses = addresses.iter()
+            .filter(|address| address["family"] == "inet6")
+            .filter_map(|address| {
+                let prefix_length = address["prefixlen"].as_u64()? as u8;
+                let origin = match (address["scope"].as_str(),
+                                    address["dynamic"].as_bool()) {
+                    (Some("link"), _) => Ipv6AddressOrigin::LinkLocal,
+                    (_, Some(true)) if prefix_length == 128 =>
+                        Ipv6AddressOrigin::DHCPv6,
+                    (_, Some(true)) => Ipv6AddressOrigin::SLAAC,
+                    (_, _) => Ipv6AddressOrigin::Static,
+                };
+                Some(Ipv6Address {
+                    address: address["local"].as_str()?.parse().ok()?,
+                    prefix_length, origin,
+                })
+            })
+            .collect();
+
+        let speed_mbps = attribute(&self.root.join(&id).join("speed"))
+            .and_then(|speed| speed.parse::<u32>().ok());
+        let settings = fs::read_to_string(network_file(&self.directory, &id))
+            .ok()
+            .map(|contents| read_settings(&contents));
+        InterfaceInventory {
+            mac_address: link["address"].as_str().map(str::to_string),
+            interface_enabled: Some(enabled),
+            link_status: Some(link_status),
+            speed_mbps,
+            mtu_size: link["mtu"].as_u64().map(|mtu| mtu as u32),
+            host_name: host_name.clone(),
+            name_servers: name_servers.to_vec(),
+            ipv4_addresses,
+            ipv6_addresses,
+            ipv6_default_gateway: gateway::<Ipv6Addr>(routes.1, &id),
+            vlan_id: Some(&link["linkinfo"])
+                .filter(|info| info["info_kind"] == "vlan")
+                .and_then(|info| info["info_data"]["id"].as_u64())
+                .map(|id| id as u16),
+            settings,
+            state: match enabled {
+                true => State::Enabled,
+                false => State::Disabled,
+            },
+            id,
+            ..Default::default()
+        }
+    }
+}
+
+impl Default for Networkd {
+    fn default() -> Self {
+        Networkd::new(Path::new(SYS_CLASS_NET), Path::new(IP),
+                      Path::new(NETWORK_DIRECTORY), Path::new(NETWORKCTL),
+                      Path::new(RESOLV_CONF))
+    }
+}
+
+impl NetworkControl for Networkd {
+    fn interfaces(&self) -> io::Result<Vec<InterfaceInventory>> {
+        let links = self.links()?;
+        let routes = (self.ip(&["route", "show", "default"])?,
+                      self.ip(&["-6", "route", "show", "default"])?);
+        let host_name = attribute(Path::new(HOSTNAME));
+        let name_servers = name_servers(&self.resolv_conf);
+        Ok(links.iter()
+           .map(|link| {
+               self.interface(link, (&routes.0, &routes.1), &host_name,
+                              &name_servers)
+           })
+           .collect())
+    }
+
+    // Replace the network file of the interface, preserving the VLANs that
+    // are on top of it, and have networkd apply it.
+    fn configure(&self, id: &str, settings: &InterfaceSettings) ->
+        io::Result<()>
+    {
+        let links = self.links()?;
+        if !links.iter().any(|link| link["ifname"] == id) {
+            return Err(io::ErrorKind::NotFound.into());
+        }
+        let vlans: Vec<String> = links.iter()
+            .filter(|link| link["link"] == id)
+            .filter_map(|link| link["ifname"].as_str().map(str::to_string))
+            .collect();
+        let path = network_file(&self.directory, id);
+        let temporary = path.with_extension("tmp");
+        fs::write(&temporary, write_settings(id, settings, &vlans))?;
+        fs::rename(&temporary, &path)?;
+        self.networkctl(&["reload"])?;
+        self.networkctl(&["reconfigure", id])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::PermissionsExt;
+
+    const LINKS: &'static str = r#"[
+        {"ifname": "lo", "flags": ["LOOPBACK", "UP", "LOWER_UP"],
+         "mtu": 65536, "link_type": "loopback", "address": "00:00:00:00:00:00",
+         "addr_info": []},
+        {"ifname": "eth0", "flags": ["BROADCAST", "MULTICAST", "UP",
+                                     "LOWER_UP"],
+         "mtu": 1500, "link_type": "ether", "address": "52:54:00:12:34:56",
+         "addr_info": [
+            {"family": "inet", "local": "10.0.2.15", "prefixlen": 24,
+             "scope": "global", "dynamic": true},
+            {"family": "inet6", "local": "fec0::5054:ff:fe12:3456",
+             "prefixlen": 64, "scope": "global", "dynamic": true},
+            {"family": "inet6", "local": "fe80::5054:ff:fe12:3456",
+             "prefixlen": 64, "scope": "link"}]},
+        {"ifname": "eth0.100", "link": "eth0",
+         "flags": ["BROADCAST", "MULTICAST", "UP", "LOWER_UP"],
+         "mtu": 1500, "link_type": "ether", "address": "52:54:00:12:34:56",
+         "linkinfo": {"info_kind": "vlan", "info_data": {"id": 100}},
+         "addr_info": [
+            {"family": "inet", "local": "192.168.100.2", "prefixlen": 24,
+             "scope": "global"}]},
+        {"ifname": "docker0", "flags": ["BROADCAST", "MULTICAST", "UP"],
+         "mtu": 1500, "link_type": "ether", "address": "02:42:ac:11:00:01",
+         "linkinfo": {"info_kind": "bridge"}, "addr_info": []}
+    ]"#;
+
+    fn executable(path: &Path, script: &str) {
+        fs::write(path, script).unwrap();
+        fs::set_permissions(path, fs::Permissions::from_mode(0o755)).unwrap();
+    }
+
+    // A fake sysfs, iproute2 and networkctl, which records its arguments
+    fn networkd(root: &Path) -> Networkd {
+        let eth0 = root.join("class/net/eth0");
+        fs::create_dir_all(eth0.join("device")).unwrap();
+        fs::write(eth0.join("speed"), "1000\n").unwrap();
+        fs::create_dir_all(root.join("class/net/docker0")).unwrap();
+        fs::create_dir_all(root.join("network")).unwrap();
+        fs::write(root.join("resolv.conf"),
+                  "# Generated\nnameserver 10.0.2.3\nsearch example.com\n")
+            .unwrap();
+        executable(&root.join("ip"), &format!(
+            "#!/bin/sh\n\
+             case \"$*\" in\n\
+             *-6*) echo '[]' ;;\n\
+             *route*) echo '[{{\"dst\": \"default\", \
+             \"gateway\": \"10.0.2.2\", \"dev\": \"eth0\"}}]' ;;\n\
+             *) cat <<'EOF'\n{}\nEOF\n;;\n\
+             esac\n", LINKS));
+        executable(&root.join("networkctl"), &format!(
+            "#!/bin/sh\necho \"$*\" >> {}\n",
+            root.join("networkctl.log").display()));
+        Networkd::new(&root.join("class/net"), &root.join("ip"),
+                      &root.join("network"), &root.join("networkctl"),
+                      &root.join("resolv.conf"))
+    }
+
+    #[test]
+    fn interfaces() {
+        let root = tempfile::tempdir().unwrap();
+        let interfaces = networkd(root.path()).interfaces().unwrap();
+        assert_eq!(vec!["eth0", "eth0.100"], interfaces.iter()
+                   .map(|interface| interface.id.as_str())
+                   .collect::<Vec<_>>());
+
+        let eth0 = &interfaces[0];
+        assert_eq!(Some("52:54:00:12:34:56".to_string()), eth0.mac_address);
+        assert_eq!(Some(LinkStatus::LinkUp), eth0.link_status);
+        assert_eq!(Some(1000), eth0.speed_mbps);
+        assert_eq!(vec![IpAddr::from([10, 0, 2, 3])], eth0.name_servers);
+        assert_eq!(vec![Ipv4Address {
+            address: Ipv4Addr::new(10, 0, 2, 15),
+            subnet_mask: Ipv4Addr::new(255, 255, 255, 0),
+            gateway: Some(Ipv4Addr::new(10, 0, 2, 2)),
+            origin: Ipv4AddressOrigin::DHCP,
+        }], eth0.ipv4_addresses);
+        assert_eq!(vec![Ipv6AddressOrigin::SLAAC,
+                        Ipv6AddressOrigin::LinkLocal],
+                   eth0.ipv6_addresses.iter()
+                   .map(|address| address.origin)
+                   .collect::<Vec<_>>());
+        assert_eq!(None, eth0.settings);
+
+        let vlan = &interfaces[1];
+        assert_eq!(Some(100), vlan.vlan_id);
+        assert_eq!(None, vlan.speed_mbps);
+        assert_eq!(Ipv4AddressOrigin::Static, vlan.ipv4_addresses[0].origin);
+        assert_eq!(None, vlan.ipv4_addresses[0].gateway);
+    }
+
+    #[test]
+    fn configure() {
+        let root = tempfile::tempdir().unwrap();
+        let networkd = networkd(root.path());
+        let settings = InterfaceSettings {
+            dhcpv4: false,
+            dhcpv6: Dhcpv6Mode::Stateless,
+            ipv4_static_addresses: vec![Ipv4Address {
+                address: Ipv4Addr::new(10, 0, 2, 20),
+                subnet_mask: Ipv4Addr::new(255, 255, 255, 0),
+                gateway: Some(Ipv4Addr::new(10, 0, 2, 2)),
+                origin: Ipv4AddressOrigin::Static,
+            }],
+            ipv6_static_addresses: vec![Ipv6Address {
+                address: "fd00::20".parse().unwrap(), prefix_length: 64,
+                origin: Ipv6AddressOrigin::Static,
+            }],
+            static_name_servers: vec![IpAddr::from([10, 0, 2, 3])],
+        };
+        networkd.configure("eth0", &settings).unwrap();
+        let contents = fs::read_to_string(
+            root.path().join("network/00-librebmc-eth0.network")).unwrap();
+        assert!(contents.contains("Name=eth0\n"));
+        assert!(contents.contains("DHCP=no\n"));
+        assert!(contents.contains("Address=10.0.2.20/24\n"));
+        assert!(contents.contains("VLAN=eth0.100\n"));
+        assert_eq!("reload\nreconfigure eth0\n", fs::read_to_string(
+            root.path().join("networkctl.log")).unwrap());
+
+        // The settings are read back from the file
+        let interfaces = networkd.interfaces().unwrap();
+        assert_eq!(Some(settings), interfaces[0].settings);
+
+        let error = networkd
+            .configure("docker0", &InterfaceSettings::default())
+            .unwrap_err();
+        assert_eq!(io::ErrorKind::NotFound, error.kind());
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
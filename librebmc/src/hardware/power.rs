@@ -0,0 +1,164 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            power.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Traits for power supplies and power meters.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::io;
+use std::sync::Arc;
+
+use serde::Serialize;
+
+use crate::models::{Health, PowerState, ResetType, State};
+
+///////////////////////////////////////////////////////////////////////////////
+// Supporting Types
+////
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum PowerSupplyType {
+    AC,
+    DC,
+    ACorDC,
+    DCRegulator,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub enum LineInputStatus {
+    Normal,
+    LossOfInput,
+    OutOfRange,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub enum NominalVoltageType {
+    AC100To127V,
+    AC100To240V,
+    AC100To277V,
+    AC120V,
+    AC200To240V,
+    AC230V,
+    AC240V,
+    AC277V,
+    DC12V,
+    DC48V,
+    DCNeg48V,
+    DC240V,
+    DC380V,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct InputRange {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nominal_voltage_type: Option<NominalVoltageType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capacity_watts: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum_voltage: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maximum_voltage: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum_frequency_hz: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maximum_frequency_hz: Option<f64>,
+}
+
+// Information about a power supply that does not change while it's installed
+#[derive(Clone, Debug, Default)]
+pub struct PowerSupplyInventory {
+    pub id: String,
+    pub name: String,
+    pub manufacturer: Option<String>,
+    pub model: Option<String>,
+    pub serial_number: Option<String>,
+    pub part_number: Option<String>,
+    pub firmware_version: Option<String>,
+    pub power_supply_type: Option<PowerSupplyType>,
+    pub power_capacity_watts: Option<f64>,
+    pub input_ranges: Vec<InputRange>,
+}
+
+#[derive(Clone, Debug)]
+pub struct PowerSupplyReading {
+    pub state: State,
+    pub health: Health,
+    pub line_input_status: LineInputStatus,
+    pub input_watts: Option<f64>,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Traits
+////
+
+// A power supply, whether that's a PMBus device, a supply exposed through
+// hwmon, or a simulated supply.
+pub trait PowerSupply: Send + Sync {
+    fn inventory(&self) -> &PowerSupplyInventory;
+    fn read(&self) -> io::Result<PowerSupplyReading>;
+}
+
+// Anything that can report the power consumed by a chassis.
+pub trait PowerMeter: Send + Sync {
+    fn power_watts(&self) -> io::Result<f64>;
+}
+
+// Whatever switches the power of the host, e.g. its power sequencer, the
+// IPMI chassis commands of its firmware, or a hypervisor.
+pub trait HostPower: Send + Sync {
+    // Backends only report On, Off or Paused. librebmc tracks the states
+    // in between while it's resetting the host.
+    fn power_state(&self) -> io::Result<PowerState>;
+
+    // The resets that the backend supports, each of which blocks until the
+    // host has reached its new state.
+    fn reset_types(&self) -> Vec<ResetType>;
+    fn reset(&self, reset_type: ResetType) -> io::Result<()>;
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// SupplyInputPower
+////
+
+// For platforms without a dedicated meter, the power consumed by the chassis
+// is the input power of its supplies.
+pub struct SupplyInputPower(pub Vec<Arc<dyn PowerSupply>>);
+
+impl PowerMeter for SupplyInputPower {
+    fn power_watts(&self) -> io::Result<f64> {
+        let mut total = 0.0;
+        for supply in &self.0 {
+            total += supply.read()?.input_watts.unwrap_or(0.0);
+        }
+        Ok(total)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
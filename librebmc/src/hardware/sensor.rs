@@ -0,0 +1,272 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            sensor.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Interface to discrete sensors, and the evaluation of their
+//                  thresholds.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::io;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::Health;
+
+///////////////////////////////////////////////////////////////////////////////
+// Supporting Types
+////
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub enum ReadingType {
+    Temperature,
+    Humidity,
+    Power,
+    EnergykWh,
+    EnergyJoules,
+    Voltage,
+    Current,
+    Frequency,
+    Pressure,
+    Rotational,
+    AirFlow,
+    Percent,
+}
+
+impl ReadingType {
+    // The UCUM units for readings of this type
+    pub fn units(&self) -> &'static str {
+        match self {
+            ReadingType::Temperature => "Cel",
+            ReadingType::Humidity => "%",
+            ReadingType::Power => "W",
+            ReadingType::EnergykWh => "kW.h",
+            ReadingType::EnergyJoules => "J",
+            ReadingType::Voltage => "V",
+            ReadingType::Current => "A",
+            ReadingType::Frequency => "Hz",
+            ReadingType::Pressure => "Pa",
+            ReadingType::Rotational => "RPM",
+            ReadingType::AirFlow => "[ft_i]3/min",
+            ReadingType::Percent => "%",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum PhysicalContext {
+    Room,
+    Intake,
+    Exhaust,
+    Front,
+    Back,
+    Upper,
+    Lower,
+    CPU,
+    GPU,
+    Backplane,
+    SystemBoard,
+    PowerSupply,
+    VoltageRegulator,
+    StorageDevice,
+    NetworkingDevice,
+    Memory,
+    Fan,
+    Chassis,
+}
+
+// The side of a reading's range that a threshold bounds
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Side {
+    Lower,
+    Upper,
+}
+
+// The caution and critical thresholds that a reading has crossed, if any
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Assertion {
+    pub caution: Option<Side>,
+    pub critical: Option<Side>,
+}
+
+impl Assertion {
+    pub fn health(&self) -> Health {
+        match (self.caution, self.critical) {
+            (_, Some(_)) => Health::Critical,
+            (Some(_), None) => Health::Warning,
+            (None, None) => Health::OK,
+        }
+    }
+}
+
+// Thresholds for a sensor reading. Readings beyond a caution threshold are
+// reported as Warning, and beyond a critical threshold as Critical. Once
+// crossed, a threshold remains asserted until the reading returns past it by
+// the hysteresis.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Thresholds {
+    pub lower_critical: Option<f64>,
+    pub lower_caution: Option<f64>,
+    pub upper_caution: Option<f64>,
+    pub upper_critical: Option<f64>,
+    pub hysteresis: f64,
+}
+
+impl Thresholds {
+    pub fn health(&self, reading: f64) -> Health {
+        let below = |threshold: Option<f64>| {
+            threshold.is_some_and(|threshold| reading <= threshold)
+        };
+        let above = |threshold: Option<f64>| {
+            threshold.is_some_and(|threshold| reading >= threshold)
+        };
+        if below(self.lower_critical) || above(self.upper_critical) {
+            Health::Critical
+        } else if below(self.lower_caution) || above(self.upper_caution) {
+            Health::Warning
+        } else {
+            Health::OK
+        }
+    }
+
+    // The side of a pair of thresholds that's asserted by a reading, given
+    // the side that was asserted by the previous reading. Only the threshold
+    // that was asserted deasserts with hysteresis.
+    fn side(&self, reading: f64, lower: Option<f64>, upper: Option<f64>,
+            previous: Option<Side>) -> Option<Side>
+    {
+        let hysteresis = match previous {
+            Some(Side::Lower) => (self.hysteresis, 0.0),
+            Some(Side::Upper) => (0.0, self.hysteresis),
+            None => (0.0, 0.0),
+        };
+        if lower.is_some_and(|lower| reading <= lower + hysteresis.0) {
+            Some(Side::Lower)
+        } else if upper.is_some_and(|upper| reading >= upper - hysteresis.1) {
+            Some(Side::Upper)
+        } else {
+            None
+        }
+    }
+
+    // The thresholds asserted by a reading, given those asserted by the
+    // previous reading
+    pub fn assertion(&self, reading: f64, previous: &Assertion) -> Assertion {
+        Assertion {
+            caution: self.side(reading, self.lower_caution,
+                               self.upper_caution, previous.caution),
+            critical: self.side(reading, self.lower_critical,
+                                self.upper_critical, previous.critical),
+        }
+    }
+}
+
+// Information about a sensor that does not change while it's present
+#[derive(Clone, Debug)]
+pub struct SensorInfo {
+    pub id: String,
+    pub name: String,
+    pub reading_type: ReadingType,
+    pub physical_context: Option<PhysicalContext>,
+    pub thresholds: Thresholds,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Sensor
+////
+
+pub trait Sensor: Send + Sync {
+    fn info(&self) -> &SensorInfo;
+    fn read(&self) -> io::Result<f64>;
+
+    // Sensors that track their state over time override this.
+    fn health(&self) -> Health {
+        match self.read() {
+            Ok(reading) => self.info().thresholds.health(reading),
+            Err(_) => Health::Warning,
+        }
+    }
+}
+
+// A source of sensors, such as a hardware monitoring driver
+pub trait SensorProvider {
+    fn sensors(&self) -> io::Result<Vec<Arc<dyn Sensor>>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn thresholds_determine_health() {
+        let thresholds = Thresholds {
+            lower_critical: Some(5.0), lower_caution: None,
+            upper_caution: Some(80.0), upper_critical: Some(90.0),
+            hysteresis: 0.0,
+        };
+        assert_eq!(Health::Critical, thresholds.health(5.0));
+        assert_eq!(Health::OK, thresholds.health(40.0));
+        assert_eq!(Health::Warning, thresholds.health(85.0));
+        assert_eq!(Health::Critical, thresholds.health(95.0));
+    }
+
+    #[test]
+    fn thresholds_deassert_with_hysteresis() {
+        let thresholds = Thresholds {
+            upper_caution: Some(80.0), upper_critical: Some(90.0),
+            lower_caution: Some(10.0), hysteresis: 2.0,
+            ..Default::default()
+        };
+        let trace = |readings: &[f64]| {
+            let mut assertion = Assertion::default();
+            readings.iter()
+                .map(|reading| {
+                    assertion = thresholds.assertion(*reading, &assertion);
+                    assertion.health()
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(vec![
+            Health::Warning, Health::Critical, Health::Critical,
+            Health::Warning, Health::Warning, Health::OK,
+            Health::Warning, Health::Warning, Health::OK,
+        ], trace(&[85.0, 91.0, 89.0, 87.5, 79.0, 77.5, 9.0, 11.0, 12.5]));
+
+        // Hysteresis only holds the threshold that was crossed
+        assert_eq!(vec![Health::Warning, Health::OK], trace(&[85.0, 11.0]));
+        assert_eq!(vec![Health::Warning, Health::OK], trace(&[9.0, 79.0]));
+        let mut assertion = Assertion::default();
+        for reading in [85.0, 9.0, 11.0] {
+            assertion = thresholds.assertion(reading, &assertion);
+        }
+        assert_eq!(Some(Side::Lower), assertion.caution);
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,659 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            simulator.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Simulated hardware, for development and testing without a
+//                  platform.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::fs;
+use std::io;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use crate::hardware::boot::{BootControl, BootOverride, BootSource};
+use crate::hardware::storage::{
+    ControllerInventory, DriveInventory, MediaType, Protocol,
+    StorageInventory, StorageProvider, VolumeInventory,
+};
+use crate::hardware::inventory::{
+    ChassisInfo, InstructionSet, InventorySource, MemoryDeviceType,
+    MemoryInventory, ProcessorArchitecture, ProcessorInventory, SystemInfo,
+    SystemInventory,
+};
+use crate::hardware::network::{
+    Dhcpv6Mode, InterfaceInventory, InterfaceSettings, Ipv4Address,
+    Ipv4AddressOrigin, Ipv6Address, Ipv6AddressOrigin, LinkStatus,
+    NetworkControl,
+};
+use crate::hardware::power::{
+    HostPower, InputRange, LineInputStatus, NominalVoltageType, PowerSupply,
+    PowerSupplyInventory, PowerSupplyReading, PowerSupplyType,
+};
+use crate::hardware::sensor::{
+    PhysicalContext, ReadingType, Sensor, SensorInfo, SensorProvider,
+    Thresholds,
+};
+use crate::hardware::thermal::{Fan, FanControl, FanInventory, FanReading};
+use crate::hardware::time::{NtpSettings, TimeSync};
+use crate::models::{Health, PowerState, ResetType, State};
+use crate::update::{Component, UpdateError, Updater};
+
+///////////////////////////////////////////////////////////////////////////////
+// SimulatedPowerSupply
+////
+
+pub struct SimulatedPowerSupply {
+    inventory: PowerSupplyInventory,
+    reading: Mutex<PowerSupplyReading>,
+}
+
+impl SimulatedPowerSupply {
+    pub fn new(id: &str, capacity_watts: f64, input_watts: f64) -> Self {
+        let inventory = PowerSupplyInventory {
+            id: id.to_string(),
+            name: format!("Power Supply {}", id),
+            manufacturer: Some("librebmc".to_string()),
+            model: Some("Simulated PSU".to_string()),
+            serial_number: Some(format!("SIM-PSU-{}", id)),
+            part_number: None,
+            firmware_version: None,
+            power_supply_type: Some(PowerSupplyType::AC),
+            power_capacity_watts: Some(capacity_watts),
+            input_ranges: vec![InputRange {
+                nominal_voltage_type: Some(NominalVoltageType::AC200To240V),
+                capacity_watts: Some(capacity_watts),
+                minimum_voltage: Some(180.0),
+                maximum_voltage: Some(264.0),
+                minimum_frequency_hz: Some(47.0),
+                maximum_frequency_hz: Some(63.0),
+            }],
+        };
+        let reading = PowerSupplyReading {
+            state: State::Enabled,
+            health: Health::OK,
+            line_input_status: LineInputStatus::Normal,
+            input_watts: Some(input_watts),
+        };
+        SimulatedPowerSupply { inventory, reading: Mutex::new(reading) }
+    }
+
+    pub fn set_input_watts(&self, watts: f64) {
+        self.reading.lock().unwrap().input_watts = Some(watts);
+    }
+
+    pub fn lose_input(&self) {
+        let mut reading = self.reading.lock().unwrap();
+        reading.line_input_status = LineInputStatus::LossOfInput;
+        reading.health = Health::Critical;
+        reading.input_watts = Some(0.0);
+    }
+}
+
+impl PowerSupply for SimulatedPowerSupply {
+    fn inventory(&self) -> &PowerSupplyInventory { &self.inventory }
+    fn read(&self) -> io::Result<PowerSupplyReading> {
+        Ok(self.reading.lock().unwrap().clone())
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// SimulatedSensor
+////
+
+pub struct SimulatedSensor {
+    info: SensorInfo,
+    reading: Mutex<f64>,
+}
+
+impl SimulatedSensor {
+    pub fn new(info: SensorInfo, reading: f64) -> Self {
+        SimulatedSensor { info, reading: Mutex::new(reading) }
+    }
+
+    pub fn set_reading(&self, reading: f64) {
+        *self.reading.lock().unwrap() = reading;
+    }
+}
+
+impl Sensor for SimulatedSensor {
+    fn info(&self) -> &SensorInfo { &self.info }
+    fn read(&self) -> io::Result<f64> { Ok(*self.reading.lock().unwrap()) }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// SimulatedFan
+////
+
+// The duty cycle of simulated fans under automatic control
+const AUTOMATIC_PERCENT: f64 = 40.0;
+
+pub struct SimulatedFan {
+    inventory: FanInventory,
+    max_rpm: f64,
+    control: Mutex<FanControl>,
+}
+
+impl SimulatedFan {
+    pub fn new(id: &str, max_rpm: f64) -> Self {
+        let inventory = FanInventory {
+            id: id.to_string(),
+            name: format!("Fan {}", id),
+            manufacturer: Some("librebmc".to_string()),
+            model: Some("Simulated Fan".to_string()),
+            serial_number: Some(format!("SIM-FAN-{}", id)),
+            hot_pluggable: true,
+            controllable: true,
+            ..Default::default()
+        };
+        SimulatedFan {
+            inventory, max_rpm, control: Mutex::new(FanControl::Automatic),
+        }
+    }
+}
+
+impl Fan for SimulatedFan {
+    fn inventory(&self) -> &FanInventory { &self.inventory }
+    fn read(&self) -> io::Result<FanReading> {
+        let percent = match *self.control.lock().unwrap() {
+            FanControl::Automatic => AUTOMATIC_PERCENT,
+            FanControl::Manual(percent) => percent,
+        };
+        Ok(FanReading {
+            state: State::Enabled,
+            health: Health::OK,
+            speed_rpm: Some(self.max_rpm * percent / 100.0),
+            speed_percent: Some(percent),
+        })
+    }
+
+    fn control(&self) -> FanControl { *self.control.lock().unwrap() }
+    fn set_control(&self, control: FanControl) -> io::Result<()> {
+        *self.control.lock().unwrap() = control;
+        Ok(())
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// SimulatedSensors
+////
+
+// The sensors of the simulated platform
+pub struct SimulatedSensors;
+
+impl SensorProvider for SimulatedSensors {
+    fn sensors(&self) -> io::Result<Vec<Arc<dyn Sensor>>> {
+        let sensor = |id: &str, name: &str, reading_type, context, reading,
+                      thresholds| {
+            Arc::new(SimulatedSensor::new(SensorInfo {
+                id: id.to_string(),
+                name: name.to_string(),
+                reading_type,
+                physical_context: Some(context),
+                thresholds,
+            }, reading)) as Arc<dyn Sensor>
+        };
+        Ok(vec![
+            sensor("Intake", "Intake Temperature", ReadingType::Temperature,
+                   PhysicalContext::Intake, 24.0, Thresholds {
+                       upper_caution: Some(35.0), upper_critical: Some(40.0),
+                       hysteresis: 1.0, ..Default::default()
+                   }),
+            sensor("Exhaust", "Exhaust Temperature", ReadingType::Temperature,
+                   PhysicalContext::Exhaust, 38.0, Thresholds {
+                       upper_caution: Some(60.0), upper_critical: Some(70.0),
+                       hysteresis: 2.0, ..Default::default()
+                   }),
+            sensor("CPU0", "CPU0 Temperature", ReadingType::Temperature,
+                   PhysicalContext::CPU, 55.0, Thresholds {
+                       upper_caution: Some(85.0), upper_critical: Some(95.0),
+                       hysteresis: 2.0, ..Default::default()
+                   }),
+            sensor("P12V", "12V Rail", ReadingType::Voltage,
+                   PhysicalContext::SystemBoard, 12.05, Thresholds {
+                       lower_critical: Some(10.8), lower_caution: Some(11.4),
+                       upper_caution: Some(12.6), upper_critical: Some(13.2),
+                       hysteresis: 0.1,
+                   }),
+        ])
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// SimulatedUpdater
+////
+
+pub struct SimulatedUpdater {
+    components: Mutex<Vec<Component>>,
+}
+
+impl SimulatedUpdater {
+    pub fn new() -> Self {
+        let component = |id: &str, name: &str, version: &str| Component {
+            id: id.to_string(),
+            name: name.to_string(),
+            version: version.to_string(),
+            updateable: true,
+        };
+        SimulatedUpdater {
+            components: Mutex::new(vec![
+                component("BMC", "BMC Firmware", env!("CARGO_PKG_VERSION")),
+                component("BIOS", "BIOS", "1.0.0"),
+                component("CPLD", "CPLD Firmware", "1.0.0"),
+            ]),
+        }
+    }
+}
+
+impl Default for SimulatedUpdater {
+    fn default() -> Self { Self::new() }
+}
+
+impl Updater for SimulatedUpdater {
+    fn components(&self) -> Vec<Component> {
+        self.components.lock().unwrap().clone()
+    }
+
+    fn apply(&self, target: &str, image: &Path, version: &str,
+             progress: &dyn Fn(u8)) -> Result<(), UpdateError>
+    {
+        fs::metadata(image)?;
+        progress(50);
+
+        let mut components = self.components.lock().unwrap();
+        let component = components.iter_mut()
+            .find(|component| component.id == target)
+            .ok_or_else(|| UpdateError::Rejected(
+                format!("no component {}", target)))?;
+        component.version = version.to_string();
+        progress(100);
+        Ok(())
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// SimulatedBoot
+////
+
+// The boot options of the simulated host: its disk, network and shell
+const BOOT_OPTIONS: &[&str] = &["Boot0000", "Boot0001", "Boot0002"];
+
+pub struct SimulatedBoot {
+    boot_override: Mutex<BootOverride>,
+    order: Mutex<Vec<String>>,
+}
+
+impl SimulatedBoot {
+    pub fn new() -> Self {
+        SimulatedBoot {
+            boot_override: Mutex::new(BootOverride::default()),
+            order: Mutex::new(BOOT_OPTIONS.iter()
+                              .map(|option| option.to_string())
+                              .collect()),
+        }
+    }
+}
+
+impl Default for SimulatedBoot {
+    fn default() -> Self { Self::new() }
+}
+
+impl BootControl for SimulatedBoot {
+    fn targets(&self) -> Vec<BootSource> {
+        vec![BootSource::None, BootSource::Pxe, BootSource::Hdd,
+             BootSource::Cd, BootSource::Usb, BootSource::BiosSetup,
+             BootSource::UefiShell, BootSource::UefiHttp]
+    }
+
+    fn boot_override(&self) -> io::Result<BootOverride> {
+        Ok(*self.boot_override.lock().unwrap())
+    }
+
+    fn set_boot_override(&self, value: &BootOverride) -> io::Result<()> {
+        *self.boot_override.lock().unwrap() = *value;
+        Ok(())
+    }
+
+    fn boot_order(&self) -> io::Result<Option<Vec<String>>> {
+        Ok(Some(self.order.lock().unwrap().clone()))
+    }
+
+    fn set_boot_order(&self, order: &[String]) -> io::Result<()> {
+        *self.order.lock().unwrap() = order.to_vec();
+        Ok(())
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// SimulatedHost
+////
+
+pub struct SimulatedHost {
+    state: Mutex<PowerState>,
+}
+
+impl SimulatedHost {
+    pub fn new(state: PowerState) -> Self {
+        SimulatedHost { state: Mutex::new(state) }
+    }
+}
+
+impl HostPower for SimulatedHost {
+    fn power_state(&self) -> io::Result<PowerState> {
+        Ok(*self.state.lock().unwrap())
+    }
+
+    fn reset_types(&self) -> Vec<ResetType> {
+        vec![ResetType::On, ResetType::ForceOn, ResetType::ForceOff,
+             ResetType::GracefulShutdown, ResetType::GracefulRestart,
+             ResetType::ForceRestart, ResetType::PowerCycle,
+             ResetType::PushPowerButton, ResetType::Nmi, ResetType::Pause,
+             ResetType::Resume]
+    }
+
+    fn reset(&self, reset_type: ResetType) -> io::Result<()> {
+        if !self.reset_types().contains(&reset_type) {
+            return Err(io::ErrorKind::Unsupported.into());
+        }
+        let mut state = self.state.lock().unwrap();
+        *state = match (reset_type, *state) {
+            (ResetType::On | ResetType::ForceOn, _) => PowerState::On,
+            (ResetType::ForceOff | ResetType::GracefulShutdown, _) =>
+                PowerState::Off,
+            (ResetType::PushPowerButton, PowerState::Off) => PowerState::On,
+            (ResetType::PushPowerButton, _) => PowerState::Off,
+            (ResetType::Pause, PowerState::On) => PowerState::Paused,
+            (ResetType::Resume, PowerState::Paused) => PowerState::On,
+            (ResetType::Nmi | ResetType::Pause | ResetType::Resume, state) =>
+                state,
+
+            // Restarts and power cycles leave the host running
+            (_, _) => PowerState::On,
+        };
+        Ok(())
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// SimulatedInventory
+////
+
+// A host with two sockets, four DIMMs and two network interfaces
+pub struct SimulatedInventory;
+
+impl InventorySource for SimulatedInventory {
+    fn inventory(&self) -> io::Result<SystemInventory> {
+        let processor = |id: usize| ProcessorInventory {
+            id: id.to_string(),
+            socket: Some(format!("CPU{}", id)),
+            processor_architecture: Some(ProcessorArchitecture::X86),
+            instruction_set: Some(InstructionSet::X86_64),
+            manufacturer: Some("librebmc".to_string()),
+            model: Some("Simulated Processor".to_string()),
+            max_speed_mhz: Some(3000),
+            total_cores: Some(16),
+            total_threads: Some(32),
+            ..Default::default()
+        };
+        let module = |id: usize| MemoryInventory {
+            id: format!("DIMM{}", id),
+            device_locator: Some(format!("DIMM{}", id)),
+            memory_device_type: Some(MemoryDeviceType::DDR4),
+            capacity_mib: Some(16384),
+            operating_speed_mhz: Some(3200),
+            manufacturer: Some("librebmc".to_string()),
+            serial_number: Some(format!("SIM-DIMM-{}", id)),
+            ..Default::default()
+        };
+        let interface = |id: usize| InterfaceInventory {
+            id: format!("eno{}", id),
+            mac_address: Some(format!("02:00:00:00:01:{:02x}", id)),
+            interface_enabled: Some(true),
+            link_status: Some(match id {
+                1 => LinkStatus::LinkUp,
+                _ => LinkStatus::NoLink,
+            }),
+            speed_mbps: Some(10000),
+            mtu_size: Some(1500),
+            host_name: Some("host".to_string()),
+            ipv4_addresses: match id {
+                1 => vec![Ipv4Address {
+                    address: Ipv4Addr::new(192, 168, 0, 50),
+                    subnet_mask: Ipv4Addr::new(255, 255, 255, 0),
+                    gateway: Some(Ipv4Addr::new(192, 168, 0, 1)),
+                    origin: Ipv4AddressOrigin::DHCP,
+                }],
+                _ => Vec::new(),
+            },
+            ..Default::default()
+        };
+        Ok(SystemInventory {
+            processors: (0..2).map(processor).collect(),
+            memory: (0..4).map(module).collect(),
+            ethernet_interfaces: (1..3).map(interface).collect(),
+            system: SystemInfo {
+                manufacturer: Some("librebmc".to_string()),
+                model: Some("Simulated System".to_string()),
+                ..Default::default()
+            },
+            chassis: ChassisInfo {
+                manufacturer: Some("librebmc".to_string()),
+                model: Some("Simulated Chassis".to_string()),
+                ..Default::default()
+            },
+        })
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// SimulatedStorage
+////
+
+// An NVMe drive, and two SATA drives on an AHCI controller
+pub struct SimulatedStorage;
+
+impl StorageProvider for SimulatedStorage {
+    fn storage(&self) -> io::Result<Vec<StorageInventory>> {
+        let nvme = StorageInventory {
+            id: "nvme0".to_string(),
+            name: Some("NVMe Controller nvme0".to_string()),
+            controllers: vec![ControllerInventory {
+                name: Some("nvme0".to_string()),
+                model: Some("Simulated NVMe SSD".to_string()),
+                controller_protocols: vec![Protocol::PCIe],
+                device_protocols: vec![Protocol::NVMe],
+                ..Default::default()
+            }],
+            drives: vec![DriveInventory {
+                id: "nvme0".to_string(),
+                model: Some("Simulated NVMe SSD".to_string()),
+                serial_number: Some("SIM-NVME-0".to_string()),
+                capacity_bytes: Some(1_000_204_886_016),
+                media_type: Some(MediaType::SSD),
+                protocol: Some(Protocol::NVMe),
+                predicted_media_life_left_percent: Some(98.0),
+                ..Default::default()
+            }],
+            volumes: vec![VolumeInventory {
+                id: "nvme0n1".to_string(),
+                capacity_bytes: Some(1_000_204_886_016),
+                drives: vec!["nvme0".to_string()],
+                ..Default::default()
+            }],
+        };
+        let drive = |id: usize, media_type: MediaType| DriveInventory {
+            id: format!("sd{}", char::from(b'a' + id as u8)),
+            model: Some(format!("Simulated {:?}", media_type)),
+            serial_number: Some(format!("SIM-SATA-{}", id)),
+            capacity_bytes: Some(4_000_787_030_016),
+            media_type: Some(media_type),
+            protocol: Some(Protocol::SATA),
+            ..Default::default()
+        };
+        let sata = StorageInventory {
+            id: "ahci0".to_string(),
+            name: Some("Storage Controller ahci0".to_string()),
+            controllers: vec![ControllerInventory {
+                name: Some("ahci".to_string()),
+                controller_protocols: vec![Protocol::PCIe],
+                device_protocols: vec![Protocol::SATA],
+                ..Default::default()
+            }],
+            drives: vec![drive(0, MediaType::HDD), drive(1, MediaType::SSD)],
+            volumes: Vec::new(),
+        };
+        Ok(vec![nvme, sata])
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// SimulatedNetwork
+////
+
+// The dedicated management port of the BMC, which is on a network with DHCP
+// servers and routers that advertise a unique local prefix.
+pub struct SimulatedNetwork {
+    settings: Mutex<InterfaceSettings>,
+}
+
+impl SimulatedNetwork {
+    pub fn new() -> Self {
+        SimulatedNetwork {
+            settings: Mutex::new(InterfaceSettings {
+                dhcpv4: true,
+                dhcpv6: Dhcpv6Mode::Stateless,
+                ..Default::default()
+            }),
+        }
+    }
+}
+
+impl Default for SimulatedNetwork {
+    fn default() -> Self { Self::new() }
+}
+
+impl NetworkControl for SimulatedNetwork {
+    fn interfaces(&self) -> io::Result<Vec<InterfaceInventory>> {
+        let settings = self.settings.lock().unwrap().clone();
+        let router = Ipv4Addr::new(192, 168, 0, 1);
+        let mut ipv4_addresses = Vec::new();
+        let mut name_servers = settings.static_name_servers.clone();
+        if settings.dhcpv4 {
+            ipv4_addresses.push(Ipv4Address {
+                address: Ipv4Addr::new(192, 168, 0, 100),
+                subnet_mask: Ipv4Addr::new(255, 255, 255, 0),
+                gateway: Some(router), origin: Ipv4AddressOrigin::DHCP,
+            });
+            name_servers.push(router.into());
+        }
+        ipv4_addresses.extend(settings.ipv4_static_addresses.iter().cloned());
+
+        let address = |address: &str, prefix_length, origin| Ipv6Address {
+            address: address.parse().unwrap(), prefix_length, origin,
+        };
+        let mut ipv6_addresses = vec![
+            address("fe80::ff:fe00:1", 64, Ipv6AddressOrigin::LinkLocal),
+        ];
+        if settings.dhcpv6 != Dhcpv6Mode::Disabled {
+            ipv6_addresses.push(
+                address("fd00::ff:fe00:1", 64, Ipv6AddressOrigin::SLAAC));
+        }
+        if settings.dhcpv6 == Dhcpv6Mode::Stateful {
+            ipv6_addresses.push(
+                address("fd00::100", 128, Ipv6AddressOrigin::DHCPv6));
+        }
+        ipv6_addresses.extend(settings.ipv6_static_addresses.iter().cloned());
+        let router6 = Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 1);
+        let ipv6_default_gateway = Some(router6)
+            .filter(|_| settings.dhcpv6 != Dhcpv6Mode::Disabled);
+
+        Ok(vec![InterfaceInventory {
+            id: "eth0".to_string(),
+            description: Some("Management Network Interface".to_string()),
+            mac_address: Some("02:00:00:00:00:01".to_string()),
+            interface_enabled: Some(true),
+            link_status: Some(LinkStatus::LinkUp),
+            speed_mbps: Some(1000),
+            mtu_size: Some(1500),
+            host_name: Some("bmc".to_string()),
+            name_servers,
+            ipv4_addresses,
+            ipv6_addresses,
+            ipv6_default_gateway,
+            settings: Some(settings),
+            ..Default::default()
+        }])
+    }
+
+    fn configure(&self, id: &str, settings: &InterfaceSettings) ->
+        io::Result<()>
+    {
+        if id != "eth0" {
+            return Err(io::ErrorKind::NotFound.into());
+        }
+        *self.settings.lock().unwrap() = settings.clone();
+        Ok(())
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// SimulatedTimeSync
+////
+
+pub struct SimulatedTimeSync {
+    settings: Mutex<NtpSettings>,
+}
+
+impl SimulatedTimeSync {
+    pub fn new() -> Self {
+        SimulatedTimeSync {
+            settings: Mutex::new(NtpSettings {
+                enabled: true,
+                servers: vec!["pool.ntp.org".to_string()],
+            }),
+        }
+    }
+}
+
+impl Default for SimulatedTimeSync {
+    fn default() -> Self { Self::new() }
+}
+
+impl TimeSync for SimulatedTimeSync {
+    fn ntp(&self) -> io::Result<NtpSettings> {
+        Ok(self.settings.lock().unwrap().clone())
+    }
+
+    fn set_ntp(&self, settings: &NtpSettings) -> io::Result<()> {
+        *self.settings.lock().unwrap() = settings.clone();
+        Ok(())
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
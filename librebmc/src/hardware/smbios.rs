@@ -0,0 +1,439 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            smbios.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Parses the SMBIOS structure table of the host into its
+//                  inventory.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::io;
+use std::path::Path;
+
+use crate::hardware::inventory::{
+    ChassisInfo, InstructionSet, InventorySource, MemoryDeviceType,
+    MemoryInventory, ParsedFile, ProcessorArchitecture, ProcessorInventory,
+    ProcessorType, SystemInfo, SystemInventory,
+};
+use crate::models::{Health, State};
+
+// Where Linux exports the table provided by the firmware
+pub const DMI_TABLE: &'static str = "/sys/firmware/dmi/tables/DMI";
+
+// Types of the structures in the table
+const BIOS_INFORMATION: u8 = 0;
+const SYSTEM_INFORMATION: u8 = 1;
+const SYSTEM_ENCLOSURE: u8 = 3;
+const PROCESSOR_INFORMATION: u8 = 4;
+const MEMORY_DEVICE: u8 = 17;
+const END_OF_TABLE: u8 = 127;
+
+// The processor family is given in the second family field
+const FAMILY_2: u8 = 0xfe;
+
+// Fields of the processor status, and the processor characteristics
+const SOCKET_POPULATED: u8 = 0x40;
+const CPU_STATUS: u8 = 0x07;
+const CPU_DISABLED_BY_USER: u8 = 2;
+const CPU_DISABLED_BY_FIRMWARE: u8 = 3;
+const CAPABLE_64_BIT: u16 = 0x0004;
+
+// Sizes of memory devices
+const SIZE_NOT_INSTALLED: u16 = 0;
+const SIZE_UNKNOWN: u16 = 0xffff;
+const SIZE_EXTENDED: u16 = 0x7fff;
+const SIZE_IN_KIB: u16 = 0x8000;
+
+fn malformed(reason: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, reason.to_string())
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Structure
+////
+
+// A structure of the table: its formatted area, which begins with the header,
+// and the strings that follow it.
+struct Structure<'a> {
+    formatted: &'a [u8],
+    strings: Vec<&'a [u8]>,
+}
+
+impl<'a> Structure<'a> {
+    fn kind(&self) -> u8 { self.formatted[0] }
+
+    // Fields past the end of the formatted area were added by a later version
+    // of the specification than the one the table conforms to.
+    fn byte(&self, offset: usize) -> Option<u8> {
+        self.formatted.get(offset).copied()
+    }
+
+    fn word(&self, offset: usize) -> Option<u16> {
+        let bytes = self.formatted.get(offset..offset + 2)?;
+        Some(u16::from_le_bytes(bytes.try_into().unwrap()))
+    }
+
+    fn dword(&self, offset: usize) -> Option<u32> {
+        let bytes = self.formatted.get(offset..offset + 4)?;
+        Some(u32::from_le_bytes(bytes.try_into().unwrap()))
+    }
+
+    // Strings are numbered from one, and zero means there's no string
+    fn string(&self, offset: usize) -> Option<String> {
+        let index = (self.byte(offset)? as usize).checked_sub(1)?;
+        let value = String::from_utf8_lossy(self.strings.get(index)?);
+        Some(value.trim().to_string()).filter(|value| !value.is_empty())
+    }
+}
+
+// Split the table into its structures, up to the end-of-table structure
+fn structures(table: &[u8]) -> io::Result<Vec<Structure<'_>>> {
+    let mut structures = Vec::new();
+    let mut rest = table;
+    while !rest.is_empty() {
+        let length = *rest.get(1)
+            .ok_or_else(|| malformed("truncated structure header"))? as usize;
+        if length < 4 || rest.len() < length {
+            return Err(malformed("truncated structure"));
+        }
+        let (formatted, remainder) = rest.split_at(length);
+
+        // The strings are terminated by an empty string, so a structure
+        // without any strings is followed by two NULs.
+        let end = remainder.windows(2).position(|pair| pair == [0, 0])
+            .ok_or_else(|| malformed("unterminated strings"))?;
+        let strings = match end {
+            0 => Vec::new(),
+            _ => remainder[..end].split(|byte| *byte == 0).collect(),
+        };
+        rest = &remainder[end + 2..];
+
+        let structure = Structure { formatted, strings };
+        if structure.kind() == END_OF_TABLE {
+            break;
+        }
+        structures.push(structure);
+    }
+    Ok(structures)
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Structure Types
+////
+
+fn system(structures: &[Structure]) -> SystemInfo {
+    let mut info = SystemInfo::default();
+    for structure in structures {
+        match structure.kind() {
+            BIOS_INFORMATION => info.bios_version = structure.string(0x05),
+            SYSTEM_INFORMATION => {
+                info.manufacturer = structure.string(0x04);
+                info.model = structure.string(0x05);
+                info.serial_number = structure.string(0x07);
+                info.sku = structure.string(0x19);
+            },
+            _ => {},
+        }
+    }
+    info
+}
+
+fn chassis(structure: &Structure) -> ChassisInfo {
+    // The SKU follows the contained elements, whose number and size vary
+    let elements = structure.byte(0x13).unwrap_or(0) as usize
+        * structure.byte(0x14).unwrap_or(0) as usize;
+    ChassisInfo {
+        manufacturer: structure.string(0x04),
+        serial_number: structure.string(0x07),
+        asset_tag: structure.string(0x08),
+        sku: structure.string(0x15 + elements),
+        ..Default::default()
+    }
+}
+
+// Only the families which identify an architecture are recognized
+fn architecture(family: u16) -> Option<ProcessorArchitecture> {
+    match family {
+        0x03..=0x06 | 0x0b..=0x15 | 0x18..=0x1d | 0x1f | 0x28..=0x2f
+            | 0x38..=0x3f | 0x46..=0x4f | 0x66..=0x6b | 0x83..=0x8f
+            | 0xa1..=0xb3 | 0xb5..=0xb7 | 0xb9..=0xc7 | 0xcd..=0xcf
+            | 0xd2..=0xf9 => Some(ProcessorArchitecture::X86),
+        0x82 | 0xb8 => Some(ProcessorArchitecture::IA64),
+        0x20..=0x27 => Some(ProcessorArchitecture::Power),
+        0x40..=0x45 => Some(ProcessorArchitecture::MIPS),
+        0x100..=0x102 | 0x118 | 0x119 => Some(ProcessorArchitecture::ARM),
+        0x200..=0x202 => Some(ProcessorArchitecture::RISCV),
+        _ => None,
+    }
+}
+
+fn instruction_set(architecture: ProcessorArchitecture, family: u16,
+                   wide: bool) -> Option<InstructionSet> {
+    match (architecture, wide) {
+        (ProcessorArchitecture::X86, true) => Some(InstructionSet::X86_64),
+        (ProcessorArchitecture::X86, false) => Some(InstructionSet::X86),
+        (ProcessorArchitecture::IA64, _) => Some(InstructionSet::IA64),
+        (ProcessorArchitecture::Power, _) => Some(InstructionSet::PowerISA),
+        (ProcessorArchitecture::MIPS, true) => Some(InstructionSet::MIPS64),
+        (ProcessorArchitecture::MIPS, false) => Some(InstructionSet::MIPS32),
+        (ProcessorArchitecture::ARM, true) => Some(InstructionSet::ARMA64),
+        (ProcessorArchitecture::ARM, false) => Some(InstructionSet::ARMA32),
+        (ProcessorArchitecture::RISCV, _) => match family {
+            0x200 => Some(InstructionSet::RV32),
+            0x201 => Some(InstructionSet::RV64),
+            _ => None,
+        },
+    }
+}
+
+fn processor(id: usize, structure: &Structure) -> ProcessorInventory {
+    let socket = structure.string(0x04);
+    let status = structure.byte(0x18).unwrap_or(0);
+    if status & SOCKET_POPULATED == 0 {
+        return ProcessorInventory {
+            id: id.to_string(), socket, state: State::Absent,
+            ..Default::default()
+        };
+    }
+
+    let family = match structure.byte(0x06) {
+        Some(FAMILY_2) => structure.word(0x28),
+        family => family.map(u16::from),
+    };
+    let wide = structure.word(0x26)
+        .is_some_and(|characteristics| characteristics & CAPABLE_64_BIT != 0);
+    let processor_architecture = family.and_then(architecture);
+    let instruction_set = processor_architecture.zip(family)
+        .and_then(|(architecture, family)| {
+            instruction_set(architecture, family, wide)
+        });
+
+    // Counts greater than 254 are given in the second count fields
+    let count = |offset: usize, offset_2: usize| match structure.byte(offset) {
+        Some(0xff) => structure.word(offset_2).map(u32::from),
+        count => count.map(u32::from),
+    }.filter(|count| *count != 0);
+    let (state, health) = match status & CPU_STATUS {
+        CPU_DISABLED_BY_USER => (State::Disabled, Health::OK),
+        CPU_DISABLED_BY_FIRMWARE => (State::Disabled, Health::Critical),
+        // Enabled, idle or unknown
+        _ => (State::Enabled, Health::OK),
+    };
+    ProcessorInventory {
+        id: id.to_string(),
+        socket,
+        processor_type: match structure.byte(0x05) {
+            Some(4) => ProcessorType::Accelerator,
+            Some(5) => ProcessorType::DSP,
+            Some(6) => ProcessorType::GPU,
+            _ => ProcessorType::CPU,
+        },
+        processor_architecture,
+        instruction_set,
+        manufacturer: structure.string(0x07),
+        model: structure.string(0x10),
+        max_speed_mhz: structure.word(0x14).filter(|speed| *speed != 0)
+            .map(u32::from),
+        total_cores: count(0x23, 0x2a),
+        total_threads: count(0x25, 0x2e),
+        state,
+        health,
+    }
+}
+
+fn memory_device_type(value: u8) -> Option<MemoryDeviceType> {
+    match value {
+        0x12 => Some(MemoryDeviceType::DDR),
+        0x13 => Some(MemoryDeviceType::DDR2),
+        0x18 => Some(MemoryDeviceType::DDR3),
+        0x1a => Some(MemoryDeviceType::DDR4),
+        0x1d => Some(MemoryDeviceType::LPDDR3),
+        0x1e => Some(MemoryDeviceType::LPDDR4),
+        0x22 => Some(MemoryDeviceType::DDR5),
+        0x23 => Some(MemoryDeviceType::LPDDR5),
+        _ => None,
+    }
+}
+
+fn capacity_mib(size: u16, structure: &Structure) -> Option<u32> {
+    match size {
+        SIZE_UNKNOWN => None,
+        SIZE_EXTENDED => structure.dword(0x1c).map(|size| size & 0x7fff_ffff),
+        size if size & SIZE_IN_KIB != 0 =>
+            Some(u32::from(size & !SIZE_IN_KIB) / 1024),
+        size => Some(u32::from(size)),
+    }
+}
+
+// Speeds too great for a word are given in a second, extended field
+fn speed(structure: &Structure, offset: usize, extended: usize) ->
+    Option<u32>
+{
+    match structure.word(offset)? {
+        0 => None,
+        0xffff => structure.dword(extended).filter(|speed| *speed != 0),
+        speed => Some(u32::from(speed)),
+    }
+}
+
+fn memory(id: usize, structure: &Structure) -> MemoryInventory {
+    let id = format!("DIMM{}", id);
+    let device_locator = structure.string(0x10);
+    let size = structure.word(0x0c).unwrap_or(SIZE_UNKNOWN);
+    if size == SIZE_NOT_INSTALLED {
+        return MemoryInventory {
+            id, device_locator, state: State::Absent, ..Default::default()
+        };
+    }
+    MemoryInventory {
+        id,
+        device_locator,
+        memory_device_type: structure.byte(0x12)
+            .and_then(memory_device_type),
+        capacity_mib: capacity_mib(size, structure),
+        operating_speed_mhz: speed(structure, 0x20, 0x58)
+            .or_else(|| speed(structure, 0x15, 0x54)),
+        manufacturer: structure.string(0x17),
+        serial_number: structure.string(0x18),
+        part_number: structure.string(0x1a),
+        ..Default::default()
+    }
+}
+
+// Read the inventory from an SMBIOS 3.x structure table
+pub fn parse(table: &[u8]) -> io::Result<SystemInventory> {
+    let structures = structures(table)?;
+    let of_kind = |kind: u8| {
+        structures.iter().filter(move |structure| structure.kind() == kind)
+    };
+    Ok(SystemInventory {
+        system: system(&structures),
+        chassis: of_kind(SYSTEM_ENCLOSURE).next().map(chassis)
+            .unwrap_or_default(),
+        processors: of_kind(PROCESSOR_INFORMATION).enumerate()
+            .map(|(index, structure)| processor(index, structure))
+            .collect(),
+        memory: of_kind(MEMORY_DEVICE).enumerate()
+            .map(|(index, structure)| memory(index, structure))
+            .collect(),
+
+        // The network interfaces of the host aren't described by SMBIOS
+        ethernet_interfaces: Vec::new(),
+    })
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Smbios
+////
+
+// The table exported by the firmware, when librebmc runs on the host itself,
+// or a copy of it uploaded by an agent on the host.
+pub struct Smbios {
+    table: ParsedFile,
+}
+
+impl Smbios {
+    pub fn new(path: &Path) -> Self {
+        Smbios { table: ParsedFile::new(path) }
+    }
+}
+
+impl InventorySource for Smbios {
+    // Until the table has been uploaded, the host has no inventory
+    fn inventory(&self) -> io::Result<SystemInventory> {
+        self.table.read(parse)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn structure(kind: u8, formatted: &[u8], strings: &[&str]) -> Vec<u8> {
+        let mut structure = vec![kind, formatted.len() as u8 + 4, 0, 0];
+        structure.extend_from_slice(formatted);
+        for string in strings {
+            structure.extend_from_slice(string.as_bytes());
+            structure.push(0);
+        }
+        if strings.is_empty() {
+            structure.push(0);
+        }
+        structure.push(0);
+        structure
+    }
+
+    #[test]
+    fn missing_strings() {
+        let mut table = structure(BIOS_INFORMATION, &[0, 0], &[]);
+        table.extend(structure(
+            SYSTEM_INFORMATION, &[1, 0, 0, 2], &["Vendor", "  "]));
+        table.extend(structure(END_OF_TABLE, &[], &[]));
+        table.extend([0; 16]);
+        let inventory = parse(&table).unwrap();
+        assert_eq!(SystemInfo {
+            manufacturer: Some("Vendor".to_string()),
+            ..Default::default()
+        }, inventory.system);
+    }
+
+    #[test]
+    fn extended_fields() {
+        let mut processor = [0u8; 0x30 - 4];
+        processor[0x06 - 4] = FAMILY_2;
+        processor[0x18 - 4] = SOCKET_POPULATED | 1;
+        processor[0x23 - 4] = 0xff;
+        processor[0x26 - 4] = CAPABLE_64_BIT as u8;
+        processor[0x28 - 4..0x2c - 4].copy_from_slice(&[1, 1, 0, 1]);
+        let mut memory = [0u8; 0x28 - 4];
+        memory[0x0c - 4..0x0e - 4].copy_from_slice(&[0x00, 0x88]);
+        let mut table = structure(PROCESSOR_INFORMATION, &processor, &[]);
+        table.extend(structure(MEMORY_DEVICE, &memory, &[]));
+        memory[0x0c - 4..0x0e - 4].copy_from_slice(&[0xff, 0x7f]);
+        memory[0x1c - 4..0x20 - 4].copy_from_slice(&[0, 0, 2, 0]);
+        table.extend(structure(MEMORY_DEVICE, &memory, &[]));
+
+        let inventory = parse(&table).unwrap();
+        let processor = &inventory.processors[0];
+        assert_eq!(Some(ProcessorArchitecture::ARM),
+                   processor.processor_architecture);
+        assert_eq!(Some(InstructionSet::ARMA64), processor.instruction_set);
+        assert_eq!(Some(256), processor.total_cores);
+        assert_eq!(None, processor.total_threads);
+        assert_eq!(Some(2), inventory.memory[0].capacity_mib);
+        assert_eq!(Some(131072), inventory.memory[1].capacity_mib);
+    }
+
+    #[test]
+    fn truncated_tables() {
+        let table = structure(SYSTEM_INFORMATION, &[1, 2, 3, 4], &["Vendor"]);
+        assert!(parse(&table[..6]).is_err());
+        assert!(parse(&table[..table.len() - 1]).is_err());
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
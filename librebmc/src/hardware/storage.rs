@@ -0,0 +1,150 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            storage.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     The storage controllers, drives and volumes of the host.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::io;
+
+use serde::Serialize;
+
+use crate::models::{Health, State};
+
+///////////////////////////////////////////////////////////////////////////////
+// Supporting Enums
+////
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum MediaType {
+    HDD,
+    SSD,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum Protocol {
+    PCIe,
+    AHCI,
+    SAS,
+    SATA,
+    USB,
+    NVMe,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum RaidType {
+    RAID0,
+    RAID1,
+    RAID5,
+    RAID6,
+    RAID10,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Inventory
+////
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ControllerInventory {
+    pub name: Option<String>,
+    pub manufacturer: Option<String>,
+    pub model: Option<String>,
+    pub serial_number: Option<String>,
+    pub firmware_version: Option<String>,
+    pub controller_protocols: Vec<Protocol>,
+    pub device_protocols: Vec<Protocol>,
+    pub state: State,
+    pub health: Health,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DriveInventory {
+    pub id: String,
+    pub name: Option<String>,
+    pub manufacturer: Option<String>,
+    pub model: Option<String>,
+    pub serial_number: Option<String>,
+    pub revision: Option<String>,
+    pub capacity_bytes: Option<u64>,
+    pub media_type: Option<MediaType>,
+    pub protocol: Option<Protocol>,
+    pub predicted_media_life_left_percent: Option<f64>,
+    pub state: State,
+    pub health: Health,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct VolumeInventory {
+    pub id: String,
+    pub name: Option<String>,
+    pub capacity_bytes: Option<u64>,
+    pub raid_type: Option<RaidType>,
+
+    // The ids of the drives the volume is placed on
+    pub drives: Vec<String>,
+    pub state: State,
+    pub health: Health,
+}
+
+// A storage subsystem: the controllers, and the drives and volumes that are
+// attached to them.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StorageInventory {
+    pub id: String,
+    pub name: Option<String>,
+    pub controllers: Vec<ControllerInventory>,
+    pub drives: Vec<DriveInventory>,
+    pub volumes: Vec<VolumeInventory>,
+}
+
+impl StorageInventory {
+    // The worst health of anything in the subsystem
+    pub fn health(&self) -> Health {
+        let controllers = self.controllers.iter()
+            .map(|controller| controller.health);
+        let drives = self.drives.iter().map(|drive| drive.health);
+        let volumes = self.volumes.iter().map(|volume| volume.health);
+        controllers.chain(drives).chain(volumes)
+            .fold(Health::OK, Health::worst)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// StorageProvider
+////
+
+// Like an InventorySource, the provider is read whenever the storage is
+// requested, because drives may be replaced while the host is running.
+pub trait StorageProvider: Send + Sync {
+    fn storage(&self) -> io::Result<Vec<StorageInventory>>;
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,107 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            thermal.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Interface to fans and their controllers.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::io;
+
+use serde::{Deserialize, Serialize};
+
+use crate::hardware::sensor::PhysicalContext;
+use crate::models::{Health, State};
+
+///////////////////////////////////////////////////////////////////////////////
+// Supporting Types
+////
+
+// Information about a fan that does not change while it's installed
+#[derive(Clone, Debug, Default)]
+pub struct FanInventory {
+    pub id: String,
+    pub name: String,
+    pub manufacturer: Option<String>,
+    pub model: Option<String>,
+    pub serial_number: Option<String>,
+    pub part_number: Option<String>,
+    pub physical_context: Option<PhysicalContext>,
+    pub hot_pluggable: bool,
+
+    // Whether the speed of this fan can be overridden
+    pub controllable: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct FanReading {
+    pub state: State,
+    pub health: Health,
+    pub speed_rpm: Option<f64>,
+    pub speed_percent: Option<f64>,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub enum FanMode {
+    // The speed of the fan is governed by the platform's thermal policy
+    Automatic,
+
+    // The speed of the fan is held at a fixed duty cycle
+    Manual,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FanControl {
+    Automatic,
+    Manual(f64),
+}
+
+impl FanControl {
+    pub fn mode(&self) -> FanMode {
+        match self {
+            FanControl::Automatic => FanMode::Automatic,
+            FanControl::Manual(_) => FanMode::Manual,
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Fan
+////
+
+pub trait Fan: Send + Sync {
+    fn inventory(&self) -> &FanInventory;
+    fn read(&self) -> io::Result<FanReading>;
+
+    // Fans that are controllable override these.
+    fn control(&self) -> FanControl { FanControl::Automatic }
+    fn set_control(&self, _control: FanControl) -> io::Result<()> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,59 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            time.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     The NTP settings of the BMC, and the trait of the backends
+//                  that apply them.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::io;
+
+///////////////////////////////////////////////////////////////////////////////
+// NtpSettings
+////
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NtpSettings {
+    pub enabled: bool,
+
+    // The host names or addresses of the servers
+    pub servers: Vec<String>,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// TimeSync
+////
+
+// Whatever synchronizes the clock of the BMC, e.g. systemd-timesyncd or
+// chrony.
+pub trait TimeSync: Send + Sync {
+    fn ntp(&self) -> io::Result<NtpSettings>;
+    fn set_ntp(&self, settings: &NtpSettings) -> io::Result<()>;
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,179 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            timesyncd.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     NTP settings applied through systemd-timesyncd.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use crate::hardware::time::{NtpSettings, TimeSync};
+
+pub const TIMESYNCD_CONF: &'static str =
+    "/etc/systemd/timesyncd.conf.d/librebmc.conf";
+pub const TIMEDATECTL: &'static str = "/usr/bin/timedatectl";
+pub const SYSTEMCTL: &'static str = "/usr/bin/systemctl";
+
+const HEADER: &'static str =
+    "# Written by librebmc. Changes to this file will be overwritten.\n";
+
+fn run(command: &mut Command) -> io::Result<Vec<u8>> {
+    let output = command
+        .stdin(Stdio::null())
+        .stderr(Stdio::null())
+        .output()?;
+    match output.status.success() {
+        true => Ok(output.stdout),
+        false => Err(io::Error::other(
+            format!("{:?} exited with {}", command.get_program(),
+                    output.status))),
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Timesyncd
+////
+
+// The servers are kept in a drop-in of the configuration of timesyncd, and
+// whether it runs at all is decided by timedatectl.
+pub struct Timesyncd {
+    path: PathBuf,
+    timedatectl: PathBuf,
+    systemctl: PathBuf,
+}
+
+impl Timesyncd {
+    pub fn new(path: &Path, timedatectl: &Path, systemctl: &Path) -> Self {
+        Timesyncd {
+            path: path.to_owned(), timedatectl: timedatectl.to_owned(),
+            systemctl: systemctl.to_owned(),
+        }
+    }
+}
+
+impl TimeSync for Timesyncd {
+    fn ntp(&self) -> io::Result<NtpSettings> {
+        let enabled = run(Command::new(&self.timedatectl)
+                          .args(["show", "--property=NTP", "--value"]))?;
+        let servers = match fs::read_to_string(&self.path) {
+            Ok(contents) => contents.lines()
+                .filter_map(|line| line.trim().strip_prefix("NTP="))
+                .flat_map(str::split_whitespace)
+                .map(str::to_string)
+                .collect(),
+            Err(error) if error.kind() == io::ErrorKind::NotFound =>
+                Vec::new(),
+            Err(error) => return Err(error),
+        };
+        Ok(NtpSettings {
+            enabled: String::from_utf8_lossy(&enabled).trim() == "yes",
+            servers,
+        })
+    }
+
+    fn set_ntp(&self, settings: &NtpSettings) -> io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let temporary = self.path.with_extension("tmp");
+        fs::write(&temporary, format!(
+            "{}\n[Time]\nNTP={}\n", HEADER, settings.servers.join(" ")))?;
+        fs::rename(&temporary, &self.path)?;
+
+        // timesyncd only reads its configuration when it starts
+        run(Command::new(&self.systemctl)
+            .args(["try-restart", "systemd-timesyncd.service"]))?;
+        let enabled = match settings.enabled {
+            true => "true",
+            false => "false",
+        };
+        run(Command::new(&self.timedatectl).args(["set-ntp", enabled]))?;
+        Ok(())
+    }
+}
+
+impl Default for Timesyncd {
+    fn default() -> Self {
+        Timesyncd::new(Path::new(TIMESYNCD_CONF), Path::new(TIMEDATECTL),
+                       Path::new(SYSTEMCTL))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::PermissionsExt;
+
+    // A timedatectl that keeps the state of NTP in a file beside it, and a
+    // systemctl that records its arguments.
+    fn timesyncd(root: &Path) -> Timesyncd {
+        let state = root.join("ntp");
+        fs::write(&state, "no\n").unwrap();
+        let timedatectl = root.join("timedatectl");
+        fs::write(&timedatectl, format!(
+            "#!/bin/sh\n\
+             case \"$1\" in\n\
+             show) cat {0} ;;\n\
+             set-ntp) [ \"$2\" = true ] && echo yes > {0} \
+                      || echo no > {0} ;;\n\
+             esac\n", state.display())).unwrap();
+        let systemctl = root.join("systemctl");
+        fs::write(&systemctl, format!(
+            "#!/bin/sh\necho \"$*\" >> {}\n",
+            root.join("systemctl.log").display())).unwrap();
+        for path in [&timedatectl, &systemctl] {
+            fs::set_permissions(path, fs::Permissions::from_mode(0o755))
+                .unwrap();
+        }
+        Timesyncd::new(&root.join("timesyncd.conf.d/librebmc.conf"),
+                       &timedatectl, &systemctl)
+    }
+
+    #[test]
+    fn servers_are_applied() {
+        let root = tempfile::tempdir().unwrap();
+        let timesyncd = timesyncd(root.path());
+        assert_eq!(NtpSettings::default(), timesyncd.ntp().unwrap());
+
+        let settings = NtpSettings {
+            enabled: true,
+            servers: vec!["0.pool.ntp.org".to_string(),
+                          "10.0.0.123".to_string()],
+        };
+        timesyncd.set_ntp(&settings).unwrap();
+        assert_eq!(settings, timesyncd.ntp().unwrap());
+        assert_eq!("try-restart systemd-timesyncd.service\n",
+                   fs::read_to_string(root.path().join("systemctl.log"))
+                   .unwrap());
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,167 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            health.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Health rollup across the resource tree. Rollups are
+//                  recomputed when the health of a subordinate resource
+//                  changes, rather than on every request.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::sync::{Arc, Mutex, RwLock, Weak};
+
+use crate::models::{Health, State, Status};
+
+// A resource that contributes to the health of the resources above it. The
+// rollup of a node is the worst of its own health and the rollups of its
+// children.
+pub struct HealthNode {
+    health: Mutex<Health>,
+    rollup: Mutex<Health>,
+    children: RwLock<Vec<Arc<HealthNode>>>,
+    parents: RwLock<Vec<Weak<HealthNode>>>,
+}
+
+impl HealthNode {
+    pub fn new() -> Arc<Self> {
+        Arc::new(HealthNode {
+            health: Mutex::new(Health::OK),
+            rollup: Mutex::new(Health::OK),
+            children: RwLock::new(Vec::new()),
+            parents: RwLock::new(Vec::new()),
+        })
+    }
+
+    pub fn health(&self) -> Health { *self.health.lock().unwrap() }
+    pub fn rollup(&self) -> Health { *self.rollup.lock().unwrap() }
+
+    pub fn add_child(self: &Arc<Self>, child: &Arc<HealthNode>) {
+        self.children.write().unwrap().push(child.clone());
+        child.parents.write().unwrap().push(Arc::downgrade(self));
+        self.recompute();
+    }
+
+    pub fn set_health(&self, health: Health) {
+        let changed = {
+            let mut current = self.health.lock().unwrap();
+            let changed = *current != health;
+            *current = health;
+            changed
+        };
+        if changed {
+            self.recompute();
+        }
+    }
+
+    // The status of the resource. Only resources with subordinates report a
+    // rollup.
+    pub fn status(&self, state: State) -> Status {
+        let health_rollup = match self.children.read().unwrap().is_empty() {
+            true => None,
+            false => Some(self.rollup()),
+        };
+        Status { state, health: self.health(), health_rollup }
+    }
+
+    fn recompute(&self) {
+        // Locks are only ever taken from ancestor to descendant, and released
+        // before notifying the parents.
+        let changed = {
+            let mut rollup = self.rollup.lock().unwrap();
+            let health = self.children.read().unwrap().iter()
+                .map(|child| child.rollup())
+                .fold(self.health(), Health::worst);
+            let changed = *rollup != health;
+            *rollup = health;
+            changed
+        };
+        if changed {
+            let parents = self.parents.read().unwrap().clone();
+            parents.iter()
+                .filter_map(Weak::upgrade)
+                .for_each(|parent| parent.recompute());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rollup_propagates_to_every_ancestor() {
+        let system = HealthNode::new();
+        let chassis = HealthNode::new();
+        let thermal = HealthNode::new();
+        let (cpu, inlet) = (HealthNode::new(), HealthNode::new());
+        system.add_child(&chassis);
+        chassis.add_child(&thermal);
+        thermal.add_child(&cpu);
+        thermal.add_child(&inlet);
+
+        cpu.set_health(Health::Critical);
+        inlet.set_health(Health::Warning);
+        assert_eq!(Health::Critical, system.rollup());
+        assert_eq!(Health::OK, system.health());
+        assert_eq!(Status {
+            state: State::Enabled, health: Health::OK,
+            health_rollup: Some(Health::Critical),
+        }, chassis.status(State::Enabled));
+        assert_eq!(None, cpu.status(State::Enabled).health_rollup);
+
+        cpu.set_health(Health::OK);
+        assert_eq!(Health::Warning, system.rollup());
+        inlet.set_health(Health::OK);
+        assert_eq!(Health::OK, system.rollup());
+    }
+
+    #[test]
+    fn rollup_includes_own_health() {
+        let (parent, child) = (HealthNode::new(), HealthNode::new());
+        child.set_health(Health::Warning);
+        parent.add_child(&child);
+        assert_eq!(Health::Warning, parent.rollup());
+        parent.set_health(Health::Critical);
+        assert_eq!(Health::Critical, parent.rollup());
+        parent.set_health(Health::OK);
+        assert_eq!(Health::Warning, parent.rollup());
+    }
+
+    #[test]
+    fn shared_subordinates() {
+        // A chassis may be contained by more than one system
+        let (first, second) = (HealthNode::new(), HealthNode::new());
+        let chassis = HealthNode::new();
+        first.add_child(&chassis);
+        second.add_child(&chassis);
+        chassis.set_health(Health::Critical);
+        assert_eq!(Health::Critical, first.rollup());
+        assert_eq!(Health::Critical, second.rollup());
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
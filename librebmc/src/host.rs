@@ -0,0 +1,320 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            host.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Tracks the power of the host through its resets, and the
+//                  settings that govern them.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::thread;
+use std::time::Duration;
+
+use odata::{Link, ResourceMetadata};
+use serde::{Deserialize, Serialize};
+
+use crate::bus::{EventBus, StateChange};
+use crate::events::EventType;
+use crate::hardware::power::HostPower;
+use crate::models::{ComputerSystem, PowerState, ResetType};
+use crate::registries::resource_event;
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub enum PowerRestorePolicy {
+    AlwaysOn,
+    AlwaysOff,
+    #[default]
+    LastState,
+}
+
+// The settings of the host, which are kept across restarts of librebmc
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct PowerSettings {
+    pub power_restore_policy: PowerRestorePolicy,
+    pub power_on_delay_seconds: f64,
+    pub power_cycle_delay_seconds: f64,
+
+    // The state that the host was last seen in, for the LastState policy
+    pub last_state: Option<PowerState>,
+}
+
+fn delay(seconds: f64) {
+    if seconds > 0.0 {
+        thread::sleep(Duration::from_secs_f64(seconds));
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Host
+////
+
+pub struct Host {
+    backend: Arc<dyn HostPower>,
+    path: PathBuf,
+    settings: Mutex<PowerSettings>,
+
+    // The state reported while a reset is in progress, in place of the
+    // backend's. Resets are performed one at a time.
+    transition: Mutex<Option<PowerState>>,
+    resetting: Mutex<()>,
+
+    // The state that the host was last observed in
+    state: Mutex<Option<PowerState>>,
+
+    bus: OnceLock<(EventBus, PathBuf)>,
+}
+
+impl Host {
+    // Manage the host through the backend, keeping its settings in the file
+    // at path.
+    pub fn open(backend: Arc<dyn HostPower>, path: &Path) ->
+        io::Result<Arc<Self>>
+    {
+        let settings = match fs::read(path) {
+            Ok(contents) => serde_json::from_slice(&contents)?,
+            Err(error) if error.kind() == io::ErrorKind::NotFound =>
+                PowerSettings::default(),
+            Err(error) => return Err(error),
+        };
+        Ok(Arc::new(Host {
+            backend, path: path.to_owned(), settings: Mutex::new(settings),
+            transition: Mutex::new(None), resetting: Mutex::new(()),
+            state: Mutex::new(None), bus: OnceLock::new(),
+        }))
+    }
+
+    // Publish changes in the power of the host as changes to the origin
+    pub fn publish_to(&self, bus: &EventBus, origin: &Path) {
+        let _ = self.bus.set((bus.clone(), origin.to_owned()));
+    }
+
+    pub fn settings(&self) -> PowerSettings {
+        self.settings.lock().unwrap().clone()
+    }
+
+    pub fn set_settings(&self, settings: &PowerSettings) -> io::Result<()> {
+        let mut current = self.settings.lock().unwrap();
+        let settings = PowerSettings {
+            last_state: current.last_state, ..settings.clone()
+        };
+        save(&self.path, &settings)?;
+        *current = settings;
+        Ok(())
+    }
+
+    pub fn reset_types(&self) -> Vec<ResetType> {
+        self.backend.reset_types()
+    }
+
+    // The state of the host, as it was last observed. The backend may be
+    // slow, so it's only read by observe, which isn't called for requests.
+    pub fn power_state(&self) -> Option<PowerState> {
+        let transition = *self.transition.lock().unwrap();
+        transition.or(*self.state.lock().unwrap())
+    }
+
+    // Read the state of the host from the backend, and record it if it's
+    // changed since it was last seen.
+    pub fn observe(&self) -> io::Result<PowerState> {
+        let state = self.backend.power_state()?;
+        *self.state.lock().unwrap() = Some(state);
+        let mut settings = self.settings.lock().unwrap();
+        if !matches!(state, PowerState::On | PowerState::Off)
+            || settings.last_state == Some(state) {
+            return Ok(state);
+        }
+        let previous = settings.last_state.replace(state);
+        save(&self.path, &settings)?;
+        drop(settings);
+
+        if let (Some(_), Some((bus, origin))) = (previous, self.bus.get()) {
+            let resource = origin.to_string_lossy();
+            let message = match state {
+                PowerState::On => resource_event::resource_powered_on(
+                    &resource),
+                _ => resource_event::resource_powered_off(&resource),
+            };
+            bus.publish(StateChange::new(
+                EventType::StatusChange, Link::from(origin.clone()),
+                Some(ComputerSystem::ODATA_TYPE), message));
+        }
+        Ok(state)
+    }
+
+    // Perform a step of a reset, reporting the state in between
+    fn transition<F>(&self, state: PowerState, step: F) -> io::Result<()>
+    where F: FnOnce() -> io::Result<()>
+    {
+        *self.transition.lock().unwrap() = Some(state);
+        let result = step();
+        *self.transition.lock().unwrap() = None;
+        result?;
+        self.observe().map(|_| ())
+    }
+
+    // Reset the host, blocking until it's complete. Powering on is delayed
+    // by PowerOnDelaySeconds, and power cycles are split in two so that the
+    // host stays off for PowerCycleDelaySeconds.
+    pub fn reset(&self, reset_type: ResetType) -> io::Result<()> {
+        let _resetting = self.resetting.lock().unwrap();
+        let settings = self.settings();
+        let backend = &self.backend;
+        let supported = backend.reset_types();
+        match reset_type {
+            ResetType::On | ResetType::ForceOn => {
+                self.transition(PowerState::PoweringOn, || {
+                    delay(settings.power_on_delay_seconds);
+                    backend.reset(reset_type)
+                })
+            },
+            ResetType::PowerCycle | ResetType::FullPowerCycle
+                if settings.power_cycle_delay_seconds > 0.0
+                && supported.contains(&ResetType::ForceOff)
+                && supported.contains(&ResetType::On) =>
+            {
+                self.transition(PowerState::PoweringOff, || {
+                    backend.reset(ResetType::ForceOff)
+                })?;
+                self.transition(PowerState::PoweringOn, || {
+                    delay(settings.power_cycle_delay_seconds);
+                    backend.reset(ResetType::On)
+                })
+            },
+            ResetType::ForceOff | ResetType::GracefulShutdown
+                | ResetType::PowerCycle | ResetType::FullPowerCycle => {
+                self.transition(PowerState::PoweringOff, || {
+                    backend.reset(reset_type)
+                })
+            },
+            ResetType::PushPowerButton => {
+                let state = match backend.power_state()? {
+                    PowerState::Off => PowerState::PoweringOn,
+                    _ => PowerState::PoweringOff,
+                };
+                self.transition(state, || backend.reset(reset_type))
+            },
+            _ => {
+                backend.reset(reset_type)?;
+                self.observe().map(|_| ())
+            },
+        }
+    }
+
+    // Apply the restore policy, as though power has just been restored to
+    // the host. A host that's running is never powered off.
+    pub fn restore(&self) -> io::Result<()> {
+        let settings = self.settings();
+        let power_on = match settings.power_restore_policy {
+            PowerRestorePolicy::AlwaysOn => true,
+            PowerRestorePolicy::AlwaysOff => false,
+            PowerRestorePolicy::LastState =>
+                settings.last_state == Some(PowerState::On),
+        };
+        match power_on && self.backend.power_state()? == PowerState::Off {
+            true => self.reset(ResetType::On),
+            false => self.observe().map(|_| ()),
+        }
+    }
+}
+
+// Replace the file atomically, so that a crash can't lose the settings
+fn save(path: &Path, settings: &PowerSettings) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let temporary = path.with_extension("tmp");
+    fs::write(&temporary, serde_json::to_vec_pretty(settings)?)?;
+    fs::rename(&temporary, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hardware::simulator::SimulatedHost;
+
+    fn host(directory: &Path, state: PowerState) -> Arc<Host> {
+        Host::open(Arc::new(SimulatedHost::new(state)),
+                   &directory.join("host.json")).unwrap()
+    }
+
+    #[test]
+    fn power_is_restored_by_policy() {
+        let directory = tempfile::tempdir().unwrap();
+        host(directory.path(), PowerState::On).restore().unwrap();
+        let restored = host(directory.path(), PowerState::Off);
+        restored.restore().unwrap();
+        assert_eq!(PowerState::On, restored.power_state().unwrap());
+
+        // A host that's running is left alone, even if it should be off
+        restored.set_settings(&PowerSettings {
+            power_restore_policy: PowerRestorePolicy::AlwaysOff,
+            ..Default::default()
+        }).unwrap();
+        for state in [PowerState::Off, PowerState::On] {
+            let restored = host(directory.path(), state);
+            restored.restore().unwrap();
+            assert_eq!(state, restored.power_state().unwrap());
+        }
+    }
+
+    #[test]
+    fn power_state_is_served_from_observations() {
+        let directory = tempfile::tempdir().unwrap();
+        let host = host(directory.path(), PowerState::Off);
+        assert_eq!(None, host.power_state());
+        host.observe().unwrap();
+        host.backend.reset(ResetType::On).unwrap();
+        assert_eq!(Some(PowerState::Off), host.power_state());
+        host.observe().unwrap();
+        assert_eq!(Some(PowerState::On), host.power_state());
+    }
+
+    #[test]
+    fn changes_in_power_are_published() {
+        let directory = tempfile::tempdir().unwrap();
+        let bus = EventBus::new();
+        let mut changes = bus.subscribe();
+        let host = host(directory.path(), PowerState::Off);
+        host.publish_to(&bus, Path::new("/redfish/v1/Systems/system"));
+        host.restore().unwrap();
+        host.reset(ResetType::On).unwrap();
+        host.reset(ResetType::ForceOff).unwrap();
+        let ids: Vec<String> = (0..2)
+            .map(|_| changes.try_recv().unwrap().message.message_id)
+            .collect();
+        assert_eq!(vec!["ResourceEvent.1.3.0.ResourcePoweredOn",
+                        "ResourceEvent.1.3.0.ResourcePoweredOff"], ids);
+        assert!(changes.try_recv().is_err());
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
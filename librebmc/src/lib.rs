@@ -0,0 +1,518 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            lib.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Library interface to the librebmc service, shared by the
+//                  server and its tooling.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::path::{Path, PathBuf};
+use std::io;
+use std::process;
+use std::sync::Arc;
+
+use odata::Resource;
+use uuid::Uuid;
+
+pub mod audit;
+pub mod bus;
+pub mod config;
+pub mod events;
+pub mod hardware;
+pub mod health;
+pub mod host;
+pub mod logs;
+pub mod models;
+pub mod monitor;
+pub mod protocols;
+pub mod registries;
+pub mod service;
+pub mod tasks;
+pub mod update;
+
+use crate::audit::AuditLog;
+use crate::bus::{EventBus, StateChange};
+use crate::config::{Config, FanProviderConfig, HostProviderConfig,
+                    InventoryProviderConfig, JournalConfig, LogConfig,
+                    NetworkProviderConfig, PlatformUpdaterConfig,
+                    PowerSupplyProviderConfig, SensorProviderConfig,
+                    StorageProviderConfig, TimeProviderConfig};
+use crate::events::{EventBroker, EventType};
+use crate::hardware::block::BlockDevices;
+use crate::hardware::boot::{BootControl, CachedBoot};
+use crate::hardware::hwmon::Hwmon;
+use crate::hardware::inventory::{InventoryFile, InventorySource};
+use crate::hardware::ipmitool::Ipmitool;
+use crate::hardware::network::NetworkControl;
+use crate::hardware::networkd::Networkd;
+use crate::hardware::power::{self, HostPower, SupplyInputPower};
+use crate::hardware::sensor::{ReadingType, Sensor as _, SensorProvider};
+use crate::hardware::simulator::{
+    SimulatedBoot, SimulatedFan, SimulatedHost, SimulatedInventory,
+    SimulatedNetwork, SimulatedPowerSupply, SimulatedSensors,
+    SimulatedStorage, SimulatedTimeSync, SimulatedUpdater,
+};
+use crate::hardware::smbios::Smbios;
+use crate::hardware::storage::StorageProvider;
+use crate::hardware::thermal;
+use crate::hardware::time::TimeSync;
+use crate::hardware::timesyncd::Timesyncd;
+use crate::health::HealthNode;
+use crate::host::Host;
+use crate::logs::{LogSource, LogStore};
+use crate::logs::journald::Journald;
+use crate::logs::syslog::Syslog;
+use crate::models::{Health, PowerState};
+use crate::models::computer_system::Inventory;
+use crate::models::{Chassis, ChassisBuilder, Collection, ComputerSystemBuilder,
+                    EnvironmentMetrics, EventDestinationCollection,
+                    EventService, Fan, LogEntryType, LogService,
+                    LogServiceCollection, ManagerBuilder, ManagerInterfaces,
+                    ManagerNetworkProtocol, Member, PowerSubsystem,
+                    PowerSupply, Sensor, ServiceRoot, ServiceRootBuilder,
+                    SystemStorage, TaskService, ThermalMetrics,
+                    ThermalSubsystem, UpdateService};
+use crate::monitor::SensorMonitor;
+use crate::protocols::Protocols;
+use crate::service::{Dispatch, ODataResource, ResourceService};
+use crate::tasks::TaskManager;
+use crate::update::{Staging, Updater, Updaters};
+use crate::update::image::{TrustedKeys, Verifier};
+use crate::update::slots::{RESTART_STATUS, SlotUpdater, Slots};
+
+pub const SERVICE_ROOT: &'static str = "/redfish/v1";
+
+// Place each member of a collection at <path>/<id>
+fn collection<T, F>(path: PathBuf, members: Vec<T>, id: F) ->
+    ODataResource<Collection<T>>
+where T: Member + odata::Serialize + odata::ResourceMetadata + Clone
+       + Dispatch,
+      F: Fn(&T) -> String,
+{
+    let members = members.into_iter()
+        .map(|member| Resource::new(path.join(id(&member)), member).into())
+        .collect();
+    Resource::new(path, Collection::new(members)).into()
+}
+
+fn sensor_provider(config: &Config) -> Box<dyn SensorProvider> {
+    match &config.sensors.provider {
+        SensorProviderConfig::Simulator => Box::new(SimulatedSensors),
+        SensorProviderConfig::Hwmon { root } => Box::new(Hwmon::new(root)),
+    }
+}
+
+// The backends that control the power and boot override of the host
+fn host_provider(config: &Config) ->
+    (Arc<dyn HostPower>, Arc<dyn BootControl>)
+{
+    match &config.host.provider {
+        HostProviderConfig::Simulator => (
+            Arc::new(SimulatedHost::new(PowerState::Off)),
+            Arc::new(SimulatedBoot::new()),
+        ),
+        HostProviderConfig::Ipmitool { command, arguments } => {
+            let ipmitool = Arc::new(Ipmitool::new(command, arguments));
+            (ipmitool.clone(), ipmitool)
+        },
+    }
+}
+
+fn inventory_source(config: &Config) -> Arc<dyn InventorySource> {
+    match &config.host.inventory {
+        InventoryProviderConfig::Simulator => Arc::new(SimulatedInventory),
+        InventoryProviderConfig::Static(inventory) =>
+            Arc::new(inventory.as_ref().clone()),
+        InventoryProviderConfig::File { path } =>
+            Arc::new(InventoryFile::new(path)),
+        InventoryProviderConfig::Smbios { path } =>
+            Arc::new(Smbios::new(path)),
+    }
+}
+
+fn storage_provider(config: &Config) -> Arc<dyn StorageProvider> {
+    match &config.host.storage {
+        StorageProviderConfig::Simulator => Arc::new(SimulatedStorage),
+        StorageProviderConfig::Sysfs { root, nvme } =>
+            Arc::new(BlockDevices::new(root, nvme)),
+    }
+}
+
+fn time_sync(config: &Config) -> Arc<dyn TimeSync> {
+    match &config.network.time {
+        TimeProviderConfig::Simulator => Arc::new(SimulatedTimeSync::new()),
+        TimeProviderConfig::Timesyncd { path, timedatectl, systemctl } =>
+            Arc::new(Timesyncd::new(path, timedatectl, systemctl)),
+    }
+}
+
+fn power_supplies(config: &Config) -> Vec<Arc<dyn power::PowerSupply>> {
+    match &config.chassis.power_supplies {
+        PowerSupplyProviderConfig::Simulator => vec![
+            Arc::new(SimulatedPowerSupply::new("0", 1600.0, 212.0)),
+            Arc::new(SimulatedPowerSupply::new("1", 1600.0, 208.0)),
+        ],
+        PowerSupplyProviderConfig::Hwmon { root } =>
+            Hwmon::new(root).power_supplies().unwrap_or_else(|error| {
+                eprintln!("failed to find power supplies: {}", error);
+                Vec::new()
+            }),
+        PowerSupplyProviderConfig::None => Vec::new(),
+    }
+}
+
+fn fans(config: &Config) -> Vec<Arc<dyn thermal::Fan>> {
+    match &config.chassis.fans {
+        FanProviderConfig::Simulator => (0..4)
+            .map(|index| Arc::new(SimulatedFan::new(&index.to_string(),
+                                                    16000.0))
+                 as Arc<dyn thermal::Fan>)
+            .collect(),
+        FanProviderConfig::Hwmon { root } =>
+            Hwmon::new(root).fans().unwrap_or_else(|error| {
+                eprintln!("failed to find fans: {}", error);
+                Vec::new()
+            }),
+        FanProviderConfig::None => Vec::new(),
+    }
+}
+
+fn network_control(config: &Config) -> Arc<dyn NetworkControl> {
+    match &config.network.provider {
+        NetworkProviderConfig::Simulator => Arc::new(SimulatedNetwork::new()),
+        NetworkProviderConfig::Networkd {
+            root, ip, directory, networkctl, resolv_conf,
+        } => Arc::new(Networkd::new(root, ip, directory, networkctl,
+                                    resolv_conf)),
+    }
+}
+
+// Sample the health of each component into a new child of the parent node
+fn watch<T, F>(monitor: &mut SensorMonitor, parent: &Arc<HealthNode>,
+               components: &[Arc<T>], probe: F)
+where T: ?Sized + Send + Sync + 'static,
+      F: Fn(&T) -> Health + Clone + Send + Sync + 'static,
+{
+    for component in components {
+        let node = HealthNode::new();
+        let (component, probe) = (component.clone(), probe.clone());
+        monitor.watch(&node, move || probe(&component));
+        parent.add_child(&node);
+    }
+}
+
+fn chassis(path: PathBuf, monitor: &mut SensorMonitor, bus: &EventBus,
+           health: Arc<HealthNode>, supplies: Vec<Arc<dyn power::PowerSupply>>,
+           fans: Vec<Arc<dyn thermal::Fan>>,
+           inventory: Arc<dyn InventorySource>) -> Chassis
+{
+    let power_health = HealthNode::new();
+    watch(monitor, &power_health, &supplies, |supply| {
+        supply.read().map_or(Health::Warning, |reading| reading.health)
+    });
+    health.add_child(&power_health);
+    let power_path = path.join("PowerSubsystem");
+    let power_supplies = collection(
+        power_path.join("PowerSupplies"),
+        supplies.iter().cloned().map(PowerSupply::from).collect(),
+        |supply| supply.get_id().to_string());
+    let power_subsystem = PowerSubsystem::new(power_supplies, power_health);
+    let environment_metrics = EnvironmentMetrics::new(
+        Arc::new(SupplyInputPower(supplies)));
+
+    let thermal_health = HealthNode::new();
+    watch(monitor, &thermal_health, &fans, |fan| {
+        fan.read().map_or(Health::Warning, |reading| reading.health)
+    });
+    health.add_child(&thermal_health);
+    let temperatures = monitor.sensors().iter()
+        .filter(|sensor| {
+            sensor.info().reading_type == ReadingType::Temperature
+        })
+        .inspect(|sensor| thermal_health.add_child(sensor.health_node()))
+        .map(|sensor| sensor.clone() as Arc<dyn hardware::sensor::Sensor>)
+        .collect();
+    let thermal_path = path.join("ThermalSubsystem");
+    let thermal_subsystem = ThermalSubsystem::new(
+        collection(thermal_path.join("Fans"),
+                   fans.into_iter().map(Fan::from).collect(),
+                   |fan| fan.get_id().to_string()),
+        Resource::new(thermal_path.join("ThermalMetrics"),
+                      ThermalMetrics::new(temperatures)).into(),
+        thermal_health);
+
+    // Sensors outside of the subsystems still contribute to the chassis
+    monitor.sensors().iter()
+        .filter(|sensor| {
+            sensor.info().reading_type != ReadingType::Temperature
+        })
+        .for_each(|sensor| health.add_child(sensor.health_node()));
+
+    monitor.publish_to(bus, &path.join("Sensors"));
+    let sensors = collection(
+        path.join("Sensors"),
+        monitor.sensors().iter().cloned().map(Sensor::from).collect(),
+        |sensor| sensor.get_id().to_string());
+
+    ChassisBuilder::default()
+        .id(path.file_name().unwrap().to_str().unwrap())
+        .power_subsystem(Resource::new(power_path, power_subsystem))
+        .thermal_subsystem(Resource::new(thermal_path, thermal_subsystem))
+        .environment_metrics(Resource::new(
+            path.join("EnvironmentMetrics"), environment_metrics))
+        .sensors(sensors)
+        .inventory(inventory)
+        .health(health)
+        .build().unwrap()
+}
+
+// Open a log in a subdirectory of the log directory, which records the
+// changes on the bus that are accepted by the filter.
+fn log<F>(config: &LogConfig, name: &str, bus: &EventBus, accept: F) ->
+    io::Result<Arc<LogStore>>
+where F: Fn(&StateChange) -> bool + Send + 'static
+{
+    let store = LogStore::open(&config.directory.join(name),
+                               config.max_records, config.overwrite_policy)?;
+    store.listen(bus, accept);
+    Ok(store)
+}
+
+// The log of the BMC's own services, if one is configured
+fn journal(config: &LogConfig) -> Option<Arc<dyn LogSource>> {
+    match &config.journal {
+        JournalConfig::Disabled => None,
+        JournalConfig::Journald { command } =>
+            Some(Arc::new(Journald::new(command))),
+        JournalConfig::Syslog { path } => Some(Arc::new(Syslog::new(path))),
+    }
+}
+
+fn log_services(path: PathBuf, services: Vec<LogService>) ->
+    ODataResource<LogServiceCollection>
+{
+    collection(path, services, |service| service.get_id().to_string())
+}
+
+// True for changes to the host and its hardware, rather than to the BMC
+fn in_host(change: &StateChange) -> bool {
+    let origin: &Path = change.origin.as_ref();
+    let root = Path::new(SERVICE_ROOT);
+    origin.starts_with(root.join("Systems"))
+        || origin.starts_with(root.join("Chassis"))
+}
+
+// The BMC is updated through its slots if it supervises itself, and the
+// other components through the platform.
+fn updater(config: &Config) -> Arc<dyn Updater> {
+    let mut updaters: Vec<Arc<dyn Updater>> = Vec::new();
+    if let Some(root) = &config.updates.slots {
+        updaters.push(Arc::new(SlotUpdater::new(Slots::new(root), || {
+            process::exit(RESTART_STATUS)
+        })));
+    }
+    match &config.updates.platform {
+        PlatformUpdaterConfig::Simulator =>
+            updaters.push(Arc::new(SimulatedUpdater::new())),
+        PlatformUpdaterConfig::None => {},
+    }
+    Arc::new(Updaters::new(updaters))
+}
+
+// Compose the resource tree served by librebmc. Must be called from within
+// the tokio runtime.
+pub fn service(config: &Config) -> io::Result<ResourceService<ServiceRoot>> {
+    Ok(compose(config)?.0)
+}
+
+// Compose the resource tree, and the listeners that it's served on, which
+// are started by Protocols::serve. Must be called from within the tokio
+// runtime.
+pub fn compose(config: &Config) ->
+    io::Result<(ResourceService<ServiceRoot>, Arc<Protocols>)>
+{
+    let bus = EventBus::new();
+    let mut monitor = SensorMonitor::new(sensor_provider(config).sensors()?);
+    monitor.poll();
+
+    // The system is contained by the chassis, so faults in the chassis are
+    // reflected in the rollup of the system.
+    let root = PathBuf::from(SERVICE_ROOT);
+    let inventory = inventory_source(config);
+    let chassis_health = HealthNode::new();
+    let chassis = collection(
+        root.join("Chassis"),
+        vec![chassis(root.join("Chassis").join("chassis"), &mut monitor,
+                     &bus, chassis_health.clone(), power_supplies(config),
+                     fans(config), inventory.clone())],
+        |chassis| chassis.get_id().to_string());
+    let system_health = HealthNode::new();
+    system_health.add_child(&chassis_health);
+
+    // Alerts and status changes of the host are kept in its SEL, and
+    // everything that happens outside of the host in the log of the BMC.
+    let system_log = log(&config.logs, "system", &bus, |change| {
+        in_host(change) && matches!(
+            change.event_type, EventType::Alert | EventType::StatusChange)
+    })?;
+    let manager_log = log(&config.logs, "bmc", &bus, |change| {
+        !in_host(change)
+    })?;
+    let audit_log = AuditLog::open(
+        LogStore::open(&config.logs.directory.join("audit"),
+                       config.logs.max_records, config.logs.overwrite_policy)?,
+        config.logs.audit_file.as_deref())?;
+
+    // Power is restored to the host as though the BMC has just powered on
+    let tasks = TaskManager::new(root.join("TaskService"));
+    let system_path = root.join("Systems").join("system");
+    let (power, boot) = host_provider(config);
+    let host = Host::open(power, &config.host.settings)?;
+    host.publish_to(&bus, &system_path);
+    let observed = Arc::downgrade(&host);
+    monitor.observe(move || {
+        let Some(host) = observed.upgrade() else { return false };
+        if let Err(error) = host.observe() {
+            eprintln!("failed to read the power state of the host: {}",
+                      error);
+        }
+        true
+    });
+    let boot = Arc::new(CachedBoot::new(boot));
+    let observed = Arc::downgrade(&boot);
+    monitor.observe(move || {
+        let Some(boot) = observed.upgrade() else { return false };
+        if let Err(error) = boot.refresh() {
+            eprintln!("failed to read the boot override of the host: {}",
+                      error);
+        }
+        true
+    });
+    let restore = host.clone();
+    tokio::task::spawn_blocking(move || {
+        if let Err(error) = restore.restore() {
+            eprintln!("failed to restore power to the host: {}", error);
+        }
+    });
+
+    // Drive failures are reflected in the rollup of the system, and recorded
+    // in its SEL.
+    let storage = SystemStorage::new(&system_path, storage_provider(config));
+    storage.publish_to(&bus);
+    let storage_health = HealthNode::new();
+    let observed = storage.clone();
+    monitor.watch(&storage_health, move || observed.observe());
+    system_health.add_child(&storage_health);
+
+    let system = ComputerSystemBuilder::default()
+        .id("system")
+        .health(system_health)
+        .boot(boot as Arc<dyn BootControl>)
+        .power(host)
+        .inventory(Inventory::new(&system_path, inventory))
+        .storage(storage)
+        .tasks(tasks.clone())
+        .log_services(log_services(system_path.join("LogServices"), vec![
+            LogService::new(system_path.join("LogServices").join("SEL"),
+                            "System Event Log", LogEntryType::Event,
+                            system_log),
+        ]))
+        .build().unwrap();
+    let systems = collection(root.join("Systems"), vec![system],
+                             |system| system.get_id().to_string());
+    monitor.start(config.sensors.poll_interval())?;
+
+    // The service is discovered by SSDP with the same UUID as its root
+    let uuid = Uuid::default();
+    let protocols = Protocols::open(&config.network, uuid)?;
+    let manager_path = root.join("Managers").join("bmc");
+    let manager_logs = manager_path.join("LogServices");
+    let mut manager_services = vec![
+        LogService::new(manager_logs.join("EventLog"), "Event Log",
+                        LogEntryType::Event, manager_log),
+        LogService::append_only(
+            manager_logs.join("AuditLog"), "Audit Log", LogEntryType::Event,
+            audit_log.store().clone()),
+    ];
+    if let Some(journal) = journal(&config.logs) {
+        manager_services.push(LogService::from_source(
+            manager_logs.join("Journal"), "Journal", LogEntryType::Event,
+            journal));
+    }
+    let manager = ManagerBuilder::default()
+        .id("bmc")
+        .log_services(log_services(manager_logs, manager_services))
+        .ethernet_interfaces(ManagerInterfaces::new(
+            &manager_path, network_control(config)))
+        .network_protocol(ODataResource::from(Resource::new(
+            manager_path.join("NetworkProtocol"),
+            ManagerNetworkProtocol::new(protocols.clone(), time_sync(config),
+                                        &config.network))))
+        .build().unwrap();
+    let managers = collection(root.join("Managers"), vec![manager],
+                              |manager| manager.get_id().to_string());
+
+    let broker = EventBroker::new(&config.events)?;
+    broker.listen(&bus);
+    let events_path = root.join("EventService");
+    let subscriptions_path = events_path.join("Subscriptions");
+    let subscriptions = Resource::new(
+        subscriptions_path.clone(),
+        EventDestinationCollection::new(subscriptions_path, broker.clone()));
+    let event_service = Resource::new(
+        events_path.clone(),
+        EventService::new(events_path, broker, subscriptions.into()));
+
+    let task_service = Resource::new(
+        tasks.get_path().to_owned(), TaskService::new(tasks.clone()));
+    let update_path = root.join("UpdateService");
+    let verifier = Verifier::new(
+        TrustedKeys::load(&config.updates.trusted_keys)?,
+        config.updates.allow_downgrade);
+    let staging = Staging::new(&config.updates.staging,
+                               config.updates.max_image_size);
+    let update_service = Resource::new(
+        update_path.clone(),
+        UpdateService::new(update_path, updater(config), verifier, staging,
+                           tasks));
+
+    let service: ODataResource<_> = Resource::new(
+        root,
+        ServiceRootBuilder::default()
+            .systems(systems)
+            .chassis(chassis)
+            .managers(managers)
+            .event_service(ODataResource::from(event_service))
+            .tasks(ODataResource::from(task_service))
+            .update_service(ODataResource::from(update_service))
+            .uuid(uuid)
+            .build().unwrap()).into();
+    Ok((ResourceService::new(service, bus).with_audit(audit_log), protocols))
+}
+
+///////////////////////////////////////////////////////////////////////////////
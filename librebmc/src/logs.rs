@@ -0,0 +1,415 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            logs.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Persistent, rotating log stores that back the LogServices
+//                  of the system and the BMC.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::collections::VecDeque;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast::error::RecvError;
+
+use crate::bus::{EventBus, StateChange};
+use crate::models::{Health, Message};
+
+pub mod journald;
+pub mod syslog;
+
+// A log is kept in this many segments. When a log wraps, its oldest records
+// are discarded, and segments are removed once all of their records are.
+const SEGMENTS: usize = 4;
+const SEGMENT_EXTENSION: &'static str = "log";
+
+///////////////////////////////////////////////////////////////////////////////
+// LogRecord
+////
+
+// What happens to new entries once a log is full
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub enum OverWritePolicy {
+    #[default]
+    WrapsWhenFull,
+    NeverOverWrites,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct LogRecord {
+    pub id: u64,
+    pub created: DateTime<Utc>,
+
+    #[serde(flatten)]
+    pub message: Message,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub origin: Option<PathBuf>,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Segment
+////
+
+// A file of records, one JSON object per line, named for the id of the first
+// record that was written to it.
+struct Segment {
+    path: PathBuf,
+    records: Vec<LogRecord>,
+}
+
+impl Segment {
+    fn new(directory: &Path, first: u64) -> Self {
+        let path = directory.join(
+            format!("{:020}.{}", first, SEGMENT_EXTENSION));
+        Segment { path, records: Vec::new() }
+    }
+
+    fn first(&self) -> Option<u64> {
+        self.path.file_stem()?.to_str()?.parse().ok()
+    }
+
+    // A record that was only partially written when the BMC went down is
+    // skipped.
+    fn load(path: PathBuf) -> io::Result<Self> {
+        let records = fs::read_to_string(&path)?.lines()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect();
+        Ok(Segment { path, records })
+    }
+
+    fn append(&mut self, record: LogRecord) -> io::Result<()> {
+        let mut line = serde_json::to_vec(&record)?;
+        line.push(b'\n');
+        let mut file = OpenOptions::new()
+            .create(true).append(true).open(&self.path)?;
+        file.write_all(&line)?;
+        file.sync_data()?;
+        self.records.push(record);
+        Ok(())
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// LogStore
+////
+
+struct Segments {
+    segments: VecDeque<Segment>,
+    next_id: u64,
+}
+
+impl Segments {
+    fn len(&self) -> usize {
+        self.segments.iter().map(|segment| segment.records.len()).sum()
+    }
+
+    // The newest records, up to max. Older records are kept on the disk
+    // until the whole of their segment is discarded.
+    fn records(&self, max: usize) -> impl Iterator<Item = &LogRecord> {
+        let discarded = self.len().saturating_sub(max);
+        self.segments.iter()
+            .flat_map(|segment| segment.records.iter())
+            .skip(discarded)
+    }
+}
+
+// The entries of a log, kept in a directory so that they survive a restart.
+// Entry ids are never reused, even after the log is cleared.
+pub struct LogStore {
+    directory: PathBuf,
+    max_records: usize,
+    overwrite_policy: OverWritePolicy,
+    segments: Mutex<Segments>,
+}
+
+impl LogStore {
+    pub fn open(directory: &Path, max_records: usize,
+                overwrite_policy: OverWritePolicy) -> io::Result<Arc<Self>>
+    {
+        let mut segments = Vec::new();
+        match fs::read_dir(directory) {
+            Ok(entries) => for entry in entries {
+                let path = entry?.path();
+                if path.extension().is_some_and(|extension| {
+                    extension == SEGMENT_EXTENSION
+                }) {
+                    segments.push(Segment::load(path)?);
+                }
+            },
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {},
+            Err(error) => return Err(error),
+        }
+        segments.retain(|segment| segment.first().is_some());
+        segments.sort_by_key(Segment::first);
+
+        let next_id = segments.iter()
+            .flat_map(|segment| {
+                let last = segment.records.last().map(|record| record.id + 1);
+                [segment.first(), last]
+            })
+            .flatten()
+            .max()
+            .unwrap_or(1);
+        Ok(Arc::new(LogStore {
+            directory: directory.to_owned(),
+            max_records: max_records.max(1),
+            overwrite_policy,
+            segments: Mutex::new(Segments {
+                segments: segments.into(), next_id,
+            }),
+        }))
+    }
+
+    pub fn max_records(&self) -> usize { self.max_records }
+    pub fn overwrite_policy(&self) -> OverWritePolicy { self.overwrite_policy }
+
+    fn segment_size(&self) -> usize { self.max_records.div_ceil(SEGMENTS) }
+
+    pub fn entries(&self) -> Vec<LogRecord> {
+        self.segments.lock().unwrap().records(self.max_records)
+            .cloned()
+            .collect()
+    }
+
+    pub fn entry(&self, id: u64) -> Option<LogRecord> {
+        self.segments.lock().unwrap().records(self.max_records)
+            .find(|record| record.id == id)
+            .cloned()
+    }
+
+    // Add an entry to the log, returning its id, or None if the log is full
+    // and never overwrites.
+    pub fn append(&self, created: DateTime<Utc>, message: Message,
+                  origin: Option<PathBuf>) -> io::Result<Option<u64>>
+    {
+        let mut segments = self.segments.lock().unwrap();
+        if segments.len() >= self.max_records
+            && self.overwrite_policy == OverWritePolicy::NeverOverWrites {
+            return Ok(None);
+        }
+
+        let id = segments.next_id;
+        if segments.segments.back().is_none_or(|segment| {
+            segment.records.len() >= self.segment_size()
+        }) {
+            segments.segments.push_back(Segment::new(&self.directory, id));
+        }
+        fs::create_dir_all(&self.directory)?;
+        segments.segments.back_mut().unwrap().append(LogRecord {
+            id, created, message, origin,
+        })?;
+        segments.next_id += 1;
+
+        // The segment being written is never removed, even if the log has
+        // shrunk since it was started.
+        while segments.segments.len() > 1 && segments.segments[0].records.len()
+            <= segments.len().saturating_sub(self.max_records)
+        {
+            let oldest = segments.segments.pop_front().unwrap();
+            remove(&oldest.path)?;
+        }
+        Ok(Some(id))
+    }
+
+    pub fn clear(&self) -> io::Result<()> {
+        let mut segments = self.segments.lock().unwrap();
+        while let Some(segment) = segments.segments.pop_front() {
+            remove(&segment.path)?;
+        }
+        // An empty segment records the next id across a restart
+        fs::create_dir_all(&self.directory)?;
+        let empty = Segment::new(&self.directory, segments.next_id);
+        fs::write(&empty.path, "")?;
+        segments.segments.push_back(empty);
+        Ok(())
+    }
+
+    // Record the changes on the bus that are accepted by the filter. Writes
+    // to the disk may block, so they're made off of the runtime. Must be
+    // called from within the tokio runtime.
+    pub fn listen<F>(self: &Arc<Self>, bus: &EventBus, accept: F)
+    where F: Fn(&StateChange) -> bool + Send + 'static
+    {
+        let store = Arc::downgrade(self);
+        let mut changes = bus.subscribe();
+        tokio::spawn(async move {
+            loop {
+                let change = match changes.recv().await {
+                    Ok(change) if accept(&change) => change,
+                    Ok(_) | Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => return,
+                };
+                let Some(store) = store.upgrade() else { return; };
+                // There's nowhere to report a failure to write the log
+                let _ = tokio::task::spawn_blocking(move || store.append(
+                    change.timestamp, change.message,
+                    Some(change.origin.into()))).await;
+            }
+        });
+    }
+}
+
+fn remove(path: &Path) -> io::Result<()> {
+    match fs::remove_file(path) {
+        Err(error) if error.kind() != io::ErrorKind::NotFound => Err(error),
+        _ => Ok(()),
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// LogSource
+////
+
+// An entry of a log that's kept outside of librebmc
+#[derive(Clone, Debug, PartialEq)]
+pub struct SourceEntry {
+    // Identifies the entry in its log. Cursors are stable across reads.
+    pub cursor: String,
+    pub created: DateTime<Utc>,
+    pub severity: Health,
+    pub message: String,
+}
+
+// A log that's kept outside of librebmc, such as the systemd journal. Reads
+// may block.
+pub trait LogSource: Send + Sync {
+    // The number of entries in the log, unless it can only be counted by
+    // reading the whole of it, in which case clients page to the end.
+    fn count(&self) -> io::Result<Option<usize>>;
+
+    // Up to count entries, starting after the entry at the cursor, or at the
+    // beginning of the log.
+    fn read(&self, after: Option<&str>, count: usize) ->
+        io::Result<Vec<SourceEntry>>;
+
+    fn entry(&self, cursor: &str) -> io::Result<Option<SourceEntry>>;
+}
+
+// The severity of a syslog(3) priority, from LOG_EMERG (0) to LOG_DEBUG (7)
+pub fn severity(priority: u8) -> Health {
+    match priority {
+        0..=2 => Health::Critical,
+        3..=4 => Health::Warning,
+        _ => Health::OK,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registries::base;
+
+    fn append(store: &LogStore) -> Option<u64> {
+        store.append(Utc::now(), base::general_error(), None).unwrap()
+    }
+
+    fn ids(store: &LogStore) -> Vec<u64> {
+        store.entries().iter().map(|record| record.id).collect()
+    }
+
+    #[test]
+    fn entries_survive_a_restart() {
+        let directory = tempfile::tempdir().unwrap();
+        let store = LogStore::open(
+            directory.path(), 8, OverWritePolicy::WrapsWhenFull).unwrap();
+        (0..3).for_each(|_| { append(&store); });
+        drop(store);
+
+        let store = LogStore::open(
+            directory.path(), 8, OverWritePolicy::WrapsWhenFull).unwrap();
+        assert_eq!(vec![1, 2, 3], ids(&store));
+        assert_eq!(Some(4), append(&store));
+        assert_eq!(base::general_error(), store.entry(2).unwrap().message);
+    }
+
+    #[test]
+    fn oldest_segment_is_discarded_when_full() {
+        let directory = tempfile::tempdir().unwrap();
+        let store = LogStore::open(
+            directory.path(), 8, OverWritePolicy::WrapsWhenFull).unwrap();
+        (0..9).for_each(|_| { append(&store); });
+        assert_eq!((2..=9).collect::<Vec<_>>(), ids(&store));
+        assert_eq!(5, fs::read_dir(directory.path()).unwrap().count());
+        append(&store);
+        assert_eq!((3..=10).collect::<Vec<_>>(), ids(&store));
+        assert_eq!(4, fs::read_dir(directory.path()).unwrap().count());
+
+        let store = LogStore::open(
+            directory.path(), 2, OverWritePolicy::NeverOverWrites).unwrap();
+        store.clear().unwrap();
+        assert_eq!(Some(11), append(&store));
+        assert_eq!(Some(12), append(&store));
+        assert_eq!(None, append(&store));
+    }
+
+    #[test]
+    fn newest_records_are_kept_when_the_log_shrinks() {
+        let directory = tempfile::tempdir().unwrap();
+        let store = LogStore::open(
+            directory.path(), 8, OverWritePolicy::WrapsWhenFull).unwrap();
+        (0..7).for_each(|_| { append(&store); });
+        drop(store);
+
+        let store = LogStore::open(
+            directory.path(), 1, OverWritePolicy::WrapsWhenFull).unwrap();
+        assert_eq!(vec![7], ids(&store));
+        drop(store);
+
+        let store = LogStore::open(
+            directory.path(), 3, OverWritePolicy::WrapsWhenFull).unwrap();
+        assert_eq!(vec![5, 6, 7], ids(&store));
+        assert_eq!(Some(8), append(&store));
+        assert_eq!(vec![6, 7, 8], ids(&store));
+        assert_eq!(None, store.entry(5));
+        assert_eq!(3, fs::read_dir(directory.path()).unwrap().count());
+    }
+
+    #[test]
+    fn ids_are_not_reused_after_clearing() {
+        let directory = tempfile::tempdir().unwrap();
+        let store = LogStore::open(
+            directory.path(), 8, OverWritePolicy::WrapsWhenFull).unwrap();
+        (0..2).for_each(|_| { append(&store); });
+        store.clear().unwrap();
+        assert!(store.entries().is_empty());
+        drop(store);
+
+        let store = LogStore::open(
+            directory.path(), 8, OverWritePolicy::WrapsWhenFull).unwrap();
+        assert!(store.entries().is_empty());
+        assert_eq!(Some(3), append(&store));
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
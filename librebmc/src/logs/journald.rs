@@ -0,0 +1,171 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            journald.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     A LogSource that reads the systemd journal through
+//                  journalctl.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::io::{self, BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+use crate::logs::{self, LogSource, SourceEntry};
+
+pub const JOURNALCTL: &'static str = "journalctl";
+
+// The priority of messages logged without one, LOG_INFO
+const DEFAULT_PRIORITY: u8 = 6;
+
+// Binary-safe fields are exported as arrays of bytes
+fn text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(value) => Some(value.clone()),
+        Value::Array(bytes) => {
+            let bytes: Option<Vec<u8>> = bytes.iter()
+                .map(|byte| byte.as_u64()?.try_into().ok())
+                .collect();
+            Some(String::from_utf8_lossy(&bytes?).into_owned())
+        },
+        _ => None,
+    }
+}
+
+// Parse an entry from the output of journalctl --output=json
+fn parse(line: &str) -> Option<SourceEntry> {
+    let fields: Value = serde_json::from_str(line).ok()?;
+    let cursor = fields["__CURSOR"].as_str()?.to_string();
+    let microseconds: i64 = fields["__REALTIME_TIMESTAMP"].as_str()?
+        .parse().ok()?;
+    let priority = fields["PRIORITY"].as_str()
+        .and_then(|priority| priority.parse().ok())
+        .unwrap_or(DEFAULT_PRIORITY);
+    let message = text(&fields["MESSAGE"]).unwrap_or_default();
+    let message = match text(&fields["SYSLOG_IDENTIFIER"]) {
+        Some(identifier) => format!("{}: {}", identifier, message),
+        None => message,
+    };
+    Some(SourceEntry {
+        cursor,
+        created: DateTime::<Utc>::from_timestamp_micros(microseconds)?,
+        severity: logs::severity(priority),
+        message,
+    })
+}
+
+pub struct Journald {
+    command: PathBuf,
+}
+
+impl Journald {
+    pub fn new(command: &Path) -> Self {
+        Journald { command: command.to_owned() }
+    }
+
+    // Read up to limit entries from journalctl, which is stopped once
+    // enough have been read.
+    fn entries(&self, arguments: &[&str], limit: usize) ->
+        io::Result<Vec<SourceEntry>>
+    {
+        let mut child = Command::new(&self.command)
+            .args(["--output=json", "--no-pager", "--quiet"])
+            .args(arguments)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()?;
+        let output = BufReader::new(child.stdout.take().unwrap());
+        let mut entries = Vec::new();
+        for line in output.lines() {
+            if entries.len() == limit {
+                break;
+            }
+            entries.extend(parse(&line?));
+        }
+        let _ = child.kill();
+        child.wait()?;
+        Ok(entries)
+    }
+}
+
+impl LogSource for Journald {
+    // journalctl can't count the entries of the journal without exporting
+    // every one of them.
+    fn count(&self) -> io::Result<Option<usize>> { Ok(None) }
+
+    fn read(&self, after: Option<&str>, count: usize) ->
+        io::Result<Vec<SourceEntry>>
+    {
+        match after {
+            Some(cursor) => self.entries(
+                &[&format!("--after-cursor={}", cursor)], count),
+            None => self.entries(&[], count),
+        }
+    }
+
+    fn entry(&self, cursor: &str) -> io::Result<Option<SourceEntry>> {
+        let entries = self.entries(&[&format!("--cursor={}", cursor)], 1)?;
+        Ok(entries.into_iter().find(|entry| entry.cursor == cursor))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Health;
+
+    #[test]
+    fn entries_are_parsed() {
+        let entry = parse(r#"{
+            "__CURSOR": "s=0a;i=1f;b=9c;m=5e;t=6d;x=2b",
+            "__REALTIME_TIMESTAMP": "1792332000123456",
+            "PRIORITY": "3",
+            "SYSLOG_IDENTIFIER": "librebmc",
+            "MESSAGE": "failed to read sensor"
+        }"#).unwrap();
+        assert_eq!("s=0a;i=1f;b=9c;m=5e;t=6d;x=2b", entry.cursor);
+        assert_eq!("2026-10-18T14:00:00.123456+00:00",
+                   entry.created.to_rfc3339());
+        assert_eq!(Health::Warning, entry.severity);
+        assert_eq!("librebmc: failed to read sensor", entry.message);
+
+        // Messages that aren't valid UTF-8 are exported as bytes
+        let entry = parse(r#"{
+            "__CURSOR": "s=0a;i=20", "__REALTIME_TIMESTAMP": "0",
+            "MESSAGE": [104, 105, 255]
+        }"#).unwrap();
+        assert_eq!(Health::OK, entry.severity);
+        assert_eq!("hi\u{fffd}", entry.message);
+        assert!(parse(r#"{"MESSAGE": "no cursor"}"#).is_none());
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
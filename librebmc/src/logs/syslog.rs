@@ -0,0 +1,227 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            syslog.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     A LogSource that reads a plain syslog file.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Datelike, NaiveDateTime, Utc};
+
+use crate::logs::{self, LogSource, SourceEntry};
+
+// The priority of lines without a PRI part, LOG_NOTICE
+const DEFAULT_PRIORITY: u8 = 5;
+
+// Split an optional "<PRI>" from the start of a line
+fn priority(line: &str) -> (u8, &str) {
+    let parsed = line.strip_prefix('<')
+        .and_then(|rest| rest.split_once('>'))
+        .and_then(|(pri, rest)| Some(((pri.parse::<u16>().ok()? & 7) as u8,
+                                      rest)));
+    parsed.unwrap_or((DEFAULT_PRIORITY, line))
+}
+
+// Split a RFC 3339 (RFC 5424) or BSD (RFC 3164) timestamp from a line
+fn timestamp(line: &str) -> Option<(DateTime<Utc>, &str)> {
+    let line = line.strip_prefix("1 ").unwrap_or(line);
+    if let Some((stamp, rest)) = line.split_once(' ') {
+        if let Ok(created) = DateTime::parse_from_rfc3339(stamp) {
+            return Some((created.with_timezone(&Utc), rest));
+        }
+    }
+
+    // BSD timestamps are "Mmm dd hh:mm:ss", without a year
+    let stamp = line.get(..15)?;
+    let year = Utc::now().year();
+    let created = NaiveDateTime::parse_from_str(
+        &format!("{} {}", year, stamp), "%Y %b %e %H:%M:%S").ok()?;
+    Some((created.and_utc(), line[15..].trim_start()))
+}
+
+// Parse a line of the file, which starts at the byte offset
+fn parse(offset: u64, line: &str) -> SourceEntry {
+    let (priority, line) = priority(line);
+    let (created, message) = timestamp(line)
+        .unwrap_or((DateTime::<Utc>::UNIX_EPOCH, line));
+    SourceEntry {
+        cursor: offset.to_string(),
+        created,
+        severity: logs::severity(priority),
+        message: message.to_string(),
+    }
+}
+
+pub struct Syslog {
+    path: PathBuf,
+}
+
+impl Syslog {
+    pub fn new(path: &Path) -> Self {
+        Syslog { path: path.to_owned() }
+    }
+
+    // Read up to limit lines, starting from the byte offset. A missing
+    // file is an empty log.
+    fn lines(&self, offset: u64, limit: usize) -> io::Result<Vec<SourceEntry>>
+    {
+        let mut file = match File::open(&self.path) {
+            Ok(file) => file,
+            Err(error) if error.kind() == io::ErrorKind::NotFound =>
+                return Ok(Vec::new()),
+            Err(error) => return Err(error),
+        };
+        file.seek(SeekFrom::Start(offset))?;
+        let mut reader = BufReader::new(file);
+        let mut entries = Vec::new();
+        let mut position = offset;
+        let mut line = Vec::new();
+        while entries.len() < limit {
+            line.clear();
+            let length = reader.read_until(b'\n', &mut line)?;
+            if length == 0 {
+                break;
+            }
+            let text = String::from_utf8_lossy(&line);
+            let text = text.trim_end_matches(['\r', '\n']);
+            if !text.is_empty() {
+                entries.push(parse(position, text));
+            }
+            position += length as u64;
+        }
+        Ok(entries)
+    }
+
+    // Whether the byte offset is the start of a line
+    fn at_line(&self, offset: u64) -> io::Result<bool> {
+        if offset == 0 {
+            return Ok(true);
+        }
+        let mut file = match File::open(&self.path) {
+            Ok(file) => file,
+            Err(error) if error.kind() == io::ErrorKind::NotFound =>
+                return Ok(false),
+            Err(error) => return Err(error),
+        };
+        file.seek(SeekFrom::Start(offset - 1))?;
+        let mut previous = [0u8];
+        match io::Read::read(&mut file, &mut previous)? {
+            1 => Ok(previous[0] == b'\n'),
+            _ => Ok(false),
+        }
+    }
+}
+
+impl LogSource for Syslog {
+    fn count(&self) -> io::Result<Option<usize>> {
+        Ok(Some(self.lines(0, usize::MAX)?.len()))
+    }
+
+    fn read(&self, after: Option<&str>, count: usize) ->
+        io::Result<Vec<SourceEntry>>
+    {
+        let Some(cursor) = after else {
+            return self.lines(0, count);
+        };
+        let Ok(offset) = cursor.parse::<u64>() else {
+            return Ok(Vec::new());
+        };
+        if !self.at_line(offset)? {
+            return Ok(Vec::new());
+        }
+        let mut entries = self.lines(offset, count.saturating_add(1))?;
+        if entries.first().is_some_and(|entry| entry.cursor == cursor) {
+            entries.remove(0);
+        }
+        entries.truncate(count);
+        Ok(entries)
+    }
+
+    fn entry(&self, cursor: &str) -> io::Result<Option<SourceEntry>> {
+        let Ok(offset) = cursor.parse::<u64>() else {
+            return Ok(None);
+        };
+        if !self.at_line(offset)? {
+            return Ok(None);
+        }
+        Ok(self.lines(offset, 1)?.into_iter()
+           .find(|entry| entry.cursor == cursor))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Health;
+
+    #[test]
+    fn lines_are_parsed() {
+        let entry = parse(
+            0, "<11>1 2026-10-18T14:00:00Z bmc librebmc - - - sensor failed");
+        assert_eq!(Health::Warning, entry.severity);
+        assert_eq!("2026-10-18T14:00:00+00:00", entry.created.to_rfc3339());
+        assert_eq!("bmc librebmc - - - sensor failed", entry.message);
+
+        let entry = parse(42, "Oct  8 09:15:02 bmc kernel: oops");
+        assert_eq!("42", entry.cursor);
+        assert_eq!(Health::OK, entry.severity);
+        assert_eq!((10, 8), (entry.created.month(), entry.created.day()));
+        assert_eq!("bmc kernel: oops", entry.message);
+
+        let entry = parse(0, "<2>no timestamp");
+        assert_eq!(Health::Critical, entry.severity);
+        assert_eq!(DateTime::<Utc>::UNIX_EPOCH, entry.created);
+        assert_eq!("no timestamp", entry.message);
+    }
+
+    #[test]
+    fn lines_are_read_by_offset() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("messages");
+        let syslog = Syslog::new(&path);
+        assert_eq!(Some(0), syslog.count().unwrap());
+
+        std::fs::write(&path, "<6>first\n<4>second\n<3>third\n").unwrap();
+        assert_eq!(Some(3), syslog.count().unwrap());
+        let page = syslog.read(None, 2).unwrap();
+        assert_eq!(vec!["0", "9"], page.iter()
+                   .map(|entry| entry.cursor.as_str())
+                   .collect::<Vec<_>>());
+        let rest = syslog.read(Some("9"), 2).unwrap();
+        assert_eq!(1, rest.len());
+        assert_eq!("third", rest[0].message);
+        assert_eq!("second", syslog.entry("9").unwrap().unwrap().message);
+        assert!(syslog.entry("10").unwrap().is_none());
+        assert!(syslog.read(Some("10"), 2).unwrap().is_empty());
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -7,7 +7,7 @@
 //
 // CREATED:         02/26/2022
 //
-// LAST EDITED:     04/09/2022
+// LAST EDITED:     10/18/2026
 //
 // Copyright 2022, Ethan D. Twardy
 //
@@ -30,31 +30,66 @@
 // IN THE SOFTWARE.
 ////
 
-use std::path::PathBuf;
-use hyper;
-use odata::Resource;
+use std::env;
+use std::path::Path;
+use std::future;
+use std::process;
 
-mod models;
-mod service;
+use librebmc::config::Config;
+use librebmc::update::slots::{self, Slots, Supervisor};
 
-use crate::models::{ServiceRootBuilder, ComputerSystemCollectionBuilder};
-use crate::service::{ODataResource, ResourceService, ServiceFactory};
+// Run the instances of librebmc from the slots, rather than serving
+fn supervise(config: &Config, root: &Path) -> ! {
+    let factory = env::current_exe().unwrap_or_else(|error| {
+        eprintln!("failed to locate librebmc: {}", error);
+        process::exit(1);
+    });
+    let supervisor = Supervisor::new(
+        Slots::new(root), factory, env::args_os().skip(1).collect(),
+        config.updates.trial_timeout());
+    match supervisor.run() {
+        Ok(status) => process::exit(status.code().unwrap_or(1)),
+        Err(error) => {
+            eprintln!("failed to supervise librebmc: {}", error);
+            process::exit(1);
+        },
+    }
+}
 
 #[tokio::main]
-async fn main() {
-    let systems = ComputerSystemCollectionBuilder::default().build().unwrap();
-    let service: ODataResource<_> = Resource::new(
-        PathBuf::from("/redfish/v1"),
-        ServiceRootBuilder::default()
-            .systems(ODataResource::from(Resource::new(
-                PathBuf::from("/Systems"), systems)))
-            .build().unwrap()).into();
-    let service: ResourceService<_> = service.into();
+async fn serve(config: Config) {
+    let (service, protocols) = librebmc::compose(&config)
+        .unwrap_or_else(|error| {
+            eprintln!("failed to start service: {}", error);
+            process::exit(1);
+        });
+    if let Err(error) = protocols.serve(service) {
+        eprintln!("failed to start service: {}", error);
+        process::exit(1);
+    }
+
+    // The instance is healthy once it's ready to serve requests. The
+    // listeners run until librebmc is stopped.
+    if let Err(error) = slots::confirm_healthy() {
+        eprintln!("failed to confirm health: {}", error);
+    }
+    future::pending::<()>().await;
+}
 
-    hyper::Server::bind(&"127.0.0.1:3000".parse().unwrap())
-        .serve(ServiceFactory::from(service))
-        .await
-        .unwrap();
+fn main() {
+    // The path to the configuration file is the only argument
+    let config = match env::args_os().nth(1) {
+        Some(path) => Config::load(Path::new(&path)).unwrap_or_else(|error| {
+            eprintln!("{}: {}", path.to_string_lossy(), error);
+            process::exit(1);
+        }),
+        None => Config::default(),
+    };
+    if let Some(root) = config.updates.slots.as_ref()
+        .filter(|_| !slots::is_supervised()) {
+        supervise(&config, root);
+    }
+    serve(config);
 }
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -7,7 +7,7 @@
 //
 // CREATED:         03/28/2022
 //
-// LAST EDITED:     04/03/2022
+// LAST EDITED:     10/18/2026
 //
 // Copyright 2022, Ethan D. Twardy
 //
@@ -30,12 +30,88 @@
 // IN THE SOFTWARE.
 ////
 
+pub mod resource;
+pub use resource::{Action, Health, Oem, PowerState, ResetType, State, Status};
+
+mod message;
+pub use message::Message;
+
 mod service_root;
 pub use service_root::ServiceRoot;
 pub use service_root::ServiceRootBuilder;
 
-mod computer_system_collection;
-pub use computer_system_collection::ComputerSystemCollection;
-pub use  computer_system_collection::ComputerSystemCollectionBuilder;
+mod collection;
+pub use collection::{Collection, Member};
+
+pub mod computer_system;
+pub use computer_system::{ComputerSystem, ComputerSystemBuilder, SystemType};
+pub type ComputerSystemCollection = Collection<ComputerSystem>;
+
+mod processor;
+pub use processor::{Processor, ProcessorSummary};
+pub type ProcessorCollection = Collection<Processor>;
+
+mod memory;
+pub use memory::{Memory, MemorySummary};
+pub type MemoryCollection = Collection<Memory>;
+
+pub mod storage;
+pub use storage::{Storage, SystemStorage};
+pub type StorageCollection = Collection<Storage>;
+
+mod drive;
+pub use drive::Drive;
+
+mod volume;
+pub use volume::Volume;
+pub type VolumeCollection = Collection<Volume>;
+
+pub mod chassis;
+pub use chassis::{Chassis, ChassisBuilder, ChassisType};
+pub type ChassisCollection = Collection<Chassis>;
+
+mod power_subsystem;
+pub use power_subsystem::{PowerSubsystem, PowerSupply};
+pub type PowerSupplyCollection = Collection<PowerSupply>;
+
+mod thermal_subsystem;
+pub use thermal_subsystem::{Fan, ThermalSubsystem};
+pub type FanCollection = Collection<Fan>;
+
+mod thermal_metrics;
+pub use thermal_metrics::ThermalMetrics;
+
+mod sensor;
+pub use sensor::Sensor;
+pub type SensorCollection = Collection<Sensor>;
+
+mod environment_metrics;
+pub use environment_metrics::EnvironmentMetrics;
+
+pub mod manager;
+pub use manager::{Manager, ManagerBuilder, ManagerType};
+pub type ManagerCollection = Collection<Manager>;
+
+mod ethernet_interface;
+pub use ethernet_interface::{EthernetInterface, ManagerInterfaces};
+pub type EthernetInterfaceCollection = Collection<EthernetInterface>;
+
+mod manager_network_protocol;
+pub use manager_network_protocol::ManagerNetworkProtocol;
+
+mod log_service;
+pub use log_service::{LogEntry, LogEntryCollection, LogEntryType, LogService};
+pub type LogServiceCollection = Collection<LogService>;
+
+pub mod task_service;
+pub use task_service::{Task, TaskCollection, TaskService};
+
+mod update_service;
+pub use update_service::{SoftwareInventory, SoftwareInventoryCollection,
+                         UpdateService};
+
+mod event_service;
+pub use event_service::{EventDestination, EventDestinationCollection,
+                        EventService};
 
 ///////////////////////////////////////////////////////////////////////////////
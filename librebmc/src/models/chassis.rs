@@ -0,0 +1,216 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            chassis.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Chassis model.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::convert::Infallible;
+use std::path::Path;
+use std::sync::Arc;
+
+use derive_builder::Builder;
+use hyper::{Body, Request, Response};
+use serde::{Deserialize, Serialize};
+
+use crate::hardware::inventory::InventorySource;
+use crate::health::HealthNode;
+use crate::models::{EnvironmentMetrics, Member, PowerSubsystem,
+                    SensorCollection, State, ThermalSubsystem};
+use crate::service::{Dispatch, NotFound, ODataResource};
+
+const DEFAULT_NAME: &'static str = "Chassis";
+
+///////////////////////////////////////////////////////////////////////////////
+// Supporting Enums
+////
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum ChassisType {
+    Blade,
+    Card,
+    Cartridge,
+    Component,
+    Drawer,
+    Enclosure,
+    Expansion,
+    IPBasedDrive,
+    Module,
+    Other,
+    Pod,
+    Rack,
+    RackGroup,
+    #[default]
+    RackMount,
+    Row,
+    Shelf,
+    Sidecar,
+    Sled,
+    StandAlone,
+    StorageEnclosure,
+    Zone,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Chassis
+////
+
+#[derive(Builder, Clone)]
+#[builder(setter(into))]
+pub struct Chassis {
+    id: String,
+
+    #[builder(default = "DEFAULT_NAME.to_string()")]
+    name: String,
+
+    #[builder(default)]
+    chassis_type: ChassisType,
+
+    #[builder(default, setter(strip_option))]
+    manufacturer: Option<String>,
+
+    #[builder(default, setter(strip_option))]
+    model: Option<String>,
+
+    #[builder(default, setter(strip_option))]
+    serial_number: Option<String>,
+
+    #[builder(default, setter(strip_option))]
+    part_number: Option<String>,
+
+    // Asset information reported by the host overrides the fields above
+    #[builder(default, setter(strip_option))]
+    inventory: Option<Arc<dyn InventorySource>>,
+
+    #[builder(default)]
+    state: State,
+
+    #[builder(default = "HealthNode::new()")]
+    health: Arc<HealthNode>,
+
+    #[builder(default, setter(strip_option))]
+    power_subsystem: Option<ODataResource<PowerSubsystem>>,
+
+    #[builder(default, setter(strip_option))]
+    thermal_subsystem: Option<ODataResource<ThermalSubsystem>>,
+
+    #[builder(default, setter(strip_option))]
+    environment_metrics: Option<ODataResource<EnvironmentMetrics>>,
+
+    #[builder(default, setter(strip_option))]
+    sensors: Option<ODataResource<SensorCollection>>,
+}
+
+impl Chassis {
+    pub fn get_id(&self) -> &str { &self.id }
+}
+
+impl Member for Chassis {
+    const COLLECTION_TYPE: &'static str =
+        "#ChassisCollection.ChassisCollection";
+    const COLLECTION_NAME: &'static str = "Chassis Collection";
+}
+
+impl odata::ResourceMetadata for Chassis {
+    const ODATA_TYPE: &'static str = "#Chassis.v1_20_0.Chassis";
+}
+
+impl odata::Serialize for Chassis {
+    const CARDINALITY: usize = 14;
+    fn serialize<S>(&self, serializer: &mut S, _me: &Path) ->
+        Result<(), S::Error>
+    where S: serde::ser::SerializeStruct
+    {
+        serializer.serialize_field("Id", &self.id)?;
+        serializer.serialize_field("Name", &self.name)?;
+        serializer.serialize_field("ChassisType", &self.chassis_type)?;
+        let info = self.inventory.as_ref()
+            .and_then(|source| source.inventory().ok())
+            .map(|inventory| inventory.chassis)
+            .unwrap_or_default();
+        let strings = [
+            ("Manufacturer", info.manufacturer.or(self.manufacturer.clone())),
+            ("Model", info.model.or(self.model.clone())),
+            ("SerialNumber",
+             info.serial_number.or(self.serial_number.clone())),
+            ("PartNumber", info.part_number.or(self.part_number.clone())),
+            ("SKU", info.sku),
+            ("AssetTag", info.asset_tag),
+        ];
+        for (name, value) in strings {
+            if let Some(value) = value {
+                serializer.serialize_field(name, &value)?;
+            }
+        }
+        serializer.serialize_field("Status", &self.health.status(self.state))?;
+        if let Some(power_subsystem) = &self.power_subsystem {
+            serializer.serialize_field(
+                "PowerSubsystem", &power_subsystem.as_ref().get_id())?;
+        }
+        if let Some(thermal_subsystem) = &self.thermal_subsystem {
+            serializer.serialize_field(
+                "ThermalSubsystem", &thermal_subsystem.as_ref().get_id())?;
+        }
+        if let Some(environment_metrics) = &self.environment_metrics {
+            serializer.serialize_field(
+                "EnvironmentMetrics", &environment_metrics.as_ref().get_id())?;
+        }
+        if let Some(sensors) = &self.sensors {
+            serializer.serialize_field("Sensors", &sensors.as_ref().get_id())?;
+        }
+        Ok(())
+    }
+}
+
+impl Dispatch for Chassis {
+    type Error = Infallible;
+    async fn dispatch(&self, path: &Path, request: Request<Body>) ->
+        Result<Response<Body>, Self::Error>
+    {
+        if let Some(power_subsystem) = self.power_subsystem.as_ref()
+            .filter(|power_subsystem| power_subsystem.contains(path)) {
+            return power_subsystem.dispatch(path, request).await;
+        }
+        if let Some(thermal_subsystem) = self.thermal_subsystem.as_ref()
+            .filter(|thermal_subsystem| thermal_subsystem.contains(path)) {
+            return thermal_subsystem.dispatch(path, request).await;
+        }
+        if let Some(environment_metrics) = self.environment_metrics.as_ref()
+            .filter(|environment_metrics| environment_metrics.contains(path)) {
+            return environment_metrics.dispatch(path, request).await;
+        }
+        if let Some(sensors) = self.sensors.as_ref()
+            .filter(|sensors| sensors.contains(path)) {
+            return sensors.dispatch(path, request).await;
+        }
+        Ok(NotFound.into())
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
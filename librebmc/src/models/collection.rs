@@ -0,0 +1,103 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            collection.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Generic model for collections of resources.
+//
+// CREATED:         04/03/2022
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2022, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::path::Path;
+
+use hyper::{Body, Request, Response};
+use serde;
+use odata;
+
+use crate::service::{Dispatch, NotFound, ODataResource};
+
+// Implemented by resources that can be members of a Collection
+pub trait Member {
+    const COLLECTION_TYPE: &'static str;
+    const COLLECTION_NAME: &'static str;
+}
+
+#[derive(Clone)]
+pub struct Collection<T>
+where T: Member + odata::Serialize + odata::ResourceMetadata + Clone + Dispatch
+{
+    name: String,
+    members: Vec<ODataResource<T>>,
+}
+
+impl<T> Collection<T>
+where T: Member + odata::Serialize + odata::ResourceMetadata + Clone + Dispatch
+{
+    pub fn new(members: Vec<ODataResource<T>>) -> Self {
+        Collection { name: T::COLLECTION_NAME.to_string(), members }
+    }
+
+    pub fn members(&self) -> &[ODataResource<T>] { &self.members }
+}
+
+impl<T> odata::ResourceMetadata for Collection<T>
+where T: Member + odata::Serialize + odata::ResourceMetadata + Clone + Dispatch
+{
+    const ODATA_TYPE: &'static str = T::COLLECTION_TYPE;
+}
+
+impl<T> odata::Serialize for Collection<T>
+where T: Member + odata::Serialize + odata::ResourceMetadata + Clone + Dispatch
+{
+    const CARDINALITY: usize = 3;
+    fn serialize<S>(&self, serializer: &mut S, _me: &Path) ->
+        Result<(), S::Error>
+    where S: serde::ser::SerializeStruct
+    {
+        serializer.serialize_field("Name", &self.name)?;
+        serializer.serialize_field("Members@odata.count",
+                                   &self.members.len())?;
+        let members: Vec<odata::Link> = self.members.iter()
+            .map(|member| member.as_ref().get_id())
+            .collect();
+        serializer.serialize_field("Members", &members)
+    }
+}
+
+impl<T> Dispatch for Collection<T>
+where T: Member + odata::Serialize + odata::ResourceMetadata + Clone + Dispatch
+{
+    type Error = <T as Dispatch>::Error;
+    async fn dispatch(&self, path: &Path, request: Request<Body>) ->
+        Result<Response<Body>, Self::Error>
+    {
+        match self.members.iter().find(|member| member.contains(path)) {
+            Some(member) => member.dispatch(path, request).await,
+            None => Ok(NotFound.into()),
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
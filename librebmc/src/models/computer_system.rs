@@ -0,0 +1,500 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            computer_system.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     ComputerSystem model.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::convert::Infallible;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use derive_builder::Builder;
+use hyper::{Body, Method, Request, Response};
+use odata::Resource;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::hardware::boot::{
+    BootControl, BootOverride, BootSource, BootSourceOverrideEnabled,
+    BootSourceOverrideMode,
+};
+use crate::hardware::inventory::{InventorySource, SystemInventory};
+use crate::health::HealthNode;
+use crate::host::{Host, PowerSettings};
+use crate::models::{Action, Collection, EthernetInterface,
+                    LogServiceCollection, Member, Memory, MemorySummary,
+                    Processor, ProcessorSummary, ResetType, State,
+                    SystemStorage};
+use crate::registries::base;
+use crate::service::{ActionResult, Dispatch, NotFound, ODataResource,
+                     Parameters, Patch, RedfishError};
+use crate::tasks::TaskManager;
+
+const DEFAULT_NAME: &'static str = "Computer System";
+const RESET: &'static str = "ComputerSystem.Reset";
+
+const READ_ONLY: &[&str] = &[
+    "Id", "Name", "SystemType", "UUID", "Status", "SerialNumber", "HostName",
+    "LogServices", "PowerState", "Actions", "Processors", "Memory",
+    "ProcessorSummary", "MemorySummary", "Manufacturer", "Model", "SKU",
+    "BiosVersion", "Storage", "EthernetInterfaces",
+];
+
+///////////////////////////////////////////////////////////////////////////////
+// Supporting Enums
+////
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub enum SystemType {
+    Composed,
+    DPU,
+    OS,
+    #[default]
+    Physical,
+    PhysicallyPartitioned,
+    Virtual,
+    VirtuallyPartitioned,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct Boot {
+    boot_source_override_target: BootSource,
+    #[serde(rename = "BootSourceOverrideTarget@Redfish.AllowableValues")]
+    allowable_targets: Vec<BootSource>,
+    boot_source_override_enabled: BootSourceOverrideEnabled,
+    boot_source_override_mode: BootSourceOverrideMode,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    boot_order: Option<Vec<String>>,
+}
+
+impl Boot {
+    fn read(control: &dyn BootControl) -> io::Result<Self> {
+        let boot_override = control.boot_override()?;
+        Ok(Boot {
+            boot_source_override_target: boot_override.target,
+            allowable_targets: control.targets(),
+            boot_source_override_enabled: boot_override.enabled,
+            boot_source_override_mode: boot_override.mode,
+            boot_order: control.boot_order()?,
+        })
+    }
+}
+
+fn backend_error(_: io::Error) -> RedfishError {
+    RedfishError::internal(vec![base::general_error()])
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Inventory
+////
+
+// Place each item of the inventory at <path>/<id>
+fn collection<T, I, F>(path: &Path, items: Vec<I>, id: F) ->
+    ODataResource<Collection<T>>
+where T: From<I> + Member + odata::Serialize + odata::ResourceMetadata
+         + Clone + Dispatch,
+      F: Fn(&I) -> String,
+{
+    let members = items.into_iter()
+        .map(|item| Resource::new(path.join(id(&item)), T::from(item)).into())
+        .collect();
+    Resource::new(path.to_owned(), Collection::new(members)).into()
+}
+
+// The processors, memory and network interfaces of a system, which are
+// read from the source whenever they're requested.
+#[derive(Clone)]
+pub struct Inventory {
+    processors: PathBuf,
+    memory: PathBuf,
+    ethernet_interfaces: PathBuf,
+    source: Arc<dyn InventorySource>,
+}
+
+impl Inventory {
+    // The collections are placed beneath the system at path
+    pub fn new(path: &Path, source: Arc<dyn InventorySource>) -> Self {
+        Inventory {
+            processors: path.join("Processors"), memory: path.join("Memory"),
+            ethernet_interfaces: path.join("EthernetInterfaces"), source,
+        }
+    }
+
+    fn read(&self) -> Result<SystemInventory, RedfishError> {
+        self.source.inventory()
+            .map_err(|_| RedfishError::internal(vec![base::general_error()]))
+    }
+
+    async fn dispatch(&self, path: &Path, request: Request<Body>) ->
+        Response<Body>
+    {
+        let inventory = match self.read() {
+            Ok(inventory) => inventory,
+            Err(error) => return error.into(),
+        };
+        let result = if path.starts_with(&self.processors) {
+            collection::<Processor, _, _>(
+                &self.processors, inventory.processors,
+                |processor| processor.id.clone())
+                .dispatch(path, request).await
+        } else if path.starts_with(&self.memory) {
+            collection::<Memory, _, _>(
+                &self.memory, inventory.memory, |module| module.id.clone())
+                .dispatch(path, request).await
+        } else {
+            collection::<EthernetInterface, _, _>(
+                &self.ethernet_interfaces, inventory.ethernet_interfaces,
+                |interface| interface.id.clone())
+                .dispatch(path, request).await
+        };
+        result.unwrap_or_else(|error| match error {})
+    }
+
+    fn contains(&self, path: &Path) -> bool {
+        path.starts_with(&self.processors) || path.starts_with(&self.memory)
+            || path.starts_with(&self.ethernet_interfaces)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// ComputerSystem
+////
+
+#[derive(Builder, Clone)]
+#[builder(setter(into))]
+pub struct ComputerSystem {
+    id: String,
+
+    #[builder(default = "DEFAULT_NAME.to_string()")]
+    name: String,
+
+    #[builder(default)]
+    system_type: SystemType,
+
+    #[builder(default)]
+    uuid: Uuid,
+
+    #[builder(default)]
+    state: State,
+
+    #[builder(default = "HealthNode::new()")]
+    health: Arc<HealthNode>,
+
+    #[builder(default, setter(strip_option))]
+    serial_number: Option<String>,
+
+    #[builder(default, setter(strip_option))]
+    host_name: Option<String>,
+
+    #[builder(default, setter(strip_option))]
+    log_services: Option<ODataResource<LogServiceCollection>>,
+
+    #[builder(default, setter(strip_option))]
+    boot: Option<Arc<dyn BootControl>>,
+
+    #[builder(default, setter(strip_option))]
+    power: Option<Arc<Host>>,
+
+    #[builder(default, setter(strip_option))]
+    inventory: Option<Inventory>,
+
+    #[builder(default, setter(strip_option))]
+    storage: Option<SystemStorage>,
+
+    // Resets are tracked by tasks, if there's a task service
+    #[builder(default, setter(strip_option))]
+    tasks: Option<Arc<TaskManager>>,
+}
+
+impl ComputerSystem {
+    pub fn get_id(&self) -> &str { &self.id }
+
+    // Determine the boot override and order requested by the Boot object
+    fn requested_boot(control: &dyn BootControl, patch: &mut Patch) ->
+        Result<(BootOverride, Option<Vec<String>>), RedfishError>
+    {
+        let mut boot_override = control.boot_override()
+            .map_err(backend_error)?;
+        if let Some(target) = patch.take_enum("BootSourceOverrideTarget") {
+            match control.targets().contains(&target) {
+                true => boot_override.target = target,
+                false => patch.reject(
+                    "BootSourceOverrideTarget",
+                    base::property_value_not_in_list(
+                        &format!("{:?}", target),
+                        "Boot/BootSourceOverrideTarget")),
+            }
+        }
+        if let Some(enabled) = patch.take_enum("BootSourceOverrideEnabled") {
+            boot_override.enabled = enabled;
+        }
+        if let Some(mode) = patch.take_enum("BootSourceOverrideMode") {
+            boot_override.mode = mode;
+        }
+
+        // The order may be rearranged, but options can't be added or removed
+        let Some(order) = patch.take::<Vec<String>>("BootOrder") else {
+            return Ok((boot_override, None));
+        };
+        let Some(mut current) = control.boot_order().map_err(backend_error)?
+        else {
+            patch.reject("BootOrder",
+                         base::property_not_writable("Boot/BootOrder"));
+            return Ok((boot_override, None));
+        };
+        let mut requested = order.clone();
+        requested.sort();
+        current.sort();
+        if requested != current {
+            patch.reject("BootOrder", base::property_value_format_error(
+                &serde_json::to_string(&order).unwrap(), "Boot/BootOrder"));
+        }
+        Ok((boot_override, Some(order)))
+    }
+}
+
+// Determine the power settings requested by a PATCH
+fn requested_power(host: &Host, patch: &mut Patch) -> Option<PowerSettings> {
+    let mut settings = host.settings();
+    let policy = patch.take_enum("PowerRestorePolicy");
+    let delays = [
+        ("PowerOnDelaySeconds", &mut settings.power_on_delay_seconds),
+        ("PowerCycleDelaySeconds", &mut settings.power_cycle_delay_seconds),
+    ];
+    let mut changed = policy.is_some();
+    for (name, setting) in delays {
+        let Some(seconds) = patch.take::<f64>(name) else {
+            continue;
+        };
+        match seconds >= 0.0 {
+            true => *setting = seconds,
+            false => patch.reject(name, base::property_value_out_of_range(
+                &seconds.to_string(), name)),
+        }
+        changed = true;
+    }
+    if let Some(policy) = policy {
+        settings.power_restore_policy = policy;
+    }
+    Some(settings).filter(|_| changed)
+}
+
+impl odata::ResourceMetadata for ComputerSystem {
+    const ODATA_TYPE: &'static str = "#ComputerSystem.v1_16_1.ComputerSystem";
+}
+
+impl Member for ComputerSystem {
+    const COLLECTION_TYPE: &'static str =
+        "#ComputerSystemCollection.ComputerSystemCollection";
+    const COLLECTION_NAME: &'static str = "Computer System Collection";
+}
+
+impl odata::Serialize for ComputerSystem {
+    const CARDINALITY: usize = 24;
+    fn serialize<S>(&self, serializer: &mut S, me: &Path) ->
+        Result<(), S::Error>
+    where S: serde::ser::SerializeStruct
+    {
+        serializer.serialize_field("Id", &self.id)?;
+        serializer.serialize_field("Name", &self.name)?;
+        serializer.serialize_field("SystemType", &self.system_type)?;
+        serializer.serialize_field("UUID", &self.uuid)?;
+        serializer.serialize_field("Status", &self.health.status(self.state))?;
+
+        // What the inventory reports takes precedence over the configuration
+        let contents = self.inventory.as_ref()
+            .and_then(|inventory| inventory.source.inventory().ok());
+        let info = contents.as_ref()
+            .map(|contents| contents.system.clone())
+            .unwrap_or_default();
+        let strings = [
+            ("Manufacturer", info.manufacturer.as_ref()),
+            ("Model", info.model.as_ref()),
+            ("SKU", info.sku.as_ref()),
+            ("SerialNumber",
+             info.serial_number.as_ref().or(self.serial_number.as_ref())),
+            ("BiosVersion", info.bios_version.as_ref()),
+        ];
+        for (name, value) in strings {
+            if let Some(value) = value {
+                serializer.serialize_field(name, value)?;
+            }
+        }
+        if let Some(host_name) = &self.host_name {
+            serializer.serialize_field("HostName", host_name)?;
+        }
+        if let Some(log_services) = &self.log_services {
+            serializer.serialize_field(
+                "LogServices", &log_services.as_ref().get_id())?;
+        }
+        if let Some(boot) = self.boot.as_ref()
+            .and_then(|control| Boot::read(control.as_ref()).ok()) {
+            serializer.serialize_field("Boot", &boot)?;
+        }
+        if let Some(inventory) = &self.inventory {
+            let processors = odata::Link::from(inventory.processors.clone());
+            serializer.serialize_field("Processors", &processors)?;
+            let memory = odata::Link::from(inventory.memory.clone());
+            serializer.serialize_field("Memory", &memory)?;
+            let interfaces = odata::Link::from(
+                inventory.ethernet_interfaces.clone());
+            serializer.serialize_field("EthernetInterfaces", &interfaces)?;
+            if let Some(inventory) = &contents {
+                serializer.serialize_field(
+                    "ProcessorSummary",
+                    &ProcessorSummary::from(&inventory.processors[..]))?;
+                serializer.serialize_field(
+                    "MemorySummary",
+                    &MemorySummary::from(&inventory.memory[..]))?;
+            }
+        }
+        if let Some(storage) = &self.storage {
+            let storage = odata::Link::from(storage.get_path().to_owned());
+            serializer.serialize_field("Storage", &storage)?;
+        }
+        if let Some(host) = &self.power {
+            if let Some(state) = host.power_state() {
+                serializer.serialize_field("PowerState", &state)?;
+            }
+            let settings = host.settings();
+            serializer.serialize_field(
+                "PowerRestorePolicy", &settings.power_restore_policy)?;
+            serializer.serialize_field(
+                "PowerOnDelaySeconds", &settings.power_on_delay_seconds)?;
+            serializer.serialize_field(
+                "PowerCycleDelaySeconds",
+                &settings.power_cycle_delay_seconds)?;
+
+            let reset_types: Vec<&str> = host.reset_types().iter()
+                .map(|reset_type| reset_type.as_str())
+                .collect();
+            let mut actions = Action::targets(me, &[RESET]);
+            for action in actions.values_mut() {
+                action.allow("ResetType", &reset_types);
+            }
+            serializer.serialize_field("Actions", &actions)?;
+        }
+        Ok(())
+    }
+}
+
+impl Dispatch for ComputerSystem {
+    type Error = Infallible;
+
+    fn allowed(&self) -> Vec<Method> {
+        match self.boot.is_some() || self.power.is_some() {
+            true => vec![Method::GET, Method::PATCH],
+            false => vec![Method::GET],
+        }
+    }
+
+    async fn patch(&self, mut patch: Patch) -> Result<(), RedfishError> {
+        let mut boot = None;
+        if let Some(control) = self.boot.as_ref() {
+            if let Some(mut properties) = patch.take_object("Boot") {
+                boot = Some(Self::requested_boot(
+                    control.as_ref(), &mut properties)?);
+                patch.merge(properties, &[]);
+            }
+        }
+        let power = self.power.as_ref()
+            .and_then(|host| requested_power(host, &mut patch));
+        patch.finish(READ_ONLY)?;
+
+        if let (Some(host), Some(settings)) = (self.power.as_ref(), power) {
+            host.set_settings(&settings).map_err(backend_error)?;
+        }
+
+        // The backend may take a while, so it's kept off of the runtime
+        let Some(control) = self.boot.clone() else { return Ok(()) };
+        let Some((boot_override, order)) = boot else { return Ok(()) };
+        tokio::task::spawn_blocking(move || {
+            control.set_boot_override(&boot_override)?;
+            match order {
+                Some(order) => control.set_boot_order(&order),
+                None => Ok(()),
+            }
+        }).await
+            .map_err(|_| RedfishError::internal(vec![base::general_error()]))?
+            .map_err(backend_error)
+    }
+
+    async fn dispatch(&self, path: &Path, request: Request<Body>) ->
+        Result<Response<Body>, Self::Error>
+    {
+        if let Some(log_services) = self.log_services.as_ref()
+            .filter(|log_services| log_services.contains(path)) {
+            return log_services.dispatch(path, request).await;
+        }
+        if let Some(inventory) = self.inventory.as_ref()
+            .filter(|inventory| inventory.contains(path)) {
+            return Ok(inventory.dispatch(path, request).await);
+        }
+        if let Some(storage) = self.storage.as_ref()
+            .filter(|storage| storage.contains(path)) {
+            return Ok(storage.dispatch(path, request).await);
+        }
+        Ok(NotFound.into())
+    }
+
+    async fn action(&self, name: &str, mut parameters: Parameters) ->
+        ActionResult
+    {
+        let Some(host) = self.power.clone().filter(|_| name == RESET) else {
+            return Err(RedfishError::not_found(
+                vec![base::action_not_supported(name)]));
+        };
+        let reset_type: Option<ResetType> =
+            parameters.require_enum("ResetType");
+        if let Some(reset_type) = reset_type
+            .filter(|reset_type| !host.reset_types().contains(reset_type)) {
+            parameters.reject("ResetType",
+                              base::action_parameter_value_not_in_list(
+                                  reset_type.as_str(), "ResetType", RESET));
+        }
+        parameters.finish()?;
+        let Some(reset_type) = reset_type else {
+            return Err(RedfishError::internal(vec![base::general_error()]));
+        };
+
+        let reset = move || host.reset(reset_type).map_err(backend_error);
+        match &self.tasks {
+            Some(tasks) => Ok(Some(tasks.spawn(RESET, |_| async move {
+                tokio::task::spawn_blocking(reset).await
+                    .map_err(|_| RedfishError::internal(
+                        vec![base::general_error()]))?
+            }))),
+            None => reset().map(|_| None),
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,101 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            drive.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Drive model.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::convert::Infallible;
+use std::path::Path;
+
+use crate::hardware::storage::DriveInventory;
+use crate::models::Status;
+use crate::service::Dispatch;
+
+#[derive(Clone)]
+pub struct Drive(DriveInventory);
+
+impl Drive {
+    pub fn get_id(&self) -> &str { &self.0.id }
+}
+
+impl From<DriveInventory> for Drive {
+    fn from(value: DriveInventory) -> Self { Drive(value) }
+}
+
+impl odata::ResourceMetadata for Drive {
+    const ODATA_TYPE: &'static str = "#Drive.v1_15_0.Drive";
+}
+
+impl odata::Serialize for Drive {
+    const CARDINALITY: usize = 11;
+    fn serialize<S>(&self, serializer: &mut S, _me: &Path) ->
+        Result<(), S::Error>
+    where S: serde::ser::SerializeStruct
+    {
+        let inventory = &self.0;
+        let name = inventory.name.clone()
+            .unwrap_or_else(|| format!("Drive {}", inventory.id));
+        serializer.serialize_field("Id", &inventory.id)?;
+        serializer.serialize_field("Name", &name)?;
+        let strings = [
+            ("Manufacturer", &inventory.manufacturer),
+            ("Model", &inventory.model),
+            ("SerialNumber", &inventory.serial_number),
+            ("Revision", &inventory.revision),
+        ];
+        for (name, value) in strings {
+            if let Some(value) = value {
+                serializer.serialize_field(name, value)?;
+            }
+        }
+        if let Some(capacity) = inventory.capacity_bytes {
+            serializer.serialize_field("CapacityBytes", &capacity)?;
+        }
+        if let Some(media_type) = &inventory.media_type {
+            serializer.serialize_field("MediaType", media_type)?;
+        }
+        if let Some(protocol) = &inventory.protocol {
+            serializer.serialize_field("Protocol", protocol)?;
+        }
+        if let Some(life_left) = inventory.predicted_media_life_left_percent {
+            serializer.serialize_field(
+                "PredictedMediaLifeLeftPercent", &life_left)?;
+        }
+        serializer.serialize_field("Status", &Status {
+            state: inventory.state, health: inventory.health,
+            health_rollup: None,
+        })
+    }
+}
+
+impl Dispatch for Drive {
+    type Error = Infallible;
+}
+
+///////////////////////////////////////////////////////////////////////////////
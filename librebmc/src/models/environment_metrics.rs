@@ -1,15 +1,15 @@
 ///////////////////////////////////////////////////////////////////////////////
-// NAME:            computer_system_collection.rs
+// NAME:            environment_metrics.rs
 //
 // AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
 //
-// DESCRIPTION:     ComputerSystemCollection model.
+// DESCRIPTION:     EnvironmentMetrics model.
 //
-// CREATED:         04/03/2022
+// CREATED:         10/18/2026
 //
-// LAST EDITED:     04/09/2022
+// LAST EDITED:     10/18/2026
 //
-// Copyright 2022, Ethan D. Twardy
+// Copyright 2026, Ethan D. Twardy
 //
 // Permission is hereby granted, free of charge, to any person obtaining a copy
 // of this software and associated documentation files (the "Software"), to
@@ -31,47 +31,52 @@
 ////
 
 use std::convert::Infallible;
-use std::default::Default;
 use std::path::Path;
+use std::sync::Arc;
 
-use hyper::{Body, Request, Response};
-use serde;
-use derive_builder::Builder;
-use odata;
+use serde::Serialize;
 
+use crate::hardware::power::PowerMeter;
 use crate::service::Dispatch;
 
-const DEFAULT_NAME: &'static str = "Computer System Collection";
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct SensorPowerExcerpt {
+    reading: Option<f64>,
+}
+
+#[derive(Clone)]
+pub struct EnvironmentMetrics {
+    power: Arc<dyn PowerMeter>,
+}
 
-#[derive(Builder, Clone, Default)]
-#[builder(setter(into))]
-pub struct ComputerSystemCollection {
-    #[builder(default = "DEFAULT_NAME.to_string()")]
-    name: String,
+impl EnvironmentMetrics {
+    pub fn new(power: Arc<dyn PowerMeter>) -> Self {
+        EnvironmentMetrics { power }
+    }
 }
 
-impl odata::ResourceMetadata for ComputerSystemCollection {
+impl odata::ResourceMetadata for EnvironmentMetrics {
     const ODATA_TYPE: &'static str =
-        "#ComputerSystemCollection.ComputerSystemCollection";
+        "#EnvironmentMetrics.v1_2_0.EnvironmentMetrics";
 }
 
-impl odata::Serialize for ComputerSystemCollection {
-    const CARDINALITY: usize = 1;
+impl odata::Serialize for EnvironmentMetrics {
+    const CARDINALITY: usize = 3;
     fn serialize<S>(&self, serializer: &mut S, _me: &Path) ->
         Result<(), S::Error>
     where S: serde::ser::SerializeStruct
     {
-        serializer.serialize_field("Name", &self.name)
-        // state.serialize_field("Members@odata.count", &self.members.len())?;
-        // state.serialize_field("Members", &self.members)?;
+        serializer.serialize_field("Id", "EnvironmentMetrics")?;
+        serializer.serialize_field("Name", "Chassis Environment Metrics")?;
+        serializer.serialize_field("PowerWatts", &SensorPowerExcerpt {
+            reading: self.power.power_watts().ok(),
+        })
     }
 }
 
-impl Dispatch for ComputerSystemCollection {
+impl Dispatch for EnvironmentMetrics {
     type Error = Infallible;
-    fn dispatch(&self, _path: &Path, _request: &Request<Body>) ->
-        Result<Option<Response<Body>>, Self::Error>
-    { Ok(None) }
 }
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,436 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            ethernet_interface.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     The EthernetInterface resource, for the network interfaces
+//                  of managers and systems.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::convert::Infallible;
+use std::io;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use hyper::{Body, Method, Request, Response};
+use odata::Resource;
+use serde::{Deserialize, Serialize};
+
+use crate::hardware::network::{
+    self, Dhcpv6Mode, InterfaceInventory, InterfaceSettings, Ipv4Address,
+    Ipv4AddressOrigin, Ipv6Address, Ipv6AddressOrigin, NetworkControl,
+};
+use crate::models::{Collection, Member, Status};
+use crate::registries::base;
+use crate::service::{Dispatch, ODataResource, Patch, RedfishError};
+
+const READ_ONLY: &[&str] = &[
+    "Id", "Name", "Description", "MACAddress", "InterfaceEnabled",
+    "LinkStatus", "SpeedMbps", "MTUSize", "HostName", "NameServers",
+    "IPv4Addresses", "IPv6Addresses", "IPv6DefaultGateway", "VLAN", "Status",
+];
+
+fn backend_error(_: io::Error) -> RedfishError {
+    RedfishError::internal(vec![base::general_error()])
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Addresses
+////
+
+// An element of IPv4Addresses, or of IPv4StaticAddresses without its origin
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct Ipv4Entry {
+    address: Ipv4Addr,
+    subnet_mask: Ipv4Addr,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    address_origin: Option<Ipv4AddressOrigin>,
+    gateway: Option<Ipv4Addr>,
+}
+
+impl Ipv4Entry {
+    fn new(address: &Ipv4Address, origin: Option<Ipv4AddressOrigin>) -> Self
+    {
+        Ipv4Entry {
+            address: address.address, subnet_mask: address.subnet_mask,
+            address_origin: origin, gateway: address.gateway,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct Ipv6Entry {
+    address: Ipv6Addr,
+    prefix_length: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    address_origin: Option<Ipv6AddressOrigin>,
+}
+
+impl Ipv6Entry {
+    fn new(address: &Ipv6Address, origin: Option<Ipv6AddressOrigin>) -> Self
+    {
+        Ipv6Entry {
+            address: address.address, prefix_length: address.prefix_length,
+            address_origin: origin,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct Vlan {
+    #[serde(rename = "VLANEnable")]
+    enable: bool,
+    #[serde(rename = "VLANId")]
+    id: u16,
+}
+
+// The static addresses of a PATCH, which are validated once they're taken
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase", deny_unknown_fields)]
+struct RequestedIpv4 {
+    address: String,
+    subnet_mask: String,
+    #[serde(default)]
+    gateway: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase", deny_unknown_fields)]
+struct RequestedIpv6 {
+    address: String,
+    prefix_length: u8,
+}
+
+fn parse<T: FromStr>(patch: &mut Patch, property: &str, value: &str) ->
+    Option<T>
+{
+    let parsed = value.parse().ok();
+    if parsed.is_none() {
+        patch.reject(property, base::property_value_format_error(
+            value, property));
+    }
+    parsed
+}
+
+fn requested_ipv4(patch: &mut Patch, requested: Vec<RequestedIpv4>) ->
+    Vec<Ipv4Address>
+{
+    let mut addresses = Vec::new();
+    for (index, address) in requested.into_iter().enumerate() {
+        let property = |name| {
+            format!("IPv4StaticAddresses/{}/{}", index, name)
+        };
+        let parsed = parse(patch, &property("Address"), &address.address);
+
+        // The bits of a mask must be contiguous
+        let mask = address.subnet_mask.parse::<Ipv4Addr>().ok()
+            .filter(|mask| network::prefix_length(*mask).is_some());
+        if mask.is_none() {
+            patch.reject(&property("SubnetMask"),
+                         base::property_value_format_error(
+                             &address.subnet_mask, &property("SubnetMask")));
+        }
+        let gateway = match &address.gateway {
+            Some(gateway) =>
+                parse(patch, &property("Gateway"), gateway).map(Some),
+            None => Some(None),
+        };
+        if let (Some(address), Some(subnet_mask), Some(gateway)) =
+            (parsed, mask, gateway) {
+            addresses.push(Ipv4Address {
+                address, subnet_mask, gateway,
+                origin: Ipv4AddressOrigin::Static,
+            });
+        }
+    }
+    addresses
+}
+
+fn requested_ipv6(patch: &mut Patch, requested: Vec<RequestedIpv6>) ->
+    Vec<Ipv6Address>
+{
+    let mut addresses = Vec::new();
+    for (index, address) in requested.into_iter().enumerate() {
+        let property = |name| {
+            format!("IPv6StaticAddresses/{}/{}", index, name)
+        };
+        let parsed = parse(patch, &property("Address"), &address.address);
+        if address.prefix_length > 128 {
+            patch.reject(&property("PrefixLength"),
+                         base::property_value_out_of_range(
+                             &address.prefix_length.to_string(),
+                             &property("PrefixLength")));
+            continue;
+        }
+        if let Some(parsed) = parsed {
+            addresses.push(Ipv6Address {
+                address: parsed, prefix_length: address.prefix_length,
+                origin: Ipv6AddressOrigin::Static,
+            });
+        }
+    }
+    addresses
+}
+
+// Determine the settings requested by a PATCH
+fn requested_settings(current: InterfaceSettings, patch: &mut Patch) ->
+    InterfaceSettings
+{
+    let mut settings = current;
+    if let Some(mut dhcp) = patch.take_object("DHCPv4") {
+        if let Some(enabled) = dhcp.take("DHCPEnabled") {
+            settings.dhcpv4 = enabled;
+        }
+        patch.merge(dhcp, &[]);
+    }
+    if let Some(mut dhcp) = patch.take_object("DHCPv6") {
+        if let Some(mode) = dhcp.take_enum::<Dhcpv6Mode>("OperatingMode") {
+            settings.dhcpv6 = mode;
+        }
+        patch.merge(dhcp, &[]);
+    }
+    if let Some(requested) = patch.take("IPv4StaticAddresses") {
+        settings.ipv4_static_addresses = requested_ipv4(patch, requested);
+    }
+    if let Some(requested) = patch.take("IPv6StaticAddresses") {
+        settings.ipv6_static_addresses = requested_ipv6(patch, requested);
+    }
+    if let Some(servers) = patch.take::<Vec<String>>("StaticNameServers") {
+        settings.static_name_servers = servers.iter().enumerate()
+            .filter_map(|(index, server)| {
+                let property = format!("StaticNameServers/{}", index);
+                parse::<IpAddr>(patch, &property, server)
+            })
+            .collect();
+    }
+    settings
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// EthernetInterface
+////
+
+#[derive(Clone)]
+pub struct EthernetInterface {
+    inventory: InterfaceInventory,
+
+    // Interfaces of the BMC are configured through the backend, and read
+    // from it again whenever they're serialized.
+    network: Option<Arc<dyn NetworkControl>>,
+}
+
+impl EthernetInterface {
+    pub fn new(inventory: InterfaceInventory,
+               network: Option<Arc<dyn NetworkControl>>) -> Self
+    {
+        EthernetInterface { inventory, network }
+    }
+
+    pub fn get_id(&self) -> &str { &self.inventory.id }
+
+    fn read(&self) -> io::Result<InterfaceInventory> {
+        let Some(network) = &self.network else {
+            return Ok(self.inventory.clone());
+        };
+        network.interfaces()?.into_iter()
+            .find(|interface| interface.id == self.inventory.id)
+            .ok_or_else(|| io::ErrorKind::NotFound.into())
+    }
+}
+
+impl From<InterfaceInventory> for EthernetInterface {
+    fn from(value: InterfaceInventory) -> Self {
+        EthernetInterface::new(value, None)
+    }
+}
+
+impl odata::ResourceMetadata for EthernetInterface {
+    const ODATA_TYPE: &'static str =
+        "#EthernetInterface.v1_12_0.EthernetInterface";
+}
+
+impl Member for EthernetInterface {
+    const COLLECTION_TYPE: &'static str =
+        "#EthernetInterfaceCollection.EthernetInterfaceCollection";
+    const COLLECTION_NAME: &'static str =
+        "Ethernet Network Interface Collection";
+}
+
+impl odata::Serialize for EthernetInterface {
+    const CARDINALITY: usize = 20;
+    fn serialize<S>(&self, serializer: &mut S, _me: &Path) ->
+        Result<(), S::Error>
+    where S: serde::ser::SerializeStruct
+    {
+        let inventory = self.read().unwrap_or_else(|_| self.inventory.clone());
+        serializer.serialize_field("Id", &inventory.id)?;
+        serializer.serialize_field(
+            "Name", &format!("Ethernet Interface {}", inventory.id))?;
+        let strings = [
+            ("Description", &inventory.description),
+            ("MACAddress", &inventory.mac_address),
+            ("HostName", &inventory.host_name),
+        ];
+        for (name, value) in strings {
+            if let Some(value) = value {
+                serializer.serialize_field(name, value)?;
+            }
+        }
+        if let Some(enabled) = inventory.interface_enabled {
+            serializer.serialize_field("InterfaceEnabled", &enabled)?;
+        }
+        if let Some(link_status) = &inventory.link_status {
+            serializer.serialize_field("LinkStatus", link_status)?;
+        }
+        if let Some(speed) = inventory.speed_mbps {
+            serializer.serialize_field("SpeedMbps", &speed)?;
+        }
+        if let Some(mtu) = inventory.mtu_size {
+            serializer.serialize_field("MTUSize", &mtu)?;
+        }
+        serializer.serialize_field("NameServers", &inventory.name_servers)?;
+        let ipv4: Vec<Ipv4Entry> = inventory.ipv4_addresses.iter()
+            .map(|address| Ipv4Entry::new(address, Some(address.origin)))
+            .collect();
+        serializer.serialize_field("IPv4Addresses", &ipv4)?;
+        let ipv6: Vec<Ipv6Entry> = inventory.ipv6_addresses.iter()
+            .map(|address| Ipv6Entry::new(address, Some(address.origin)))
+            .collect();
+        serializer.serialize_field("IPv6Addresses", &ipv6)?;
+        if let Some(gateway) = &inventory.ipv6_default_gateway {
+            serializer.serialize_field("IPv6DefaultGateway", gateway)?;
+        }
+        if let Some(id) = inventory.vlan_id {
+            serializer.serialize_field("VLAN", &Vlan { enable: true, id })?;
+        }
+
+        if let Some(settings) = &inventory.settings {
+            #[derive(Serialize)]
+            struct Dhcpv4 {
+                #[serde(rename = "DHCPEnabled")]
+                enabled: bool,
+            }
+            #[derive(Serialize)]
+            struct Dhcpv6 {
+                #[serde(rename = "OperatingMode")]
+                mode: Dhcpv6Mode,
+            }
+            serializer.serialize_field(
+                "DHCPv4", &Dhcpv4 { enabled: settings.dhcpv4 })?;
+            serializer.serialize_field(
+                "DHCPv6", &Dhcpv6 { mode: settings.dhcpv6 })?;
+            let ipv4: Vec<Ipv4Entry> = settings.ipv4_static_addresses.iter()
+                .map(|address| Ipv4Entry::new(address, None))
+                .collect();
+            serializer.serialize_field("IPv4StaticAddresses", &ipv4)?;
+            let ipv6: Vec<Ipv6Entry> = settings.ipv6_static_addresses.iter()
+                .map(|address| Ipv6Entry::new(address, None))
+                .collect();
+            serializer.serialize_field("IPv6StaticAddresses", &ipv6)?;
+            serializer.serialize_field(
+                "StaticNameServers", &settings.static_name_servers)?;
+        }
+        serializer.serialize_field("Status", &Status {
+            state: inventory.state, health: inventory.health,
+            health_rollup: None,
+        })
+    }
+}
+
+impl Dispatch for EthernetInterface {
+    type Error = Infallible;
+
+    fn allowed(&self) -> Vec<Method> {
+        match self.network.is_some() {
+            true => vec![Method::GET, Method::PATCH],
+            false => vec![Method::GET],
+        }
+    }
+
+    async fn patch(&self, mut patch: Patch) -> Result<(), RedfishError> {
+        let Some(network) = &self.network else {
+            return Err(RedfishError::internal(vec![base::general_error()]));
+        };
+        let current = self.read().map_err(backend_error)?;
+        let settings = requested_settings(
+            current.settings.unwrap_or_default(), &mut patch);
+        patch.finish(READ_ONLY)?;
+        network.configure(&current.id, &settings).map_err(backend_error)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// ManagerInterfaces
+////
+
+// The network interfaces of a manager, which are read from the backend
+// whenever they're requested.
+#[derive(Clone)]
+pub struct ManagerInterfaces {
+    path: PathBuf,
+    network: Arc<dyn NetworkControl>,
+}
+
+impl ManagerInterfaces {
+    // The collection is placed beneath the manager at path
+    pub fn new(path: &Path, network: Arc<dyn NetworkControl>) -> Self {
+        ManagerInterfaces { path: path.join("EthernetInterfaces"), network }
+    }
+
+    pub fn get_path(&self) -> &Path { &self.path }
+
+    pub async fn dispatch(&self, path: &Path, request: Request<Body>) ->
+        Response<Body>
+    {
+        let interfaces = match self.network.interfaces() {
+            Ok(interfaces) => interfaces,
+            Err(error) => return backend_error(error).into(),
+        };
+        let members = interfaces.into_iter()
+            .map(|interface| {
+                let path = self.path.join(&interface.id);
+                let interface = EthernetInterface::new(
+                    interface, Some(self.network.clone()));
+                Resource::new(path, interface).into()
+            })
+            .collect();
+        let collection: ODataResource<Collection<EthernetInterface>> =
+            Resource::new(self.path.clone(), Collection::new(members)).into();
+        collection.dispatch(path, request).await
+            .unwrap_or_else(|error| match error {})
+    }
+
+    pub fn contains(&self, path: &Path) -> bool {
+        path.starts_with(&self.path)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
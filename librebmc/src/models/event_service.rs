@@ -0,0 +1,455 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            event_service.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     EventService, and the subscriptions (EventDestinations)
+//                  that receive its events.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::convert::Infallible;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use hyper::{Body, Method, Request, Response};
+use odata::Resource;
+use serde::{Deserialize, Serialize};
+
+use crate::events::{self, DeliveryRetryPolicy, EventBroker, EventRecord,
+                    EventType, Subscription};
+use crate::events::filter::Filter;
+use crate::models::{Action, Health, Member, Message, State, Status};
+use crate::registries::base;
+use crate::service::{self, ActionResult, Dispatch, MethodNotAllowed,
+                     NotFound, ODataResource, Parameters, Patch,
+                     RedfishError};
+
+const SUBMIT_TEST_EVENT: &'static str = "EventService.SubmitTestEvent";
+const SERVER_SENT_EVENTS: &'static str = "SSE";
+const RESUME_SUBSCRIPTION: &'static str =
+    "EventDestination.ResumeSubscription";
+
+const DESTINATION_READ_ONLY: &[&str] = &[
+    "Id", "Name", "Description", "Destination", "Protocol", "SubscriptionType",
+    "EventFormatType", "EventTypes", "RegistryPrefixes", "ResourceTypes",
+    "Status", "Actions",
+];
+
+fn internal_error(_: std::io::Error) -> RedfishError {
+    RedfishError::internal(vec![base::general_error()])
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Supporting Enums
+////
+
+// Only the subset of each enumeration that librebmc supports is accepted when
+// creating a subscription.
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+enum EventDestinationProtocol {
+    Redfish,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+enum SubscriptionType {
+    RedfishEvent,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+enum EventFormatType {
+    Event,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// EventDestination
+////
+
+#[derive(Clone)]
+pub struct EventDestination {
+    id: String,
+    broker: Arc<EventBroker>,
+}
+
+impl Member for EventDestination {
+    const COLLECTION_TYPE: &'static str =
+        "#EventDestinationCollection.EventDestinationCollection";
+    const COLLECTION_NAME: &'static str = "Event Destination Collection";
+}
+
+impl odata::ResourceMetadata for EventDestination {
+    const ODATA_TYPE: &'static str =
+        "#EventDestination.v1_11_0.EventDestination";
+}
+
+impl odata::Serialize for EventDestination {
+    const CARDINALITY: usize = 14;
+    fn serialize<S>(&self, serializer: &mut S, me: &Path) ->
+        Result<(), S::Error>
+    where S: serde::ser::SerializeStruct
+    {
+        let subscription = self.broker.subscription(&self.id)
+            .unwrap_or_default();
+        serializer.serialize_field("Id", &self.id)?;
+        serializer.serialize_field(
+            "Name", &format!("Event Subscription {}", self.id))?;
+        serializer.serialize_field(
+            "Destination", &subscription.destination)?;
+        serializer.serialize_field("Context", &subscription.context)?;
+        serializer.serialize_field(
+            "Protocol", &EventDestinationProtocol::Redfish)?;
+        serializer.serialize_field(
+            "SubscriptionType", &SubscriptionType::RedfishEvent)?;
+        serializer.serialize_field(
+            "EventFormatType", &EventFormatType::Event)?;
+        serializer.serialize_field("EventTypes", &subscription.event_types)?;
+        serializer.serialize_field(
+            "RegistryPrefixes", &subscription.registry_prefixes)?;
+        serializer.serialize_field(
+            "ResourceTypes", &subscription.resource_types)?;
+        serializer.serialize_field(
+            "DeliveryRetryPolicy", &subscription.delivery_retry_policy)?;
+        // Suspended subscriptions are reported as Disabled
+        serializer.serialize_field("Status", &Status {
+            state: match subscription.suspended {
+                true => State::Disabled,
+                false => State::Enabled,
+            },
+            ..Default::default()
+        })?;
+        serializer.serialize_field(
+            "Actions", &Action::targets(me, &[RESUME_SUBSCRIPTION]))
+    }
+}
+
+impl Dispatch for EventDestination {
+    type Error = Infallible;
+    fn allowed(&self) -> Vec<Method> {
+        vec![Method::GET, Method::PATCH, Method::DELETE]
+    }
+
+    async fn patch(&self, mut patch: Patch) -> Result<(), RedfishError> {
+        let context: Option<Option<String>> = patch.take("Context");
+        let policy: Option<DeliveryRetryPolicy> =
+            patch.take_enum("DeliveryRetryPolicy");
+        patch.finish(DESTINATION_READ_ONLY)?;
+        self.broker.update(&self.id, |subscription| {
+            if let Some(context) = context {
+                subscription.context = context;
+            }
+            if let Some(policy) = policy {
+                subscription.delivery_retry_policy = policy;
+            }
+        }).map_err(internal_error)?;
+        Ok(())
+    }
+
+    async fn delete(&self) -> Result<(), RedfishError> {
+        self.broker.unsubscribe(&self.id).map_err(internal_error)?;
+        Ok(())
+    }
+
+    async fn action(&self, name: &str, parameters: Parameters) ->
+        ActionResult
+    {
+        if name != RESUME_SUBSCRIPTION {
+            return Err(RedfishError::not_found(
+                vec![base::action_not_supported(name)]));
+        }
+        parameters.finish()?;
+        self.broker.update(&self.id, |subscription| {
+            subscription.suspended = false;
+        }).map_err(internal_error)?;
+        Ok(None)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// EventDestinationCollection
+////
+
+// Unlike other collections, members are created and deleted by clients.
+#[derive(Clone)]
+pub struct EventDestinationCollection {
+    path: PathBuf,
+    broker: Arc<EventBroker>,
+}
+
+impl EventDestinationCollection {
+    pub fn new(path: PathBuf, broker: Arc<EventBroker>) -> Self {
+        EventDestinationCollection { path, broker }
+    }
+
+    // Validate the properties of a new subscription
+    fn subscription(patch: &mut Patch) -> Subscription {
+        let destination: Option<String> = patch.require("Destination");
+        let _: Option<EventDestinationProtocol> =
+            patch.require_enum("Protocol");
+        let _: Option<SubscriptionType> = patch.take_enum("SubscriptionType");
+        let _: Option<EventFormatType> = patch.take_enum("EventFormatType");
+        let registry_prefixes: Vec<String> = patch.take("RegistryPrefixes")
+            .unwrap_or_default();
+
+        if let Some(destination) = &destination {
+            if !events::valid_destination(destination) {
+                patch.reject("Destination", base::property_value_format_error(
+                    destination, "Destination"));
+            }
+        }
+        for prefix in &registry_prefixes {
+            if !events::REGISTRY_PREFIXES.contains(&prefix.as_str()) {
+                patch.reject("RegistryPrefixes",
+                             base::property_value_not_in_list(
+                                 prefix, "RegistryPrefixes"));
+            }
+        }
+
+        Subscription {
+            destination: destination.unwrap_or_default(),
+            context: patch.take("Context"),
+            event_types: patch.take_enum("EventTypes").unwrap_or_default(),
+            registry_prefixes,
+            resource_types: patch.take("ResourceTypes").unwrap_or_default(),
+            delivery_retry_policy: patch.take_enum("DeliveryRetryPolicy")
+                .unwrap_or_default(),
+            ..Default::default()
+        }
+    }
+}
+
+impl odata::ResourceMetadata for EventDestinationCollection {
+    const ODATA_TYPE: &'static str = EventDestination::COLLECTION_TYPE;
+}
+
+impl odata::Serialize for EventDestinationCollection {
+    const CARDINALITY: usize = 3;
+    fn serialize<S>(&self, serializer: &mut S, _me: &Path) ->
+        Result<(), S::Error>
+    where S: serde::ser::SerializeStruct
+    {
+        let members: Vec<odata::Link> = self.broker.subscriptions().iter()
+            .map(|subscription| self.path.join(&subscription.id).into())
+            .collect();
+        serializer.serialize_field("Name", EventDestination::COLLECTION_NAME)?;
+        serializer.serialize_field("Members@odata.count", &members.len())?;
+        serializer.serialize_field("Members", &members)
+    }
+}
+
+impl Dispatch for EventDestinationCollection {
+    type Error = Infallible;
+    async fn dispatch(&self, path: &Path, request: Request<Body>) ->
+        Result<Response<Body>, Self::Error>
+    {
+        let id = path.strip_prefix(&self.path).ok()
+            .and_then(|path| path.iter().next())
+            .map(|id| id.to_string_lossy().to_string())
+            .filter(|id| self.broker.subscription(id).is_some());
+        let Some(id) = id else { return Ok(NotFound.into()); };
+        let destination: ODataResource<_> = Resource::new(
+            self.path.join(&id),
+            EventDestination { id, broker: self.broker.clone() }).into();
+        destination.dispatch(path, request).await
+    }
+
+    fn allowed(&self) -> Vec<Method> { vec![Method::GET, Method::POST] }
+
+    async fn create(&self, mut patch: Patch) -> Result<String, RedfishError> {
+        let subscription = Self::subscription(&mut patch);
+        patch.finish(&["Id", "Name", "Description", "Status"])?;
+        self.broker.subscribe(subscription).map_err(internal_error)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// EventService
+////
+
+#[derive(Clone)]
+pub struct EventService {
+    path: PathBuf,
+    broker: Arc<EventBroker>,
+    subscriptions: ODataResource<EventDestinationCollection>,
+}
+
+// Properties that may be used in the $filter of the event stream
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct FilterProperties {
+    event_format_type: bool,
+    message_id: bool,
+    metric_report_definition: bool,
+    origin_resource: bool,
+    registry_prefix: bool,
+    resource_type: bool,
+}
+
+const FILTER_PROPERTIES: FilterProperties = FilterProperties {
+    event_format_type: false,
+    message_id: true,
+    metric_report_definition: false,
+    origin_resource: true,
+    registry_prefix: true,
+    resource_type: true,
+};
+
+impl EventService {
+    pub fn new(
+        path: PathBuf, broker: Arc<EventBroker>,
+        subscriptions: ODataResource<EventDestinationCollection>,
+    ) -> Self {
+        EventService { path, broker, subscriptions }
+    }
+
+    // Open the Server-Sent Events stream
+    fn stream(&self, request: &Request<Body>) -> Response<Body> {
+        if request.method() != Method::GET {
+            return MethodNotAllowed::new(vec![Method::GET]).into();
+        }
+        let filter = match service::query_parameter(request, "$filter") {
+            Some(filter) => match Filter::parse(&filter) {
+                Ok(filter) => filter,
+                Err(_) => return RedfishError::bad_request(vec![
+                    base::query_parameter_value_format_error(
+                        &filter, "$filter")]).into(),
+            },
+            None => Filter::all(),
+        };
+        let last_event_id = request.headers().get("Last-Event-ID")
+            .and_then(|id| id.to_str().ok())
+            .and_then(|id| id.parse().ok());
+        Response::builder()
+            .status(200)
+            .header("Content-Type", "text/event-stream")
+            .header("Cache-Control", "no-cache")
+            .body(self.broker.stream(filter, last_event_id))
+            .unwrap()
+    }
+
+    fn submit_test_event(&self, mut parameters: Parameters) ->
+        Result<(), RedfishError>
+    {
+        let message_id: Option<String> = parameters.require("MessageId");
+        let message: Option<String> = parameters.take("Message");
+        let message_args: Option<Vec<String>> =
+            parameters.take("MessageArgs");
+        // Severity was deprecated in favor of MessageSeverity
+        let severity: Option<Health> = parameters.take_enum("Severity");
+        let message_severity: Option<Health> =
+            parameters.take_enum("MessageSeverity");
+        let event_type: Option<EventType> = parameters.take_enum("EventType");
+        // Events are sequenced by the broker, so a client can't pick an id
+        // that would disturb Last-Event-ID resumption.
+        let _: Option<String> = parameters.take("EventId");
+        let event_timestamp: Option<DateTime<Utc>> =
+            parameters.take("EventTimestamp");
+        let origin: Option<PathBuf> = parameters.take("OriginOfCondition");
+        parameters.finish()?;
+
+        let mut record = EventRecord::new(
+            event_type.unwrap_or(EventType::Other), Message {
+                message_id: message_id.unwrap_or_default(),
+                message: message.unwrap_or_default(),
+                message_args: message_args.unwrap_or_default(),
+                message_severity: message_severity.or(severity)
+                    .unwrap_or_default(),
+                resolution: String::new(),
+                related_properties: Vec::new(),
+            });
+        if let Some(event_timestamp) = event_timestamp {
+            record.event_timestamp = event_timestamp;
+        }
+        if let Some(origin) = origin {
+            record = record.with_origin(origin.into(), None);
+        }
+        self.broker.publish(record);
+        Ok(())
+    }
+}
+
+impl odata::ResourceMetadata for EventService {
+    const ODATA_TYPE: &'static str = "#EventService.v1_8_0.EventService";
+}
+
+impl odata::Serialize for EventService {
+    const CARDINALITY: usize = 12;
+    fn serialize<S>(&self, serializer: &mut S, me: &Path) ->
+        Result<(), S::Error>
+    where S: serde::ser::SerializeStruct
+    {
+        serializer.serialize_field("Id", "EventService")?;
+        serializer.serialize_field("Name", "Event Service")?;
+        serializer.serialize_field("ServiceEnabled", &true)?;
+        serializer.serialize_field("Status", &Status::default())?;
+        serializer.serialize_field(
+            "DeliveryRetryAttempts", &self.broker.delivery_retry_attempts())?;
+        serializer.serialize_field(
+            "DeliveryRetryIntervalSeconds",
+            &self.broker.delivery_retry_interval().as_secs())?;
+        serializer.serialize_field(
+            "EventFormatTypes", &[EventFormatType::Event])?;
+        serializer.serialize_field(
+            "RegistryPrefixes", events::REGISTRY_PREFIXES)?;
+        serializer.serialize_field(
+            "ServerSentEventUri", &me.join(SERVER_SENT_EVENTS))?;
+        serializer.serialize_field(
+            "SSEFilterPropertiesSupported", &FILTER_PROPERTIES)?;
+        serializer.serialize_field(
+            "Subscriptions", &self.subscriptions.as_ref().get_id())?;
+        serializer.serialize_field(
+            "Actions", &Action::targets(me, &[SUBMIT_TEST_EVENT]))
+    }
+}
+
+impl Dispatch for EventService {
+    type Error = Infallible;
+    async fn dispatch(&self, path: &Path, request: Request<Body>) ->
+        Result<Response<Body>, Self::Error>
+    {
+        if path == self.path.join(SERVER_SENT_EVENTS) {
+            return Ok(self.stream(&request));
+        }
+        match self.subscriptions.contains(path) {
+            true => self.subscriptions.dispatch(path, request).await,
+            false => Ok(NotFound.into()),
+        }
+    }
+
+    async fn action(&self, name: &str, parameters: Parameters) ->
+        ActionResult
+    {
+        match name {
+            SUBMIT_TEST_EVENT => self.submit_test_event(parameters)
+                .map(|_| None),
+            _ => Err(RedfishError::not_found(
+                vec![base::action_not_supported(name)])),
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
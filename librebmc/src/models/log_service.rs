@@ -0,0 +1,407 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            log_service.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     LogService, LogEntry and their collections, which serve the
+//                  entries of a LogStore or of a LogSource.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::convert::Infallible;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use hyper::{Body, Request, Response, StatusCode};
+use odata::Resource;
+use serde::Serialize;
+
+use crate::logs::{LogRecord, LogSource, LogStore, SourceEntry};
+use crate::models::{Action, Member, Status};
+use crate::registries::base;
+use crate::service::{self, ActionResult, Dispatch, NotFound, ODataResource,
+                     Parameters, RedfishError};
+
+const CLEAR_LOG: &'static str = "LogService.ClearLog";
+
+// The number of entries of a LogSource in each page of the collection
+const PAGE_SIZE: usize = 100;
+
+///////////////////////////////////////////////////////////////////////////////
+// Supporting Types
+////
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum LogEntryType {
+    Event,
+    SEL,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct LogEntryLinks {
+    origin_of_condition: odata::Link,
+}
+
+// Entries are either recorded by librebmc, or read from a log kept by
+// another part of the system, e.g. the journal.
+#[derive(Clone)]
+enum Backend {
+    Store(Arc<LogStore>),
+    Source(Arc<dyn LogSource>),
+}
+
+#[derive(Clone)]
+enum Record {
+    Stored(LogRecord),
+    Read(SourceEntry),
+}
+
+// Reads from a LogSource may block, so they're kept off of the runtime
+async fn read<T, F>(source: &Arc<dyn LogSource>, read: F) ->
+    Result<T, RedfishError>
+where T: Send + 'static,
+      F: FnOnce(&dyn LogSource) -> io::Result<T> + Send + 'static,
+{
+    let source = source.clone();
+    tokio::task::spawn_blocking(move || read(source.as_ref())).await
+        .ok()
+        .and_then(|result| result.ok())
+        .ok_or_else(|| RedfishError::internal(vec![base::general_error()]))
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// LogEntry
+////
+
+#[derive(Clone)]
+pub struct LogEntry {
+    record: Record,
+    entry_type: LogEntryType,
+}
+
+impl LogEntry {
+    // Cursors may contain characters that aren't allowed in a URI
+    pub fn get_id(&self) -> String {
+        match &self.record {
+            Record::Stored(record) => record.id.to_string(),
+            Record::Read(entry) => service::percent_encode(&entry.cursor),
+        }
+    }
+}
+
+impl Member for LogEntry {
+    const COLLECTION_TYPE: &'static str =
+        "#LogEntryCollection.LogEntryCollection";
+    const COLLECTION_NAME: &'static str = "Log Entry Collection";
+}
+
+impl odata::ResourceMetadata for LogEntry {
+    const ODATA_TYPE: &'static str = "#LogEntry.v1_15_0.LogEntry";
+}
+
+impl odata::Serialize for LogEntry {
+    const CARDINALITY: usize = 10;
+    fn serialize<S>(&self, serializer: &mut S, _me: &Path) ->
+        Result<(), S::Error>
+    where S: serde::ser::SerializeStruct
+    {
+        let id = self.get_id();
+        serializer.serialize_field("Id", &id)?;
+        serializer.serialize_field("Name", &format!("Log Entry {}", id))?;
+        serializer.serialize_field("EntryType", &self.entry_type)?;
+        let record = match &self.record {
+            Record::Stored(record) => record,
+            Record::Read(entry) => {
+                serializer.serialize_field("Created", &entry.created)?;
+                serializer.serialize_field("Severity", &entry.severity)?;
+                serializer.serialize_field(
+                    "MessageSeverity", &entry.severity)?;
+                return serializer.serialize_field("Message", &entry.message);
+            },
+        };
+        serializer.serialize_field("Created", &record.created)?;
+        serializer.serialize_field(
+            "Severity", &record.message.message_severity)?;
+        serializer.serialize_field(
+            "MessageSeverity", &record.message.message_severity)?;
+        serializer.serialize_field("Message", &record.message.message)?;
+        serializer.serialize_field("MessageId", &record.message.message_id)?;
+        serializer.serialize_field(
+            "MessageArgs", &record.message.message_args)?;
+        if let Some(origin) = &record.origin {
+            serializer.serialize_field("Links", &LogEntryLinks {
+                origin_of_condition: origin.clone().into(),
+            })?;
+        }
+        Ok(())
+    }
+}
+
+impl Dispatch for LogEntry {
+    type Error = Infallible;
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// LogEntryCollection
+////
+
+// Members are served in full, so that clients can read a log without a
+// request for each entry. The entries of a LogStore may be paged with $top
+// and $skip, and those of a LogSource are paged by the cursor of the last
+// entry of the previous page, in $skiptoken.
+#[derive(Clone)]
+pub struct LogEntryCollection {
+    members: Vec<ODataResource<LogEntry>>,
+    count: Option<usize>,
+    next: Option<String>,
+}
+
+impl odata::ResourceMetadata for LogEntryCollection {
+    const ODATA_TYPE: &'static str = LogEntry::COLLECTION_TYPE;
+}
+
+impl odata::Serialize for LogEntryCollection {
+    const CARDINALITY: usize = 4;
+    fn serialize<S>(&self, serializer: &mut S, _me: &Path) ->
+        Result<(), S::Error>
+    where S: serde::ser::SerializeStruct
+    {
+        serializer.serialize_field("Name", LogEntry::COLLECTION_NAME)?;
+        if let Some(count) = self.count {
+            serializer.serialize_field("Members@odata.count", &count)?;
+        }
+        serializer.serialize_field("Members", &self.members)?;
+        if let Some(next) = &self.next {
+            serializer.serialize_field("Members@odata.nextLink", next)?;
+        }
+        Ok(())
+    }
+}
+
+impl Dispatch for LogEntryCollection {
+    type Error = Infallible;
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// LogService
+////
+
+#[derive(Clone)]
+pub struct LogService {
+    id: String,
+    name: String,
+    entry_type: LogEntryType,
+    backend: Backend,
+    entries: PathBuf,
+
+    // Logs kept for security reviews can't be cleared by a client
+    clearable: bool,
+}
+
+impl LogService {
+    fn with_backend(path: PathBuf, name: &str, entry_type: LogEntryType,
+                    backend: Backend) -> Self
+    {
+        LogService {
+            id: path.file_name().unwrap().to_string_lossy().to_string(),
+            name: name.to_string(), entry_type, backend,
+            entries: path.join("Entries"), clearable: true,
+        }
+    }
+
+    pub fn new(path: PathBuf, name: &str, entry_type: LogEntryType,
+               store: Arc<LogStore>) -> Self
+    {
+        Self::with_backend(path, name, entry_type, Backend::Store(store))
+    }
+
+    // A log of records that are only ever appended, e.g. the audit log
+    pub fn append_only(path: PathBuf, name: &str, entry_type: LogEntryType,
+                       store: Arc<LogStore>) -> Self
+    {
+        let service = Self::new(path, name, entry_type, store);
+        LogService { clearable: false, ..service }
+    }
+
+    pub fn from_source(path: PathBuf, name: &str, entry_type: LogEntryType,
+                       source: Arc<dyn LogSource>) -> Self
+    {
+        Self::with_backend(path, name, entry_type, Backend::Source(source))
+    }
+
+    pub fn get_id(&self) -> &str { &self.id }
+
+    fn entry(&self, record: Record) -> ODataResource<LogEntry> {
+        let entry = LogEntry { record, entry_type: self.entry_type };
+        Resource::new(self.entries.join(entry.get_id()), entry).into()
+    }
+
+    async fn collection(&self, request: &Request<Body>) ->
+        Result<LogEntryCollection, RedfishError>
+    {
+        let source = match &self.backend {
+            Backend::Store(store) => {
+                let members: Vec<_> = store.entries().into_iter()
+                    .map(|record| self.entry(Record::Stored(record)))
+                    .collect();
+                return Ok(LogEntryCollection {
+                    count: Some(members.len()), members, next: None,
+                });
+            },
+            Backend::Source(source) => source,
+        };
+
+        // Read one more than a page, to learn whether there's another
+        let after = service::query_parameter(request, "$skiptoken");
+        let (count, mut page) = read(source, move |source| {
+            let page = source.read(after.as_deref(), PAGE_SIZE + 1)?;
+            Ok((source.count()?, page))
+        }).await?;
+        let next = match page.len() > PAGE_SIZE {
+            true => {
+                page.truncate(PAGE_SIZE);
+                page.last().map(|entry| format!(
+                    "{}?$skiptoken={}", self.entries.to_string_lossy(),
+                    service::percent_encode(&entry.cursor)))
+            },
+            false => None,
+        };
+        let members = page.into_iter()
+            .map(|entry| self.entry(Record::Read(entry)))
+            .collect();
+        Ok(LogEntryCollection { members, count, next })
+    }
+
+    async fn record(&self, id: &str) -> Result<Option<Record>, RedfishError> {
+        match &self.backend {
+            Backend::Store(store) => Ok(id.parse().ok()
+               .and_then(|id| store.entry(id))
+               .map(Record::Stored)),
+            Backend::Source(source) => {
+                let cursor = service::percent_decode(id);
+                let entry = read(source, move |source| source.entry(&cursor))
+                    .await?;
+                Ok(entry.map(Record::Read))
+            },
+        }
+    }
+}
+
+impl Member for LogService {
+    const COLLECTION_TYPE: &'static str =
+        "#LogServiceCollection.LogServiceCollection";
+    const COLLECTION_NAME: &'static str = "Log Service Collection";
+}
+
+impl odata::ResourceMetadata for LogService {
+    const ODATA_TYPE: &'static str = "#LogService.v1_4_0.LogService";
+}
+
+impl odata::Serialize for LogService {
+    const CARDINALITY: usize = 9;
+    fn serialize<S>(&self, serializer: &mut S, me: &Path) ->
+        Result<(), S::Error>
+    where S: serde::ser::SerializeStruct
+    {
+        serializer.serialize_field("Id", &self.id)?;
+        serializer.serialize_field("Name", &self.name)?;
+        serializer.serialize_field("ServiceEnabled", &true)?;
+        serializer.serialize_field("Status", &Status::default())?;
+        serializer.serialize_field("LogEntryType", &self.entry_type)?;
+        serializer.serialize_field(
+            "Entries", &odata::Link::from(self.entries.clone()))?;
+
+        // Logs kept by other parts of the system are only read
+        if let Backend::Store(store) = &self.backend {
+            serializer.serialize_field(
+                "MaxNumberOfRecords", &store.max_records())?;
+            serializer.serialize_field(
+                "OverWritePolicy", &store.overwrite_policy())?;
+            if self.clearable {
+                serializer.serialize_field(
+                    "Actions", &Action::targets(me, &[CLEAR_LOG]))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Dispatch for LogService {
+    type Error = Infallible;
+    async fn dispatch(&self, path: &Path, request: Request<Body>) ->
+        Result<Response<Body>, Self::Error>
+    {
+        if path == self.entries {
+            return match self.collection(&request).await {
+                Ok(collection) => ODataResource::from(
+                    Resource::new(self.entries.clone(), collection))
+                    .dispatch(path, request).await,
+                Err(error) => Ok(error.into()),
+            };
+        }
+
+        let id = path.strip_prefix(&self.entries).ok()
+            .and_then(|id| id.to_str())
+            .filter(|id| !id.is_empty() && !id.contains('/'));
+        let Some(id) = id else {
+            return Ok(NotFound.into());
+        };
+        match self.record(id).await {
+            Ok(Some(record)) => self.entry(record)
+                .dispatch(path, request).await,
+            Ok(None) => Ok(NotFound.into()),
+            Err(error) => Ok(error.into()),
+        }
+    }
+
+    async fn action(&self, name: &str, parameters: Parameters) ->
+        ActionResult
+    {
+        let Backend::Store(store) = &self.backend else {
+            return Err(RedfishError::not_found(
+                vec![base::action_not_supported(name)]));
+        };
+        if name != CLEAR_LOG {
+            return Err(RedfishError::not_found(
+                vec![base::action_not_supported(name)]));
+        }
+        if !self.clearable {
+            return Err(RedfishError::new(
+                StatusCode::METHOD_NOT_ALLOWED,
+                vec![base::action_not_supported(name)]));
+        }
+        parameters.finish()?;
+        store.clear().map_err(|_| {
+            RedfishError::internal(vec![base::general_error()])
+        })?;
+        Ok(None)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
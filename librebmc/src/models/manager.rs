@@ -0,0 +1,162 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            manager.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     The Manager resource, which represents the BMC itself.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::convert::Infallible;
+use std::path::Path;
+
+use derive_builder::Builder;
+use hyper::{Body, Request, Response};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::models::{LogServiceCollection, ManagerInterfaces,
+                    ManagerNetworkProtocol, Member, Status};
+use crate::service::{Dispatch, NotFound, ODataResource};
+
+const DEFAULT_NAME: &'static str = "Manager";
+
+///////////////////////////////////////////////////////////////////////////////
+// Supporting Enums
+////
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum ManagerType {
+    AuxiliaryController,
+    #[default]
+    BMC,
+    EnclosureManager,
+    ManagementController,
+    RackManager,
+    Service,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Manager
+////
+
+#[derive(Builder, Clone)]
+#[builder(setter(into))]
+pub struct Manager {
+    id: String,
+
+    #[builder(default = "DEFAULT_NAME.to_string()")]
+    name: String,
+
+    #[builder(default)]
+    manager_type: ManagerType,
+
+    #[builder(default = "env!(\"CARGO_PKG_VERSION\").to_string()")]
+    firmware_version: String,
+
+    #[builder(default)]
+    uuid: Uuid,
+
+    #[builder(default)]
+    status: Status,
+
+    #[builder(default, setter(strip_option))]
+    log_services: Option<ODataResource<LogServiceCollection>>,
+
+    #[builder(default, setter(strip_option))]
+    ethernet_interfaces: Option<ManagerInterfaces>,
+
+    #[builder(default, setter(strip_option))]
+    network_protocol: Option<ODataResource<ManagerNetworkProtocol>>,
+}
+
+impl Manager {
+    pub fn get_id(&self) -> &str { &self.id }
+}
+
+impl odata::ResourceMetadata for Manager {
+    const ODATA_TYPE: &'static str = "#Manager.v1_17_0.Manager";
+}
+
+impl Member for Manager {
+    const COLLECTION_TYPE: &'static str =
+        "#ManagerCollection.ManagerCollection";
+    const COLLECTION_NAME: &'static str = "Manager Collection";
+}
+
+impl odata::Serialize for Manager {
+    const CARDINALITY: usize = 9;
+    fn serialize<S>(&self, serializer: &mut S, _me: &Path) ->
+        Result<(), S::Error>
+    where S: serde::ser::SerializeStruct
+    {
+        serializer.serialize_field("Id", &self.id)?;
+        serializer.serialize_field("Name", &self.name)?;
+        serializer.serialize_field("ManagerType", &self.manager_type)?;
+        serializer.serialize_field(
+            "FirmwareVersion", &self.firmware_version)?;
+        serializer.serialize_field("UUID", &self.uuid)?;
+        serializer.serialize_field("Status", &self.status)?;
+        if let Some(log_services) = &self.log_services {
+            serializer.serialize_field(
+                "LogServices", &log_services.as_ref().get_id())?;
+        }
+        if let Some(interfaces) = &self.ethernet_interfaces {
+            let interfaces = odata::Link::from(
+                interfaces.get_path().to_owned());
+            serializer.serialize_field("EthernetInterfaces", &interfaces)?;
+        }
+        if let Some(network_protocol) = &self.network_protocol {
+            serializer.serialize_field(
+                "NetworkProtocol", &network_protocol.as_ref().get_id())?;
+        }
+        Ok(())
+    }
+}
+
+impl Dispatch for Manager {
+    type Error = Infallible;
+    async fn dispatch(&self, path: &Path, request: Request<Body>) ->
+        Result<Response<Body>, Self::Error>
+    {
+        if let Some(log_services) = self.log_services.as_ref()
+            .filter(|log_services| log_services.contains(path)) {
+            return log_services.dispatch(path, request).await;
+        }
+        if let Some(interfaces) = self.ethernet_interfaces.as_ref()
+            .filter(|interfaces| interfaces.contains(path)) {
+            return Ok(interfaces.dispatch(path, request).await);
+        }
+        if let Some(network_protocol) = self.network_protocol.as_ref()
+            .filter(|network_protocol| network_protocol.contains(path)) {
+            return network_protocol.dispatch(path, request).await;
+        }
+        Ok(NotFound.into())
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,201 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            manager_network_protocol.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     The protocols served by a manager, some of which may be
+//                  changed at runtime.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::convert::Infallible;
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+
+use hyper::Method;
+use serde::Serialize;
+
+use crate::config::{NetworkConfig, ProtocolConfig};
+use crate::hardware::time::TimeSync;
+use crate::models::Status;
+use crate::protocols::{ListenerSettings, Protocols};
+use crate::registries::base;
+use crate::service::{Dispatch, Patch, RedfishError};
+
+const NTP_PORT: u16 = 123;
+
+// The protocols served by other daemons are only described
+const READ_ONLY: &[&str] = &[
+    "Id", "Name", "HTTPS", "SSH", "IPMI", "SNMP", "Status",
+];
+
+fn backend_error(_: io::Error) -> RedfishError {
+    RedfishError::internal(vec![base::general_error()])
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct Protocol {
+    protocol_enabled: bool,
+    port: u16,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct NtpProtocol {
+    protocol_enabled: bool,
+    port: u16,
+    #[serde(rename = "NTPServers")]
+    ntp_servers: Vec<String>,
+}
+
+// Apply a PATCH of HTTP or SSDP to the settings of its listener
+fn requested_listener(patch: &mut Patch, name: &str,
+                      settings: &mut ListenerSettings)
+{
+    let Some(mut listener) = patch.take_object(name) else { return };
+    if let Some(enabled) = listener.take("ProtocolEnabled") {
+        settings.protocol_enabled = enabled;
+    }
+    if let Some(port) = listener.take::<u32>("Port") {
+        match u16::try_from(port).ok().filter(|port| *port != 0) {
+            Some(port) => settings.port = port,
+            None => listener.reject("Port", base::property_value_out_of_range(
+                &port.to_string(), &format!("{}/Port", name))),
+        }
+    }
+    patch.merge(listener, &[]);
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// ManagerNetworkProtocol
+////
+
+#[derive(Clone)]
+pub struct ManagerNetworkProtocol {
+    protocols: Arc<Protocols>,
+    time: Arc<dyn TimeSync>,
+    external: Vec<(&'static str, ProtocolConfig)>,
+}
+
+impl ManagerNetworkProtocol {
+    pub fn new(protocols: Arc<Protocols>, time: Arc<dyn TimeSync>,
+               config: &NetworkConfig) -> Self
+    {
+        let external = [
+            ("HTTPS", &config.https), ("SSH", &config.ssh),
+            ("IPMI", &config.ipmi), ("SNMP", &config.snmp),
+        ];
+        ManagerNetworkProtocol {
+            protocols, time,
+            external: external.into_iter()
+                .filter_map(|(name, protocol)| Some((name, protocol.clone()?)))
+                .collect(),
+        }
+    }
+}
+
+impl odata::ResourceMetadata for ManagerNetworkProtocol {
+    const ODATA_TYPE: &'static str =
+        "#ManagerNetworkProtocol.v1_10_0.ManagerNetworkProtocol";
+}
+
+impl odata::Serialize for ManagerNetworkProtocol {
+    const CARDINALITY: usize = 10;
+    fn serialize<S>(&self, serializer: &mut S, _me: &Path) ->
+        Result<(), S::Error>
+    where S: serde::ser::SerializeStruct
+    {
+        serializer.serialize_field("Id", "NetworkProtocol")?;
+        serializer.serialize_field("Name", "Manager Network Protocol")?;
+        let settings = self.protocols.settings();
+        let listeners = [("HTTP", settings.http), ("SSDP", settings.ssdp)];
+        for (name, listener) in listeners {
+            serializer.serialize_field(name, &Protocol {
+                protocol_enabled: listener.protocol_enabled,
+                port: listener.port,
+            })?;
+        }
+        if let Ok(ntp) = self.time.ntp() {
+            serializer.serialize_field("NTP", &NtpProtocol {
+                protocol_enabled: ntp.enabled, port: NTP_PORT,
+                ntp_servers: ntp.servers,
+            })?;
+        }
+        for (name, protocol) in &self.external {
+            serializer.serialize_field(name, &Protocol {
+                protocol_enabled: protocol.enabled, port: protocol.port,
+            })?;
+        }
+        serializer.serialize_field("Status", &Status::default())
+    }
+}
+
+impl Dispatch for ManagerNetworkProtocol {
+    type Error = Infallible;
+
+    fn allowed(&self) -> Vec<Method> { vec![Method::GET, Method::PATCH] }
+
+    async fn patch(&self, mut patch: Patch) -> Result<(), RedfishError> {
+        let mut settings = self.protocols.settings();
+        requested_listener(&mut patch, "HTTP", &mut settings.http);
+        requested_listener(&mut patch, "SSDP", &mut settings.ssdp);
+
+        let current = self.time.ntp().map_err(backend_error)?;
+        let mut ntp = current.clone();
+        if let Some(mut requested) = patch.take_object("NTP") {
+            if let Some(enabled) = requested.take("ProtocolEnabled") {
+                ntp.enabled = enabled;
+            }
+            if let Some(servers) = requested.take::<Vec<String>>("NTPServers")
+            {
+                for (index, server) in servers.iter().enumerate() {
+                    let valid = !server.is_empty()
+                        && !server.contains(char::is_whitespace);
+                    if !valid {
+                        let property = format!("NTPServers/{}", index);
+                        requested.reject(
+                            &property, base::property_value_format_error(
+                                server, &format!("NTP/{}", property)));
+                    }
+                }
+                ntp.servers = servers;
+            }
+            patch.merge(requested, &["Port"]);
+        }
+        patch.finish(READ_ONLY)?;
+
+        // The listeners may fail to start, e.g. if the port is in use
+        self.protocols.set_settings(&settings).map_err(backend_error)?;
+        if ntp != current {
+            self.time.set_ntp(&ntp).map_err(backend_error)?;
+        }
+        Ok(())
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
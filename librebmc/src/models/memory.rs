@@ -0,0 +1,144 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            memory.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Memory model, and the summary of the memory of a system.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::convert::Infallible;
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::hardware::inventory::MemoryInventory;
+use crate::models::{Health, Member, State, Status};
+use crate::service::Dispatch;
+
+const MIB_PER_GIB: f64 = 1024.0;
+
+///////////////////////////////////////////////////////////////////////////////
+// Memory
+////
+
+#[derive(Clone)]
+pub struct Memory(MemoryInventory);
+
+impl Memory {
+    pub fn get_id(&self) -> &str { &self.0.id }
+}
+
+impl From<MemoryInventory> for Memory {
+    fn from(value: MemoryInventory) -> Self { Memory(value) }
+}
+
+impl Member for Memory {
+    const COLLECTION_TYPE: &'static str = "#MemoryCollection.MemoryCollection";
+    const COLLECTION_NAME: &'static str = "Memory Module Collection";
+}
+
+impl odata::ResourceMetadata for Memory {
+    const ODATA_TYPE: &'static str = "#Memory.v1_17_0.Memory";
+}
+
+impl odata::Serialize for Memory {
+    const CARDINALITY: usize = 10;
+    fn serialize<S>(&self, serializer: &mut S, _me: &Path) ->
+        Result<(), S::Error>
+    where S: serde::ser::SerializeStruct
+    {
+        let inventory = &self.0;
+        let name = inventory.device_locator.clone()
+            .unwrap_or_else(|| format!("Memory {}", inventory.id));
+        serializer.serialize_field("Id", &inventory.id)?;
+        serializer.serialize_field("Name", &name)?;
+        if let Some(device_type) = &inventory.memory_device_type {
+            serializer.serialize_field("MemoryDeviceType", device_type)?;
+        }
+        let strings = [
+            ("DeviceLocator", &inventory.device_locator),
+            ("Manufacturer", &inventory.manufacturer),
+            ("PartNumber", &inventory.part_number),
+            ("SerialNumber", &inventory.serial_number),
+        ];
+        for (name, value) in strings {
+            if let Some(value) = value {
+                serializer.serialize_field(name, value)?;
+            }
+        }
+        let integers = [
+            ("CapacityMiB", inventory.capacity_mib),
+            ("OperatingSpeedMhz", inventory.operating_speed_mhz),
+        ];
+        for (name, value) in integers {
+            if let Some(value) = value {
+                serializer.serialize_field(name, &value)?;
+            }
+        }
+        serializer.serialize_field("Status", &Status {
+            state: inventory.state, health: inventory.health,
+            health_rollup: None,
+        })
+    }
+}
+
+impl Dispatch for Memory {
+    type Error = Infallible;
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// MemorySummary
+////
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct MemorySummary {
+    #[serde(rename = "TotalSystemMemoryGiB")]
+    total_system_memory_gib: f64,
+    status: Status,
+}
+
+// Absent modules are left out of the summary
+impl From<&[MemoryInventory]> for MemorySummary {
+    fn from(value: &[MemoryInventory]) -> Self {
+        let present = value.iter()
+            .filter(|module| module.state != State::Absent);
+        let (capacity_mib, health) = present.fold(
+            (0u64, Health::OK), |(capacity, health), module| (
+                capacity + module.capacity_mib.unwrap_or(0) as u64,
+                health.worst(module.health),
+            ));
+        MemorySummary {
+            total_system_memory_gib: capacity_mib as f64 / MIB_PER_GIB,
+            status: Status {
+                state: State::Enabled, health, health_rollup: Some(health),
+            },
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,65 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            message.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Redfish Message, as reported in extended error information,
+//                  task messages and events.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::Health;
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Message {
+    pub message_id: String,
+    pub message: String,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub message_args: Vec<String>,
+
+    pub message_severity: Health,
+
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub resolution: String,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub related_properties: Vec<String>,
+}
+
+impl Message {
+    // Associate this message with the properties, as JSON pointers, that
+    // caused it.
+    pub fn related_to(mut self, property: &str) -> Self {
+        self.related_properties.push(format!("#/{}", property));
+        self
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
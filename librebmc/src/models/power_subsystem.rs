@@ -0,0 +1,169 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            power_subsystem.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     PowerSubsystem and PowerSupply models.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::convert::Infallible;
+use std::path::Path;
+use std::sync::Arc;
+
+use hyper::{Body, Request, Response};
+
+use crate::hardware::power;
+use crate::health::HealthNode;
+use crate::models::{Collection, Health, Member, State, Status};
+use crate::service::{Dispatch, ODataResource};
+
+///////////////////////////////////////////////////////////////////////////////
+// PowerSupply
+////
+
+#[derive(Clone)]
+pub struct PowerSupply(Arc<dyn power::PowerSupply>);
+
+impl PowerSupply {
+    pub fn get_id(&self) -> &str { &self.0.inventory().id }
+}
+
+impl From<Arc<dyn power::PowerSupply>> for PowerSupply {
+    fn from(value: Arc<dyn power::PowerSupply>) -> Self { PowerSupply(value) }
+}
+
+impl Member for PowerSupply {
+    const COLLECTION_TYPE: &'static str =
+        "#PowerSupplyCollection.PowerSupplyCollection";
+    const COLLECTION_NAME: &'static str = "Power Supply Collection";
+}
+
+impl odata::ResourceMetadata for PowerSupply {
+    const ODATA_TYPE: &'static str = "#PowerSupply.v1_5_0.PowerSupply";
+}
+
+impl odata::Serialize for PowerSupply {
+    const CARDINALITY: usize = 13;
+    fn serialize<S>(&self, serializer: &mut S, _me: &Path) ->
+        Result<(), S::Error>
+    where S: serde::ser::SerializeStruct
+    {
+        let inventory = self.0.inventory();
+        serializer.serialize_field("Id", &inventory.id)?;
+        serializer.serialize_field("Name", &inventory.name)?;
+        let strings = [
+            ("Manufacturer", &inventory.manufacturer),
+            ("Model", &inventory.model),
+            ("SerialNumber", &inventory.serial_number),
+            ("PartNumber", &inventory.part_number),
+            ("FirmwareVersion", &inventory.firmware_version),
+        ];
+        for (name, value) in strings {
+            if let Some(value) = value {
+                serializer.serialize_field(name, value)?;
+            }
+        }
+        if let Some(power_supply_type) = &inventory.power_supply_type {
+            serializer.serialize_field("PowerSupplyType", power_supply_type)?;
+        }
+        if let Some(capacity) = &inventory.power_capacity_watts {
+            serializer.serialize_field("PowerCapacityWatts", capacity)?;
+        }
+        serializer.serialize_field("InputRanges", &inventory.input_ranges)?;
+
+        match self.0.read() {
+            Ok(reading) => {
+                serializer.serialize_field("Status", &Status {
+                    state: reading.state, health: reading.health,
+                    health_rollup: None,
+                })?;
+                serializer.serialize_field(
+                    "LineInputStatus", &reading.line_input_status)
+            },
+            Err(_) => serializer.serialize_field("Status", &Status {
+                state: State::UnavailableOffline, health: Health::Warning,
+                health_rollup: None,
+            }),
+        }
+    }
+}
+
+impl Dispatch for PowerSupply {
+    type Error = Infallible;
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// PowerSubsystem
+////
+
+#[derive(Clone)]
+pub struct PowerSubsystem {
+    health: Arc<HealthNode>,
+    power_supplies: ODataResource<Collection<PowerSupply>>,
+}
+
+impl PowerSubsystem {
+    pub fn new(
+        power_supplies: ODataResource<Collection<PowerSupply>>,
+        health: Arc<HealthNode>,
+    ) -> Self {
+        PowerSubsystem { health, power_supplies }
+    }
+}
+
+impl odata::ResourceMetadata for PowerSubsystem {
+    const ODATA_TYPE: &'static str = "#PowerSubsystem.v1_1_0.PowerSubsystem";
+}
+
+impl odata::Serialize for PowerSubsystem {
+    const CARDINALITY: usize = 5;
+    fn serialize<S>(&self, serializer: &mut S, _me: &Path) ->
+        Result<(), S::Error>
+    where S: serde::ser::SerializeStruct
+    {
+        serializer.serialize_field("Id", "PowerSubsystem")?;
+        serializer.serialize_field("Name", "Power Subsystem")?;
+        let capacity: f64 = self.power_supplies.as_ref().get().members().iter()
+            .filter_map(|supply| supply.as_ref().get().0.inventory()
+                        .power_capacity_watts)
+            .sum();
+        serializer.serialize_field("CapacityWatts", &capacity)?;
+        serializer.serialize_field(
+            "Status", &self.health.status(State::Enabled))?;
+        serializer.serialize_field(
+            "PowerSupplies", &self.power_supplies.as_ref().get_id())
+    }
+}
+
+impl Dispatch for PowerSubsystem {
+    type Error = Infallible;
+    async fn dispatch(&self, path: &Path, request: Request<Body>) ->
+        Result<Response<Body>, Self::Error>
+    { self.power_supplies.dispatch(path, request).await }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,163 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            processor.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Processor model, and the summary of the processors of a
+//                  system.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::convert::Infallible;
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::hardware::inventory::ProcessorInventory;
+use crate::models::{Health, Member, State, Status};
+use crate::service::Dispatch;
+
+///////////////////////////////////////////////////////////////////////////////
+// Processor
+////
+
+#[derive(Clone)]
+pub struct Processor(ProcessorInventory);
+
+impl Processor {
+    pub fn get_id(&self) -> &str { &self.0.id }
+}
+
+impl From<ProcessorInventory> for Processor {
+    fn from(value: ProcessorInventory) -> Self { Processor(value) }
+}
+
+impl Member for Processor {
+    const COLLECTION_TYPE: &'static str =
+        "#ProcessorCollection.ProcessorCollection";
+    const COLLECTION_NAME: &'static str = "Processor Collection";
+}
+
+impl odata::ResourceMetadata for Processor {
+    const ODATA_TYPE: &'static str = "#Processor.v1_16_0.Processor";
+}
+
+impl odata::Serialize for Processor {
+    const CARDINALITY: usize = 12;
+    fn serialize<S>(&self, serializer: &mut S, _me: &Path) ->
+        Result<(), S::Error>
+    where S: serde::ser::SerializeStruct
+    {
+        let inventory = &self.0;
+        let name = inventory.socket.clone()
+            .unwrap_or_else(|| format!("Processor {}", inventory.id));
+        serializer.serialize_field("Id", &inventory.id)?;
+        serializer.serialize_field("Name", &name)?;
+        serializer.serialize_field(
+            "ProcessorType", &inventory.processor_type)?;
+        if let Some(architecture) = &inventory.processor_architecture {
+            serializer.serialize_field("ProcessorArchitecture", architecture)?;
+        }
+        if let Some(instruction_set) = &inventory.instruction_set {
+            serializer.serialize_field("InstructionSet", instruction_set)?;
+        }
+        let strings = [
+            ("Socket", &inventory.socket),
+            ("Manufacturer", &inventory.manufacturer),
+            ("Model", &inventory.model),
+        ];
+        for (name, value) in strings {
+            if let Some(value) = value {
+                serializer.serialize_field(name, value)?;
+            }
+        }
+        let integers = [
+            ("MaxSpeedMHz", inventory.max_speed_mhz),
+            ("TotalCores", inventory.total_cores),
+            ("TotalThreads", inventory.total_threads),
+        ];
+        for (name, value) in integers {
+            if let Some(value) = value {
+                serializer.serialize_field(name, &value)?;
+            }
+        }
+        serializer.serialize_field("Status", &Status {
+            state: inventory.state, health: inventory.health,
+            health_rollup: None,
+        })
+    }
+}
+
+impl Dispatch for Processor {
+    type Error = Infallible;
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// ProcessorSummary
+////
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ProcessorSummary {
+    count: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    core_count: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    logical_processor_count: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    model: Option<String>,
+    status: Status,
+}
+
+// Absent processors are left out of the summary
+impl From<&[ProcessorInventory]> for ProcessorSummary {
+    fn from(value: &[ProcessorInventory]) -> Self {
+        let present: Vec<_> = value.iter()
+            .filter(|processor| processor.state != State::Absent)
+            .collect();
+        let sum = |count: fn(&ProcessorInventory) -> Option<u32>| {
+            present.iter().map(|processor| count(processor))
+                .try_fold(0, |total, count| Some(total + count?))
+                .filter(|_| !present.is_empty())
+        };
+        let health = present.iter()
+            .fold(Health::OK, |health, processor| {
+                health.worst(processor.health)
+            });
+        ProcessorSummary {
+            count: present.len(),
+            core_count: sum(|processor| processor.total_cores),
+            logical_processor_count: sum(|processor| processor.total_threads),
+            model: present.iter()
+                .find_map(|processor| processor.model.clone()),
+            status: Status {
+                state: State::Enabled, health, health_rollup: Some(health),
+            },
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,213 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            resource.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Common types defined by the Redfish Resource schema, shared
+//                  between resources.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+///////////////////////////////////////////////////////////////////////////////
+// Status
+////
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub enum State {
+    Absent,
+    Deferring,
+    Disabled,
+    #[default]
+    Enabled,
+    InTest,
+    Qualified,
+    Quiesced,
+    StandbyOffline,
+    StandbySpare,
+    Starting,
+    UnavailableOffline,
+    Updating,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub enum Health {
+    Critical,
+    #[default]
+    OK,
+    Warning,
+}
+
+impl Health {
+    fn severity(&self) -> u8 {
+        match self {
+            Health::OK => 0,
+            Health::Warning => 1,
+            Health::Critical => 2,
+        }
+    }
+
+    // The more severe of two health values
+    pub fn worst(self, other: Health) -> Health {
+        if other.severity() > self.severity() { other } else { self }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct Status {
+    pub state: State,
+    pub health: Health,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub health_rollup: Option<Health>,
+}
+
+// OEM extensions to a resource are reported under a single vendor key
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Oem<T> {
+    #[serde(rename = "Librebmc")]
+    pub librebmc: T,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// PowerState
+////
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub enum PowerState {
+    Off,
+    On,
+    Paused,
+    PoweringOff,
+    PoweringOn,
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// ResetType
+////
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub enum ResetType {
+    ForceOff,
+    ForceOn,
+    ForceRestart,
+    FullPowerCycle,
+    GracefulRestart,
+    GracefulShutdown,
+    Nmi,
+    On,
+    PowerCycle,
+    PushPowerButton,
+    Pause,
+    Resume,
+    Suspend,
+}
+
+impl ResetType {
+    pub const ALL: [ResetType; 13] = [
+        ResetType::ForceOff, ResetType::ForceOn, ResetType::ForceRestart,
+        ResetType::FullPowerCycle, ResetType::GracefulRestart,
+        ResetType::GracefulShutdown, ResetType::Nmi, ResetType::On,
+        ResetType::PowerCycle, ResetType::PushPowerButton, ResetType::Pause,
+        ResetType::Resume, ResetType::Suspend,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ResetType::ForceOff => "ForceOff",
+            ResetType::ForceOn => "ForceOn",
+            ResetType::ForceRestart => "ForceRestart",
+            ResetType::FullPowerCycle => "FullPowerCycle",
+            ResetType::GracefulRestart => "GracefulRestart",
+            ResetType::GracefulShutdown => "GracefulShutdown",
+            ResetType::Nmi => "Nmi",
+            ResetType::On => "On",
+            ResetType::PowerCycle => "PowerCycle",
+            ResetType::PushPowerButton => "PushPowerButton",
+            ResetType::Pause => "Pause",
+            ResetType::Resume => "Resume",
+            ResetType::Suspend => "Suspend",
+        }
+    }
+}
+
+impl fmt::Display for ResetType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ResetType {
+    type Err = String;
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        ResetType::ALL.iter()
+            .find(|reset_type| reset_type.as_str() == value)
+            .copied()
+            .ok_or_else(|| format!("unknown ResetType: {}", value))
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Action
+////
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Action {
+    target: PathBuf,
+
+    // Annotations of the parameters, e.g. ResetType@Redfish.AllowableValues
+    #[serde(flatten)]
+    annotations: BTreeMap<String, Vec<String>>,
+}
+
+impl Action {
+    // The Actions property of a resource, e.g. for ComputerSystem.Reset:
+    // {"#ComputerSystem.Reset":
+    //     {"target": "<me>/Actions/ComputerSystem.Reset"}}
+    pub fn targets(me: &Path, names: &[&str]) -> BTreeMap<String, Action> {
+        names.iter()
+            .map(|name| (format!("#{}", name), Action {
+                target: me.join("Actions").join(name),
+                annotations: BTreeMap::new(),
+            }))
+            .collect()
+    }
+
+    // Advertise the values that a parameter of the action accepts
+    pub fn allow(&mut self, parameter: &str, values: &[&str]) {
+        self.annotations.insert(
+            format!("{}@Redfish.AllowableValues", parameter),
+            values.iter().map(|value| value.to_string()).collect());
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,196 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            sensor.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Sensor model.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::convert::Infallible;
+use std::path::Path;
+use std::sync::Arc;
+
+use serde::Serialize;
+
+use crate::hardware::sensor::{Sensor as _, Thresholds};
+use crate::models::{Member, State, Status};
+use crate::monitor::MonitoredSensor;
+use crate::service::Dispatch;
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct Threshold {
+    reading: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hysteresis_reading: Option<f64>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct SensorThresholds {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lower_critical: Option<Threshold>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lower_caution: Option<Threshold>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    upper_caution: Option<Threshold>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    upper_critical: Option<Threshold>,
+}
+
+impl From<&Thresholds> for SensorThresholds {
+    fn from(value: &Thresholds) -> Self {
+        let hysteresis_reading = Some(value.hysteresis)
+            .filter(|hysteresis| *hysteresis > 0.0);
+        let threshold = |reading: Option<f64>| {
+            reading.map(|reading| Threshold { reading, hysteresis_reading })
+        };
+        SensorThresholds {
+            lower_critical: threshold(value.lower_critical),
+            lower_caution: threshold(value.lower_caution),
+            upper_caution: threshold(value.upper_caution),
+            upper_critical: threshold(value.upper_critical),
+        }
+    }
+}
+
+// Sensors are served from the cache of the monitor
+#[derive(Clone)]
+pub struct Sensor(Arc<MonitoredSensor>);
+
+impl Sensor {
+    pub fn get_id(&self) -> &str { &self.0.info().id }
+}
+
+impl From<Arc<MonitoredSensor>> for Sensor {
+    fn from(value: Arc<MonitoredSensor>) -> Self { Sensor(value) }
+}
+
+impl Member for Sensor {
+    const COLLECTION_TYPE: &'static str = "#SensorCollection.SensorCollection";
+    const COLLECTION_NAME: &'static str = "Sensor Collection";
+}
+
+impl odata::ResourceMetadata for Sensor {
+    const ODATA_TYPE: &'static str = "#Sensor.v1_7_0.Sensor";
+}
+
+impl odata::Serialize for Sensor {
+    const CARDINALITY: usize = 9;
+    fn serialize<S>(&self, serializer: &mut S, _me: &Path) ->
+        Result<(), S::Error>
+    where S: serde::ser::SerializeStruct
+    {
+        let info = self.0.info();
+        serializer.serialize_field("Id", &info.id)?;
+        serializer.serialize_field("Name", &info.name)?;
+        serializer.serialize_field("ReadingType", &info.reading_type)?;
+        serializer.serialize_field(
+            "ReadingUnits", info.reading_type.units())?;
+        if let Some(physical_context) = &info.physical_context {
+            serializer.serialize_field("PhysicalContext", physical_context)?;
+        }
+        serializer.serialize_field(
+            "Thresholds", &SensorThresholds::from(&info.thresholds))?;
+
+        let sample = self.0.sample();
+        let reading = sample.and_then(|sample| sample.reading);
+        serializer.serialize_field("Reading", &reading)?;
+        if let Some(sample) = &sample {
+            serializer.serialize_field("ReadingTime", &sample.time)?;
+        }
+        let state = match reading {
+            Some(_) => State::Enabled,
+            None => State::UnavailableOffline,
+        };
+        serializer.serialize_field("Status", &Status {
+            state, health: self.0.health(), health_rollup: None,
+        })
+    }
+}
+
+impl Dispatch for Sensor {
+    type Error = Infallible;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use odata::Resource;
+    use serde_json::json;
+
+    use super::*;
+    use crate::hardware::sensor::{PhysicalContext, ReadingType, SensorInfo};
+    use crate::hardware::simulator::SimulatedSensor;
+    use crate::monitor::SensorMonitor;
+
+    #[test]
+    fn reading_type_units_and_thresholds() {
+        let simulated = Arc::new(SimulatedSensor::new(SensorInfo {
+            id: "VR_in1".to_string(),
+            name: "VCORE".to_string(),
+            reading_type: ReadingType::Voltage,
+            physical_context: Some(PhysicalContext::VoltageRegulator),
+            thresholds: Thresholds {
+                lower_critical: Some(0.8), upper_critical: Some(1.5),
+                ..Default::default()
+            },
+        }, 1.6));
+        let monitor = SensorMonitor::new(vec![simulated.clone()]);
+        monitor.poll();
+        let sensor = Sensor::from(monitor.sensors()[0].clone());
+        let resource = Resource::new(
+            PathBuf::from("/redfish/v1/Chassis/chassis/Sensors/VR_in1"),
+            sensor);
+        let mut payload = serde_json::to_value(&resource).unwrap();
+        assert!(payload.as_object_mut().unwrap().remove("ReadingTime")
+                .is_some());
+        assert_eq!(json!({
+            "@odata.id": "/redfish/v1/Chassis/chassis/Sensors/VR_in1",
+            "@odata.type": "#Sensor.v1_7_0.Sensor",
+            "Id": "VR_in1",
+            "Name": "VCORE",
+            "ReadingType": "Voltage",
+            "ReadingUnits": "V",
+            "PhysicalContext": "VoltageRegulator",
+            "Thresholds": {
+                "LowerCritical": {"Reading": 0.8},
+                "UpperCritical": {"Reading": 1.5},
+            },
+            "Reading": 1.6,
+            "Status": {"State": "Enabled", "Health": "Critical"},
+        }), payload);
+
+        simulated.set_reading(1.2);
+        monitor.poll();
+        let payload = serde_json::to_value(&resource).unwrap();
+        assert_eq!("OK", payload["Status"]["Health"]);
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
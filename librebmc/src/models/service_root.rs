@@ -7,7 +7,7 @@
 //
 // CREATED:         03/28/2022
 //
-// LAST EDITED:     04/09/2022
+// LAST EDITED:     10/18/2026
 //
 // Copyright 2022, Ethan D. Twardy
 //
@@ -39,8 +39,9 @@ use derive_builder::Builder;
 use uuid::Uuid;
 use hyper::{Request, Response, Body};
 
-use crate::models::ComputerSystemCollection;
-use crate::service::{Dispatch, ODataResource};
+use crate::models::{ChassisCollection, ComputerSystemCollection, EventService,
+                    ManagerCollection, TaskService, UpdateService};
+use crate::service::{Dispatch, NotFound, ODataResource};
 
 const SCHEMA_VERSION: &'static str = "1.6.0";
 const DEFAULT_NAME: &'static str = "Root Service";
@@ -63,6 +64,21 @@ pub struct ServiceRoot {
 
     #[builder(default)]
     systems: Option<ODataResource<ComputerSystemCollection>>,
+
+    #[builder(default)]
+    chassis: Option<ODataResource<ChassisCollection>>,
+
+    #[builder(default)]
+    managers: Option<ODataResource<ManagerCollection>>,
+
+    #[builder(default)]
+    event_service: Option<ODataResource<EventService>>,
+
+    #[builder(default)]
+    tasks: Option<ODataResource<TaskService>>,
+
+    #[builder(default)]
+    update_service: Option<ODataResource<UpdateService>>,
 }
 
 impl odata::ResourceMetadata for ServiceRoot {
@@ -70,8 +86,8 @@ impl odata::ResourceMetadata for ServiceRoot {
 }
 
 impl odata::Serialize for ServiceRoot {
-    const CARDINALITY: usize = 4;
-    fn serialize<S>(&self, serializer: &mut S, me: &Path) ->
+    const CARDINALITY: usize = 10;
+    fn serialize<S>(&self, serializer: &mut S, _me: &Path) ->
         Result<(), S::Error>
     where S: serde::ser::SerializeStruct
     {
@@ -80,7 +96,26 @@ impl odata::Serialize for ServiceRoot {
         serializer.serialize_field("RedfishVersion", &self.redfish_version)?;
         if let Some(systems) = &self.systems {
             serializer.serialize_field(
-                "Systems", &systems.as_ref().get_id().resolve(me))?;
+                "Systems", &systems.as_ref().get_id())?;
+        }
+        if let Some(chassis) = &self.chassis {
+            serializer.serialize_field(
+                "Chassis", &chassis.as_ref().get_id())?;
+        }
+        if let Some(managers) = &self.managers {
+            serializer.serialize_field(
+                "Managers", &managers.as_ref().get_id())?;
+        }
+        if let Some(event_service) = &self.event_service {
+            serializer.serialize_field(
+                "EventService", &event_service.as_ref().get_id())?;
+        }
+        if let Some(tasks) = &self.tasks {
+            serializer.serialize_field("Tasks", &tasks.as_ref().get_id())?;
+        }
+        if let Some(update_service) = &self.update_service {
+            serializer.serialize_field(
+                "UpdateService", &update_service.as_ref().get_id())?;
         }
         serializer.serialize_field("UUID", &self.uuid)
     }
@@ -88,13 +123,34 @@ impl odata::Serialize for ServiceRoot {
 
 impl Dispatch for ServiceRoot {
     type Error = Infallible;
-    fn dispatch(&self, path: &Path, request: &Request<Body>) ->
-        Result<Option<Response<Body>>, Self::Error>
+    async fn dispatch(&self, path: &Path, request: Request<Body>) ->
+        Result<Response<Body>, Self::Error>
     {
-        match &self.systems {
-            Some(systems) => systems.dispatch(path, request),
-            None => Ok(None),
+        if let Some(systems) = self.systems.as_ref()
+            .filter(|systems| systems.contains(path)) {
+            return systems.dispatch(path, request).await;
+        }
+        if let Some(chassis) = self.chassis.as_ref()
+            .filter(|chassis| chassis.contains(path)) {
+            return chassis.dispatch(path, request).await;
+        }
+        if let Some(managers) = self.managers.as_ref()
+            .filter(|managers| managers.contains(path)) {
+            return managers.dispatch(path, request).await;
+        }
+        if let Some(event_service) = self.event_service.as_ref()
+            .filter(|event_service| event_service.contains(path)) {
+            return event_service.dispatch(path, request).await;
+        }
+        if let Some(tasks) = self.tasks.as_ref()
+            .filter(|tasks| tasks.contains(path)) {
+            return tasks.dispatch(path, request).await;
+        }
+        if let Some(update_service) = self.update_service.as_ref()
+            .filter(|update_service| update_service.contains(path)) {
+            return update_service.dispatch(path, request).await;
         }
+        Ok(NotFound.into())
     }
 }
 
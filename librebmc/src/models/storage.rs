@@ -0,0 +1,288 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            storage.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Storage model, and the storage subsystems of a system.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
+
+use hyper::{Body, Request, Response};
+use odata::{Resource, ResourceMetadata};
+use serde::Serialize;
+
+use crate::bus::{EventBus, StateChange};
+use crate::events::EventType;
+use crate::hardware::storage::{
+    ControllerInventory, Protocol, StorageInventory, StorageProvider,
+};
+use crate::models::{Collection, Drive, Health, Member, State, Status, Volume,
+                    VolumeCollection};
+use crate::registries::{base, resource_event};
+use crate::service::{Dispatch, NotFound, ODataResource, RedfishError};
+
+///////////////////////////////////////////////////////////////////////////////
+// Storage
+////
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct StorageController<'a> {
+    #[serde(rename = "@odata.id")]
+    odata_id: String,
+    member_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<&'a String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    manufacturer: Option<&'a String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    model: Option<&'a String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    serial_number: Option<&'a String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    firmware_version: Option<&'a String>,
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    supported_controller_protocols: &'a [Protocol],
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    supported_device_protocols: &'a [Protocol],
+    status: Status,
+}
+
+impl<'a> StorageController<'a> {
+    fn new(storage: &Path, index: usize, controller: &'a ControllerInventory)
+        -> Self
+    {
+        StorageController {
+            odata_id: format!("{}#/StorageControllers/{}",
+                              storage.display(), index),
+            member_id: index.to_string(),
+            name: controller.name.as_ref(),
+            manufacturer: controller.manufacturer.as_ref(),
+            model: controller.model.as_ref(),
+            serial_number: controller.serial_number.as_ref(),
+            firmware_version: controller.firmware_version.as_ref(),
+            supported_controller_protocols: &controller.controller_protocols,
+            supported_device_protocols: &controller.device_protocols,
+            status: Status {
+                state: controller.state, health: controller.health,
+                health_rollup: None,
+            },
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Storage {
+    inventory: StorageInventory,
+    drives: Vec<ODataResource<Drive>>,
+    volumes: ODataResource<VolumeCollection>,
+}
+
+impl Storage {
+    // The drives and volumes of the subsystem are placed beneath it at path
+    pub fn new(path: &Path, inventory: StorageInventory) -> Self {
+        let drives_path = path.join("Drives");
+        let drives = inventory.drives.iter().cloned()
+            .map(|drive| {
+                Resource::new(drives_path.join(&drive.id), Drive::from(drive))
+                    .into()
+            })
+            .collect();
+        let volumes_path = path.join("Volumes");
+        let volumes = inventory.volumes.iter().cloned()
+            .map(|volume| {
+                let path = volumes_path.join(&volume.id);
+                Resource::new(path, Volume::new(volume, &drives_path)).into()
+            })
+            .collect();
+        let volumes = Resource::new(volumes_path, Collection::new(volumes))
+            .into();
+        Storage { inventory, drives, volumes }
+    }
+
+    pub fn get_id(&self) -> &str { &self.inventory.id }
+}
+
+impl Member for Storage {
+    const COLLECTION_TYPE: &'static str =
+        "#StorageCollection.StorageCollection";
+    const COLLECTION_NAME: &'static str = "Storage Collection";
+}
+
+impl ResourceMetadata for Storage {
+    const ODATA_TYPE: &'static str = "#Storage.v1_13_0.Storage";
+}
+
+impl odata::Serialize for Storage {
+    const CARDINALITY: usize = 7;
+    fn serialize<S>(&self, serializer: &mut S, me: &Path) ->
+        Result<(), S::Error>
+    where S: serde::ser::SerializeStruct
+    {
+        let inventory = &self.inventory;
+        let name = inventory.name.clone()
+            .unwrap_or_else(|| format!("Storage {}", inventory.id));
+        serializer.serialize_field("Id", &inventory.id)?;
+        serializer.serialize_field("Name", &name)?;
+        let controllers: Vec<StorageController> = inventory.controllers.iter()
+            .enumerate()
+            .map(|(index, controller)| {
+                StorageController::new(me, index, controller)
+            })
+            .collect();
+        serializer.serialize_field("StorageControllers", &controllers)?;
+        let drives: Vec<odata::Link> = self.drives.iter()
+            .map(|drive| drive.as_ref().get_id())
+            .collect();
+        serializer.serialize_field("Drives@odata.count", &drives.len())?;
+        serializer.serialize_field("Drives", &drives)?;
+        serializer.serialize_field(
+            "Volumes", &self.volumes.as_ref().get_id())?;
+        let health = inventory.controllers.iter()
+            .fold(Health::OK, |health, controller| {
+                health.worst(controller.health)
+            });
+        serializer.serialize_field("Status", &Status {
+            state: State::Enabled, health,
+            health_rollup: Some(inventory.health()),
+        })
+    }
+}
+
+impl Dispatch for Storage {
+    type Error = Infallible;
+    async fn dispatch(&self, path: &Path, request: Request<Body>) ->
+        Result<Response<Body>, Self::Error>
+    {
+        if let Some(drive) = self.drives.iter()
+            .find(|drive| drive.contains(path)) {
+            return drive.dispatch(path, request).await;
+        }
+        if self.volumes.contains(path) {
+            return self.volumes.dispatch(path, request).await;
+        }
+        Ok(NotFound.into())
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// SystemStorage
+////
+
+// The storage subsystems of a system, which are read from the provider
+// whenever they're requested. Drive failures are among the most common
+// faults of a host, so changes to the health of each drive are published.
+#[derive(Clone)]
+pub struct SystemStorage {
+    path: PathBuf,
+    provider: Arc<dyn StorageProvider>,
+    bus: Arc<OnceLock<EventBus>>,
+
+    // The health of each drive when the storage was last observed
+    drives: Arc<Mutex<Option<HashMap<PathBuf, Health>>>>,
+}
+
+impl SystemStorage {
+    // The collection is placed beneath the system at path
+    pub fn new(path: &Path, provider: Arc<dyn StorageProvider>) -> Self {
+        SystemStorage {
+            path: path.join("Storage"), provider,
+            bus: Arc::new(OnceLock::new()),
+            drives: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    pub fn get_path(&self) -> &Path { &self.path }
+
+    pub fn publish_to(&self, bus: &EventBus) {
+        let _ = self.bus.set(bus.clone());
+    }
+
+    // Read the storage, publishing the health of any drive which has changed
+    // since the storage was last observed, and return the worst health of
+    // the storage.
+    pub fn observe(&self) -> Health {
+        let Ok(subsystems) = self.provider.storage() else {
+            return Health::Warning;
+        };
+        let drives: HashMap<PathBuf, Health> = subsystems.iter()
+            .flat_map(|storage| {
+                let path = self.path.join(&storage.id).join("Drives");
+                storage.drives.iter()
+                    .map(move |drive| (path.join(&drive.id), drive.health))
+            })
+            .collect();
+        let previous = self.drives.lock().unwrap().replace(drives.clone());
+        if let (Some(previous), Some(bus)) = (previous, self.bus.get()) {
+            for (path, health) in &drives {
+                // Drives that appear are presumed to have been healthy
+                if previous.get(path).copied().unwrap_or(Health::OK) == *health
+                {
+                    continue;
+                }
+                let message = resource_event::resource_status_changed(
+                    &path.to_string_lossy(), *health);
+                bus.publish(StateChange::new(
+                    EventType::StatusChange, path.clone().into(),
+                    Some(Drive::ODATA_TYPE), message));
+            }
+        }
+        subsystems.iter()
+            .fold(Health::OK, |health, storage| health.worst(storage.health()))
+    }
+
+    pub async fn dispatch(&self, path: &Path, request: Request<Body>) ->
+        Response<Body>
+    {
+        let subsystems = match self.provider.storage() {
+            Ok(subsystems) => subsystems,
+            Err(_) => return RedfishError::internal(
+                vec![base::general_error()]).into(),
+        };
+        let members = subsystems.into_iter()
+            .map(|storage| {
+                let path = self.path.join(&storage.id);
+                Resource::new(path.clone(), Storage::new(&path, storage))
+                    .into()
+            })
+            .collect();
+        let collection: ODataResource<Collection<Storage>> =
+            Resource::new(self.path.clone(), Collection::new(members)).into();
+        collection.dispatch(path, request).await
+            .unwrap_or_else(|error| match error {})
+    }
+
+    pub fn contains(&self, path: &Path) -> bool {
+        path.starts_with(&self.path)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
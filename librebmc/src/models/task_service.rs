@@ -0,0 +1,205 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            task_service.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     TaskService and Task, which serve the tasks of a
+//                  TaskManager, and the monitors that clients poll for the
+//                  outcome of a task.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::convert::Infallible;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use hyper::{Body, Method, Request, Response};
+use odata::Resource;
+
+use crate::models::{Collection, Member, Status};
+use crate::service::{Dispatch, MethodNotAllowed, NotFound, ODataResource};
+use crate::tasks::{TaskManager, TaskRecord, TaskState};
+
+///////////////////////////////////////////////////////////////////////////////
+// Task
+////
+
+#[derive(Clone)]
+pub struct Task(Arc<TaskRecord>);
+
+impl Task {
+    pub fn new(record: Arc<TaskRecord>) -> Self { Task(record) }
+
+    pub fn resource(record: Arc<TaskRecord>) -> ODataResource<Task> {
+        Resource::new(record.path().to_owned(), Task(record)).into()
+    }
+}
+
+impl Member for Task {
+    const COLLECTION_TYPE: &'static str = "#TaskCollection.TaskCollection";
+    const COLLECTION_NAME: &'static str = "Task Collection";
+}
+
+impl odata::ResourceMetadata for Task {
+    const ODATA_TYPE: &'static str = "#Task.v1_7_0.Task";
+}
+
+impl odata::Serialize for Task {
+    const CARDINALITY: usize = 9;
+    fn serialize<S>(&self, serializer: &mut S, _me: &Path) ->
+        Result<(), S::Error>
+    where S: serde::ser::SerializeStruct
+    {
+        let task = &self.0;
+        serializer.serialize_field("Id", &task.get_id().to_string())?;
+        serializer.serialize_field("Name", task.name())?;
+        serializer.serialize_field("TaskState", &task.state())?;
+        serializer.serialize_field("TaskStatus", &task.status())?;
+        serializer.serialize_field(
+            "PercentComplete", &task.percent_complete())?;
+        serializer.serialize_field("StartTime", &task.start_time())?;
+        if let Some(end_time) = task.end_time() {
+            serializer.serialize_field("EndTime", &end_time)?;
+        }
+        serializer.serialize_field("Messages", &task.messages())?;
+        serializer.serialize_field("TaskMonitor", task.monitor())
+    }
+}
+
+impl Dispatch for Task {
+    type Error = Infallible;
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Task Monitor
+////
+
+// While the task is running, its monitor responds with 202 Accepted and the
+// task. Once the operation has completed, it responds as the operation would
+// have, had it completed within the original request.
+fn monitor(task: Arc<TaskRecord>, request: &Request<Body>) -> Response<Body>
+{
+    let resource = Task::resource(task.clone());
+    match *request.method() {
+        Method::GET => match task.state() {
+            TaskState::New | TaskState::Running => accepted(&resource),
+            TaskState::Completed =>
+                Response::builder().status(204).body(Body::empty()).unwrap(),
+            TaskState::Exception => task.error().unwrap().into(),
+            TaskState::Cancelled => resource.to_response(),
+        },
+        Method::DELETE => match task.cancel() {
+            true => Response::builder().status(204).body(Body::empty())
+                .unwrap(),
+            false => MethodNotAllowed::new(vec![Method::GET]).into(),
+        },
+        _ => MethodNotAllowed::new(vec![Method::GET, Method::DELETE]).into(),
+    }
+}
+
+// The response to a request that started a task, or to a poll of its monitor
+// while it's running.
+pub fn accepted(task: &ODataResource<Task>) -> Response<Body> {
+    let mut response = task.to_response();
+    *response.status_mut() = hyper::StatusCode::ACCEPTED;
+    let location = task.as_ref().get().0.monitor().to_string_lossy()
+        .into_owned();
+    response.headers_mut().insert(
+        "Location", location.parse().unwrap());
+    response
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// TaskService
+////
+
+pub type TaskCollection = Collection<Task>;
+
+#[derive(Clone)]
+pub struct TaskService {
+    tasks: Arc<TaskManager>,
+}
+
+impl TaskService {
+    pub fn new(tasks: Arc<TaskManager>) -> Self { TaskService { tasks } }
+
+    fn tasks_path(&self) -> PathBuf { self.tasks.get_path().join("Tasks") }
+
+    // The task addressed by the path, relative to a directory of the service
+    fn task(&self, directory: &str, path: &Path) -> Option<Arc<TaskRecord>> {
+        path.strip_prefix(self.tasks.get_path().join(directory)).ok()
+            .and_then(|id| id.to_str())
+            .and_then(|id| id.parse().ok())
+            .and_then(|id| self.tasks.task(id))
+    }
+}
+
+impl odata::ResourceMetadata for TaskService {
+    const ODATA_TYPE: &'static str = "#TaskService.v1_2_0.TaskService";
+}
+
+impl odata::Serialize for TaskService {
+    const CARDINALITY: usize = 6;
+    fn serialize<S>(&self, serializer: &mut S, _me: &Path) ->
+        Result<(), S::Error>
+    where S: serde::ser::SerializeStruct
+    {
+        serializer.serialize_field("Id", "TaskService")?;
+        serializer.serialize_field("Name", "Task Service")?;
+        serializer.serialize_field("ServiceEnabled", &true)?;
+        serializer.serialize_field("Status", &Status::default())?;
+        serializer.serialize_field(
+            "CompletedTaskOverWritePolicy", "Oldest")?;
+        serializer.serialize_field(
+            "Tasks", &odata::Link::from(self.tasks_path()))
+    }
+}
+
+impl Dispatch for TaskService {
+    type Error = Infallible;
+    async fn dispatch(&self, path: &Path, request: Request<Body>) ->
+        Result<Response<Body>, Self::Error>
+    {
+        let tasks_path = self.tasks_path();
+        if path == tasks_path {
+            let members = self.tasks.tasks().into_iter()
+                .map(Task::resource)
+                .collect();
+            return ODataResource::from(Resource::new(
+                tasks_path, TaskCollection::new(members)))
+                .dispatch(path, request).await;
+        }
+        if let Some(task) = self.task("Tasks", path) {
+            return Task::resource(task).dispatch(path, request).await;
+        }
+        match self.task("TaskMonitors", path) {
+            Some(task) => Ok(monitor(task, &request)),
+            None => Ok(NotFound.into()),
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,132 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            thermal_metrics.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     ThermalMetrics model.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::convert::Infallible;
+use std::path::Path;
+use std::sync::Arc;
+
+use serde::Serialize;
+
+use crate::hardware::sensor::{PhysicalContext, Sensor};
+use crate::service::Dispatch;
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct SensorExcerpt {
+    reading: f64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct SensorArrayExcerpt<'a> {
+    device_name: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    physical_context: Option<PhysicalContext>,
+    reading: Option<f64>,
+}
+
+#[derive(Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct TemperatureSummary {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ambient: Option<SensorExcerpt>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exhaust: Option<SensorExcerpt>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    intake: Option<SensorExcerpt>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    internal: Option<SensorExcerpt>,
+}
+
+impl TemperatureSummary {
+    // Each summary reports the hottest temperature in its context
+    fn add(&mut self, context: Option<PhysicalContext>, reading: f64) {
+        let summary = match context {
+            Some(PhysicalContext::Room) => &mut self.ambient,
+            Some(PhysicalContext::Exhaust) => &mut self.exhaust,
+            Some(PhysicalContext::Intake) => &mut self.intake,
+            _ => &mut self.internal,
+        };
+        if summary.as_ref().is_none_or(|summary| reading > summary.reading) {
+            *summary = Some(SensorExcerpt { reading });
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct ThermalMetrics {
+    temperatures: Vec<Arc<dyn Sensor>>,
+}
+
+impl ThermalMetrics {
+    pub fn new(temperatures: Vec<Arc<dyn Sensor>>) -> Self {
+        ThermalMetrics { temperatures }
+    }
+}
+
+impl odata::ResourceMetadata for ThermalMetrics {
+    const ODATA_TYPE: &'static str = "#ThermalMetrics.v1_3_0.ThermalMetrics";
+}
+
+impl odata::Serialize for ThermalMetrics {
+    const CARDINALITY: usize = 4;
+    fn serialize<S>(&self, serializer: &mut S, _me: &Path) ->
+        Result<(), S::Error>
+    where S: serde::ser::SerializeStruct
+    {
+        serializer.serialize_field("Id", "ThermalMetrics")?;
+        serializer.serialize_field("Name", "Chassis Thermal Metrics")?;
+
+        let mut summary = TemperatureSummary::default();
+        let mut readings = Vec::new();
+        for sensor in &self.temperatures {
+            let info = sensor.info();
+            let reading = sensor.read().ok();
+            if let Some(reading) = reading {
+                summary.add(info.physical_context, reading);
+            }
+            readings.push(SensorArrayExcerpt {
+                device_name: &info.name,
+                physical_context: info.physical_context,
+                reading,
+            });
+        }
+        serializer.serialize_field("TemperatureSummaryCelsius", &summary)?;
+        serializer.serialize_field("TemperatureReadingsCelsius", &readings)
+    }
+}
+
+impl Dispatch for ThermalMetrics {
+    type Error = Infallible;
+}
+
+///////////////////////////////////////////////////////////////////////////////
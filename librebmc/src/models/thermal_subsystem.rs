@@ -0,0 +1,274 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            thermal_subsystem.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     ThermalSubsystem and Fan models.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::convert::Infallible;
+use std::path::Path;
+use std::sync::Arc;
+
+use hyper::{Body, Method, Request, Response};
+use serde::Serialize;
+
+use crate::hardware::thermal::{self, FanControl, FanMode};
+use crate::health::HealthNode;
+use crate::models::{Collection, Health, Member, Oem, State, Status,
+                    ThermalMetrics};
+use crate::registries::base;
+use crate::service::{Dispatch, NotFound, ODataResource, Patch, RedfishError};
+
+///////////////////////////////////////////////////////////////////////////////
+// Fan
+////
+
+const FAN_READ_ONLY: &[&str] = &[
+    "Id", "Name", "Description", "Manufacturer", "Model", "SerialNumber",
+    "PartNumber", "PhysicalContext", "HotPluggable", "SpeedPercent", "Status",
+];
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct SensorFanExcerpt {
+    reading: Option<f64>,
+    #[serde(rename = "SpeedRPM")]
+    speed_rpm: Option<f64>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct FanOem {
+    fan_mode: FanMode,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    speed_control_percent: Option<f64>,
+}
+
+#[derive(Clone)]
+pub struct Fan(Arc<dyn thermal::Fan>);
+
+impl Fan {
+    pub fn get_id(&self) -> &str { &self.0.inventory().id }
+
+    // Determine the new control setting requested by the OEM properties
+    fn requested_control(&self, patch: &mut Patch) -> Option<FanControl> {
+        let mode: Option<FanMode> = patch.take_enum("FanMode");
+        let percent: Option<f64> = patch.take("SpeedControlPercent");
+        if let Some(percent) = percent {
+            if !(0.0..=100.0).contains(&percent) {
+                patch.reject("SpeedControlPercent",
+                             base::property_value_out_of_range(
+                                 &percent.to_string(),
+                                 "Oem/Librebmc/SpeedControlPercent"));
+                return None;
+            }
+        }
+
+        match (mode, percent) {
+            (Some(FanMode::Automatic), Some(_)) => {
+                patch.reject("SpeedControlPercent",
+                             base::property_value_conflict(
+                                 "Oem/Librebmc/SpeedControlPercent",
+                                 "Oem/Librebmc/FanMode"));
+                None
+            },
+            (Some(FanMode::Automatic), None) => Some(FanControl::Automatic),
+            (_, Some(percent)) => Some(FanControl::Manual(percent)),
+            (Some(FanMode::Manual), None) => {
+                // Hold the fan at its current speed
+                let percent = match self.0.control() {
+                    FanControl::Manual(percent) => Some(percent),
+                    FanControl::Automatic => self.0.read().ok()
+                        .and_then(|reading| reading.speed_percent),
+                };
+                Some(FanControl::Manual(percent.unwrap_or(100.0)))
+            },
+            (None, None) => None,
+        }
+    }
+}
+
+impl From<Arc<dyn thermal::Fan>> for Fan {
+    fn from(value: Arc<dyn thermal::Fan>) -> Self { Fan(value) }
+}
+
+impl Member for Fan {
+    const COLLECTION_TYPE: &'static str = "#FanCollection.FanCollection";
+    const COLLECTION_NAME: &'static str = "Fan Collection";
+}
+
+impl odata::ResourceMetadata for Fan {
+    const ODATA_TYPE: &'static str = "#Fan.v1_5_0.Fan";
+}
+
+impl odata::Serialize for Fan {
+    const CARDINALITY: usize = 12;
+    fn serialize<S>(&self, serializer: &mut S, _me: &Path) ->
+        Result<(), S::Error>
+    where S: serde::ser::SerializeStruct
+    {
+        let inventory = self.0.inventory();
+        serializer.serialize_field("Id", &inventory.id)?;
+        serializer.serialize_field("Name", &inventory.name)?;
+        let strings = [
+            ("Manufacturer", &inventory.manufacturer),
+            ("Model", &inventory.model),
+            ("SerialNumber", &inventory.serial_number),
+            ("PartNumber", &inventory.part_number),
+        ];
+        for (name, value) in strings {
+            if let Some(value) = value {
+                serializer.serialize_field(name, value)?;
+            }
+        }
+        if let Some(physical_context) = &inventory.physical_context {
+            serializer.serialize_field("PhysicalContext", physical_context)?;
+        }
+        serializer.serialize_field("HotPluggable", &inventory.hot_pluggable)?;
+
+        match self.0.read() {
+            Ok(reading) => {
+                serializer.serialize_field("SpeedPercent", &SensorFanExcerpt {
+                    reading: reading.speed_percent,
+                    speed_rpm: reading.speed_rpm,
+                })?;
+                serializer.serialize_field("Status", &Status {
+                    state: reading.state, health: reading.health,
+                    health_rollup: None,
+                })?;
+            },
+            Err(_) => serializer.serialize_field("Status", &Status {
+                state: State::UnavailableOffline, health: Health::Warning,
+                health_rollup: None,
+            })?,
+        }
+
+        if inventory.controllable {
+            let control = self.0.control();
+            let speed_control_percent = match control {
+                FanControl::Manual(percent) => Some(percent),
+                FanControl::Automatic => None,
+            };
+            serializer.serialize_field("Oem", &Oem {
+                librebmc: FanOem {
+                    fan_mode: control.mode(), speed_control_percent,
+                },
+            })?;
+        }
+        Ok(())
+    }
+}
+
+impl Dispatch for Fan {
+    type Error = Infallible;
+
+    fn allowed(&self) -> Vec<Method> {
+        match self.0.inventory().controllable {
+            true => vec![Method::GET, Method::PATCH],
+            false => vec![Method::GET],
+        }
+    }
+
+    async fn patch(&self, mut patch: Patch) -> Result<(), RedfishError> {
+        let mut control = None;
+        if let Some(mut oem) = patch.take_object("Oem") {
+            if let Some(mut librebmc) = oem.take_object("Librebmc") {
+                control = self.requested_control(&mut librebmc);
+                oem.merge(librebmc, &[]);
+            }
+            patch.merge(oem, &[]);
+        }
+        patch.finish(FAN_READ_ONLY)?;
+
+        if let Some(control) = control {
+            self.0.set_control(control).map_err(|_| {
+                RedfishError::internal(vec![base::general_error()])
+            })?;
+        }
+        Ok(())
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// ThermalSubsystem
+////
+
+#[derive(Clone)]
+pub struct ThermalSubsystem {
+    health: Arc<HealthNode>,
+    fans: ODataResource<Collection<Fan>>,
+    thermal_metrics: ODataResource<ThermalMetrics>,
+}
+
+impl ThermalSubsystem {
+    pub fn new(
+        fans: ODataResource<Collection<Fan>>,
+        thermal_metrics: ODataResource<ThermalMetrics>,
+        health: Arc<HealthNode>,
+    ) -> Self {
+        ThermalSubsystem { health, fans, thermal_metrics }
+    }
+}
+
+impl odata::ResourceMetadata for ThermalSubsystem {
+    const ODATA_TYPE: &'static str =
+        "#ThermalSubsystem.v1_3_0.ThermalSubsystem";
+}
+
+impl odata::Serialize for ThermalSubsystem {
+    const CARDINALITY: usize = 5;
+    fn serialize<S>(&self, serializer: &mut S, _me: &Path) ->
+        Result<(), S::Error>
+    where S: serde::ser::SerializeStruct
+    {
+        serializer.serialize_field("Id", "ThermalSubsystem")?;
+        serializer.serialize_field("Name", "Thermal Subsystem")?;
+        serializer.serialize_field(
+            "Status", &self.health.status(State::Enabled))?;
+        serializer.serialize_field("Fans", &self.fans.as_ref().get_id())?;
+        serializer.serialize_field(
+            "ThermalMetrics", &self.thermal_metrics.as_ref().get_id())
+    }
+}
+
+impl Dispatch for ThermalSubsystem {
+    type Error = Infallible;
+    async fn dispatch(&self, path: &Path, request: Request<Body>) ->
+        Result<Response<Body>, Self::Error>
+    {
+        if self.fans.contains(path) {
+            self.fans.dispatch(path, request).await
+        } else if self.thermal_metrics.contains(path) {
+            self.thermal_metrics.dispatch(path, request).await
+        } else {
+            Ok(NotFound.into())
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,536 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            update_service.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     The UpdateService, through which the firmware of the
+//                  components of the system is inventoried and updated.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::collections::HashSet;
+use std::convert::Infallible;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use hyper::{Body, Method, Request, Response, StatusCode, Uri};
+use odata::Resource;
+use serde::Deserialize;
+use serde_json::Value;
+use tokio::io::AsyncWriteExt;
+
+use crate::models::{Action, Collection, Member, Status};
+use crate::models::task_service::{self, Task};
+use crate::registries::{base, firmware, update};
+use crate::service::{
+    ActionResult, Dispatch, MethodNotAllowed, NotFound, ODataResource,
+    Parameters, Patch, RedfishError,
+};
+use crate::tasks::{TaskManager, TaskRecord};
+use crate::update::image::{Manifest, VerificationError, Verifier};
+use crate::update::multipart::{self, Multipart};
+use crate::update::{self as images, Component, StagedImage, Staging,
+                    UpdateError, Updater};
+
+const SIMPLE_UPDATE: &'static str = "UpdateService.SimpleUpdate";
+const MULTIPART_PUSH: &'static str = "MultipartHttpPush";
+
+// The parameters of a multipart push are read into memory, unlike its image
+const MAX_UPDATE_PARAMETERS: usize = 65536;
+
+///////////////////////////////////////////////////////////////////////////////
+// SoftwareInventory
+////
+
+#[derive(Clone)]
+pub struct SoftwareInventory(Component);
+
+impl Member for SoftwareInventory {
+    const COLLECTION_TYPE: &'static str =
+        "#SoftwareInventoryCollection.SoftwareInventoryCollection";
+    const COLLECTION_NAME: &'static str = "Firmware Inventory Collection";
+}
+
+impl odata::ResourceMetadata for SoftwareInventory {
+    const ODATA_TYPE: &'static str =
+        "#SoftwareInventory.v1_9_0.SoftwareInventory";
+}
+
+impl odata::Serialize for SoftwareInventory {
+    const CARDINALITY: usize = 5;
+    fn serialize<S>(&self, serializer: &mut S, _me: &Path) ->
+        Result<(), S::Error>
+    where S: serde::ser::SerializeStruct
+    {
+        serializer.serialize_field("Id", &self.0.id)?;
+        serializer.serialize_field("Name", &self.0.name)?;
+        serializer.serialize_field("Version", &self.0.version)?;
+        serializer.serialize_field("Updateable", &self.0.updateable)?;
+        serializer.serialize_field("Status", &Status::default())
+    }
+}
+
+impl Dispatch for SoftwareInventory {
+    type Error = Infallible;
+}
+
+pub type SoftwareInventoryCollection = Collection<SoftwareInventory>;
+
+///////////////////////////////////////////////////////////////////////////////
+// Installation
+////
+
+// The names of the targets of an update, for messages. The service is the
+// target of an update until the manifest of its image determines one.
+fn names(targets: &[Component]) -> String {
+    match targets.is_empty() {
+        true => "UpdateService".to_string(),
+        false => targets.iter()
+            .map(|target| target.name.as_str())
+            .collect::<Vec<_>>()
+            .join(", "),
+    }
+}
+
+// Verifies images, and applies them with the updater
+struct Installer {
+    updater: Arc<dyn Updater>,
+    verifier: Verifier,
+    staging: Staging,
+
+    // The ids of the components that are being updated
+    updating: Mutex<HashSet<String>>,
+}
+
+// Components are updated by one task at a time, and are reserved until the
+// task that's updating them has finished.
+struct Reservation {
+    installer: Arc<Installer>,
+    ids: Vec<String>,
+}
+
+impl Drop for Reservation {
+    fn drop(&mut self) {
+        let mut updating = self.installer.updating.lock().unwrap();
+        for id in &self.ids {
+            updating.remove(id);
+        }
+    }
+}
+
+fn in_use() -> RedfishError {
+    RedfishError::new(StatusCode::CONFLICT, vec![base::resource_in_use()])
+}
+
+impl Installer {
+    // Reserve the targets of an update, unless any of them are already
+    // being updated.
+    fn reserve(self: &Arc<Self>, targets: &[Component]) ->
+        Result<Reservation, RedfishError>
+    {
+        let mut updating = self.updating.lock().unwrap();
+        if targets.iter().any(|target| updating.contains(&target.id)) {
+            return Err(in_use());
+        }
+        let ids: Vec<String> = targets.iter()
+            .map(|target| target.id.clone())
+            .collect();
+        updating.extend(ids.iter().cloned());
+        Ok(Reservation { installer: self.clone(), ids })
+    }
+
+    // Verify a staged image on a blocking thread, returning its manifest
+    // and payload.
+    async fn verify(self: &Arc<Self>, image: StagedImage, name: &str) ->
+        Result<(Manifest, StagedImage), RedfishError>
+    {
+        let installer = self.clone();
+        let result = tokio::task::spawn_blocking(move || {
+            installer.verifier.verify(image.path(), &installer.staging)
+        }).await;
+        let message = match result {
+            Ok(Ok(verified)) => return Ok(verified),
+            Ok(Err(VerificationError::Malformed)) =>
+                firmware::image_malformed(name),
+            Ok(Err(VerificationError::Untrusted)) =>
+                firmware::signature_untrusted(name),
+            Ok(Err(VerificationError::DigestMismatch)) =>
+                firmware::digest_mismatch(name),
+            _ => return Err(RedfishError::internal(
+                vec![base::general_error()])),
+        };
+        Err(RedfishError::bad_request(vec![message]))
+    }
+
+    // The components that the image is applied to: the targets of the
+    // request, or the component named by the manifest if there are none.
+    // Every target must be the component that the image is for, and must
+    // not be downgraded unless that's allowed.
+    fn resolve(&self, manifest: &Manifest, name: &str,
+               targets: Vec<Component>) ->
+        Result<Vec<Component>, RedfishError>
+    {
+        let targets = match targets.is_empty() {
+            true => self.updater.components().into_iter()
+                .filter(|component| {
+                    component.updateable && component.id == manifest.component
+                })
+                .collect(),
+            false => targets,
+        };
+        if targets.is_empty() {
+            return Err(RedfishError::bad_request(
+                vec![update::no_targets_determined(name)]));
+        }
+        for target in &targets {
+            if target.id != manifest.component {
+                return Err(RedfishError::bad_request(vec![
+                    firmware::component_mismatch(
+                        name, &manifest.component, &target.name)]));
+            }
+            if !self.verifier.permits(&target.version, manifest) {
+                return Err(RedfishError::bad_request(vec![
+                    firmware::downgrade_rejected(
+                        name, &manifest.version, &target.version)]));
+            }
+        }
+        Ok(targets)
+    }
+
+    // Verify a staged image, and apply its payload to each of the targets in
+    // turn on a blocking thread. Installation accounts for the part of the
+    // task's progress after start. Targets given by the request were
+    // reserved when it was received, but the component named by the
+    // manifest is only reserved once the image has been verified.
+    async fn install(self: Arc<Self>, task: Arc<TaskRecord>,
+                     image: StagedImage, name: String,
+                     targets: Vec<Component>, start: u8) ->
+        Result<(), RedfishError>
+    {
+        let (manifest, payload) = self.verify(image, &name).await?;
+        task.push_message(firmware::image_verified(
+            &name, &manifest.component, &manifest.version));
+        let reserved = !targets.is_empty();
+        let targets = self.resolve(&manifest, &name, targets)?;
+        let _reservation = match reserved {
+            true => None,
+            false => Some(self.reserve(&targets)?),
+        };
+
+        // The payload outlives the task if it's cancelled during
+        // installation
+        let payload = Arc::new(payload);
+        let span = (100 - start as usize) / targets.len();
+        for (index, target) in targets.into_iter().enumerate() {
+            task.push_message(update::installing_on_component(
+                &name, &target.name));
+            let offset = start as usize + index * span;
+            let apply = {
+                let (task, installer, payload) =
+                    (task.clone(), self.clone(), payload.clone());
+                let id = target.id.clone();
+                let version = manifest.version.clone();
+                move || installer.updater.apply(
+                    &id, payload.path(), &version, &|p| {
+                        let percent =
+                            offset + span * p.min(100) as usize / 100;
+                        task.set_percent_complete(percent as u8);
+                    })
+            };
+            match tokio::task::spawn_blocking(apply).await {
+                Ok(Ok(())) => {},
+                Ok(Err(UpdateError::Rejected(_))) =>
+                    return Err(RedfishError::bad_request(vec![
+                        update::verification_failed(&name, &target.name)])),
+                _ => return Err(RedfishError::internal(vec![
+                    update::apply_failed(&name, &target.name)])),
+            }
+            task.push_message(update::update_successful(
+                &target.name, &name));
+        }
+        Ok(())
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// UpdateService
+////
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum TransferProtocol {
+    HTTP,
+}
+
+#[derive(Clone)]
+pub struct UpdateService {
+    path: PathBuf,
+    installer: Arc<Installer>,
+    tasks: Arc<TaskManager>,
+}
+
+impl UpdateService {
+    pub fn new(path: PathBuf, updater: Arc<dyn Updater>, verifier: Verifier,
+               staging: Staging, tasks: Arc<TaskManager>) -> Self
+    {
+        let installer = Arc::new(Installer {
+            updater, verifier, staging, updating: Mutex::new(HashSet::new()),
+        });
+        UpdateService { path, installer, tasks }
+    }
+
+    fn inventory_path(&self) -> PathBuf { self.path.join("FirmwareInventory") }
+    fn push_path(&self) -> PathBuf { self.path.join("update-multipart") }
+
+    // The inventory is read from the updater on each request, so that it
+    // reflects the versions installed by updates.
+    fn inventory(&self) -> ODataResource<SoftwareInventoryCollection> {
+        let path = self.inventory_path();
+        let members = self.installer.updater.components().into_iter()
+            .map(|component| Resource::new(
+                path.join(&component.id), SoftwareInventory(component))
+                 .into())
+            .collect();
+        Resource::new(path, SoftwareInventoryCollection::new(members)).into()
+    }
+
+    // The components addressed by the URIs of an update's Targets. On
+    // failure, returns the target that isn't an updateable component.
+    fn targets(&self, targets: &[String]) -> Result<Vec<Component>, String> {
+        let components: Vec<Component> = self.installer.updater.components()
+            .into_iter()
+            .filter(|component| component.updateable)
+            .collect();
+        let inventory = self.inventory_path();
+        targets.iter()
+            .map(|target| {
+                Path::new(target).strip_prefix(&inventory).ok()
+                    .and_then(|id| id.to_str())
+                    .and_then(|id| components.iter().find(|component| {
+                        component.id == id
+                    }))
+                    .cloned()
+                    .ok_or_else(|| target.clone())
+            })
+            .collect()
+    }
+
+    // Receive an image and its parameters from a multipart/form-data
+    // request, streaming the image to the staging directory.
+    async fn receive(&self, request: Request<Body>) ->
+        Result<Arc<TaskRecord>, RedfishError>
+    {
+        let boundary = request.headers().get("Content-Type")
+            .and_then(|value| value.to_str().ok())
+            .and_then(multipart::boundary)
+            .ok_or_else(|| RedfishError::new(
+                StatusCode::UNSUPPORTED_MEDIA_TYPE,
+                vec![base::general_error()]))?;
+        let malformed = |_| {
+            RedfishError::bad_request(vec![base::general_error()])
+        };
+        let internal = |_| RedfishError::internal(vec![base::general_error()]);
+
+        let mut multipart = Multipart::new(request.into_body(), &boundary);
+        let mut targets = Vec::new();
+        let mut image = None;
+        while let Some(part) = multipart.next_part().await.map_err(malformed)?
+        {
+            match part.name.as_str() {
+                "UpdateParameters" => {
+                    let data = multipart.read_to_end(MAX_UPDATE_PARAMETERS)
+                        .await.map_err(malformed)?;
+                    let properties = match serde_json::from_slice(&data) {
+                        Ok(Value::Object(properties)) => properties,
+                        _ => return Err(RedfishError::bad_request(
+                            vec![base::malformed_json()])),
+                    };
+                    let mut parameters = Patch::new(properties);
+                    targets = parameters.take("Targets").unwrap_or_default();
+                    parameters.finish(&[])?;
+                },
+                "UpdateFile" => {
+                    let staging = &self.installer.staging;
+                    let (staged, mut file) = staging.create().await
+                        .map_err(internal)?;
+                    let mut received: u64 = 0;
+                    while let Some(chunk) = multipart.chunk().await
+                        .map_err(malformed)?
+                    {
+                        received += chunk.len() as u64;
+                        if received > staging.max_size() {
+                            return Err(RedfishError::new(
+                                StatusCode::PAYLOAD_TOO_LARGE,
+                                vec![base::general_error()]));
+                        }
+                        file.write_all(&chunk).await.map_err(internal)?;
+                    }
+                    file.flush().await.map_err(internal)?;
+                    image = Some((staged, part.filename.unwrap_or(part.name)));
+                },
+                name => return Err(RedfishError::bad_request(
+                    vec![base::property_unknown(name)])),
+            }
+        }
+
+        let (image, name) = image.ok_or_else(|| {
+            RedfishError::bad_request(vec![base::property_missing(
+                "UpdateFile")])
+        })?;
+        let targets = self.targets(&targets).map_err(|target| {
+            RedfishError::bad_request(vec![base::property_value_not_in_list(
+                &target, "Targets").related_to("Targets")])
+        })?;
+        let reservation = self.installer.reserve(&targets)?;
+        let installer = self.installer.clone();
+        Ok(self.tasks.spawn(MULTIPART_PUSH, move |task| async move {
+            let _reservation = reservation;
+            installer.install(task, image, name, targets, 0).await
+        }))
+    }
+
+    // Validate the parameters of SimpleUpdate, and start a task that
+    // downloads the image and installs it.
+    fn simple_update(&self, mut parameters: Parameters) -> ActionResult {
+        let image_uri: Option<String> = parameters.require("ImageURI");
+        let protocol: Option<TransferProtocol> =
+            parameters.take_enum("TransferProtocol");
+        let targets: Vec<String> =
+            parameters.take("Targets").unwrap_or_default();
+        let username: Option<String> = parameters.take("Username");
+        let password: Option<String> = parameters.take("Password");
+
+        // The scheme of the URI may be given by TransferProtocol instead
+        let uri = image_uri.as_ref().and_then(|image_uri| {
+            let uri = match (image_uri.contains("://"), protocol) {
+                (false, Some(TransferProtocol::HTTP)) =>
+                    format!("http://{}", image_uri),
+                _ => image_uri.clone(),
+            };
+            uri.parse::<Uri>().ok()
+                .filter(|uri| uri.scheme_str() == Some("http"))
+                .filter(|uri| uri.authority().is_some())
+        });
+        if let (Some(image_uri), None) = (&image_uri, &uri) {
+            parameters.reject(
+                "ImageURI", base::action_parameter_value_format_error(
+                    image_uri, "ImageURI", SIMPLE_UPDATE));
+        }
+        let targets = match self.targets(&targets) {
+            Ok(targets) => targets,
+            Err(target) => {
+                parameters.reject("Targets",
+                                  base::action_parameter_value_not_in_list(
+                                      &target, "Targets", SIMPLE_UPDATE));
+                Vec::new()
+            },
+        };
+        parameters.finish()?;
+        let (Some(image_uri), Some(uri)) = (image_uri, uri) else {
+            return Err(RedfishError::internal(vec![base::general_error()]));
+        };
+        let reservation = self.installer.reserve(&targets)?;
+
+        let authorization = username.map(|username| format!(
+            "Basic {}", STANDARD.encode(format!(
+                "{}:{}", username, password.unwrap_or_default()))));
+        let installer = self.installer.clone();
+        Ok(Some(self.tasks.spawn(SIMPLE_UPDATE, move |task| async move {
+            let _reservation = reservation;
+            let name = names(&targets);
+            task.push_message(update::transferring_to_component(
+                &image_uri, &name));
+            let image = images::download(
+                &installer.staging, uri, authorization,
+                |percent| task.set_percent_complete(percent / 2)).await
+                .map_err(|_| RedfishError::internal(vec![
+                    update::transfer_failed(&image_uri, &name)]))?;
+            installer.install(task, image, image_uri, targets, 50).await
+        })))
+    }
+}
+
+impl odata::ResourceMetadata for UpdateService {
+    const ODATA_TYPE: &'static str = "#UpdateService.v1_11_0.UpdateService";
+}
+
+impl odata::Serialize for UpdateService {
+    const CARDINALITY: usize = 8;
+    fn serialize<S>(&self, serializer: &mut S, me: &Path) ->
+        Result<(), S::Error>
+    where S: serde::ser::SerializeStruct
+    {
+        serializer.serialize_field("Id", "UpdateService")?;
+        serializer.serialize_field("Name", "Update Service")?;
+        serializer.serialize_field("ServiceEnabled", &true)?;
+        serializer.serialize_field("Status", &Status::default())?;
+        serializer.serialize_field(
+            "FirmwareInventory", &odata::Link::from(self.inventory_path()))?;
+        serializer.serialize_field("MultipartHttpPushUri", &self.push_path())?;
+        serializer.serialize_field(
+            "MaxImageSizeBytes", &self.installer.staging.max_size())?;
+        let mut actions = Action::targets(me, &[SIMPLE_UPDATE]);
+        for action in actions.values_mut() {
+            action.allow("TransferProtocol", &["HTTP"]);
+        }
+        serializer.serialize_field("Actions", &actions)
+    }
+}
+
+impl Dispatch for UpdateService {
+    type Error = Infallible;
+    async fn dispatch(&self, path: &Path, request: Request<Body>) ->
+        Result<Response<Body>, Self::Error>
+    {
+        if path.starts_with(self.inventory_path()) {
+            return self.inventory().dispatch(path, request).await;
+        }
+        if path != self.push_path() {
+            return Ok(NotFound.into());
+        }
+        if request.method() != Method::POST {
+            return Ok(MethodNotAllowed::new(vec![Method::POST]).into());
+        }
+        Ok(match self.receive(request).await {
+            Ok(task) => task_service::accepted(&Task::resource(task)),
+            Err(error) => error.into(),
+        })
+    }
+
+    async fn action(&self, name: &str, parameters: Parameters) ->
+        ActionResult
+    {
+        match name {
+            SIMPLE_UPDATE => self.simple_update(parameters),
+            _ => Err(RedfishError::not_found(
+                vec![base::action_not_supported(name)])),
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
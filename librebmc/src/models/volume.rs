@@ -0,0 +1,109 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            volume.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Volume model.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::convert::Infallible;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::hardware::storage::VolumeInventory;
+use crate::models::{Member, Status};
+use crate::service::Dispatch;
+
+#[derive(Serialize)]
+struct Links {
+    #[serde(rename = "Drives")]
+    drives: Vec<odata::Link>,
+    #[serde(rename = "Drives@odata.count")]
+    count: usize,
+}
+
+#[derive(Clone)]
+pub struct Volume {
+    inventory: VolumeInventory,
+
+    // Where the drives of the storage subsystem are
+    drives: PathBuf,
+}
+
+impl Volume {
+    pub fn new(inventory: VolumeInventory, drives: &Path) -> Self {
+        Volume { inventory, drives: drives.to_owned() }
+    }
+
+    pub fn get_id(&self) -> &str { &self.inventory.id }
+}
+
+impl Member for Volume {
+    const COLLECTION_TYPE: &'static str = "#VolumeCollection.VolumeCollection";
+    const COLLECTION_NAME: &'static str = "Volume Collection";
+}
+
+impl odata::ResourceMetadata for Volume {
+    const ODATA_TYPE: &'static str = "#Volume.v1_8_0.Volume";
+}
+
+impl odata::Serialize for Volume {
+    const CARDINALITY: usize = 6;
+    fn serialize<S>(&self, serializer: &mut S, _me: &Path) ->
+        Result<(), S::Error>
+    where S: serde::ser::SerializeStruct
+    {
+        let inventory = &self.inventory;
+        let name = inventory.name.clone()
+            .unwrap_or_else(|| format!("Volume {}", inventory.id));
+        serializer.serialize_field("Id", &inventory.id)?;
+        serializer.serialize_field("Name", &name)?;
+        if let Some(capacity) = inventory.capacity_bytes {
+            serializer.serialize_field("CapacityBytes", &capacity)?;
+        }
+        if let Some(raid_type) = &inventory.raid_type {
+            serializer.serialize_field("RAIDType", raid_type)?;
+        }
+        let drives: Vec<odata::Link> = inventory.drives.iter()
+            .map(|drive| self.drives.join(drive).into())
+            .collect();
+        serializer.serialize_field("Links", &Links {
+            count: drives.len(), drives,
+        })?;
+        serializer.serialize_field("Status", &Status {
+            state: inventory.state, health: inventory.health,
+            health_rollup: None,
+        })
+    }
+}
+
+impl Dispatch for Volume {
+    type Error = Infallible;
+}
+
+///////////////////////////////////////////////////////////////////////////////
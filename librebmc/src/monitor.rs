@@ -0,0 +1,453 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            monitor.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Background polling of sensors. Readings are cached so that
+//                  requests never wait on the hardware.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::io;
+use std::path::Path;
+use std::sync::{Arc, Mutex, OnceLock, RwLock, Weak};
+use std::thread;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use odata::{Link, ResourceMetadata};
+
+use crate::bus::{EventBus, StateChange};
+use crate::events::EventType;
+use crate::hardware::sensor::{
+    Assertion, Sensor, SensorInfo, Side, Thresholds,
+};
+use crate::health::HealthNode;
+use crate::models::{self, Health, Message};
+use crate::registries::resource_event;
+
+///////////////////////////////////////////////////////////////////////////////
+// MonitoredSensor
+////
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Sample {
+    pub reading: Option<f64>,
+    pub time: DateTime<Utc>,
+    pub health: Health,
+}
+
+// The threshold messages for a change in the thresholds asserted by a
+// reading: those that were cleared, then those that were exceeded, in the
+// order that the reading crossed them. Each cites the threshold that was
+// asserted, not whichever is nearest the reading.
+fn crossings(thresholds: &Thresholds, previous: &Assertion,
+             assertion: &Assertion) -> Vec<Message>
+{
+    let caution = |side| match side {
+        Side::Lower => thresholds.lower_caution,
+        Side::Upper => thresholds.upper_caution,
+    };
+    let critical = |side| match side {
+        Side::Lower => thresholds.lower_critical,
+        Side::Upper => thresholds.upper_critical,
+    };
+    let changed = |before: Option<Side>, after: Option<Side>| {
+        before.filter(|side| after != Some(*side))
+    };
+
+    use resource_event::*;
+    let message = |threshold: Option<f64>,
+                   message: fn(&str, &str) -> Message| {
+        threshold.map(|threshold| message("Reading", &threshold.to_string()))
+    };
+    [
+        message(changed(previous.critical, assertion.critical)
+                .and_then(critical), resource_error_threshold_cleared),
+        message(changed(previous.caution, assertion.caution)
+                .and_then(caution), resource_warning_threshold_cleared),
+        message(changed(assertion.caution, previous.caution)
+                .and_then(caution), resource_warning_threshold_exceeded),
+        message(changed(assertion.critical, previous.critical)
+                .and_then(critical), resource_error_threshold_exceeded),
+    ].into_iter().flatten().collect()
+}
+
+// A sensor whose reading is the latest sample taken by the monitor
+pub struct MonitoredSensor {
+    sensor: Arc<dyn Sensor>,
+    sample: RwLock<Option<Sample>>,
+    node: Arc<HealthNode>,
+    // The thresholds asserted by the last successful reading
+    assertion: Mutex<Assertion>,
+    // Where threshold crossings are published, and the sensor resource they
+    // originate from.
+    bus: OnceLock<(EventBus, Link)>,
+}
+
+impl MonitoredSensor {
+    fn new(sensor: Arc<dyn Sensor>) -> Self {
+        MonitoredSensor {
+            sensor, sample: RwLock::new(None), node: HealthNode::new(),
+            assertion: Mutex::new(Assertion::default()), bus: OnceLock::new(),
+        }
+    }
+
+    pub fn sample(&self) -> Option<Sample> { *self.sample.read().unwrap() }
+    pub fn health_node(&self) -> &Arc<HealthNode> { &self.node }
+
+    // Take a new reading from the hardware and evaluate it against the
+    // thresholds of the sensor.
+    pub fn poll(&self) {
+        let last = self.sample();
+        let reading = self.sensor.read().ok();
+        let thresholds = &self.sensor.info().thresholds;
+
+        // Thresholds remain asserted while the sensor can't be read
+        let mut assertion = self.assertion.lock().unwrap();
+        let previous = *assertion;
+        let health = match reading {
+            Some(reading) => {
+                *assertion = thresholds.assertion(reading, &previous);
+                assertion.health()
+            },
+            None => Health::Warning,
+        };
+        *self.sample.write().unwrap() = Some(Sample {
+            reading, time: Utc::now(), health,
+        });
+        self.node.set_health(health);
+
+        // Failing to read the sensor isn't a threshold crossing, so neither
+        // is the next successful reading.
+        let Some((bus, origin)) = self.bus.get() else { return; };
+        if reading.is_none()
+            || last.is_some_and(|sample| sample.reading.is_none())
+        {
+            return;
+        }
+        for message in crossings(thresholds, &previous, &assertion) {
+            bus.publish(StateChange::new(
+                EventType::Alert, origin.clone(),
+                Some(models::Sensor::ODATA_TYPE), message));
+        }
+    }
+}
+
+impl Sensor for MonitoredSensor {
+    fn info(&self) -> &SensorInfo { self.sensor.info() }
+
+    fn read(&self) -> io::Result<f64> {
+        self.sample()
+            .and_then(|sample| sample.reading)
+            .ok_or_else(|| io::Error::other("no reading is available"))
+    }
+
+    fn health(&self) -> Health {
+        self.sample()
+            .map(|sample| sample.health)
+            .unwrap_or(Health::OK)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// SensorMonitor
+////
+
+type Probe = Box<dyn Fn() -> Health + Send + Sync>;
+
+// Returns false once what it observes is gone
+type Observer = Box<dyn Fn() -> bool + Send + Sync>;
+
+// A component whose health is sampled along with the sensors
+struct Watch {
+    node: Weak<HealthNode>,
+    probe: Probe,
+}
+
+impl Watch {
+    // Returns false once the resource being watched is gone
+    fn poll(&self) -> bool {
+        match self.node.upgrade() {
+            Some(node) => {
+                node.set_health((self.probe)());
+                true
+            },
+            None => false,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct SensorMonitor {
+    sensors: Vec<Arc<MonitoredSensor>>,
+    watches: Vec<Arc<Watch>>,
+    observers: Vec<Arc<Observer>>,
+}
+
+impl SensorMonitor {
+    pub fn new(sensors: Vec<Arc<dyn Sensor>>) -> Self {
+        SensorMonitor {
+            sensors: sensors.into_iter()
+                .map(|sensor| Arc::new(MonitoredSensor::new(sensor)))
+                .collect(),
+            watches: Vec::new(),
+            observers: Vec::new(),
+        }
+    }
+
+    pub fn sensors(&self) -> &[Arc<MonitoredSensor>] { &self.sensors }
+
+    // Publish the threshold crossings of each sensor on the bus, originating
+    // from the sensor at <collection>/<id>.
+    pub fn publish_to(&self, bus: &EventBus, collection: &Path) {
+        for sensor in &self.sensors {
+            let origin = collection.join(&sensor.info().id).into();
+            let _ = sensor.bus.set((bus.clone(), origin));
+        }
+    }
+
+    // Sample the health of a component that isn't a sensor, such as a power
+    // supply, into its node in the health tree.
+    pub fn watch<F>(&mut self, node: &Arc<HealthNode>, probe: F)
+    where F: Fn() -> Health + Send + Sync + 'static
+    {
+        let watch = Arc::new(Watch {
+            node: Arc::downgrade(node), probe: Box::new(probe),
+        });
+        watch.poll();
+        self.watches.push(watch);
+    }
+
+    // Refresh the state of something that's too slow to be read whenever
+    // it's requested, such as the power state of the host.
+    pub fn observe<F>(&mut self, observer: F)
+    where F: Fn() -> bool + Send + Sync + 'static
+    {
+        let observer: Arc<Observer> = Arc::new(Box::new(observer));
+        observer();
+        self.observers.push(observer);
+    }
+
+    pub fn poll(&self) {
+        self.sensors.iter().for_each(|sensor| sensor.poll());
+        self.watches.iter().for_each(|watch| { watch.poll(); });
+        self.observers.iter().for_each(|observer| { observer(); });
+    }
+
+    // Sample every sensor on an interval. Polling happens on a dedicated
+    // thread, because reads from the hardware may block. The thread exits
+    // once every sensor of the monitor has been dropped.
+    pub fn start(&self, interval: Duration) ->
+        io::Result<thread::JoinHandle<()>>
+    {
+        let sensors: Vec<Weak<MonitoredSensor>> = self.sensors.iter()
+            .map(Arc::downgrade)
+            .collect();
+        let watches = self.watches.clone();
+        let observers = self.observers.clone();
+        thread::Builder::new()
+            .name("sensor-monitor".to_string())
+            .spawn(move || loop {
+                thread::sleep(interval);
+                let mut alive = false;
+                for sensor in sensors.iter().filter_map(Weak::upgrade) {
+                    alive = true;
+                    sensor.poll();
+                }
+                for watch in &watches {
+                    alive |= watch.poll();
+                }
+                for observer in &observers {
+                    alive |= observer();
+                }
+                if !alive {
+                    break;
+                }
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hardware::sensor::{ReadingType, Thresholds};
+    use crate::hardware::simulator::SimulatedSensor;
+
+    fn simulated(reading: f64) -> Arc<SimulatedSensor> {
+        Arc::new(SimulatedSensor::new(SensorInfo {
+            id: "CPU0".to_string(),
+            name: "CPU0 Temperature".to_string(),
+            reading_type: ReadingType::Temperature,
+            physical_context: None,
+            thresholds: Thresholds {
+                upper_caution: Some(85.0), upper_critical: Some(95.0),
+                hysteresis: 3.0, ..Default::default()
+            },
+        }, reading))
+    }
+
+    #[test]
+    fn readings_are_served_from_the_cache() {
+        let hardware = simulated(50.0);
+        let monitor = SensorMonitor::new(vec![hardware.clone()]);
+        let sensor = &monitor.sensors()[0];
+        assert!(sensor.read().is_err());
+
+        monitor.poll();
+        hardware.set_reading(96.0);
+        assert_eq!(50.0, sensor.read().unwrap());
+        assert_eq!(Health::OK, sensor.health());
+
+        monitor.poll();
+        assert_eq!(96.0, sensor.read().unwrap());
+        assert_eq!(Health::Critical, sensor.health());
+
+        // The alarm holds until the reading drops past the hysteresis
+        hardware.set_reading(93.0);
+        monitor.poll();
+        assert_eq!(Health::Critical, sensor.health());
+        hardware.set_reading(91.0);
+        monitor.poll();
+        assert_eq!(Health::Warning, sensor.health());
+    }
+
+    #[test]
+    fn background_polling() {
+        let hardware = simulated(50.0);
+        let monitor = SensorMonitor::new(vec![hardware.clone()]);
+        let handle = monitor.start(Duration::from_millis(5)).unwrap();
+        hardware.set_reading(60.0);
+        thread::sleep(Duration::from_millis(100));
+        let sample = monitor.sensors()[0].sample().unwrap();
+        assert_eq!(Some(60.0), sample.reading);
+        assert!(sample.time <= Utc::now());
+
+        drop(monitor);
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn health_changes_are_rolled_up() {
+        let hardware = simulated(50.0);
+        let mut monitor = SensorMonitor::new(vec![hardware.clone()]);
+        let chassis = HealthNode::new();
+        chassis.add_child(monitor.sensors()[0].health_node());
+
+        let component = HealthNode::new();
+        chassis.add_child(&component);
+        let failed = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let probe = failed.clone();
+        monitor.watch(&component, move || {
+            match probe.load(std::sync::atomic::Ordering::SeqCst) {
+                true => Health::Warning,
+                false => Health::OK,
+            }
+        });
+
+        monitor.poll();
+        assert_eq!(Health::OK, chassis.rollup());
+        failed.store(true, std::sync::atomic::Ordering::SeqCst);
+        monitor.poll();
+        assert_eq!(Health::Warning, chassis.rollup());
+        hardware.set_reading(99.0);
+        monitor.poll();
+        assert_eq!(Health::Critical, chassis.rollup());
+    }
+
+    #[test]
+    fn threshold_crossings_are_published() {
+        let hardware = simulated(50.0);
+        let monitor = SensorMonitor::new(vec![hardware.clone()]);
+        let bus = EventBus::new();
+        let mut changes = bus.subscribe();
+        monitor.publish_to(
+            &bus, Path::new("/redfish/v1/Chassis/chassis/Sensors"));
+        let mut crossed = |reading: f64| {
+            hardware.set_reading(reading);
+            monitor.poll();
+            std::iter::from_fn(|| changes.try_recv().ok())
+                .map(|change| {
+                    let origin: &Path = change.origin.as_ref();
+                    assert_eq!(
+                        Path::new("/redfish/v1/Chassis/chassis/Sensors/CPU0"),
+                        origin);
+                    let key = change.message.message_id
+                        .rsplit('.').next().unwrap().to_string();
+                    (key, change.message.message_args[1].clone())
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert!(crossed(50.0).is_empty());
+        assert_eq!(vec![("ResourceWarningThresholdExceeded".to_string(),
+                         "85".to_string())], crossed(86.0));
+        assert!(crossed(87.0).is_empty());
+        assert_eq!(vec![("ResourceErrorThresholdExceeded".to_string(),
+                         "95".to_string())], crossed(96.0));
+        assert_eq!(vec![("ResourceErrorThresholdCleared".to_string(),
+                         "95".to_string()),
+                        ("ResourceWarningThresholdCleared".to_string(),
+                         "85".to_string())], crossed(40.0));
+    }
+
+    #[test]
+    fn cleared_thresholds_are_the_ones_asserted() {
+        let hardware = Arc::new(SimulatedSensor::new(SensorInfo {
+            id: "CPU0".to_string(),
+            name: "CPU0 Temperature".to_string(),
+            reading_type: ReadingType::Temperature,
+            physical_context: None,
+            thresholds: Thresholds {
+                lower_critical: Some(5.0), upper_critical: Some(95.0),
+                ..Default::default()
+            },
+        }, 50.0));
+        let monitor = SensorMonitor::new(vec![hardware.clone()]);
+        let bus = EventBus::new();
+        let mut changes = bus.subscribe();
+        monitor.publish_to(
+            &bus, Path::new("/redfish/v1/Chassis/chassis/Sensors"));
+        let mut crossed = |reading: f64| {
+            hardware.set_reading(reading);
+            monitor.poll();
+            std::iter::from_fn(|| changes.try_recv().ok())
+                .map(|change| change.message.message_args[1].clone())
+                .collect::<Vec<_>>()
+        };
+
+        assert!(crossed(50.0).is_empty());
+        assert_eq!(vec!["95"], crossed(96.0));
+        assert_eq!(vec!["95"], crossed(40.0));
+
+        // A reading that crosses from one side to the other clears one
+        // threshold and exceeds the other
+        assert_eq!(vec!["5"], crossed(4.0));
+        assert_eq!(vec!["5", "95"], crossed(99.0));
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,311 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            protocols.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     The listeners of librebmc, which may be enabled, disabled
+//                  or moved at runtime.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::fs;
+use std::io;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr, UdpSocket};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::oneshot;
+use uuid::Uuid;
+
+use crate::config::NetworkConfig;
+use crate::models::ServiceRoot;
+use crate::service::{ResourceService, ServiceFactory};
+
+const SSDP_MULTICAST: Ipv4Addr = Ipv4Addr::new(239, 255, 255, 250);
+const REDFISH_SERVICE: &'static str = "urn:dmtf-org:service:redfish-rest:1";
+
+///////////////////////////////////////////////////////////////////////////////
+// ProtocolSettings
+////
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ListenerSettings {
+    pub protocol_enabled: bool,
+    pub port: u16,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ProtocolSettings {
+    #[serde(rename = "HTTP")]
+    pub http: ListenerSettings,
+    #[serde(rename = "SSDP")]
+    pub ssdp: ListenerSettings,
+}
+
+impl From<&NetworkConfig> for ProtocolSettings {
+    fn from(config: &NetworkConfig) -> Self {
+        ProtocolSettings {
+            http: ListenerSettings {
+                protocol_enabled: config.http.enabled,
+                port: config.http.port,
+            },
+            ssdp: ListenerSettings {
+                protocol_enabled: config.ssdp.enabled,
+                port: config.ssdp.port,
+            },
+        }
+    }
+}
+
+fn save(path: &Path, settings: &ProtocolSettings) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let temporary = path.with_extension("tmp");
+    fs::write(&temporary, serde_json::to_vec_pretty(settings)?)?;
+    fs::rename(&temporary, path)
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// SSDP
+////
+
+// The response to an M-SEARCH for the Redfish service, if the request is one
+fn ssdp_response(request: &[u8], uuid: &Uuid, location: &str) ->
+    Option<String>
+{
+    let request = std::str::from_utf8(request).ok()?;
+    let mut lines = request.lines();
+    if lines.next()?.trim() != "M-SEARCH * HTTP/1.1" {
+        return None;
+    }
+    let target = lines
+        .filter_map(|line| line.split_once(':'))
+        .find(|(name, _)| name.trim().eq_ignore_ascii_case("ST"))
+        .map(|(_, value)| value.trim())?;
+    if target != "ssdp:all" && !target.starts_with(REDFISH_SERVICE) {
+        return None;
+    }
+    Some(format!(
+        "HTTP/1.1 200 OK\r\n\
+         CACHE-CONTROL: max-age=1800\r\n\
+         ST: {1}\r\n\
+         USN: uuid:{0}::{1}\r\n\
+         AL: {2}\r\n\
+         EXT:\r\n\r\n", uuid, REDFISH_SERVICE, location))
+}
+
+// The address of the BMC that the peer can reach
+fn local_address(peer: SocketAddr) -> io::Result<IpAddr> {
+    let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0))?;
+    socket.connect(peer)?;
+    Ok(socket.local_addr()?.ip())
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Protocols
+////
+
+// A listener is stopped when its sender is dropped
+type Listener = Option<oneshot::Sender<()>>;
+
+pub struct Protocols {
+    path: PathBuf,
+    address: IpAddr,
+    uuid: Uuid,
+    settings: Arc<Mutex<ProtocolSettings>>,
+    service: OnceLock<ResourceService<ServiceRoot>>,
+    http: Mutex<Listener>,
+    ssdp: Mutex<Listener>,
+}
+
+impl Protocols {
+    // Settings changed at runtime take precedence over the configuration
+    pub fn open(config: &NetworkConfig, uuid: Uuid) -> io::Result<Arc<Self>> {
+        let settings = match fs::read(&config.settings) {
+            Ok(contents) => serde_json::from_slice(&contents)?,
+            Err(error) if error.kind() == io::ErrorKind::NotFound =>
+                ProtocolSettings::from(config),
+            Err(error) => return Err(error),
+        };
+        Ok(Arc::new(Protocols {
+            path: config.settings.clone(), address: config.http.address,
+            uuid, settings: Arc::new(Mutex::new(settings)),
+            service: OnceLock::new(), http: Mutex::new(None),
+            ssdp: Mutex::new(None),
+        }))
+    }
+
+    pub fn settings(&self) -> ProtocolSettings {
+        *self.settings.lock().unwrap()
+    }
+
+    // Start the enabled listeners. Must be called from within the tokio
+    // runtime.
+    pub fn serve(&self, service: ResourceService<ServiceRoot>) ->
+        io::Result<()>
+    {
+        if self.service.set(service).is_err() {
+            return Err(io::Error::other("the service is already served"));
+        }
+        let settings = self.settings();
+        if settings.http.protocol_enabled {
+            *self.http.lock().unwrap() =
+                Some(self.start_http(settings.http.port)?);
+        }
+        if settings.ssdp.protocol_enabled {
+            *self.ssdp.lock().unwrap() =
+                Some(self.start_ssdp(settings.ssdp.port)?);
+        }
+        Ok(())
+    }
+
+    // Restart the listeners whose settings have changed. The new listeners
+    // are started before the old are stopped, so if any can't be started,
+    // nothing is changed. Connections that are open to the old HTTP
+    // listener are closed once their requests have been answered.
+    pub fn set_settings(&self, settings: &ProtocolSettings) ->
+        io::Result<()>
+    {
+        let mut current = self.settings.lock().unwrap();
+        let mut http = None;
+        let mut ssdp = None;
+        if self.service.get().is_some() {
+            if settings.http != current.http {
+                http = Some(match settings.http.protocol_enabled {
+                    true => Some(self.start_http(settings.http.port)?),
+                    false => None,
+                });
+            }
+            if settings.ssdp != current.ssdp {
+                ssdp = Some(match settings.ssdp.protocol_enabled {
+                    true => Some(self.start_ssdp(settings.ssdp.port)?),
+                    false => None,
+                });
+            }
+        }
+        save(&self.path, settings)?;
+        *current = *settings;
+        if let Some(http) = http {
+            *self.http.lock().unwrap() = http;
+        }
+        if let Some(ssdp) = ssdp {
+            *self.ssdp.lock().unwrap() = ssdp;
+        }
+        Ok(())
+    }
+
+    fn start_http(&self, port: u16) -> io::Result<oneshot::Sender<()>> {
+        let service = self.service.get().unwrap().clone();
+        let server = hyper::Server::try_bind(&(self.address, port).into())
+            .map_err(io::Error::other)?;
+        let (sender, receiver) = oneshot::channel();
+        let server = server.serve(ServiceFactory::from(service))
+            .with_graceful_shutdown(async { let _ = receiver.await; });
+        tokio::spawn(async move {
+            if let Err(error) = server.await {
+                eprintln!("failed to serve HTTP: {}", error);
+            }
+        });
+        Ok(sender)
+    }
+
+    // Answers M-SEARCH requests for the Redfish service with the location
+    // of the HTTP listener, while it's enabled.
+    fn start_ssdp(&self, port: u16) -> io::Result<oneshot::Sender<()>> {
+        let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, port))?;
+        if let Err(error) = socket.join_multicast_v4(
+            &SSDP_MULTICAST, &Ipv4Addr::UNSPECIFIED) {
+            eprintln!("failed to join the SSDP multicast group: {}", error);
+        }
+        socket.set_nonblocking(true)?;
+        let socket = tokio::net::UdpSocket::from_std(socket)?;
+
+        let (sender, mut receiver) = oneshot::channel::<()>();
+        let settings = self.settings.clone();
+        let address = Some(self.address).filter(|a| !a.is_unspecified());
+        let uuid = self.uuid;
+        tokio::spawn(async move {
+            let mut buffer = [0; 2048];
+            loop {
+                let received = tokio::select! {
+                    _ = &mut receiver => break,
+                    received = socket.recv_from(&mut buffer) => received,
+                };
+                let Ok((length, peer)) = received else { continue };
+                let http = settings.lock().unwrap().http;
+                if !http.protocol_enabled {
+                    continue;
+                }
+                let Some(host) = address.or_else(
+                    || local_address(peer).ok()) else { continue };
+                let location = format!(
+                    "http://{}/redfish/v1/", SocketAddr::new(host, http.port));
+                if let Some(response) = ssdp_response(
+                    &buffer[..length], &uuid, &location) {
+                    let _ = socket.send_to(response.as_bytes(), peer).await;
+                }
+            }
+        });
+        Ok(sender)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_searches_for_redfish_are_answered() {
+        let uuid = Uuid::nil();
+        let location = "http://192.168.0.100:3000/redfish/v1/";
+        let search = |target: &str| format!(
+            "M-SEARCH * HTTP/1.1\r\n\
+             HOST: 239.255.255.250:1900\r\n\
+             MAN: \"ssdp:discover\"\r\n\
+             MX: 1\r\n\
+             st: {}\r\n\r\n", target);
+
+        let response = ssdp_response(
+            search(REDFISH_SERVICE).as_bytes(), &uuid, location).unwrap();
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.contains(&format!("AL: {}\r\n", location)));
+        assert!(response.contains(&format!(
+            "USN: uuid:{}::{}\r\n", uuid, REDFISH_SERVICE)));
+        assert!(ssdp_response(search("ssdp:all").as_bytes(), &uuid, location)
+                .is_some());
+        assert!(ssdp_response(
+            search("urn:schemas-upnp-org:device:MediaServer:1").as_bytes(),
+            &uuid, location).is_none());
+        assert!(ssdp_response(
+            b"NOTIFY * HTTP/1.1\r\nST: ssdp:all\r\n\r\n", &uuid, location)
+                .is_none());
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
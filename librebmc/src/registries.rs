@@ -0,0 +1,61 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            registries.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Message registries implemented by librebmc.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use crate::models::{Health, Message};
+
+pub mod audit;
+pub mod base;
+pub mod firmware;
+pub mod resource_event;
+pub mod update;
+
+// Instantiate a message from a registry. Arguments are substituted into the
+// template for %1, %2, etc.
+pub(crate) fn message(
+    registry: &str, key: &str, template: &str, args: &[&str],
+    severity: Health, resolution: &str,
+) -> Message {
+    let mut text = template.to_string();
+    for (index, arg) in args.iter().enumerate().rev() {
+        text = text.replace(&format!("%{}", index + 1), arg);
+    }
+    Message {
+        message_id: format!("{}.{}", registry, key),
+        message: text,
+        message_args: args.iter().map(|arg| arg.to_string()).collect(),
+        message_severity: severity,
+        resolution: resolution.to_string(),
+        related_properties: Vec::new(),
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,51 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            audit.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Messages from the librebmc audit registry, which describe
+//                  the requests that changed the state of the service.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use crate::models::{Health, Message};
+use crate::registries::message;
+
+pub const REGISTRY: &'static str = "LibreBMCAudit.1.0.0";
+
+pub fn request_completed(user: &str, address: &str, method: &str, uri: &str,
+                         body: &str, status: &str) -> Message {
+    let severity = match status.starts_with('2') {
+        true => Health::OK,
+        false => Health::Warning,
+    };
+    message(REGISTRY, "RequestCompleted",
+            "User '%1' at %2 sent %3 %4 with the body %5, which completed \
+             with status %6.", &[user, address, method, uri, body, status],
+            severity, "None.")
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,213 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            base.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Messages from the DMTF Base message registry.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use crate::models::{Health, Message};
+use crate::registries::message;
+
+pub const REGISTRY: &'static str = "Base.1.15.0";
+
+pub fn general_error() -> Message {
+    message(REGISTRY, "GeneralError",
+            "A general error has occurred.  See Resolution for information on \
+             how to resolve the error, or if Resolution is not provided, see \
+             ExtendedInfo for information on how to resolve the error.", &[],
+            Health::Critical, "None.")
+}
+
+pub fn malformed_json() -> Message {
+    message(REGISTRY, "MalformedJSON",
+            "The request body submitted was malformed JSON and could not be \
+             parsed by the receiving service.", &[], Health::Critical,
+            "Ensure that the request body is valid JSON and resubmit the \
+             request.")
+}
+
+pub fn property_unknown(property: &str) -> Message {
+    message(REGISTRY, "PropertyUnknown",
+            "The property %1 is not in the list of valid properties for the \
+             resource.", &[property], Health::Warning,
+            "Remove the unknown property from the request body and resubmit \
+             the request if the operation failed.")
+}
+
+pub fn property_not_writable(property: &str) -> Message {
+    message(REGISTRY, "PropertyNotWritable",
+            "The property %1 is a read-only property and cannot be assigned \
+             a value.", &[property], Health::Warning,
+            "Remove the property from the request body and resubmit the \
+             request if the operation failed.")
+}
+
+pub fn property_value_type_error(value: &str, property: &str) -> Message {
+    message(REGISTRY, "PropertyValueTypeError",
+            "The value '%1' for the property %2 is not a type that the \
+             property can accept.", &[value, property], Health::Warning,
+            "Correct the value for the property in the request body and \
+             resubmit the request if the operation failed.")
+}
+
+pub fn property_value_not_in_list(value: &str, property: &str) -> Message {
+    message(REGISTRY, "PropertyValueNotInList",
+            "The value '%1' for the property %2 is not in the list of \
+             acceptable values.", &[value, property], Health::Warning,
+            "Choose a value from the enumeration list that the \
+             implementation can support and resubmit the request if the \
+             operation failed.")
+}
+
+pub fn property_value_out_of_range(value: &str, property: &str) -> Message {
+    message(REGISTRY, "PropertyValueOutOfRange",
+            "The value '%1' for the property %2 is not in the supported \
+             range of acceptable values.", &[value, property],
+            Health::Warning,
+            "Correct the value for the property in the request body and \
+             resubmit the request if the operation failed.")
+}
+
+pub fn property_value_conflict(property: &str, other: &str) -> Message {
+    message(REGISTRY, "PropertyValueConflict",
+            "The property '%1' could not be written because its value would \
+             conflict with the value of the '%2' property.",
+            &[property, other], Health::Warning,
+            "No resolution is required.")
+}
+
+pub fn property_missing(property: &str) -> Message {
+    message(REGISTRY, "PropertyMissing",
+            "The property %1 is a required property and must be included in \
+             the request.", &[property], Health::Warning,
+            "Ensure that the property is in the request body and has a valid \
+             value and resubmit the request if the operation failed.")
+}
+
+pub fn property_value_format_error(value: &str, property: &str) -> Message {
+    message(REGISTRY, "PropertyValueFormatError",
+            "The value '%1' for the property %2 is not a format that the \
+             property can accept.", &[value, property], Health::Warning,
+            "Correct the value for the property in the request body and \
+             resubmit the request if the operation failed.")
+}
+
+pub fn action_not_supported(action: &str) -> Message {
+    message(REGISTRY, "ActionNotSupported",
+            "The action %1 is not supported by the resource.", &[action],
+            Health::Critical,
+            "The action supplied cannot be resubmitted to the \
+             implementation.  Perhaps the action was invalid, the wrong \
+             resource was the target or the implementation documentation \
+             may be of assistance.")
+}
+
+pub fn action_parameter_missing(action: &str, parameter: &str) -> Message {
+    message(REGISTRY, "ActionParameterMissing",
+            "The action %1 requires the parameter %2 to be present in the \
+             request body.", &[action, parameter], Health::Critical,
+            "Supply the action with the required parameter in the request \
+             body when the request is resubmitted.")
+}
+
+pub fn action_parameter_unknown(action: &str, parameter: &str) -> Message {
+    message(REGISTRY, "ActionParameterUnknown",
+            "The action %1 was submitted with the invalid parameter %2.",
+            &[action, parameter], Health::Warning,
+            "Correct the invalid parameter and resubmit the request if the \
+             operation failed.")
+}
+
+pub fn action_parameter_value_type_error(
+    value: &str, parameter: &str, action: &str,
+) -> Message {
+    message(REGISTRY, "ActionParameterValueTypeError",
+            "The value '%1' for the parameter %2 in the action %3 is not a \
+             type that the parameter can accept.", &[value, parameter, action],
+            Health::Warning,
+            "Correct the value for the parameter in the request body and \
+             resubmit the request if the operation failed.")
+}
+
+pub fn action_parameter_value_not_in_list(
+    value: &str, parameter: &str, action: &str,
+) -> Message {
+    message(REGISTRY, "ActionParameterValueNotInList",
+            "The value '%1' for the parameter %2 in the action %3 is not in \
+             the list of acceptable values.", &[value, parameter, action],
+            Health::Warning,
+            "Choose a value from the enumeration list that the \
+             implementation can support and resubmit the request if the \
+             operation failed.")
+}
+
+pub fn action_parameter_value_format_error(
+    value: &str, parameter: &str, action: &str,
+) -> Message {
+    message(REGISTRY, "ActionParameterValueFormatError",
+            "The value '%1' for the parameter %2 in the action %3 is of a \
+             different format than the parameter can accept.",
+            &[value, parameter, action], Health::Warning,
+            "Correct the value for the parameter in the request body and \
+             resubmit the request if the operation failed.")
+}
+
+pub fn resource_in_use() -> Message {
+    message(REGISTRY, "ResourceInUse",
+            "The change to the requested resource failed because the \
+             resource is in use or in transition.", &[], Health::Warning,
+            "Remove the condition and resubmit the request if the operation \
+             failed.")
+}
+
+pub fn query_parameter_value_format_error(value: &str, parameter: &str) ->
+    Message
+{
+    message(REGISTRY, "QueryParameterValueFormatError",
+            "The value '%1' for the parameter %2 is not a format that the \
+             parameter can accept.", &[value, parameter], Health::Warning,
+            "Correct the value for the query parameter in the request and \
+             resubmit the request if the operation failed.")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arguments_are_substituted() {
+        let message = property_value_type_error("ten", "SpeedPercent");
+        assert_eq!("Base.1.15.0.PropertyValueTypeError", message.message_id);
+        assert_eq!(
+            "The value 'ten' for the property SpeedPercent is not a type \
+             that the property can accept.", message.message);
+        assert_eq!(vec!["ten", "SpeedPercent"], message.message_args);
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,92 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            firmware.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Messages from the librebmc firmware registry, which explain
+//                  why an image was refused before it was applied.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use crate::models::{Health, Message};
+use crate::registries::message;
+
+pub const REGISTRY: &'static str = "LibreBMCFirmware.1.0.0";
+
+pub fn image_verified(image: &str, component: &str, version: &str) ->
+    Message
+{
+    message(REGISTRY, "ImageVerified",
+            "Image '%1' for '%2' is signed by a trusted key, and contains \
+             version '%3'.", &[image, component, version], Health::OK,
+            "None.")
+}
+
+pub fn image_malformed(image: &str) -> Message {
+    message(REGISTRY, "ImageMalformed",
+            "Image '%1' is not a signed librebmc firmware image.", &[image],
+            Health::Critical,
+            "Package the firmware with its manifest and signature and \
+             resubmit the request.")
+}
+
+pub fn signature_untrusted(image: &str) -> Message {
+    message(REGISTRY, "SignatureUntrusted",
+            "The signature of image '%1' is invalid, or was not made by a \
+             trusted key.", &[image], Health::Critical,
+            "Sign the image with a key that the BMC trusts and resubmit the \
+             request.")
+}
+
+pub fn digest_mismatch(image: &str) -> Message {
+    message(REGISTRY, "DigestMismatch",
+            "The contents of image '%1' do not match the digest in its \
+             manifest.", &[image], Health::Critical,
+            "Obtain an intact copy of the image and resubmit the request.")
+}
+
+pub fn component_mismatch(image: &str, component: &str, target: &str) ->
+    Message
+{
+    message(REGISTRY, "ComponentMismatch",
+            "Image '%1' is for '%2', and can't be applied to '%3'.",
+            &[image, component, target], Health::Critical,
+            "Remove the target from the request, or choose an image for it, \
+             and resubmit the request.")
+}
+
+pub fn downgrade_rejected(image: &str, version: &str, installed: &str) ->
+    Message
+{
+    message(REGISTRY, "DowngradeRejected",
+            "Image '%1' contains version '%2', which is older than the \
+             installed version '%3'.", &[image, version, installed],
+            Health::Critical,
+            "Choose an image with a newer version, or allow downgrades in \
+             the configuration of the BMC, and resubmit the request.")
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,118 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            resource_event.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Messages from the DMTF ResourceEvent message registry.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use crate::models::{Health, Message};
+use crate::registries::message;
+
+pub const REGISTRY: &'static str = "ResourceEvent.1.3.0";
+
+pub fn resource_changed() -> Message {
+    message(REGISTRY, "ResourceChanged",
+            "One or more resource properties have changed.", &[], Health::OK,
+            "None.")
+}
+
+pub fn resource_created() -> Message {
+    message(REGISTRY, "ResourceCreated",
+            "The resource has been created successfully.", &[], Health::OK,
+            "None.")
+}
+
+pub fn resource_removed() -> Message {
+    message(REGISTRY, "ResourceRemoved",
+            "The resource has been removed successfully.", &[], Health::OK,
+            "None.")
+}
+
+pub fn resource_powered_on(resource: &str) -> Message {
+    message(REGISTRY, "ResourcePoweredOn",
+            "The resource '%1' has powered on.", &[resource], Health::OK,
+            "None.")
+}
+
+pub fn resource_powered_off(resource: &str) -> Message {
+    message(REGISTRY, "ResourcePoweredOff",
+            "The resource '%1' has powered off.", &[resource], Health::OK,
+            "None.")
+}
+
+pub fn resource_warning_threshold_exceeded(property: &str, threshold: &str)
+    -> Message
+{
+    message(REGISTRY, "ResourceWarningThresholdExceeded",
+            "The resource property %1 has exceeded its warning threshold of \
+             value %2.", &[property, threshold], Health::Warning,
+            "Check the condition of the resource listed in \
+             OriginOfCondition.")
+}
+
+pub fn resource_warning_threshold_cleared(property: &str, threshold: &str)
+    -> Message
+{
+    message(REGISTRY, "ResourceWarningThresholdCleared",
+            "The resource property %1 has cleared the warning threshold of \
+             value %2.", &[property, threshold], Health::OK, "None.")
+}
+
+pub fn resource_error_threshold_exceeded(property: &str, threshold: &str)
+    -> Message
+{
+    message(REGISTRY, "ResourceErrorThresholdExceeded",
+            "The resource property %1 has exceeded error threshold of value \
+             %2.", &[property, threshold], Health::Critical,
+            "Check the condition of the resource listed in \
+             OriginOfCondition.")
+}
+
+pub fn resource_error_threshold_cleared(property: &str, threshold: &str)
+    -> Message
+{
+    message(REGISTRY, "ResourceErrorThresholdCleared",
+            "The resource property %1 has cleared the error threshold of \
+             value %2.", &[property, threshold], Health::OK, "None.")
+}
+
+pub fn resource_status_changed(resource: &str, health: Health) -> Message {
+    let (key, resolution) = match health {
+        Health::OK => ("ResourceStatusChangedOK", "None."),
+        Health::Warning => ("ResourceStatusChangedWarning",
+                            "Check the condition of the resource listed in \
+                             OriginOfCondition."),
+        Health::Critical => ("ResourceStatusChangedCritical",
+                             "Check the condition of the resource listed in \
+                              OriginOfCondition."),
+    };
+    message(REGISTRY, key, "The health of resource '%1' has changed to %2.",
+            &[resource, &format!("{:?}", health)], health, resolution)
+}
+
+///////////////////////////////////////////////////////////////////////////////
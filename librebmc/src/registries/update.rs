@@ -0,0 +1,80 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            update.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Messages from the DMTF Update message registry.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use crate::models::{Health, Message};
+use crate::registries::message;
+
+pub const REGISTRY: &'static str = "Update.1.0.2";
+
+pub fn no_targets_determined(image: &str) -> Message {
+    message(REGISTRY, "NoTargetsDetermined",
+            "No target device will be updated with image '%1'.", &[image],
+            Health::Warning, "None.")
+}
+
+pub fn transferring_to_component(image: &str, target: &str) -> Message {
+    message(REGISTRY, "TransferringToComponent",
+            "Image '%1' is being transferred to '%2'.", &[image, target],
+            Health::OK, "None.")
+}
+
+pub fn installing_on_component(image: &str, target: &str) -> Message {
+    message(REGISTRY, "InstallingOnComponent",
+            "Image '%1' is being installed on '%2'.", &[image, target],
+            Health::OK, "None.")
+}
+
+pub fn update_successful(target: &str, image: &str) -> Message {
+    message(REGISTRY, "UpdateSuccessful",
+            "Device '%1' successfully updated with image '%2'.",
+            &[target, image], Health::OK, "None.")
+}
+
+pub fn transfer_failed(image: &str, target: &str) -> Message {
+    message(REGISTRY, "TransferFailed",
+            "Transfer of image '%1' to '%2' failed.", &[image, target],
+            Health::Critical, "None.")
+}
+
+pub fn verification_failed(image: &str, target: &str) -> Message {
+    message(REGISTRY, "VerificationFailed",
+            "Verification of image '%1' at '%2' failed.", &[image, target],
+            Health::Critical, "None.")
+}
+
+pub fn apply_failed(image: &str, target: &str) -> Message {
+    message(REGISTRY, "ApplyFailed",
+            "Installation of image '%1' to '%2' failed.", &[image, target],
+            Health::Critical, "None.")
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -7,7 +7,7 @@
 //
 // CREATED:         03/20/2022
 //
-// LAST EDITED:     04/10/2022
+// LAST EDITED:     10/18/2026
 //
 // Copyright 2022, Ethan D. Twardy
 //
@@ -30,26 +30,36 @@
 // IN THE SOFTWARE.
 ////
 
-use core::future::{self, Ready};
+use core::future::{self, Future, Ready};
 use core::clone::Clone;
 use core::convert::Infallible;
 use core::fmt::Debug;
 use core::pin::Pin;
 use core::task::{Context, Poll};
+use std::net::SocketAddr;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
-use hyper::{Body, Method, Request, Response, service::Service};
+use hyper::{Body, Method, Request, Response, StatusCode, service::Service};
+use hyper::server::conn::AddrStream;
 use odata::{Resource, ResourceMetadata, Serialize};
-use serde_json;
+use serde::de::DeserializeOwned;
+use serde_json::{self, json, Map, Value};
+
+use crate::audit::{self, AuditLog, AuditRecord, RemoteAddress};
+use crate::bus::{EventBus, StateChange};
+use crate::models::{Message, Task};
+use crate::models::task_service;
+use crate::registries::base;
+use crate::tasks::TaskRecord;
 
 ///////////////////////////////////////////////////////////////////////////////
 // Convenience Responses
 ////
 
 pub struct NotFound;
-impl Into<Response<Body>> for NotFound {
-    fn into(self) -> Response<Body> {
+impl From<NotFound> for Response<Body> {
+    fn from(_: NotFound) -> Self {
         Response::builder().status(404).body("".into()).unwrap()
     }
 }
@@ -61,9 +71,9 @@ impl MethodNotAllowed {
     }
 }
 
-impl Into<Response<Body>> for MethodNotAllowed {
-    fn into(self) -> Response<Body> {
-        let allowed = self.0.iter().map(|method| method.as_str())
+impl From<MethodNotAllowed> for Response<Body> {
+    fn from(value: MethodNotAllowed) -> Self {
+        let allowed = value.0.iter().map(|method| method.as_str())
             .collect::<Vec<&str>>()
             .join(", ");
         Response::builder()
@@ -73,14 +83,360 @@ impl Into<Response<Body>> for MethodNotAllowed {
     }
 }
 
+// A Redfish error response, with extended information.
+#[derive(Debug)]
+pub struct RedfishError {
+    status: StatusCode,
+    messages: Vec<Message>,
+}
+
+impl RedfishError {
+    pub fn new(status: StatusCode, messages: Vec<Message>) -> Self {
+        RedfishError { status, messages }
+    }
+
+    pub fn bad_request(messages: Vec<Message>) -> Self {
+        RedfishError::new(StatusCode::BAD_REQUEST, messages)
+    }
+
+    pub fn not_found(messages: Vec<Message>) -> Self {
+        RedfishError::new(StatusCode::NOT_FOUND, messages)
+    }
+
+    pub fn internal(messages: Vec<Message>) -> Self {
+        RedfishError::new(StatusCode::INTERNAL_SERVER_ERROR, messages)
+    }
+
+    pub fn status(&self) -> StatusCode { self.status }
+    pub fn messages(&self) -> &[Message] { &self.messages }
+}
+
+impl From<RedfishError> for Response<Body> {
+    fn from(value: RedfishError) -> Self {
+        let general = base::general_error();
+        let first = value.messages.first().unwrap_or(&general);
+        let body = json!({
+            "error": {
+                "code": first.message_id,
+                "message": first.message,
+                "@Message.ExtendedInfo": value.messages,
+            }
+        });
+        Response::builder()
+            .status(value.status)
+            .header("Content-Type", "application/json")
+            .body(body.to_string().into())
+            .unwrap()
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Query Parameters
+////
+
+pub fn percent_decode(value: &str) -> String {
+    let mut bytes = Vec::new();
+    let mut input = value.bytes();
+    while let Some(byte) = input.next() {
+        match byte {
+            b'+' => bytes.push(b' '),
+            b'%' => {
+                let digits = [input.next(), input.next()];
+                let decoded = match digits {
+                    [Some(high), Some(low)] => {
+                        std::str::from_utf8(&[high, low]).ok()
+                            .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                    },
+                    _ => None,
+                };
+                match decoded {
+                    Some(decoded) => bytes.push(decoded),
+                    None => {
+                        bytes.push(b'%');
+                        bytes.extend(digits.iter().flatten());
+                    },
+                }
+            },
+            byte => bytes.push(byte),
+        }
+    }
+    String::from_utf8_lossy(&bytes).to_string()
+}
+
+// Escape everything but the unreserved characters of RFC 3986, so that the
+// value can be used as a path segment or in a query.
+pub fn percent_encode(value: &str) -> String {
+    value.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9'
+                | b'-' | b'.' | b'_' | b'~' => (byte as char).to_string(),
+            byte => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+// The decoded value of a query parameter, e.g. $filter
+pub fn query_parameter<T>(request: &Request<T>, name: &str) -> Option<String> {
+    request.uri().query()?.split('&')
+        .map(|parameter| parameter.split_once('=').unwrap_or((parameter, "")))
+        .find(|(key, _)| percent_decode(key) == name)
+        .map(|(_, value)| percent_decode(value))
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Patch
+////
+
+// The properties of a PATCH request, or of a POST that creates a resource.
+// Resources take the properties they support, and the remainder are reported
+// as unknown or read-only.
+pub struct Patch {
+    pointer: String,
+    properties: Map<String, Value>,
+    messages: Vec<Message>,
+}
+
+fn display(value: &Value) -> String {
+    match value {
+        Value::String(value) => value.clone(),
+        value => value.to_string(),
+    }
+}
+
+impl Patch {
+    pub fn new(properties: Map<String, Value>) -> Self {
+        Patch { pointer: String::new(), properties, messages: Vec::new() }
+    }
+
+    fn path(&self, name: &str) -> String {
+        format!("{}{}", self.pointer, name)
+    }
+
+    fn take_with<T, F>(&mut self, name: &str, error: F) -> Option<T>
+    where T: DeserializeOwned, F: Fn(&str, &str) -> Message
+    {
+        let value = self.properties.remove(name)?;
+        match serde_json::from_value(value.clone()) {
+            Ok(value) => Some(value),
+            Err(_) => {
+                let path = self.path(name);
+                self.reject(name, error(&display(&value), &path));
+                None
+            },
+        }
+    }
+
+    // Take the value of a property, if it was provided.
+    pub fn take<T: DeserializeOwned>(&mut self, name: &str) -> Option<T> {
+        self.take_with(name, base::property_value_type_error)
+    }
+
+    // Take the value of an enumerated property, if it was provided.
+    pub fn take_enum<T: DeserializeOwned>(&mut self, name: &str) -> Option<T>
+    {
+        self.take_with(name, base::property_value_not_in_list)
+    }
+
+    // Take the value of a property that must be provided.
+    pub fn require<T: DeserializeOwned>(&mut self, name: &str) -> Option<T> {
+        self.check_present(name);
+        self.take(name)
+    }
+
+    // Take the value of an enumerated property that must be provided.
+    pub fn require_enum<T: DeserializeOwned>(&mut self, name: &str) ->
+        Option<T>
+    {
+        self.check_present(name);
+        self.take_enum(name)
+    }
+
+    fn check_present(&mut self, name: &str) {
+        if !self.properties.contains_key(name) {
+            let path = self.path(name);
+            self.reject(name, base::property_missing(&path));
+        }
+    }
+
+    // Take a nested object, which is patched with the same semantics.
+    pub fn take_object(&mut self, name: &str) -> Option<Patch> {
+        let properties: Map<String, Value> = self.take(name)?;
+        Some(Patch {
+            pointer: format!("{}/", self.path(name)),
+            properties, messages: Vec::new(),
+        })
+    }
+
+    // Report an error with the value of a property.
+    pub fn reject(&mut self, name: &str, message: Message) {
+        let path = self.path(name);
+        self.messages.push(message.related_to(&path));
+    }
+
+    // Fold the result of a nested object back into this one.
+    pub fn merge(&mut self, nested: Patch, read_only: &[&str]) {
+        self.messages.extend(nested.remainder(read_only));
+    }
+
+    fn remainder(mut self, read_only: &[&str]) -> Vec<Message> {
+        for name in self.properties.keys() {
+            if name.starts_with('@') {
+                continue;
+            }
+            let path = self.path(name);
+            let message = match read_only.contains(&name.as_str()) {
+                true => base::property_not_writable(&path),
+                false => base::property_unknown(&path),
+            };
+            self.messages.push(message.related_to(&path));
+        }
+        self.messages
+    }
+
+    // Validate that every property in the request was consumed. Resources
+    // should only apply their changes once this succeeds.
+    pub fn finish(self, read_only: &[&str]) -> Result<(), RedfishError> {
+        let messages = self.remainder(read_only);
+        match messages.is_empty() {
+            true => Ok(()),
+            false => Err(RedfishError::bad_request(messages)),
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Parameters
+////
+
+// The parameters of an action, such as ComputerSystem.Reset.
+pub struct Parameters {
+    action: String,
+    parameters: Patch,
+}
+
+impl Parameters {
+    pub fn new(action: &str, parameters: Map<String, Value>) -> Self {
+        Parameters { action: action.to_string(),
+                     parameters: Patch::new(parameters) }
+    }
+
+    // Take the value of a parameter, if it was provided.
+    pub fn take<T: DeserializeOwned>(&mut self, name: &str) -> Option<T> {
+        let action = &self.action;
+        self.parameters.take_with(name, |value, parameter| {
+            base::action_parameter_value_type_error(value, parameter, action)
+        })
+    }
+
+    // Take the value of an enumerated parameter, if it was provided.
+    pub fn take_enum<T: DeserializeOwned>(&mut self, name: &str) -> Option<T>
+    {
+        let action = &self.action;
+        self.parameters.take_with(name, |value, parameter| {
+            base::action_parameter_value_not_in_list(value, parameter, action)
+        })
+    }
+
+    // Take the value of a parameter that must be provided.
+    pub fn require<T: DeserializeOwned>(&mut self, name: &str) -> Option<T> {
+        if !self.parameters.properties.contains_key(name) {
+            self.reject(name, base::action_parameter_missing(
+                &self.action, name));
+        }
+        self.take(name)
+    }
+
+    // Take the value of an enumerated parameter that must be provided.
+    pub fn require_enum<T: DeserializeOwned>(&mut self, name: &str) ->
+        Option<T>
+    {
+        if !self.parameters.properties.contains_key(name) {
+            self.reject(name, base::action_parameter_missing(
+                &self.action, name));
+        }
+        self.take_enum(name)
+    }
+
+    // Report an error with the value of a parameter.
+    pub fn reject(&mut self, name: &str, message: Message) {
+        self.parameters.reject(name, message);
+    }
+
+    // Validate that every parameter in the request was consumed. Resources
+    // should only perform the action once this succeeds.
+    pub fn finish(self) -> Result<(), RedfishError> {
+        let mut messages = self.parameters.messages;
+        for name in self.parameters.properties.keys() {
+            if !name.starts_with('@') {
+                messages.push(base::action_parameter_unknown(
+                    &self.action, name).related_to(name));
+            }
+        }
+        match messages.is_empty() {
+            true => Ok(()),
+            false => Err(RedfishError::bad_request(messages)),
+        }
+    }
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // Dispatch
 ////
 
-pub trait Dispatch {
-    type Error: Debug;
-    fn dispatch(&self, path: &Path, request: &Request<Body>) ->
-        Result<Option<Response<Body>>, Self::Error>;
+// Actions complete within the request, or return the task that tracks them
+pub type ActionResult = Result<Option<Arc<TaskRecord>>, RedfishError>;
+
+pub trait Dispatch: Send + Sync {
+    type Error: Debug + Send;
+
+    // Handle a request for a resource subordinate to this one.
+    fn dispatch(&self, _path: &Path, _request: Request<Body>) ->
+        impl Future<Output = Result<Response<Body>, Self::Error>> + Send
+    { async { Ok(NotFound.into()) } }
+
+    // The methods supported at this resource's URI
+    fn allowed(&self) -> Vec<Method> { vec![Method::GET] }
+
+    // Update this resource with the properties of a PATCH request
+    fn patch(&self, _patch: Patch) ->
+        impl Future<Output = Result<(), RedfishError>> + Send
+    { async { Err(RedfishError::internal(vec![base::general_error()])) } }
+
+    // Create a member of this collection from the properties of a POST
+    // request, returning the id of the new member.
+    fn create(&self, _properties: Patch) ->
+        impl Future<Output = Result<String, RedfishError>> + Send
+    { async { Err(RedfishError::internal(vec![base::general_error()])) } }
+
+    // Remove this resource in response to a DELETE request
+    fn delete(&self) -> impl Future<Output = Result<(), RedfishError>> + Send
+    { async { Err(RedfishError::internal(vec![base::general_error()])) } }
+
+    // Perform an action, POSTed to <resource>/Actions/<name>
+    fn action(&self, name: &str, _parameters: Parameters) ->
+        impl Future<Output = ActionResult> + Send
+    {
+        let message = base::action_not_supported(name);
+        async { Err(RedfishError::not_found(vec![message])) }
+    }
+}
+
+// Read the JSON object in the body of a request
+async fn read_object(request: Request<Body>, empty: Option<Map<String, Value>>)
+    -> Result<Map<String, Value>, RedfishError>
+{
+    let malformed = || RedfishError::bad_request(vec![base::malformed_json()]);
+    let body = hyper::body::to_bytes(request.into_body()).await
+        .map_err(|_| malformed())?;
+    if body.is_empty() {
+        if let Some(empty) = empty {
+            return Ok(empty);
+        }
+    }
+    match serde_json::from_slice(&body) {
+        Ok(Value::Object(properties)) => Ok(properties),
+        _ => Err(malformed()),
+    }
 }
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -90,34 +446,204 @@ pub trait Dispatch {
 pub struct ODataResource<T>(Resource<T>)
 where T: Serialize + ResourceMetadata + Clone + Dispatch;
 
+impl<T> ODataResource<T>
+where T: Serialize + ResourceMetadata + Clone + Dispatch {
+    // True if the path addresses this resource or one of its subordinates
+    pub fn contains(&self, path: &Path) -> bool {
+        path.starts_with(self.0.get_id())
+    }
+
+    fn link(&self) -> odata::Link {
+        PathBuf::from(self.0.get_id()).into()
+    }
+
+    // The representation of the resource, when there's a bus to report
+    // changes to it on.
+    fn snapshot(&self, bus: Option<&EventBus>) -> Option<Value> {
+        bus.map(|_| serde_json::to_value(&self.0).unwrap())
+    }
+
+    // Changes are only published if the representation of the resource
+    // differs from the snapshot, e.g. a PATCH that writes the current value
+    // of a property doesn't change anything.
+    fn publish_changes(&self, bus: Option<&EventBus>, before: Option<Value>) {
+        if let (Some(bus), Some(before)) = (bus, before) {
+            if serde_json::to_value(&self.0).unwrap() != before {
+                bus.publish(StateChange::resource_changed(
+                    self.link(), T::ODATA_TYPE));
+            }
+        }
+    }
+
+    pub fn to_response(&self) -> Response<Body> {
+        Response::builder()
+            .status(200)
+            .header("Content-Type", "application/json")
+            .body(serde_json::to_string(&self.0).unwrap().into())
+            .unwrap()
+    }
+
+    // The members of a collection may be paged with $skip and $top. A link to
+    // the next page is included while members remain.
+    fn handle_get(&self, request: &Request<Body>) -> Response<Body> {
+        let mut messages = Vec::new();
+        let mut paging = |name: &str| {
+            let value = query_parameter(request, name)?;
+            value.parse::<usize>().map_err(|_| messages.push(
+                base::query_parameter_value_format_error(&value, name))).ok()
+        };
+        let (skip, top) = (paging("$skip"), paging("$top"));
+        if !messages.is_empty() {
+            return RedfishError::bad_request(messages).into();
+        }
+        if skip.is_none() && top.is_none() {
+            return self.to_response();
+        }
+
+        let mut payload = serde_json::to_value(&self.0).unwrap();
+        if let Some(Value::Array(members)) = payload.get_mut("Members") {
+            let (skip, total) = (skip.unwrap_or(0), members.len());
+            *members = members.drain(..)
+                .skip(skip)
+                .take(top.unwrap_or(usize::MAX))
+                .collect();
+            let next = skip.saturating_add(members.len());
+            if let Some(top) = top.filter(|top| *top > 0 && next < total) {
+                payload["Members@odata.nextLink"] = Value::String(format!(
+                    "{}?$skip={}&$top={}",
+                    PathBuf::from(self.0.get_id()).to_string_lossy(), next,
+                    top));
+            }
+        }
+        Response::builder()
+            .status(200)
+            .header("Content-Type", "application/json")
+            .body(payload.to_string().into())
+            .unwrap()
+    }
+
+    async fn handle_patch(&self, request: Request<Body>) -> Response<Body> {
+        let bus = request.extensions().get::<EventBus>().cloned();
+        let properties = match read_object(request, None).await {
+            Ok(properties) => properties,
+            Err(error) => return error.into(),
+        };
+        let before = self.snapshot(bus.as_ref());
+        match self.0.get().patch(Patch::new(properties)).await {
+            Ok(()) => {
+                self.publish_changes(bus.as_ref(), before);
+                self.to_response()
+            },
+            Err(error) => error.into(),
+        }
+    }
+
+    // Respond with the new member, as though it had been requested
+    async fn handle_create(&self, request: Request<Body>) ->
+        Result<Response<Body>, <T as Dispatch>::Error>
+    {
+        let bus = request.extensions().get::<EventBus>().cloned();
+        let properties = match read_object(request, None).await {
+            Ok(properties) => properties,
+            Err(error) => return Ok(error.into()),
+        };
+        let id = match self.0.get().create(Patch::new(properties)).await {
+            Ok(id) => id,
+            Err(error) => return Ok(error.into()),
+        };
+        let location = PathBuf::from(self.0.get_id()).join(id);
+        let request = Request::get(location.to_string_lossy().as_ref())
+            .body(Body::empty()).unwrap();
+        let response = self.0.get().dispatch(&location, request).await?;
+        let (mut parts, body) = response.into_parts();
+        let body = hyper::body::to_bytes(body).await.unwrap_or_default();
+        if let Some(bus) = bus {
+            let member: Value = serde_json::from_slice(&body)
+                .unwrap_or_default();
+            bus.publish(StateChange::resource_created(
+                location.clone().into(), member["@odata.type"].as_str()));
+        }
+        parts.status = StatusCode::CREATED;
+        parts.headers.insert(
+            "Location", location.to_string_lossy().parse().unwrap());
+        Ok(Response::from_parts(parts, body.into()))
+    }
+
+    async fn handle_delete(&self, request: Request<Body>) -> Response<Body> {
+        match self.0.get().delete().await {
+            Ok(()) => {
+                if let Some(bus) = request.extensions().get::<EventBus>() {
+                    bus.publish(StateChange::resource_removed(
+                        self.link(), T::ODATA_TYPE));
+                }
+                no_content()
+            },
+            Err(error) => error.into(),
+        }
+    }
+
+    async fn handle_action(&self, name: &str, request: Request<Body>) ->
+        Response<Body>
+    {
+        if request.method() != Method::POST {
+            return MethodNotAllowed::new(vec![Method::POST]).into();
+        }
+        let bus = request.extensions().get::<EventBus>().cloned();
+        // Actions without parameters may be invoked with an empty body
+        let parameters = match read_object(request, Some(Map::new())).await {
+            Ok(parameters) => parameters,
+            Err(error) => return error.into(),
+        };
+        let parameters = Parameters::new(name, parameters);
+        let before = self.snapshot(bus.as_ref());
+        match self.0.get().action(name, parameters).await {
+            Ok(task) => {
+                self.publish_changes(bus.as_ref(), before);
+                match task {
+                    Some(task) => task_service::accepted(
+                        &Task::resource(task)),
+                    None => no_content(),
+                }
+            },
+            Err(error) => error.into(),
+        }
+    }
+}
+
+fn no_content() -> Response<Body> {
+    Response::builder().status(204).body(Body::empty()).unwrap()
+}
+
 impl<T> Dispatch for ODataResource<T>
 where T: Serialize + ResourceMetadata + Clone + Dispatch {
     type Error = <T as Dispatch>::Error;
-    fn dispatch(&self, path: &Path, request: &Request<Body>) ->
-        Result<Option<Response<Body>>, Self::Error>
+    async fn dispatch(&self, path: &Path, request: Request<Body>) ->
+        Result<Response<Body>, Self::Error>
     {
-        let this_url = self.0.get_id();
-        if this_url.as_ref() == path {
-            match request.method() {
-                &Method::GET => Ok(Some(
-                    Response::builder()
-                        .status(200)
-                        .body(serde_json::to_string(&self.0).unwrap().into())
-                        .unwrap()
-                )),
-
-                _ => Ok(Some(MethodNotAllowed::new(vec![Method::GET]).into())),
+        let id = PathBuf::from(self.0.get_id());
+        if id != path {
+            if path.parent() == Some(&id.join("Actions")) {
+                let name = path.file_name().unwrap().to_string_lossy();
+                return Ok(self.handle_action(&name, request).await);
             }
+            return match self.contains(path) {
+                true => self.0.get().dispatch(path, request).await,
+                false => Ok(NotFound.into()),
+            };
         }
 
-        else if path.starts_with(this_url.as_ref()) {
-            let sub_path = PathBuf::from("/").join(
-                path.strip_prefix(this_url).unwrap());
-            self.0.get().dispatch(&sub_path, request)
-        }
-
-        else {
-            Ok(None)
+        let allowed = self.0.get().allowed();
+        match request.method() {
+            &Method::GET => Ok(self.handle_get(&request)),
+            &Method::PATCH if allowed.contains(&Method::PATCH) =>
+                Ok(self.handle_patch(request).await),
+            &Method::POST if allowed.contains(&Method::POST) =>
+                self.handle_create(request).await,
+            &Method::DELETE if allowed.contains(&Method::DELETE) =>
+                Ok(self.handle_delete(request).await),
+            method if allowed.contains(method) =>
+                self.0.get().dispatch(path, request).await,
+            _ => Ok(MethodNotAllowed::new(allowed).into()),
         }
     }
 }
@@ -149,57 +675,95 @@ where T: Serialize + ResourceMetadata + Clone + Dispatch {
 }
 
 ///////////////////////////////////////////////////////////////////////////////
-// RouteFuture
+// ResourceService
 ////
 
-pub struct RouteFuture<T>
+pub type RouteFuture =
+    Pin<Box<dyn Future<Output = Result<Response<Body>, Infallible>> + Send>>;
+
+// Routes requests into the resource tree. Changes made by requests are
+// published on the bus of the service, and recorded in its audit log.
+#[derive(Clone)]
+pub struct ResourceService<T>
 where T: Serialize + ResourceMetadata + Clone + Dispatch {
-    resource: Arc<ODataResource<T>>,
-    request: Request<Body>,
+    root: Arc<ODataResource<T>>,
+    bus: EventBus,
+    audit: Option<Arc<AuditLog>>,
+
+    // The client of the connection served by this instance, if known
+    remote: Option<SocketAddr>,
 }
 
-impl<T> core::future::Future for RouteFuture<T>
+impl<T> ResourceService<T>
 where T: Serialize + ResourceMetadata + Clone + Dispatch {
-    type Output = Result<Response<Body>, Infallible>;
-    fn poll(self: Pin<&mut Self>, _context: &mut Context<'_>) ->
-        Poll<Self::Output>
-    {
-        let path = PathBuf::from(self.request.uri().path());
-        let result = (&*self.resource).dispatch(&path, &self.request);
-        let response: Response<Body> = match result.unwrap() {
-            Some(response) => response,
-            None => NotFound.into(),
-        };
-        Poll::Ready(Ok(response))
+    pub fn new(root: ODataResource<T>, bus: EventBus) -> Self {
+        ResourceService { root: Arc::new(root), bus, audit: None,
+                          remote: None }
     }
-}
 
-///////////////////////////////////////////////////////////////////////////////
-// ResourceService
-////
+    pub fn with_audit(mut self, audit: Arc<AuditLog>) -> Self {
+        self.audit = Some(audit);
+        self
+    }
 
-#[derive(Clone)]
-pub struct ResourceService<T>(Arc<ODataResource<T>>)
-where T: Serialize + ResourceMetadata + Clone + Dispatch;
+    pub fn bus(&self) -> &EventBus { &self.bus }
+}
 
 impl<T> Service<Request<Body>> for ResourceService<T>
-where T: Serialize + ResourceMetadata + Clone + Dispatch {
+where T: Serialize + ResourceMetadata + Clone + Dispatch + 'static {
     type Response = Response<Body>;
     type Error = Infallible;
-    type Future = RouteFuture<T>;
+    type Future = RouteFuture;
     fn poll_ready(&mut self, _context: &mut Context<'_>) ->
         Poll<Result<(), Self::Error>>
     { Ok(()).into() }
 
-    fn call(&mut self, request: Request<Body>) -> Self::Future {
-        RouteFuture { resource: self.0.clone(), request }
+    fn call(&mut self, mut request: Request<Body>) -> Self::Future {
+        let resource = self.root.clone();
+        let audit = self.audit.clone()
+            .filter(|_| audit::is_audited(request.method()));
+        request.extensions_mut().insert(self.bus.clone());
+        if let Some(remote) = self.remote {
+            request.extensions_mut().insert(RemoteAddress(remote));
+        }
+        Box::pin(async move {
+            let Some(audit) = audit else {
+                return Ok(respond(&resource, request).await);
+            };
+
+            // Audited requests are handled by a task of their own, so that
+            // they're recorded even if the client goes away before the
+            // response is sent.
+            let handled = tokio::spawn(async move {
+                let (request, mut record) = AuditRecord::new(request).await;
+                let response = respond(&resource, request).await;
+                record.status = response.status().as_u16();
+
+                // There's nowhere to report a failure to write the audit log
+                let _ = tokio::task::spawn_blocking(
+                    move || audit.record(&record)).await;
+                response
+            });
+            Ok(handled.await.unwrap_or_else(|_| RedfishError::internal(
+                vec![base::general_error()]).into()))
+        })
+    }
+}
+
+async fn respond<T>(resource: &ODataResource<T>, request: Request<Body>) ->
+    Response<Body>
+where T: Serialize + ResourceMetadata + Clone + Dispatch {
+    let path = PathBuf::from(request.uri().path());
+    match resource.dispatch(&path, request).await {
+        Ok(response) => response,
+        Err(_) => RedfishError::internal(vec![base::general_error()]).into(),
     }
 }
 
 impl<T> From<ODataResource<T>> for ResourceService<T>
 where T: Serialize + ResourceMetadata + Clone + Dispatch {
     fn from(resource: ODataResource<T>) -> Self {
-        ResourceService(Arc::new(resource))
+        ResourceService::new(resource, EventBus::new())
     }
 }
 
@@ -210,7 +774,9 @@ where T: Serialize + ResourceMetadata + Clone + Dispatch {
 pub struct ServiceFactory<T>(ResourceService<T>)
 where T: Serialize + ResourceMetadata + Clone + Dispatch;
 
-impl<R, T> Service<R> for ServiceFactory<T>
+// Each connection is served by its own instance of the service, which knows
+// the address of the client.
+impl<T> Service<&AddrStream> for ServiceFactory<T>
 where T: Serialize + ResourceMetadata + Clone + Dispatch {
     type Response = ResourceService<T>;
     type Error = Infallible;
@@ -219,8 +785,10 @@ where T: Serialize + ResourceMetadata + Clone + Dispatch {
         Poll<Result<(), Self::Error>>
     { Ok(()).into() }
 
-    fn call(&mut self, _: R) -> Self::Future {
-        future::ready(Ok(self.0.clone()))
+    fn call(&mut self, connection: &AddrStream) -> Self::Future {
+        let mut service = self.0.clone();
+        service.remote = Some(connection.remote_addr());
+        future::ready(Ok(service))
     }
 }
 
@@ -0,0 +1,354 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            tasks.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Tracks long-running operations, which are served as Tasks
+//                  of the TaskService.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::collections::VecDeque;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Utc};
+use hyper::StatusCode;
+use serde::Serialize;
+use tokio::task::JoinHandle;
+
+use crate::models::{Health, Message};
+use crate::service::RedfishError;
+
+// The number of tasks that are kept once they've finished. The oldest are
+// discarded first.
+pub const RETAINED_TASKS: usize = 32;
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub enum TaskState {
+    New,
+    Running,
+    Completed,
+    Exception,
+    Cancelled,
+}
+
+impl TaskState {
+    pub fn is_finished(&self) -> bool {
+        !matches!(self, TaskState::New | TaskState::Running)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// TaskRecord
+////
+
+struct Progress {
+    state: TaskState,
+    percent_complete: u8,
+    messages: Vec<Message>,
+    end_time: Option<DateTime<Utc>>,
+
+    // The response to the operation, if it failed
+    error: Option<(StatusCode, Vec<Message>)>,
+    handle: Option<JoinHandle<()>>,
+}
+
+// The state of a task, which is shared with the operation it tracks so that
+// the operation can report its progress.
+pub struct TaskRecord {
+    id: u64,
+    name: String,
+    path: PathBuf,
+    monitor: PathBuf,
+    start_time: DateTime<Utc>,
+    progress: Mutex<Progress>,
+}
+
+impl TaskRecord {
+    pub fn get_id(&self) -> u64 { self.id }
+    pub fn name(&self) -> &str { &self.name }
+    pub fn path(&self) -> &Path { &self.path }
+    pub fn monitor(&self) -> &Path { &self.monitor }
+    pub fn start_time(&self) -> DateTime<Utc> { self.start_time }
+
+    pub fn state(&self) -> TaskState {
+        self.progress.lock().unwrap().state
+    }
+
+    pub fn percent_complete(&self) -> u8 {
+        self.progress.lock().unwrap().percent_complete
+    }
+
+    pub fn messages(&self) -> Vec<Message> {
+        self.progress.lock().unwrap().messages.clone()
+    }
+
+    pub fn end_time(&self) -> Option<DateTime<Utc>> {
+        self.progress.lock().unwrap().end_time
+    }
+
+    // The worst severity of the messages of the task
+    pub fn status(&self) -> Health {
+        let progress = self.progress.lock().unwrap();
+        let health = match progress.state {
+            TaskState::Exception => Health::Critical,
+            TaskState::Cancelled => Health::Warning,
+            _ => Health::OK,
+        };
+        progress.messages.iter()
+            .fold(health, |health, message| {
+                health.worst(message.message_severity)
+            })
+    }
+
+    // The error that the operation failed with, if it did
+    pub fn error(&self) -> Option<RedfishError> {
+        self.progress.lock().unwrap().error.clone()
+            .map(|(status, messages)| RedfishError::new(status, messages))
+    }
+
+    pub fn set_percent_complete(&self, percent: u8) {
+        let mut progress = self.progress.lock().unwrap();
+        if !progress.state.is_finished() {
+            progress.percent_complete = percent.min(100);
+        }
+    }
+
+    pub fn push_message(&self, message: Message) {
+        self.progress.lock().unwrap().messages.push(message);
+    }
+
+    // Stop the operation, if it's still running. Work that the operation
+    // has handed to a blocking thread runs to completion, but its result is
+    // discarded.
+    pub fn cancel(&self) -> bool {
+        let mut progress = self.progress.lock().unwrap();
+        if progress.state.is_finished() {
+            return false;
+        }
+        if let Some(handle) = progress.handle.take() {
+            handle.abort();
+        }
+        progress.state = TaskState::Cancelled;
+        progress.end_time = Some(Utc::now());
+        true
+    }
+
+    fn finish(&self, result: Result<(), RedfishError>) {
+        let mut progress = self.progress.lock().unwrap();
+        if progress.state.is_finished() {
+            return;
+        }
+        progress.handle = None;
+        progress.end_time = Some(Utc::now());
+        match result {
+            Ok(()) => {
+                progress.state = TaskState::Completed;
+                progress.percent_complete = 100;
+            },
+            Err(error) => {
+                progress.state = TaskState::Exception;
+                progress.messages.extend_from_slice(error.messages());
+                progress.error = Some((error.status(),
+                                       error.messages().to_vec()));
+            },
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// TaskManager
+////
+
+struct Tasks {
+    next_id: u64,
+    tasks: VecDeque<Arc<TaskRecord>>,
+}
+
+pub struct TaskManager {
+    // The path of the TaskService, under which tasks and their monitors are
+    // served.
+    path: PathBuf,
+    tasks: Mutex<Tasks>,
+}
+
+impl TaskManager {
+    pub fn new(path: PathBuf) -> Arc<Self> {
+        Arc::new(TaskManager {
+            path,
+            tasks: Mutex::new(Tasks { next_id: 1, tasks: VecDeque::new() }),
+        })
+    }
+
+    pub fn get_path(&self) -> &Path { &self.path }
+
+    pub fn tasks(&self) -> Vec<Arc<TaskRecord>> {
+        self.tasks.lock().unwrap().tasks.iter().cloned().collect()
+    }
+
+    pub fn task(&self, id: u64) -> Option<Arc<TaskRecord>> {
+        self.tasks.lock().unwrap().tasks.iter()
+            .find(|task| task.id == id)
+            .cloned()
+    }
+
+    // Run an operation in the background, tracked by a new task. The
+    // operation is given the task, to report its progress. Must be called
+    // from within the tokio runtime.
+    pub fn spawn<F, R>(&self, name: &str, operation: F) -> Arc<TaskRecord>
+    where F: FnOnce(Arc<TaskRecord>) -> R,
+          R: Future<Output = Result<(), RedfishError>> + Send + 'static,
+    {
+        let task = {
+            let mut tasks = self.tasks.lock().unwrap();
+            let id = tasks.next_id;
+            tasks.next_id += 1;
+            let task = Arc::new(TaskRecord {
+                id,
+                name: name.to_string(),
+                path: self.path.join("Tasks").join(id.to_string()),
+                monitor: self.path.join("TaskMonitors").join(id.to_string()),
+                start_time: Utc::now(),
+                progress: Mutex::new(Progress {
+                    state: TaskState::Running,
+                    percent_complete: 0,
+                    messages: Vec::new(),
+                    end_time: None,
+                    error: None,
+                    handle: None,
+                }),
+            });
+            tasks.tasks.push_back(task.clone());
+            discard_finished(&mut tasks.tasks);
+            task
+        };
+
+        // The handle is stored before the operation can finish, since
+        // finishing takes the same lock.
+        let mut progress = task.progress.lock().unwrap();
+        let operation = operation(task.clone());
+        let record = task.clone();
+        progress.handle = Some(tokio::spawn(async move {
+            let result = operation.await;
+            record.finish(result);
+        }));
+        drop(progress);
+        task
+    }
+}
+
+// Discard the oldest finished tasks, beyond those that are retained
+fn discard_finished(tasks: &mut VecDeque<Arc<TaskRecord>>) {
+    let finished = tasks.iter()
+        .filter(|task| task.state().is_finished())
+        .count();
+    let mut excess = finished.saturating_sub(RETAINED_TASKS);
+    tasks.retain(|task| {
+        let discard = excess > 0 && task.state().is_finished();
+        if discard {
+            excess -= 1;
+        }
+        !discard
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    use crate::registries::base;
+
+    async fn wait(task: &TaskRecord) {
+        while !task.state().is_finished() {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        }
+    }
+
+    #[tokio::test]
+    async fn progress_is_reported() {
+        let tasks = TaskManager::new(PathBuf::from("/redfish/v1/TaskService"));
+        let (sender, receiver) = tokio::sync::oneshot::channel::<()>();
+        let task = tasks.spawn("Update", |task| async move {
+            task.set_percent_complete(50);
+            let _ = receiver.await;
+            Ok(())
+        });
+        assert_eq!(Path::new("/redfish/v1/TaskService/Tasks/1"), task.path());
+        assert_eq!(Path::new("/redfish/v1/TaskService/TaskMonitors/1"),
+                   task.monitor());
+        while task.percent_complete() != 50 {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        }
+        assert_eq!(TaskState::Running, task.state());
+        sender.send(()).unwrap();
+        wait(&task).await;
+        assert_eq!(TaskState::Completed, task.state());
+        assert_eq!(100, task.percent_complete());
+        assert!(task.end_time().is_some());
+        assert!(task.error().is_none());
+    }
+
+    #[tokio::test]
+    async fn failures_and_cancellation() {
+        let tasks = TaskManager::new(PathBuf::from("/redfish/v1/TaskService"));
+        let task = tasks.spawn("Fail", |_| async {
+            Err(RedfishError::internal(vec![base::general_error()]))
+        });
+        wait(&task).await;
+        assert_eq!(TaskState::Exception, task.state());
+        assert_eq!(Health::Critical, task.status());
+        assert_eq!(StatusCode::INTERNAL_SERVER_ERROR,
+                   task.error().unwrap().status());
+        assert!(!task.cancel());
+
+        let task = tasks.spawn("Forever", |_| std::future::pending());
+        assert!(task.cancel());
+        assert_eq!(TaskState::Cancelled, task.state());
+        assert!(!task.cancel());
+        assert_eq!(2, tasks.tasks().len());
+        assert!(tasks.task(2).is_some());
+    }
+
+    #[tokio::test]
+    async fn oldest_finished_tasks_are_discarded() {
+        let tasks = TaskManager::new(PathBuf::from("/redfish/v1/TaskService"));
+        let running = tasks.spawn("Forever", |_| std::future::pending());
+        for _ in 0..RETAINED_TASKS + 1 {
+            let task = tasks.spawn("Done", |_| async { Ok(()) });
+            wait(&task).await;
+        }
+        tasks.spawn("Done", |_| async { Ok(()) });
+        assert!(tasks.task(running.get_id()).is_some());
+        assert!(tasks.task(2).is_none());
+        assert!(tasks.task(3).is_some());
+        running.cancel();
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,235 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            update.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Firmware updates: staging of images, and the Updater trait
+//                  through which they're applied to the components of the
+//                  system.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use hyper::{Body, Client, Request, Uri};
+use hyper::body::HttpBody;
+use tokio::io::AsyncWriteExt;
+
+pub mod image;
+pub mod multipart;
+pub mod slots;
+
+///////////////////////////////////////////////////////////////////////////////
+// Updater
+////
+
+// A component of the system with its own firmware, e.g. the BMC or the BIOS
+#[derive(Clone, Debug, PartialEq)]
+pub struct Component {
+    pub id: String,
+    pub name: String,
+    pub version: String,
+    pub updateable: bool,
+}
+
+#[derive(Debug)]
+pub enum UpdateError {
+    Io(io::Error),
+
+    // The image can't be applied to the component, e.g. because it's for
+    // another component.
+    Rejected(String),
+}
+
+impl fmt::Display for UpdateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UpdateError::Io(error) => write!(f, "{}", error),
+            UpdateError::Rejected(reason) => write!(f, "{}", reason),
+        }
+    }
+}
+
+impl Error for UpdateError {}
+
+impl From<io::Error> for UpdateError {
+    fn from(value: io::Error) -> Self { UpdateError::Io(value) }
+}
+
+// Writes images to the firmware of components. Applying an image blocks
+// until it's complete, so it's called from a blocking thread.
+pub trait Updater: Send + Sync {
+    fn components(&self) -> Vec<Component>;
+
+    // Apply the image to the component with the given id, which is then at
+    // the version named by the image's manifest. Progress is reported as a
+    // percentage.
+    fn apply(&self, target: &str, image: &Path, version: &str,
+             progress: &dyn Fn(u8)) -> Result<(), UpdateError>;
+}
+
+// Updates each component with the first updater that provides it, e.g. the
+// BMC through its slots, and the other components through the platform.
+pub struct Updaters(Vec<Arc<dyn Updater>>);
+
+impl Updaters {
+    pub fn new(updaters: Vec<Arc<dyn Updater>>) -> Self { Updaters(updaters) }
+
+    fn provider(&self, id: &str) -> Option<&Arc<dyn Updater>> {
+        self.0.iter().find(|updater| {
+            updater.components().iter().any(|component| component.id == id)
+        })
+    }
+}
+
+impl Updater for Updaters {
+    fn components(&self) -> Vec<Component> {
+        let mut components: Vec<Component> = Vec::new();
+        for component in self.0.iter().flat_map(|updater| {
+            updater.components()
+        }) {
+            if !components.iter().any(|other| other.id == component.id) {
+                components.push(component);
+            }
+        }
+        components
+    }
+
+    fn apply(&self, target: &str, image: &Path, version: &str,
+             progress: &dyn Fn(u8)) -> Result<(), UpdateError>
+    {
+        match self.provider(target) {
+            Some(updater) => updater.apply(target, image, version, progress),
+            None => Err(UpdateError::Rejected(
+                format!("no component {}", target))),
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Staging
+////
+
+// An image received by the service, which is removed once it's dropped
+pub struct StagedImage {
+    path: PathBuf,
+}
+
+impl StagedImage {
+    pub fn path(&self) -> &Path { &self.path }
+}
+
+impl Drop for StagedImage {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+// Images are written to the staging directory before they're applied
+pub struct Staging {
+    directory: PathBuf,
+    max_size: u64,
+    next_id: AtomicU64,
+}
+
+impl Staging {
+    pub fn new(directory: &Path, max_size: u64) -> Self {
+        Staging {
+            directory: directory.to_owned(), max_size,
+            next_id: AtomicU64::new(1),
+        }
+    }
+
+    // The size of the largest image that's accepted, in bytes
+    pub fn max_size(&self) -> u64 { self.max_size }
+
+    fn next_path(&self) -> PathBuf {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        self.directory.join(format!("image-{}-{}", std::process::id(), id))
+    }
+
+    // Create an empty image, to be written through the returned file
+    pub async fn create(&self) -> io::Result<(StagedImage, tokio::fs::File)> {
+        tokio::fs::create_dir_all(&self.directory).await?;
+        let path = self.next_path();
+        let file = tokio::fs::File::create(&path).await?;
+        Ok((StagedImage { path }, file))
+    }
+
+    // Create an empty image from a blocking thread
+    pub fn create_blocking(&self) -> io::Result<(StagedImage, fs::File)> {
+        fs::create_dir_all(&self.directory)?;
+        let path = self.next_path();
+        let file = fs::File::create(&path)?;
+        Ok((StagedImage { path }, file))
+    }
+}
+
+// Download an image over HTTP into the staging directory. Progress is
+// reported as a percentage, if the server reports the size of the image.
+pub async fn download<F>(staging: &Staging, uri: Uri,
+                         authorization: Option<String>, progress: F)
+                         -> io::Result<StagedImage>
+where F: Fn(u8)
+{
+    let mut request = Request::get(uri);
+    if let Some(authorization) = authorization {
+        request = request.header("Authorization", authorization);
+    }
+    let request = request.body(Body::empty()).map_err(io::Error::other)?;
+    let response = Client::new().request(request).await
+        .map_err(io::Error::other)?;
+    if !response.status().is_success() {
+        return Err(io::Error::other(
+            format!("server responded with {}", response.status())));
+    }
+
+    let size = response.body().size_hint().exact();
+    let mut body = response.into_body();
+    let (image, mut file) = staging.create().await?;
+    let mut received: u64 = 0;
+    while let Some(data) = body.data().await {
+        let data = data.map_err(io::Error::other)?;
+        received += data.len() as u64;
+        if received > staging.max_size {
+            return Err(io::Error::other("image is too large"));
+        }
+        file.write_all(&data).await?;
+        if let Some(size) = size.filter(|size| *size > 0) {
+            progress((received.min(size) * 100 / size) as u8);
+        }
+    }
+    file.flush().await?;
+    Ok(image)
+}
+
+///////////////////////////////////////////////////////////////////////////////
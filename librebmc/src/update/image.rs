@@ -0,0 +1,509 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            image.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Signed firmware images, which carry a manifest naming the
+//                  component and version they contain, and are verified
+//                  against the keys trusted by the BMC before they're applied.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+use openssl::cms::{CMSOptions, CmsContentInfo};
+use openssl::pkey::{Id, PKey, Public};
+use openssl::sha::Sha256;
+use openssl::sign::Verifier as SignatureVerifier;
+use openssl::x509::X509;
+use openssl::x509::store::X509StoreBuilder;
+use serde::{Deserialize, Serialize};
+
+use crate::update::{StagedImage, Staging};
+
+// Signed images are laid out as:
+//   "LBMCIMG1"
+//   the length of the manifest (u32, big endian), and the manifest (JSON)
+//   the type of the signature (u8), its length (u32, big endian), and the
+//   signature of the manifest
+//   the payload, to the end of the file
+// The manifest names the digest of the payload, so the signature covers both.
+const MAGIC: &[u8; 8] = b"LBMCIMG1";
+const MAX_MANIFEST: u32 = 65536;
+const MAX_SIGNATURE: u32 = 65536;
+
+const ED25519: u8 = 1;
+const CMS: u8 = 2;
+
+///////////////////////////////////////////////////////////////////////////////
+// VerificationError
+////
+
+#[derive(Debug)]
+pub enum VerificationError {
+    Io(io::Error),
+
+    // The image isn't laid out as a signed image
+    Malformed,
+
+    // The signature isn't valid, or wasn't made by a trusted key
+    Untrusted,
+
+    // The payload doesn't match the digest in the manifest
+    DigestMismatch,
+}
+
+impl fmt::Display for VerificationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VerificationError::Io(error) => write!(f, "{}", error),
+            VerificationError::Malformed =>
+                write!(f, "image is not a signed firmware image"),
+            VerificationError::Untrusted =>
+                write!(f, "image is not signed by a trusted key"),
+            VerificationError::DigestMismatch =>
+                write!(f, "image does not match the digest in its manifest"),
+        }
+    }
+}
+
+impl Error for VerificationError {}
+
+impl From<io::Error> for VerificationError {
+    fn from(value: io::Error) -> Self { VerificationError::Io(value) }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Manifest
+////
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase", deny_unknown_fields)]
+pub struct Manifest {
+    // The id of the component the image is for, e.g. "BIOS"
+    pub component: String,
+    pub version: String,
+
+    // The SHA-256 digest of the payload, in hexadecimal
+    pub sha256: String,
+}
+
+fn hex(digest: &[u8]) -> String {
+    digest.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+impl Manifest {
+    pub fn new(component: &str, version: &str, payload: &[u8]) -> Self {
+        let mut digest = Sha256::new();
+        digest.update(payload);
+        Manifest {
+            component: component.to_string(),
+            version: version.to_string(),
+            sha256: hex(&digest.finish()),
+        }
+    }
+
+    // The bytes of the manifest that are signed
+    pub fn to_bytes(&self) -> Vec<u8> { serde_json::to_vec(self).unwrap() }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Signature {
+    // A raw Ed25519 signature
+    Ed25519(Vec<u8>),
+
+    // A DER-encoded CMS SignedData structure, detached from its content
+    Cms(Vec<u8>),
+}
+
+// Lay out a signed image, from a manifest and its signature
+pub fn pack(manifest: &[u8], signature: &Signature, payload: &[u8]) -> Vec<u8>
+{
+    let (signature_type, signature) = match signature {
+        Signature::Ed25519(signature) => (ED25519, signature),
+        Signature::Cms(signature) => (CMS, signature),
+    };
+    let mut image = MAGIC.to_vec();
+    image.extend_from_slice(&(manifest.len() as u32).to_be_bytes());
+    image.extend_from_slice(manifest);
+    image.push(signature_type);
+    image.extend_from_slice(&(signature.len() as u32).to_be_bytes());
+    image.extend_from_slice(signature);
+    image.extend_from_slice(payload);
+    image
+}
+
+// Read a length-prefixed field, which must be no longer than the limit
+fn read_field<R: Read>(reader: &mut R, limit: u32) ->
+    Result<Vec<u8>, VerificationError>
+{
+    let mut length = [0; 4];
+    reader.read_exact(&mut length).map_err(|_| VerificationError::Malformed)?;
+    let length = u32::from_be_bytes(length);
+    if length > limit {
+        return Err(VerificationError::Malformed);
+    }
+    let mut field = vec![0; length as usize];
+    reader.read_exact(&mut field).map_err(|_| VerificationError::Malformed)?;
+    Ok(field)
+}
+
+// Split a version into its numeric release, e.g. [1, 10, 0], and the
+// identifiers of its pre-release, if any. Build metadata is ignored.
+fn parse_version<'a>(version: &'a str) ->
+    Option<(Vec<u64>, Option<Vec<&'a str>>)>
+{
+    let version = version.split('+').next()?;
+    let (release, prerelease) = match version.split_once('-') {
+        Some((release, prerelease)) => (release, Some(prerelease)),
+        None => (version, None),
+    };
+    let release = release.split('.')
+        .map(|component| {
+            let digits = component.bytes().all(|c| c.is_ascii_digit());
+            component.parse().ok().filter(|_| digits)
+        })
+        .collect::<Option<Vec<u64>>>()?;
+    let identifiers = |prerelease: &'a str| {
+        let identifiers: Vec<&str> = prerelease.split('.').collect();
+        let valid = identifiers.iter().all(|identifier| {
+            !identifier.is_empty()
+                && identifier.bytes().all(|c| c.is_ascii_alphanumeric())
+        });
+        Some(identifiers).filter(|_| valid)
+    };
+    match prerelease {
+        Some(prerelease) => Some((release, Some(identifiers(prerelease)?))),
+        None => Some((release, None)),
+    }
+}
+
+// Order versions as Semantic Versioning does: by their numeric release,
+// e.g. 1.10.0 after 1.9.2, and then a pre-release, e.g. 1.0.0-rc1, before
+// the release itself. Missing components of a release are zero. None if
+// either version can't be ordered, e.g. 9a.
+pub fn compare_versions(first: &str, second: &str) -> Option<Ordering> {
+    let (first, first_prerelease) = parse_version(first)?;
+    let (second, second_prerelease) = parse_version(second)?;
+    let length = first.len().max(second.len());
+    let component = |release: &[u64], index: usize| {
+        release.get(index).copied().unwrap_or(0)
+    };
+    let ordering = (0..length)
+        .map(|index| component(&first, index).cmp(&component(&second, index)))
+        .find(|ordering| *ordering != Ordering::Equal)
+        .unwrap_or(Ordering::Equal);
+    if ordering != Ordering::Equal {
+        return Some(ordering);
+    }
+
+    // Numeric identifiers of a pre-release are ordered before the others
+    fn identifier(identifier: &str) -> (bool, u64, &str) {
+        match identifier.parse::<u64>() {
+            Ok(number) => (false, number, ""),
+            Err(_) => (true, 0, identifier),
+        }
+    }
+    Some(match (first_prerelease, second_prerelease) {
+        (None, None) => Ordering::Equal,
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(first), Some(second)) => first.into_iter().map(identifier)
+            .cmp(second.into_iter().map(identifier)),
+    })
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// TrustedKeys
+////
+
+// Ed25519 public keys, and X.509 certificates that CMS signatures must chain
+// to.
+#[derive(Clone, Default)]
+pub struct TrustedKeys {
+    keys: Vec<PKey<Public>>,
+    certificates: Vec<X509>,
+}
+
+impl TrustedKeys {
+    // Load every PEM file in the directory. There are no trusted keys if it
+    // doesn't exist, so every image is refused.
+    pub fn load(directory: &Path) -> io::Result<Self> {
+        let mut keys = TrustedKeys::default();
+        let entries = match fs::read_dir(directory) {
+            Ok(entries) => entries,
+            Err(error) if error.kind() == io::ErrorKind::NotFound =>
+                return Ok(keys),
+            Err(error) => return Err(error),
+        };
+        let mut paths = entries
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<io::Result<Vec<_>>>()?;
+        paths.sort();
+        for path in paths.iter().filter(|path| path.is_file()) {
+            keys.add_pem(&fs::read(path)?).map_err(|error| io::Error::new(
+                error.kind(), format!("{}: {}", path.display(), error)))?;
+        }
+        Ok(keys)
+    }
+
+    // Trust the Ed25519 public key or certificates in a PEM document
+    pub fn add_pem(&mut self, pem: &[u8]) -> io::Result<()> {
+        let invalid = |reason: &str| {
+            io::Error::new(io::ErrorKind::InvalidData, reason.to_string())
+        };
+        if pem.windows(11).any(|window| window == b"CERTIFICATE") {
+            let certificates = X509::stack_from_pem(pem)
+                .map_err(|_| invalid("invalid certificate"))?;
+            self.certificates.extend(certificates);
+            return Ok(());
+        }
+        let key = PKey::public_key_from_pem(pem)
+            .map_err(|_| invalid("invalid public key"))?;
+        if key.id() != Id::ED25519 {
+            return Err(invalid("public keys must be Ed25519 keys"));
+        }
+        self.keys.push(key);
+        Ok(())
+    }
+
+    fn verify(&self, manifest: &[u8], signature: &Signature) -> bool {
+        match signature {
+            Signature::Ed25519(signature) => self.keys.iter().any(|key| {
+                SignatureVerifier::new_without_digest(key)
+                    .and_then(|mut verifier| {
+                        verifier.verify_oneshot(signature, manifest)
+                    })
+                    .unwrap_or(false)
+            }),
+            Signature::Cms(signature) => {
+                let Ok(mut cms) = CmsContentInfo::from_der(signature) else {
+                    return false;
+                };
+                let Ok(mut store) = X509StoreBuilder::new() else {
+                    return false;
+                };
+                for certificate in &self.certificates {
+                    if store.add_cert(certificate.clone()).is_err() {
+                        return false;
+                    }
+                }
+                !self.certificates.is_empty() && cms.verify(
+                    None, Some(&store.build()), Some(manifest), None,
+                    CMSOptions::BINARY).is_ok()
+            },
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Verifier
+////
+
+pub struct Verifier {
+    keys: TrustedKeys,
+
+    // Whether an image may contain an older version than is installed
+    allow_downgrade: bool,
+}
+
+impl Verifier {
+    pub fn new(keys: TrustedKeys, allow_downgrade: bool) -> Self {
+        Verifier { keys, allow_downgrade }
+    }
+
+    // Verify the signature of an image, and stage its payload once it
+    // matches the digest in the manifest. Blocks until the whole image has
+    // been read.
+    pub fn verify(&self, image: &Path, staging: &Staging) ->
+        Result<(Manifest, StagedImage), VerificationError>
+    {
+        let mut reader = BufReader::new(File::open(image)?);
+        let mut magic = [0; 8];
+        reader.read_exact(&mut magic)
+            .map_err(|_| VerificationError::Malformed)?;
+        if &magic != MAGIC {
+            return Err(VerificationError::Malformed);
+        }
+        let manifest_bytes = read_field(&mut reader, MAX_MANIFEST)?;
+        let mut signature_type = [0; 1];
+        reader.read_exact(&mut signature_type)
+            .map_err(|_| VerificationError::Malformed)?;
+        let signature = read_field(&mut reader, MAX_SIGNATURE)?;
+        let signature = match signature_type[0] {
+            ED25519 => Signature::Ed25519(signature),
+            CMS => Signature::Cms(signature),
+            _ => return Err(VerificationError::Malformed),
+        };
+
+        // Nothing in the manifest is trusted until the signature is checked
+        if !self.keys.verify(&manifest_bytes, &signature) {
+            return Err(VerificationError::Untrusted);
+        }
+        let manifest: Manifest = serde_json::from_slice(&manifest_bytes)
+            .map_err(|_| VerificationError::Malformed)?;
+
+        let (payload, file) = staging.create_blocking()?;
+        let mut writer = BufWriter::new(file);
+        let mut digest = Sha256::new();
+        let mut buffer = vec![0; 65536];
+        loop {
+            let length = reader.read(&mut buffer)?;
+            if length == 0 {
+                break;
+            }
+            digest.update(&buffer[..length]);
+            writer.write_all(&buffer[..length])?;
+        }
+        writer.flush()?;
+        if !hex(&digest.finish()).eq_ignore_ascii_case(&manifest.sha256) {
+            return Err(VerificationError::DigestMismatch);
+        }
+        Ok((manifest, payload))
+    }
+
+    // True if a component with the installed version may be updated to the
+    // version in the manifest. Versions that can't be ordered are refused,
+    // since the update may be a downgrade.
+    pub fn permits(&self, installed: &str, manifest: &Manifest) -> bool {
+        self.allow_downgrade || compare_versions(
+            &manifest.version, installed)
+            .is_some_and(|ordering| ordering != Ordering::Less)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use openssl::pkey::Private;
+    use openssl::sign::Signer;
+
+    fn signed(key: &PKey<Private>, manifest: &Manifest, payload: &[u8]) ->
+        Vec<u8>
+    {
+        let manifest = manifest.to_bytes();
+        let signature = Signer::new_without_digest(key).unwrap()
+            .sign_oneshot_to_vec(&manifest).unwrap();
+        pack(&manifest, &Signature::Ed25519(signature), payload)
+    }
+
+    fn trusting(key: &PKey<Private>) -> Verifier {
+        let mut keys = TrustedKeys::default();
+        keys.add_pem(&key.public_key_to_pem().unwrap()).unwrap();
+        Verifier::new(keys, false)
+    }
+
+    fn verify(verifier: &Verifier, image: &[u8]) ->
+        Result<(Manifest, Vec<u8>), VerificationError>
+    {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("image");
+        fs::write(&path, image).unwrap();
+        let staging = Staging::new(&directory.path().join("staging"),
+                                   u64::MAX);
+        verifier.verify(&path, &staging).map(|(manifest, payload)| {
+            (manifest, fs::read(payload.path()).unwrap())
+        })
+    }
+
+    #[test]
+    fn versions_are_ordered_numerically() {
+        let compare = |first, second| compare_versions(first, second).unwrap();
+        assert_eq!(Ordering::Greater, compare("1.10.0", "1.9.2"));
+        assert_eq!(Ordering::Equal, compare("2.0.0", "2.0.0"));
+        assert_eq!(Ordering::Equal, compare("2.0", "2.0.0+build.7"));
+        assert_eq!(Ordering::Less, compare("2.0", "2.0.1"));
+        assert_eq!(Ordering::Greater, compare("10", "9"));
+    }
+
+    #[test]
+    fn prereleases_are_ordered_before_their_release() {
+        let compare = |first, second| compare_versions(first, second).unwrap();
+        assert_eq!(Ordering::Less, compare("1.0.0-rc1", "1.0.0-rc2"));
+        assert_eq!(Ordering::Less, compare("1.0.0-rc1", "1.0.0"));
+        assert_eq!(Ordering::Greater, compare("1.0.0", "1.0.0-rc1"));
+        assert_eq!(Ordering::Greater, compare("1.0.1-alpha", "1.0.0"));
+        assert_eq!(Ordering::Less, compare("1.0.0-alpha", "1.0.0-alpha.1"));
+        assert_eq!(Ordering::Less, compare("1.0.0-beta.2", "1.0.0-beta.11"));
+        assert_eq!(Ordering::Less, compare("1.0.0-1", "1.0.0-alpha"));
+    }
+
+    #[test]
+    fn unordered_versions_are_refused() {
+        assert_eq!(None, compare_versions("10", "9a"));
+        assert_eq!(None, compare_versions("1.0.0-", "1.0.0"));
+        assert_eq!(None, compare_versions("1..0", "1.0"));
+        assert_eq!(None, compare_versions("", "1.0"));
+
+        let manifest = |version: &str| Manifest {
+            component: "BIOS".to_string(), version: version.to_string(),
+            sha256: String::new(),
+        };
+        let verifier = Verifier::new(TrustedKeys::default(), false);
+        assert!(verifier.permits("1.0.0-rc1", &manifest("1.0.0")));
+        assert!(!verifier.permits("1.0.0", &manifest("1.0.0-rc1")));
+        assert!(!verifier.permits("9a", &manifest("10")));
+        let verifier = Verifier::new(TrustedKeys::default(), true);
+        assert!(verifier.permits("9a", &manifest("10")));
+    }
+
+    #[test]
+    fn signed_payloads_are_staged() {
+        let key = PKey::generate_ed25519().unwrap();
+        let manifest = Manifest::new("BIOS", "2.0.0", b"payload");
+        let image = signed(&key, &manifest, b"payload");
+        let (verified, payload) = verify(&trusting(&key), &image).unwrap();
+        assert_eq!(manifest, verified);
+        assert_eq!(b"payload".to_vec(), payload);
+
+        let other = PKey::generate_ed25519().unwrap();
+        assert!(matches!(verify(&trusting(&other), &image),
+                         Err(VerificationError::Untrusted)));
+        assert!(matches!(verify(&trusting(&key), b"payload"),
+                         Err(VerificationError::Malformed)));
+        let tampered = signed(&key, &manifest, b"pay1oad");
+        assert!(matches!(verify(&trusting(&key), &tampered),
+                         Err(VerificationError::DigestMismatch)));
+    }
+
+    #[test]
+    fn downgrades_are_refused_unless_allowed() {
+        let manifest = Manifest::new("BMC", "1.2.0", b"");
+        let verifier = Verifier::new(TrustedKeys::default(), false);
+        assert!(verifier.permits("1.2.0", &manifest));
+        assert!(!verifier.permits("1.10.0", &manifest));
+        let verifier = Verifier::new(TrustedKeys::default(), true);
+        assert!(verifier.permits("1.10.0", &manifest));
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,299 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            multipart.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     A streaming reader for multipart/form-data request bodies,
+//                  so that large uploads are never held in memory.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::io;
+
+use hyper::Body;
+use hyper::body::{Bytes, HttpBody};
+
+// The headers of a part are small, and a larger block is malformed
+const MAX_HEADERS: usize = 8192;
+
+fn malformed(reason: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, reason.to_string())
+}
+
+// The boundary parameter of a multipart/form-data Content-Type
+pub fn boundary(content_type: &str) -> Option<String> {
+    let (media_type, parameters) = content_type.split_once(';')?;
+    if !media_type.trim().eq_ignore_ascii_case("multipart/form-data") {
+        return None;
+    }
+    parameters.split(';')
+        .filter_map(|parameter| parameter.split_once('='))
+        .find(|(name, _)| name.trim().eq_ignore_ascii_case("boundary"))
+        .map(|(_, value)| value.trim().trim_matches('"').to_string())
+        .filter(|value| !value.is_empty())
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Part {
+    pub name: String,
+    pub filename: Option<String>,
+}
+
+fn parse_headers(headers: &[u8]) -> io::Result<Part> {
+    let headers = std::str::from_utf8(headers)
+        .map_err(|_| malformed("part headers are not UTF-8"))?;
+    let disposition = headers.split("\r\n")
+        .filter_map(|header| header.split_once(':'))
+        .find(|(name, _)| {
+            name.trim().eq_ignore_ascii_case("Content-Disposition")
+        })
+        .map(|(_, value)| value)
+        .ok_or_else(|| malformed("part has no Content-Disposition"))?;
+    let parameter = |wanted: &str| disposition.split(';').skip(1)
+        .filter_map(|parameter| parameter.split_once('='))
+        .find(|(name, _)| name.trim().eq_ignore_ascii_case(wanted))
+        .map(|(_, value)| value.trim().trim_matches('"').to_string());
+    Ok(Part {
+        name: parameter("name")
+            .ok_or_else(|| malformed("part has no name"))?,
+        filename: parameter("filename"),
+    })
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|window| window == needle)
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Multipart
+////
+
+// Parts are read in order: next_part() returns the headers of a part, and
+// chunk() the data of the current part until it returns None.
+pub struct Multipart {
+    body: Body,
+
+    // Every boundary but the first follows a CRLF, so the preamble is
+    // prefixed with one to treat them all alike.
+    delimiter: Vec<u8>,
+    buffer: Vec<u8>,
+    in_part: bool,
+    finished: bool,
+}
+
+impl Multipart {
+    pub fn new(body: Body, boundary: &str) -> Self {
+        Multipart {
+            body,
+            delimiter: format!("\r\n--{}", boundary).into_bytes(),
+            buffer: b"\r\n".to_vec(),
+            in_part: false,
+            finished: false,
+        }
+    }
+
+    // Read more of the body into the buffer. False at the end of the body.
+    async fn fill(&mut self) -> io::Result<bool> {
+        match self.body.data().await {
+            Some(Ok(data)) => {
+                self.buffer.extend_from_slice(&data);
+                Ok(true)
+            },
+            Some(Err(error)) => Err(io::Error::other(error)),
+            None => Ok(false),
+        }
+    }
+
+    async fn fill_or_fail(&mut self) -> io::Result<()> {
+        match self.fill().await? {
+            true => Ok(()),
+            false => Err(malformed("body ends before the final boundary")),
+        }
+    }
+
+    // The data of the current part, a chunk at a time
+    pub async fn chunk(&mut self) -> io::Result<Option<Bytes>> {
+        if !self.in_part {
+            return Ok(None);
+        }
+        loop {
+            if let Some(end) = find(&self.buffer, &self.delimiter) {
+                self.in_part = end > 0;
+                let rest = self.buffer.split_off(end);
+                let data = std::mem::replace(&mut self.buffer, rest);
+                return Ok(Some(Bytes::from(data)).filter(|_| end > 0));
+            }
+
+            // A delimiter may begin in the last bytes of the buffer
+            let keep = self.delimiter.len() - 1;
+            if self.buffer.len() > keep {
+                let rest = self.buffer.split_off(self.buffer.len() - keep);
+                let data = std::mem::replace(&mut self.buffer, rest);
+                return Ok(Some(Bytes::from(data)));
+            }
+            self.fill_or_fail().await?;
+        }
+    }
+
+    // The next part, skipping whatever remains of the current one
+    pub async fn next_part(&mut self) -> io::Result<Option<Part>> {
+        while self.chunk().await?.is_some() {}
+        if self.finished {
+            return Ok(None);
+        }
+
+        // The preamble is discarded before the first part
+        let start = loop {
+            if let Some(start) = find(&self.buffer, &self.delimiter) {
+                break start;
+            }
+            let keep = self.delimiter.len() - 1;
+            if self.buffer.len() > keep {
+                self.buffer.drain(..self.buffer.len() - keep);
+            }
+            self.fill_or_fail().await?;
+        };
+        self.buffer.drain(..start + self.delimiter.len());
+
+        // The close delimiter is followed by "--", and others by CRLF,
+        // optionally after whitespace.
+        let headers = loop {
+            if self.buffer.starts_with(b"--") {
+                self.finished = true;
+                return Ok(None);
+            }
+            let end = find(&self.buffer, b"\r\n\r\n");
+            if let Some(end) = end {
+                break end;
+            }
+            if self.buffer.len() > MAX_HEADERS {
+                return Err(malformed("part headers are too large"));
+            }
+            self.fill_or_fail().await?;
+        };
+        let line = find(&self.buffer, b"\r\n").unwrap();
+        if self.buffer[..line].iter().any(|byte| !b" \t".contains(byte)) {
+            return Err(malformed("boundary is followed by other text"));
+        }
+        let part = parse_headers(&self.buffer[line + 2..headers + 2])?;
+        self.buffer.drain(..headers + 4);
+        self.in_part = true;
+        Ok(Some(part))
+    }
+
+    // Read the whole of the current part, which must be no larger than the
+    // limit.
+    pub async fn read_to_end(&mut self, limit: usize) -> io::Result<Vec<u8>> {
+        let mut data = Vec::new();
+        while let Some(chunk) = self.chunk().await? {
+            if data.len() + chunk.len() > limit {
+                return Err(malformed("part is too large"));
+            }
+            data.extend_from_slice(&chunk);
+        }
+        Ok(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BODY: &'static str = concat!(
+        "preamble\r\n",
+        "--XyZ\r\n",
+        "Content-Disposition: form-data; name=\"UpdateParameters\"\r\n",
+        "Content-Type: application/json\r\n",
+        "\r\n",
+        "{\"Targets\": []}\r\n",
+        "--XyZ  \r\n",
+        "Content-Disposition: form-data; name=\"UpdateFile\"; ",
+        "filename=\"image.bin\"\r\n",
+        "\r\n",
+        "line one --XyZ\r\nline two\r\n",
+        "--XyZ--\r\n",
+        "epilogue",
+    );
+
+    // Deliver the body a few bytes at a time, so that boundaries are split
+    // across chunks.
+    fn body(size: usize) -> Body {
+        let (mut sender, body) = Body::channel();
+        tokio::spawn(async move {
+            for chunk in BODY.as_bytes().chunks(size) {
+                let chunk = Bytes::copy_from_slice(chunk);
+                if sender.send_data(chunk).await.is_err() {
+                    return;
+                }
+            }
+        });
+        body
+    }
+
+    #[test]
+    fn boundary_is_parsed() {
+        assert_eq!(Some("XyZ".to_string()), boundary(
+            "multipart/form-data; charset=utf-8; boundary=\"XyZ\""));
+        assert_eq!(None, boundary("application/json; boundary=XyZ"));
+        assert_eq!(None, boundary("multipart/form-data"));
+    }
+
+    #[tokio::test]
+    async fn parts_are_read_in_chunks() {
+        for size in [1, 3, 7, BODY.len()] {
+            let mut multipart = Multipart::new(body(size), "XyZ");
+            let part = multipart.next_part().await.unwrap().unwrap();
+            assert_eq!("UpdateParameters", part.name);
+            assert_eq!(None, part.filename);
+            assert_eq!(b"{\"Targets\": []}".to_vec(),
+                       multipart.read_to_end(1024).await.unwrap());
+
+            let part = multipart.next_part().await.unwrap().unwrap();
+            assert_eq!(Some("image.bin".to_string()), part.filename);
+            assert_eq!(b"line one --XyZ\r\nline two".to_vec(),
+                       multipart.read_to_end(1024).await.unwrap());
+            assert!(multipart.next_part().await.unwrap().is_none());
+            assert!(multipart.next_part().await.unwrap().is_none());
+        }
+    }
+
+    #[tokio::test]
+    async fn unread_parts_are_skipped_and_truncation_is_an_error() {
+        let mut multipart = Multipart::new(body(5), "XyZ");
+        multipart.next_part().await.unwrap();
+        let part = multipart.next_part().await.unwrap().unwrap();
+        assert_eq!("UpdateFile", part.name);
+
+        let truncated = &BODY[..BODY.len() - 20];
+        let mut multipart = Multipart::new(Body::from(truncated), "XyZ");
+        multipart.next_part().await.unwrap();
+        let error = multipart.next_part().await.unwrap();
+        assert!(error.is_some());
+        assert!(multipart.read_to_end(1024).await.is_err());
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
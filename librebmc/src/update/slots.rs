@@ -0,0 +1,458 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            slots.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     A/B slots for the librebmc binary. Updates are installed
+//                  into the inactive slot, which is booted on trial and rolled
+//                  back by the supervisor unless it becomes healthy in time.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::env;
+use std::ffi::OsString;
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, ExitStatus};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::update::{Component, UpdateError, Updater};
+
+// The binary in each slot and the version it was installed as, and the
+// file naming the slot that's booted
+const BINARY: &'static str = "librebmc";
+const VERSION: &'static str = "version";
+const STATE: &'static str = "state.json";
+
+// Instances exit with this status to be restarted into the boot slot
+pub const RESTART_STATUS: i32 = 75;
+
+// Set by the supervisor for the instances it runs: the slot they run from,
+// and the file to create once they're healthy.
+pub const SLOT_VARIABLE: &'static str = "LIBREBMC_SLOT";
+pub const HEALTHY_VARIABLE: &'static str = "LIBREBMC_HEALTHY";
+
+// How long an instance waits to restart once an update has been installed,
+// so that the task that installed it can be seen to complete.
+const RESTART_DELAY: Duration = Duration::from_secs(1);
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+///////////////////////////////////////////////////////////////////////////////
+// Slots
+////
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub enum Slot {
+    #[default]
+    A,
+    B,
+}
+
+impl Slot {
+    pub fn other(&self) -> Slot {
+        match self {
+            Slot::A => Slot::B,
+            Slot::B => Slot::A,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Slot::A => "a",
+            Slot::B => "b",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SlotState {
+    pub boot: Slot,
+
+    // The slot that was booted before an update, while the boot slot is on
+    // trial.
+    pub fallback: Option<Slot>,
+}
+
+// The slots are subdirectories a/ and b/ of the root, each holding a
+// complete librebmc binary.
+#[derive(Clone)]
+pub struct Slots {
+    root: PathBuf,
+}
+
+impl Slots {
+    pub fn new(root: &Path) -> Self { Slots { root: root.to_owned() } }
+
+    pub fn binary(&self, slot: Slot) -> PathBuf {
+        self.root.join(slot.name()).join(BINARY)
+    }
+
+    // The version named by the manifest of the image installed in the slot,
+    // unless nothing has been installed there.
+    pub fn version(&self, slot: Slot) -> io::Result<Option<String>> {
+        match fs::read_to_string(self.root.join(slot.name()).join(VERSION)) {
+            Ok(version) => Ok(Some(version)),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+
+    // Slot A is booted until an update has been installed
+    pub fn state(&self) -> io::Result<SlotState> {
+        match fs::read(self.root.join(STATE)) {
+            Ok(contents) => serde_json::from_slice(&contents)
+                .map_err(|error| io::Error::new(
+                    io::ErrorKind::InvalidData, error)),
+            Err(error) if error.kind() == io::ErrorKind::NotFound =>
+                Ok(SlotState::default()),
+            Err(error) => Err(error),
+        }
+    }
+
+    // The state is replaced atomically, so the boot slot is always valid
+    fn set_state(&self, state: &SlotState) -> io::Result<()> {
+        fs::create_dir_all(&self.root)?;
+        let temporary = self.root.join(format!("{}.new", STATE));
+        let mut file = File::create(&temporary)?;
+        file.write_all(&serde_json::to_vec(state).unwrap())?;
+        file.sync_all()?;
+        fs::rename(&temporary, self.root.join(STATE))
+    }
+
+    // Write an image of the version into the slot that isn't booted,
+    // returning the slot. Progress is reported as a percentage.
+    pub fn install(&self, image: &Path, version: &str,
+                   progress: &dyn Fn(u8)) -> io::Result<Slot>
+    {
+        let slot = self.state()?.boot.other();
+        let binary = self.binary(slot);
+        let directory = binary.parent().unwrap();
+        fs::create_dir_all(directory)?;
+
+        // The version is removed first, so that it's never recorded for
+        // another binary.
+        let version_path = directory.join(VERSION);
+        match fs::remove_file(&version_path) {
+            Err(error) if error.kind() != io::ErrorKind::NotFound =>
+                return Err(error),
+            _ => {},
+        }
+        let temporary = binary.with_extension("new");
+        let mut source = File::open(image)?;
+        let size = source.metadata()?.len().max(1);
+        let mut destination = File::create(&temporary)?;
+        let mut buffer = vec![0; 65536];
+        let mut written: u64 = 0;
+        loop {
+            let length = source.read(&mut buffer)?;
+            if length == 0 {
+                break;
+            }
+            destination.write_all(&buffer[..length])?;
+            written += length as u64;
+            progress((written.min(size) * 100 / size) as u8);
+        }
+        destination.set_permissions(fs::Permissions::from_mode(0o755))?;
+        destination.sync_all()?;
+        fs::rename(&temporary, &binary)?;
+
+        let temporary = version_path.with_extension("new");
+        let mut file = File::create(&temporary)?;
+        file.write_all(version.as_bytes())?;
+        file.sync_all()?;
+        fs::rename(&temporary, &version_path)?;
+        Ok(slot)
+    }
+
+    // Boot the slot on trial, falling back to the current boot slot. The
+    // fallback of a slot that's already on trial can't be replaced, since
+    // it's the only slot known to be good.
+    pub fn switch(&self, slot: Slot) -> io::Result<()> {
+        let state = self.state()?;
+        if state.fallback.is_some() || slot == state.boot {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("slot {} can't be booted on trial", slot.name())));
+        }
+        self.set_state(&SlotState {
+            boot: slot, fallback: Some(state.boot),
+        })
+    }
+
+    // End the trial of the boot slot
+    pub fn confirm(&self) -> io::Result<()> {
+        let state = self.state()?;
+        self.set_state(&SlotState { boot: state.boot, fallback: None })
+    }
+
+    // Boot the fallback slot again, if the boot slot is on trial. Returns
+    // the slot that was rolled back to.
+    pub fn roll_back(&self) -> io::Result<Option<Slot>> {
+        let state = self.state()?;
+        let Some(fallback) = state.fallback else {
+            return Ok(None);
+        };
+        self.set_state(&SlotState { boot: fallback, fallback: None })?;
+        Ok(Some(fallback))
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// SlotUpdater
+////
+
+// Updates the BMC by installing librebmc into the inactive slot and
+// restarting into it. The hook is expected to end this instance, e.g. by
+// exiting with RESTART_STATUS.
+pub struct SlotUpdater {
+    slots: Slots,
+    restart: Arc<dyn Fn() + Send + Sync>,
+
+    // Held from the installation of an image until its slot is switched to
+    installing: Mutex<()>,
+}
+
+impl SlotUpdater {
+    pub fn new<F>(slots: Slots, restart: F) -> Self
+    where F: Fn() + Send + Sync + 'static
+    {
+        SlotUpdater {
+            slots, restart: Arc::new(restart), installing: Mutex::new(()),
+        }
+    }
+
+    // The slot that this instance runs from. Instances that aren't
+    // supervised run from the boot slot.
+    fn running(&self) -> io::Result<Slot> {
+        match env::var(SLOT_VARIABLE).as_deref() {
+            Ok("a") => Ok(Slot::A),
+            Ok("b") => Ok(Slot::B),
+            _ => Ok(self.slots.state()?.boot),
+        }
+    }
+
+    // The version installed in the running slot. The factory binary is run
+    // until an update has been installed, so its version is our own.
+    fn version(&self) -> String {
+        self.running()
+            .and_then(|slot| self.slots.version(slot))
+            .ok()
+            .flatten()
+            .unwrap_or_else(|| env!("CARGO_PKG_VERSION").to_string())
+    }
+}
+
+impl Updater for SlotUpdater {
+    fn components(&self) -> Vec<Component> {
+        vec![Component {
+            id: "BMC".to_string(),
+            name: "BMC Firmware".to_string(),
+            version: self.version(),
+            updateable: true,
+        }]
+    }
+
+    fn apply(&self, target: &str, image: &Path, version: &str,
+             progress: &dyn Fn(u8)) -> Result<(), UpdateError>
+    {
+        if target != "BMC" {
+            return Err(UpdateError::Rejected(format!("no component {}",
+                                                     target)));
+        }
+        let Ok(_installing) = self.installing.try_lock() else {
+            return Err(UpdateError::Rejected(
+                "another image is being installed".to_string()));
+        };
+
+        // The inactive slot is the fallback of a slot on trial
+        if self.slots.state()?.fallback.is_some() {
+            return Err(UpdateError::Rejected(
+                "the running image has not been confirmed".to_string()));
+        }
+        let slot = self.slots.install(image, version, progress)?;
+        self.slots.switch(slot)?;
+        let restart = self.restart.clone();
+        thread::spawn(move || {
+            thread::sleep(RESTART_DELAY);
+            restart();
+        });
+        Ok(())
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Supervisor
+////
+
+// Tell the supervisor that this instance is healthy, if it's supervised
+pub fn confirm_healthy() -> io::Result<()> {
+    match env::var_os(HEALTHY_VARIABLE) {
+        Some(path) => File::create(path).map(|_| ()),
+        None => Ok(()),
+    }
+}
+
+// True if this instance was started by a supervisor
+pub fn is_supervised() -> bool { env::var_os(SLOT_VARIABLE).is_some() }
+
+// Runs librebmc from the boot slot, restarting it when it asks to be, and
+// rolling back a slot on trial that doesn't become healthy in time. Until
+// an update has been installed, the boot slot is empty, and the factory
+// binary is run instead.
+pub struct Supervisor {
+    slots: Slots,
+    factory: PathBuf,
+    arguments: Vec<OsString>,
+    timeout: Duration,
+}
+
+impl Supervisor {
+    pub fn new(slots: Slots, factory: PathBuf, arguments: Vec<OsString>,
+               timeout: Duration) -> Self
+    {
+        Supervisor { slots, factory, arguments, timeout }
+    }
+
+    fn spawn(&self, slot: Slot, healthy: &Path) -> io::Result<Child> {
+        let binary = self.slots.binary(slot);
+        let binary = match binary.exists() {
+            true => binary,
+            false => self.factory.clone(),
+        };
+        Command::new(binary)
+            .args(&self.arguments)
+            .env(SLOT_VARIABLE, slot.name())
+            .env(HEALTHY_VARIABLE, healthy)
+            .spawn()
+    }
+
+    // Wait for an instance on trial to become healthy. False if it exited
+    // or timed out first, in which case it's been stopped.
+    fn trial(&self, child: &mut Child, healthy: &Path) -> io::Result<bool> {
+        let deadline = Instant::now() + self.timeout;
+        loop {
+            if healthy.exists() {
+                return Ok(true);
+            }
+            if child.try_wait()?.is_some() {
+                return Ok(false);
+            }
+            if Instant::now() >= deadline {
+                child.kill()?;
+                child.wait()?;
+                return Ok(false);
+            }
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+
+    // Run until an instance exits without asking to be restarted, returning
+    // its status.
+    pub fn run(&self) -> io::Result<ExitStatus> {
+        let healthy = self.slots.root.join("healthy");
+        loop {
+            let state = self.slots.state()?;
+            match fs::remove_file(&healthy) {
+                Err(error) if error.kind() != io::ErrorKind::NotFound =>
+                    return Err(error),
+                _ => {},
+            }
+
+            let child = self.spawn(state.boot, &healthy);
+            if state.fallback.is_some() {
+                let confirmed = match child {
+                    Ok(mut child) => self.trial(&mut child, &healthy)?
+                        .then_some(child),
+                    Err(_) => None,
+                };
+                let Some(mut child) = confirmed else {
+                    let fallback = self.slots.roll_back()?.unwrap();
+                    eprintln!("slot {} did not become healthy, rolling back \
+                               to slot {}", state.boot.name(),
+                              fallback.name());
+                    continue;
+                };
+                self.slots.confirm()?;
+                let status = child.wait()?;
+                if status.code() != Some(RESTART_STATUS) {
+                    return Ok(status);
+                }
+                continue;
+            }
+
+            let status = child?.wait()?;
+            if status.code() != Some(RESTART_STATUS) {
+                return Ok(status);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn updates_are_installed_on_trial() {
+        let root = tempfile::tempdir().unwrap();
+        let slots = Slots::new(root.path());
+        assert_eq!(SlotState::default(), slots.state().unwrap());
+
+        let image = root.path().join("image");
+        fs::write(&image, b"new librebmc").unwrap();
+        let slot = slots.install(&image, "2.0.0", &|_| {}).unwrap();
+        assert_eq!(Slot::B, slot);
+        assert_eq!(b"new librebmc".to_vec(),
+                   fs::read(slots.binary(Slot::B)).unwrap());
+        assert_eq!(Some("2.0.0".to_string()), slots.version(slot).unwrap());
+        assert_eq!(None, slots.version(Slot::A).unwrap());
+
+        slots.switch(slot).unwrap();
+        assert_eq!(SlotState { boot: Slot::B, fallback: Some(Slot::A) },
+                   slots.state().unwrap());
+
+        // The fallback of a slot on trial is never replaced
+        assert!(slots.switch(Slot::A).is_err());
+        assert!(slots.switch(Slot::B).is_err());
+        assert_eq!(Some(Slot::A), slots.roll_back().unwrap());
+        assert_eq!(SlotState::default(), slots.state().unwrap());
+        assert_eq!(None, slots.roll_back().unwrap());
+
+        slots.switch(slot).unwrap();
+        slots.confirm().unwrap();
+        assert_eq!(SlotState { boot: Slot::B, fallback: None },
+                   slots.state().unwrap());
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,242 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            audit_log.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Integration tests for the audit log of mutating requests.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::fs;
+use std::time::Duration;
+
+use hyper::{Body, Method, Request, StatusCode, service::Service};
+use serde_json::{json, Value};
+use tempfile::TempDir;
+use tokio::time;
+
+use librebmc::config::Config;
+use librebmc::models::ServiceRoot;
+use librebmc::service::ResourceService;
+
+mod common;
+
+const AUDIT_LOG: &'static str =
+    "/redfish/v1/Managers/bmc/LogServices/AuditLog";
+const SUBSCRIPTIONS: &'static str = "/redfish/v1/EventService/Subscriptions";
+
+///////////////////////////////////////////////////////////////////////////////
+// Helpers
+////
+
+fn config(directory: &TempDir) -> Config {
+    let mut config = common::config(directory);
+    config.logs.audit_file = Some(directory.path().join("audit.log"));
+    config
+}
+
+async fn request(
+    service: &mut ResourceService<ServiceRoot>, method: Method, uri: &str,
+    authorization: Option<&str>, body: Option<Value>,
+) -> (StatusCode, Value) {
+    let body = match body {
+        Some(body) => Body::from(body.to_string()),
+        None => Body::empty(),
+    };
+    let mut request = Request::builder().method(method).uri(uri)
+        .header("Content-Type", "application/json");
+    if let Some(authorization) = authorization {
+        request = request.header("Authorization", authorization);
+    }
+    let response = service.call(request.body(body).unwrap()).await.unwrap();
+    let status = response.status();
+    let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or(Value::Null))
+}
+
+async fn entries(service: &mut ResourceService<ServiceRoot>) -> Vec<Value> {
+    let (_, collection) = request(
+        service, Method::GET, &format!("{}/Entries", AUDIT_LOG), None, None)
+        .await;
+    collection["Members"].as_array().unwrap().clone()
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Tests
+////
+
+#[tokio::test]
+async fn mutating_requests_are_recorded() {
+    let directory = tempfile::tempdir().unwrap();
+    let mut service = librebmc::service(&config(&directory)).unwrap();
+    let (status, log) = request(&mut service, Method::GET, AUDIT_LOG, None,
+                                None).await;
+    assert_eq!(StatusCode::OK, status);
+    assert_eq!("Event", log["LogEntryType"]);
+    assert!(entries(&mut service).await.is_empty());
+
+    let subscription = json!({
+        "Destination": "http://192.0.2.1/events", "Protocol": "Redfish",
+        "Context": "audit",
+    });
+
+    // The credentials are "operator:hunter2"
+    let (status, created) = request(
+        &mut service, Method::POST, SUBSCRIPTIONS,
+        Some("Basic b3BlcmF0b3I6aHVudGVyMg=="), Some(subscription.clone()))
+        .await;
+    assert_eq!(StatusCode::CREATED, status);
+    let subscription_uri = created["@odata.id"].as_str().unwrap();
+    let (status, _) = request(&mut service, Method::DELETE, subscription_uri,
+                              None, None).await;
+    assert_eq!(StatusCode::NO_CONTENT, status);
+
+    let members = entries(&mut service).await;
+    assert_eq!(2, members.len());
+    assert_eq!("LibreBMCAudit.1.0.0.RequestCompleted",
+               members[0]["MessageId"]);
+    assert_eq!(json!(["operator", "an unknown address", "POST", SUBSCRIPTIONS,
+                      subscription.to_string(), "201"]),
+               members[0]["MessageArgs"]);
+    assert_eq!(json!(["anonymous", "an unknown address", "DELETE",
+                      subscription_uri, "(none)", "204"]),
+               members[1]["MessageArgs"]);
+    assert_eq!("OK", members[1]["Severity"]);
+
+    let lines: Vec<Value> = fs::read_to_string(
+        directory.path().join("audit.log")).unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(2, lines.len());
+    assert_eq!("operator", lines[0]["User"]);
+    assert_eq!(SUBSCRIPTIONS, lines[0]["URI"]);
+    assert_eq!(subscription, lines[0]["Body"]);
+    assert_eq!(204, lines[1]["Status"]);
+}
+
+#[tokio::test]
+async fn credentials_are_redacted() {
+    let directory = tempfile::tempdir().unwrap();
+    let mut service = librebmc::service(&config(&directory)).unwrap();
+    let subscription = json!({
+        "Destination": "http://192.0.2.1/events", "Protocol": "Redfish",
+        "HttpHeaders": [{"Authorization": "Bearer hunter2"}],
+    });
+    let (status, _) = request(&mut service, Method::POST, SUBSCRIPTIONS, None,
+                              Some(subscription)).await;
+
+    let members = entries(&mut service).await;
+    assert_eq!(1, members.len());
+    let arguments = &members[0]["MessageArgs"];
+    assert_eq!(status.as_str(), arguments[5]);
+    let body: Value = serde_json::from_str(arguments[4].as_str().unwrap())
+        .unwrap();
+    assert_eq!(Value::Null, body["HttpHeaders"]);
+    let file = fs::read_to_string(directory.path().join("audit.log")).unwrap();
+    assert!(!file.contains("hunter2"));
+    assert!(!members[0].to_string().contains("hunter2"));
+
+    // Bodies that can't be redacted aren't recorded
+    let (status, _) = request(
+        &mut service, Method::PATCH, AUDIT_LOG, None,
+        Some(json!("Password=hunter2"))).await;
+    assert_eq!(StatusCode::METHOD_NOT_ALLOWED, status);
+    let members = entries(&mut service).await;
+    assert_eq!(2, members.len());
+    assert!(!members[1].to_string().contains("hunter2"));
+}
+
+#[tokio::test]
+async fn audit_log_cannot_be_cleared() {
+    let directory = tempfile::tempdir().unwrap();
+    let mut service = librebmc::service(&config(&directory)).unwrap();
+    let (_, log) = request(&mut service, Method::GET, AUDIT_LOG, None, None)
+        .await;
+    assert_eq!(Value::Null, log["Actions"]);
+
+    let clear = format!("{}/Actions/LogService.ClearLog", AUDIT_LOG);
+    let (status, _) = request(&mut service, Method::POST, &clear, None,
+                              Some(json!({}))).await;
+    assert_eq!(StatusCode::METHOD_NOT_ALLOWED, status);
+    let members = entries(&mut service).await;
+    assert_eq!(1, members.len());
+    assert_eq!("405", members[0]["MessageArgs"][5]);
+}
+
+#[tokio::test]
+async fn requests_are_recorded_when_the_client_goes_away() {
+    let directory = tempfile::tempdir().unwrap();
+    let mut service = librebmc::service(&config(&directory)).unwrap();
+
+    // The client goes away while the body is still being sent
+    let (mut sender, body) = Body::channel();
+    let request = Request::builder().method(Method::POST).uri(SUBSCRIPTIONS)
+        .header("Content-Type", "application/json").body(body).unwrap();
+    let call = service.call(request);
+    assert!(time::timeout(Duration::from_millis(10), call).await.is_err());
+    let subscription = json!({
+        "Destination": "http://192.0.2.1/events", "Protocol": "Redfish",
+    });
+    sender.send_data(subscription.to_string().into()).await.unwrap();
+    drop(sender);
+
+    for _ in 0..100 {
+        if !entries(&mut service).await.is_empty() {
+            break;
+        }
+        time::sleep(Duration::from_millis(10)).await;
+    }
+    let members = entries(&mut service).await;
+    assert_eq!(1, members.len());
+    assert_eq!("201", members[0]["MessageArgs"][5]);
+}
+
+#[tokio::test]
+async fn only_small_json_bodies_are_recorded() {
+    let directory = tempfile::tempdir().unwrap();
+    let mut service = librebmc::service(&config(&directory)).unwrap();
+    let subscription = json!({
+        "Destination": "http://192.0.2.1/events", "Protocol": "Redfish",
+    });
+
+    // Bodies without a Content-Type aren't read
+    let untyped = Request::builder().method(Method::POST).uri(SUBSCRIPTIONS)
+        .body(Body::from(subscription.to_string())).unwrap();
+    service.call(untyped).await.unwrap();
+
+    // Nor are bodies larger than the limit
+    let large = json!({"Destination": "x".repeat(128 * 1024)});
+    request(&mut service, Method::POST, SUBSCRIPTIONS, None, Some(large))
+        .await;
+
+    let members = entries(&mut service).await;
+    assert_eq!(2, members.len());
+    assert!(members.iter()
+            .all(|member| member["MessageArgs"][4] == "(none)"));
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,123 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            boot_override.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Exercises the boot override of the ComputerSystem through
+//                  PATCH requests.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use hyper::{Method, StatusCode};
+use serde_json::{json, Value};
+
+mod common;
+use common::{config, message_ids, request};
+
+const SYSTEM: &'static str = "/redfish/v1/Systems/system";
+
+#[tokio::test]
+async fn pxe_can_be_booted_once() {
+    let directory = tempfile::tempdir().unwrap();
+    let mut service = librebmc::service(&config(&directory)).unwrap();
+    let (status, system) = request(&mut service, Method::GET, SYSTEM, None)
+        .await;
+    assert_eq!(StatusCode::OK, status);
+    assert_eq!("None", system["Boot"]["BootSourceOverrideTarget"]);
+    assert_eq!("Disabled", system["Boot"]["BootSourceOverrideEnabled"]);
+    let targets = &system["Boot"]
+        ["BootSourceOverrideTarget@Redfish.AllowableValues"];
+    assert!(targets.as_array().unwrap().contains(&json!("Pxe")));
+
+    let (status, system) = request(
+        &mut service, Method::PATCH, SYSTEM, Some(json!({
+            "Boot": {
+                "BootSourceOverrideTarget": "Pxe",
+                "BootSourceOverrideEnabled": "Once",
+                "BootSourceOverrideMode": "Legacy",
+            }
+        }))).await;
+    assert_eq!(StatusCode::OK, status);
+    assert_eq!("Pxe", system["Boot"]["BootSourceOverrideTarget"]);
+    assert_eq!("Once", system["Boot"]["BootSourceOverrideEnabled"]);
+    assert_eq!("Legacy", system["Boot"]["BootSourceOverrideMode"]);
+}
+
+#[tokio::test]
+async fn boot_order_can_be_rearranged() {
+    let directory = tempfile::tempdir().unwrap();
+    let mut service = librebmc::service(&config(&directory)).unwrap();
+    let (_, system) = request(&mut service, Method::GET, SYSTEM, None).await;
+    let mut order: Vec<Value> = system["Boot"]["BootOrder"].as_array()
+        .unwrap().clone();
+    order.reverse();
+    let (status, system) = request(
+        &mut service, Method::PATCH, SYSTEM, Some(json!({
+            "Boot": {"BootOrder": order}
+        }))).await;
+    assert_eq!(StatusCode::OK, status);
+    assert_eq!(json!(order), system["Boot"]["BootOrder"]);
+
+    // Options can't be added to the order
+    order.push(json!("Boot0099"));
+    let (status, error) = request(
+        &mut service, Method::PATCH, SYSTEM, Some(json!({
+            "Boot": {"BootOrder": order}
+        }))).await;
+    assert_eq!(StatusCode::BAD_REQUEST, status);
+    assert_eq!(vec!["Base.1.15.0.PropertyValueFormatError"],
+               message_ids(&error));
+}
+
+#[tokio::test]
+async fn invalid_overrides_are_rejected() {
+    let directory = tempfile::tempdir().unwrap();
+    let mut service = librebmc::service(&config(&directory)).unwrap();
+    let (status, error) = request(
+        &mut service, Method::PATCH, SYSTEM, Some(json!({
+            "Boot": {
+                "BootSourceOverrideTarget": "Floppy",
+                "BootSourceOverrideEnabled": "Twice",
+                "BootNext": "Boot0001",
+            },
+            "UUID": "00000000-0000-0000-0000-000000000000",
+        }))).await;
+    assert_eq!(StatusCode::BAD_REQUEST, status);
+    let mut ids = message_ids(&error);
+    ids.sort();
+    assert_eq!(vec![
+        "Base.1.15.0.PropertyNotWritable",
+        "Base.1.15.0.PropertyUnknown",
+        "Base.1.15.0.PropertyValueNotInList",
+        "Base.1.15.0.PropertyValueNotInList",
+    ], ids);
+
+    // Nothing is applied from a request that failed validation
+    let (_, system) = request(&mut service, Method::GET, SYSTEM, None).await;
+    assert_eq!("Disabled", system["Boot"]["BootSourceOverrideEnabled"]);
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,110 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            mod.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Helpers shared by the integration tests.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+// Each test crate uses only some of these
+#![allow(dead_code)]
+
+use std::fs;
+use std::path::Path;
+
+use hyper::{Body, Method, Request, StatusCode, service::Service};
+use serde_json::Value;
+use tempfile::TempDir;
+
+use librebmc::config::Config;
+use librebmc::models::ServiceRoot;
+use librebmc::service::ResourceService;
+
+// The default configuration, with everything that librebmc keeps on disk
+// moved into the directory.
+pub fn config(directory: &TempDir) -> Config {
+    let mut config = Config::default();
+    let directory = directory.path();
+    config.events.subscriptions = directory.join("subscriptions.json");
+    config.logs.directory = directory.join("logs");
+    config.updates.staging = directory.join("updates");
+    config.updates.trusted_keys = directory.join("keys");
+    config.host.settings = directory.join("host.json");
+    config.network.settings = directory.join("protocols.json");
+    config
+}
+
+// A temperature sensor, with its caution threshold at 80C and its critical
+// threshold at 100C.
+pub fn fake_sysfs() -> TempDir {
+    let root = tempfile::tempdir().unwrap();
+    let chip = root.path().join("hwmon0");
+    fs::create_dir_all(&chip).unwrap();
+    for (name, value) in [("name", "coretemp"), ("temp1_input", "45000"),
+                          ("temp1_max", "80000"), ("temp1_crit", "100000")] {
+        fs::write(chip.join(name), format!("{}\n", value)).unwrap();
+    }
+    root
+}
+
+// Replace the reading atomically, so the monitor never reads an empty file
+pub fn set_temperature(root: &Path, millidegrees: u32) {
+    let input = root.join("hwmon0/temp1_input");
+    let temporary = input.with_extension("tmp");
+    fs::write(&temporary, format!("{}\n", millidegrees)).unwrap();
+    fs::rename(&temporary, &input).unwrap();
+}
+
+pub async fn get(service: &mut ResourceService<ServiceRoot>, uri: &str) ->
+    (StatusCode, Value)
+{
+    request(service, Method::GET, uri, None).await
+}
+
+pub async fn request(
+    service: &mut ResourceService<ServiceRoot>, method: Method, uri: &str,
+    body: Option<Value>,
+) -> (StatusCode, Value) {
+    let body = match body {
+        Some(body) => Body::from(body.to_string()),
+        None => Body::empty(),
+    };
+    let request = Request::builder().method(method).uri(uri).body(body)
+        .unwrap();
+    let response = service.call(request).await.unwrap();
+    let status = response.status();
+    let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or(Value::Null))
+}
+
+pub fn message_ids(error: &Value) -> Vec<&str> {
+    error["error"]["@Message.ExtendedInfo"].as_array().unwrap().iter()
+        .map(|message| message["MessageId"].as_str().unwrap())
+        .collect()
+}
+
+///////////////////////////////////////////////////////////////////////////////
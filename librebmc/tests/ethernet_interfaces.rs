@@ -0,0 +1,156 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            ethernet_interfaces.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Exercises the Ethernet interfaces of the Manager and the
+//                  ComputerSystem.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use hyper::{Method, StatusCode};
+use serde_json::{json, Value};
+
+mod common;
+use common::{config, message_ids, request};
+
+const MANAGER: &'static str = "/redfish/v1/Managers/bmc";
+const ETH0: &'static str = "/redfish/v1/Managers/bmc/EthernetInterfaces/eth0";
+const SYSTEM: &'static str = "/redfish/v1/Systems/system";
+
+#[tokio::test]
+async fn manager_interfaces() {
+    let directory = tempfile::tempdir().unwrap();
+    let mut service = librebmc::service(&config(&directory)).unwrap();
+    let (_, manager) = request(&mut service, Method::GET, MANAGER, None)
+        .await;
+    let uri = format!("{}/EthernetInterfaces", MANAGER);
+    assert_eq!(json!({"@odata.id": uri}), manager["EthernetInterfaces"]);
+    let (status, collection) = request(&mut service, Method::GET, &uri, None)
+        .await;
+    assert_eq!(StatusCode::OK, status);
+    assert_eq!(json!([{"@odata.id": ETH0}]), collection["Members"]);
+
+    let (_, interface) = request(&mut service, Method::GET, ETH0, None).await;
+    assert_eq!("02:00:00:00:00:01", interface["MACAddress"]);
+    assert_eq!("LinkUp", interface["LinkStatus"]);
+    assert_eq!(1000, interface["SpeedMbps"]);
+    assert_eq!(json!({"DHCPEnabled": true}), interface["DHCPv4"]);
+    assert_eq!(json!({"OperatingMode": "Stateless"}), interface["DHCPv6"]);
+    assert_eq!(json!([{
+        "Address": "192.168.0.100", "SubnetMask": "255.255.255.0",
+        "AddressOrigin": "DHCP", "Gateway": "192.168.0.1",
+    }]), interface["IPv4Addresses"]);
+}
+
+#[tokio::test]
+async fn static_addresses_can_be_assigned() {
+    let directory = tempfile::tempdir().unwrap();
+    let mut service = librebmc::service(&config(&directory)).unwrap();
+    let (status, interface) = request(
+        &mut service, Method::PATCH, ETH0, Some(json!({
+            "DHCPv4": {"DHCPEnabled": false},
+            "DHCPv6": {"OperatingMode": "Disabled"},
+            "IPv4StaticAddresses": [{
+                "Address": "10.0.0.20", "SubnetMask": "255.255.255.0",
+                "Gateway": "10.0.0.1",
+            }],
+            "IPv6StaticAddresses": [
+                {"Address": "fd00::20", "PrefixLength": 64},
+            ],
+            "StaticNameServers": ["10.0.0.53"],
+        }))).await;
+    assert_eq!(StatusCode::OK, status);
+    assert_eq!(json!({"DHCPEnabled": false}), interface["DHCPv4"]);
+    assert_eq!(json!([{
+        "Address": "10.0.0.20", "SubnetMask": "255.255.255.0",
+        "AddressOrigin": "Static", "Gateway": "10.0.0.1",
+    }]), interface["IPv4Addresses"]);
+    assert_eq!(json!([{"Address": "fd00::20", "PrefixLength": 64}]),
+               interface["IPv6StaticAddresses"]);
+    assert_eq!(json!(["10.0.0.53"]), interface["NameServers"]);
+    let origins: Vec<&Value> = interface["IPv6Addresses"].as_array().unwrap()
+        .iter()
+        .map(|address| &address["AddressOrigin"])
+        .collect();
+    assert_eq!(vec!["LinkLocal", "Static"], origins);
+
+    let (_, interface) = request(&mut service, Method::GET, ETH0, None).await;
+    assert_eq!(json!(["10.0.0.53"]), interface["StaticNameServers"]);
+}
+
+#[tokio::test]
+async fn invalid_settings_are_rejected() {
+    let directory = tempfile::tempdir().unwrap();
+    let mut service = librebmc::service(&config(&directory)).unwrap();
+    let (status, error) = request(
+        &mut service, Method::PATCH, ETH0, Some(json!({
+            "DHCPv4": {"DHCPEnabled": false},
+            "IPv4StaticAddresses": [{
+                "Address": "10.0.0.300", "SubnetMask": "255.0.255.0",
+            }],
+            "IPv6StaticAddresses": [
+                {"Address": "fd00::20", "PrefixLength": 129},
+            ],
+            "MACAddress": "02:00:00:00:00:02",
+        }))).await;
+    assert_eq!(StatusCode::BAD_REQUEST, status);
+    assert_eq!(vec!["Base.1.15.0.PropertyValueFormatError",
+                    "Base.1.15.0.PropertyValueFormatError",
+                    "Base.1.15.0.PropertyValueOutOfRange",
+                    "Base.1.15.0.PropertyNotWritable"],
+               message_ids(&error));
+
+    // Nothing is applied unless the whole request is
+    let (_, interface) = request(&mut service, Method::GET, ETH0, None).await;
+    assert_eq!(json!({"DHCPEnabled": true}), interface["DHCPv4"]);
+}
+
+#[tokio::test]
+async fn host_interfaces_are_read_only() {
+    let directory = tempfile::tempdir().unwrap();
+    let mut service = librebmc::service(&config(&directory)).unwrap();
+    let (_, system) = request(&mut service, Method::GET, SYSTEM, None).await;
+    let uri = format!("{}/EthernetInterfaces", SYSTEM);
+    assert_eq!(json!({"@odata.id": uri}), system["EthernetInterfaces"]);
+    let (_, collection) = request(&mut service, Method::GET, &uri, None)
+        .await;
+    assert_eq!(2, collection["Members@odata.count"]);
+
+    let uri = format!("{}/eno1", uri);
+    let (_, interface) = request(&mut service, Method::GET, &uri, None).await;
+    assert_eq!("LinkUp", interface["LinkStatus"]);
+    assert_eq!("host", interface["HostName"]);
+    assert_eq!("192.168.0.50", interface["IPv4Addresses"][0]["Address"]);
+    assert_eq!(None, interface.get("DHCPv4"));
+
+    let (status, _) = request(
+        &mut service, Method::PATCH, &uri,
+        Some(json!({"DHCPv4": {"DHCPEnabled": false}}))).await;
+    assert_eq!(StatusCode::METHOD_NOT_ALLOWED, status);
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,532 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            event_service.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Exercises event subscriptions and push delivery against a
+//                  local HTTP sink.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use hyper::{Body, Method, Request, Response, Server, StatusCode};
+use hyper::body::HttpBody;
+use hyper::service::{Service, make_service_fn, service_fn};
+use serde_json::{json, Value};
+use tempfile::TempDir;
+use tokio::sync::mpsc;
+
+use librebmc::config::Config;
+use librebmc::models::ServiceRoot;
+use librebmc::service::ResourceService;
+
+mod common;
+
+const SUBSCRIPTIONS: &'static str = "/redfish/v1/EventService/Subscriptions";
+const SUBMIT_TEST_EVENT: &'static str =
+    "/redfish/v1/EventService/Actions/EventService.SubmitTestEvent";
+const RESUME_SUBSCRIPTION: &'static str =
+    "Actions/EventDestination.ResumeSubscription";
+const SERVER_SENT_EVENTS: &'static str = "/redfish/v1/EventService/SSE";
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+///////////////////////////////////////////////////////////////////////////////
+// Sink
+////
+
+// An event receiver, which fails the first few deliveries it receives
+struct Sink {
+    address: SocketAddr,
+    events: mpsc::UnboundedReceiver<Value>,
+}
+
+impl Sink {
+    async fn start(failures: usize) -> Self {
+        let (sender, events) = mpsc::unbounded_channel();
+        let failures = Arc::new(AtomicUsize::new(failures));
+        let make_service = make_service_fn(move |_| {
+            let (sender, failures) = (sender.clone(), failures.clone());
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request: Request<Body>| {
+                    let sender = sender.clone();
+                    let failures = failures.clone();
+                    async move {
+                        let body = hyper::body::to_bytes(request.into_body())
+                            .await.unwrap();
+                        let failed = failures.fetch_update(
+                            Ordering::SeqCst, Ordering::SeqCst,
+                            |failures| failures.checked_sub(1)).is_ok();
+                        let status = match failed {
+                            true => StatusCode::SERVICE_UNAVAILABLE,
+                            false => {
+                                let event = serde_json::from_slice(&body)
+                                    .unwrap();
+                                let _ = sender.send(event);
+                                StatusCode::NO_CONTENT
+                            },
+                        };
+                        Ok::<_, Infallible>(Response::builder().status(status)
+                                            .body(Body::empty()).unwrap())
+                    }
+                }))
+            }
+        });
+        let server = Server::bind(&"127.0.0.1:0".parse().unwrap())
+            .serve(make_service);
+        let address = server.local_addr();
+        tokio::spawn(server);
+        Sink { address, events }
+    }
+
+    fn destination(&self) -> String {
+        format!("http://{}/events", self.address)
+    }
+
+    async fn next(&mut self) -> Value {
+        tokio::time::timeout(TIMEOUT, self.events.recv()).await
+            .expect("no event was delivered").unwrap()
+    }
+
+    async fn nothing_delivered(&mut self) {
+        let next = tokio::time::timeout(
+            Duration::from_millis(200), self.events.recv()).await;
+        assert!(next.is_err(), "unexpected event: {:?}", next);
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Event Stream
+////
+
+// A client of the Server-Sent Events stream
+struct EventStream {
+    body: Body,
+    buffer: String,
+}
+
+impl EventStream {
+    async fn open(service: &mut ResourceService<ServiceRoot>, query: &str,
+                  last_event_id: Option<&str>) -> Self {
+        let mut request = Request::get(
+            format!("{}{}", SERVER_SENT_EVENTS, query));
+        if let Some(last_event_id) = last_event_id {
+            request = request.header("Last-Event-ID", last_event_id);
+        }
+        let response = service.call(request.body(Body::empty()).unwrap())
+            .await.unwrap();
+        assert_eq!(StatusCode::OK, response.status());
+        assert_eq!("text/event-stream", response.headers()["Content-Type"]);
+        EventStream { body: response.into_body(), buffer: String::new() }
+    }
+
+    // Returns the id and data of the next event
+    async fn next(&mut self) -> (String, Value) {
+        loop {
+            if let Some((event, rest)) = self.buffer.split_once("\n\n") {
+                let event = event.to_string();
+                self.buffer = rest.to_string();
+                let field = |name: &str| event.lines()
+                    .find_map(|line| line.strip_prefix(name))
+                    .unwrap().to_string();
+                let data = serde_json::from_str(&field("data: ")).unwrap();
+                return (field("id: "), data);
+            }
+            let chunk = tokio::time::timeout(TIMEOUT, self.body.data()).await
+                .expect("no event was streamed").unwrap().unwrap();
+            self.buffer.push_str(std::str::from_utf8(&chunk).unwrap());
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Helpers
+////
+
+fn config(directory: &TempDir) -> Config {
+    let mut config = common::config(directory);
+    config.events.delivery_retry_attempts = 2;
+    config.events.delivery_retry_interval_ms = 10;
+    config
+}
+
+async fn request(
+    service: &mut ResourceService<ServiceRoot>, method: Method, uri: &str,
+    body: Option<Value>,
+) -> (StatusCode, Option<String>, Value) {
+    let body = match body {
+        Some(body) => Body::from(body.to_string()),
+        None => Body::empty(),
+    };
+    let request = Request::builder().method(method).uri(uri).body(body)
+        .unwrap();
+    let response = service.call(request).await.unwrap();
+    let status = response.status();
+    let location = response.headers().get("Location")
+        .map(|location| location.to_str().unwrap().to_string());
+    let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+    (status, location, serde_json::from_slice(&body).unwrap_or(Value::Null))
+}
+
+// Create a subscription, returning its URI
+async fn subscribe(service: &mut ResourceService<ServiceRoot>,
+                   subscription: Value) -> String {
+    let (status, location, body) = request(
+        service, Method::POST, SUBSCRIPTIONS, Some(subscription)).await;
+    assert_eq!(StatusCode::CREATED, status, "{}", body);
+    let location = location.unwrap();
+    assert_eq!(location, body["@odata.id"]);
+    location
+}
+
+async fn submit(service: &mut ResourceService<ServiceRoot>, event: Value) {
+    let (status, _, body) = request(
+        service, Method::POST, SUBMIT_TEST_EVENT, Some(event)).await;
+    assert_eq!(StatusCode::NO_CONTENT, status, "{}", body);
+}
+
+// Wait for the subscription to reach the expected state
+async fn state_of(service: &mut ResourceService<ServiceRoot>, uri: &str,
+                  expected: StatusCode) -> (StatusCode, Value) {
+    let deadline = Instant::now() + TIMEOUT;
+    loop {
+        let (status, _, body) = request(service, Method::GET, uri, None).await;
+        if status == expected || Instant::now() > deadline {
+            return (status, body);
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+}
+
+fn message_ids(error: &Value) -> Vec<&str> {
+    let mut ids: Vec<&str> = error["error"]["@Message.ExtendedInfo"]
+        .as_array().unwrap().iter()
+        .map(|message| message["MessageId"].as_str().unwrap())
+        .collect();
+    ids.sort();
+    ids
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Tests
+////
+
+#[tokio::test]
+async fn test_event_is_delivered() {
+    let directory = tempfile::tempdir().unwrap();
+    let mut service = librebmc::service(&config(&directory)).unwrap();
+    let mut sink = Sink::start(0).await;
+    let subscription = subscribe(&mut service, json!({
+        "Destination": sink.destination(),
+        "Protocol": "Redfish",
+        "Context": "test-context",
+        "RegistryPrefixes": ["Base"],
+    })).await;
+
+    let (_, _, collection) = request(
+        &mut service, Method::GET, SUBSCRIPTIONS, None).await;
+    assert_eq!(1, collection["Members@odata.count"]);
+    assert_eq!(subscription, collection["Members"][0]["@odata.id"]);
+
+    submit(&mut service, json!({
+        "MessageId": "Base.1.15.0.GeneralError",
+        "Message": "A test event.",
+        "MessageSeverity": "Critical",
+        "OriginOfCondition": "/redfish/v1/Systems/system",
+        "EventId": "999999",
+    })).await;
+    let event = sink.next().await;
+    assert_eq!("#Event.v1_7_0.Event", event["@odata.type"]);
+    assert_eq!("test-context", event["Context"]);
+    let record = &event["Events"][0];
+    assert_eq!("0", record["MemberId"]);
+    assert_eq!(event["Id"], record["EventId"]);
+    // The event id is assigned by the service, not the client
+    assert_ne!("999999", record["EventId"]);
+    assert_eq!("Base.1.15.0.GeneralError", record["MessageId"]);
+    assert_eq!("Critical", record["MessageSeverity"]);
+    assert_eq!("/redfish/v1/Systems/system",
+               record["OriginOfCondition"]["@odata.id"]);
+
+    let (status, _, _) = request(
+        &mut service, Method::POST, SUBMIT_TEST_EVENT, Some(json!({}))).await;
+    assert_eq!(StatusCode::BAD_REQUEST, status);
+}
+
+#[tokio::test]
+async fn delivery_is_retried() {
+    let directory = tempfile::tempdir().unwrap();
+    let mut service = librebmc::service(&config(&directory)).unwrap();
+    let mut sink = Sink::start(2).await;
+    let subscription = subscribe(&mut service, json!({
+        "Destination": sink.destination(), "Protocol": "Redfish",
+        "RegistryPrefixes": ["Base"],
+    })).await;
+    submit(&mut service, json!({"MessageId": "Base.1.15.0.Success"})).await;
+    let event = sink.next().await;
+    assert_eq!("Base.1.15.0.Success", event["Events"][0]["MessageId"]);
+    let (status, _, _) = request(
+        &mut service, Method::GET, &subscription, None).await;
+    assert_eq!(StatusCode::OK, status);
+}
+
+#[tokio::test]
+async fn subscription_is_terminated_after_retries() {
+    let directory = tempfile::tempdir().unwrap();
+    let mut service = librebmc::service(&config(&directory)).unwrap();
+    let mut sink = Sink::start(usize::MAX).await;
+    let subscription = subscribe(&mut service, json!({
+        "Destination": sink.destination(), "Protocol": "Redfish",
+        "DeliveryRetryPolicy": "TerminateAfterRetries",
+    })).await;
+    submit(&mut service, json!({"MessageId": "Base.1.15.0.Success"})).await;
+    let (status, _) = state_of(
+        &mut service, &subscription, StatusCode::NOT_FOUND).await;
+    assert_eq!(StatusCode::NOT_FOUND, status);
+    sink.nothing_delivered().await;
+}
+
+#[tokio::test]
+async fn suspended_subscription_can_be_resumed() {
+    let directory = tempfile::tempdir().unwrap();
+    let mut service = librebmc::service(&config(&directory)).unwrap();
+    // The initial attempt and both retries fail
+    let mut sink = Sink::start(3).await;
+    let subscription = subscribe(&mut service, json!({
+        "Destination": sink.destination(), "Protocol": "Redfish",
+        "DeliveryRetryPolicy": "SuspendRetries",
+        "RegistryPrefixes": ["Base"],
+    })).await;
+    submit(&mut service, json!({"MessageId": "Base.1.15.0.Success"})).await;
+
+    let deadline = Instant::now() + TIMEOUT;
+    let mut destination = Value::Null;
+    while Instant::now() < deadline
+        && destination["Status"]["State"] != "Disabled" {
+        (_, _, destination) = request(
+            &mut service, Method::GET, &subscription, None).await;
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    assert_eq!("Disabled", destination["Status"]["State"]);
+
+    // Events aren't queued while the subscription is suspended
+    submit(&mut service, json!({"MessageId": "Base.1.15.0.Created"})).await;
+    sink.nothing_delivered().await;
+
+    let resume = format!("{}/{}", subscription, RESUME_SUBSCRIPTION);
+    let (status, _, _) = request(&mut service, Method::POST, &resume, None)
+        .await;
+    assert_eq!(StatusCode::NO_CONTENT, status);
+    let (_, _, destination) = request(
+        &mut service, Method::GET, &subscription, None).await;
+    assert_eq!("Enabled", destination["Status"]["State"]);
+    submit(&mut service, json!({"MessageId": "Base.1.15.0.Success"})).await;
+    let event = sink.next().await;
+    assert_eq!("Base.1.15.0.Success", event["Events"][0]["MessageId"]);
+}
+
+#[tokio::test]
+async fn events_are_filtered() {
+    let directory = tempfile::tempdir().unwrap();
+    let mut service = librebmc::service(&config(&directory)).unwrap();
+    let (mut base, mut alerts) = (Sink::start(0).await, Sink::start(0).await);
+    subscribe(&mut service, json!({
+        "Destination": base.destination(), "Protocol": "Redfish",
+        "RegistryPrefixes": ["Base"],
+    })).await;
+    subscribe(&mut service, json!({
+        "Destination": alerts.destination(), "Protocol": "Redfish",
+        "EventTypes": ["Alert"],
+    })).await;
+
+    submit(&mut service, json!({
+        "MessageId": "Base.1.15.0.Success", "EventType": "Other",
+    })).await;
+    submit(&mut service, json!({
+        "MessageId": "Contoso.1.0.Overheat", "EventType": "Alert",
+    })).await;
+    let event = base.next().await;
+    assert_eq!("Base.1.15.0.Success", event["Events"][0]["MessageId"]);
+    assert_eq!("Contoso.1.0.Overheat",
+               alerts.next().await["Events"][0]["MessageId"]);
+    base.nothing_delivered().await;
+    alerts.nothing_delivered().await;
+}
+
+#[tokio::test]
+async fn invalid_subscriptions_are_rejected() {
+    let directory = tempfile::tempdir().unwrap();
+    let mut service = librebmc::service(&config(&directory)).unwrap();
+    let (status, _, error) = request(
+        &mut service, Method::POST, SUBSCRIPTIONS, Some(json!({
+            "Context": "missing",
+        }))).await;
+    assert_eq!(StatusCode::BAD_REQUEST, status);
+    assert_eq!(vec!["Base.1.15.0.PropertyMissing",
+                    "Base.1.15.0.PropertyMissing"], message_ids(&error));
+
+    let (status, _, error) = request(
+        &mut service, Method::POST, SUBSCRIPTIONS, Some(json!({
+            "Destination": "https://192.0.2.1/events",
+            "Protocol": "SNMPv2c",
+            "RegistryPrefixes": ["Contoso"],
+            "Color": "Blue",
+        }))).await;
+    assert_eq!(StatusCode::BAD_REQUEST, status);
+    assert_eq!(vec![
+        "Base.1.15.0.PropertyUnknown",
+        "Base.1.15.0.PropertyValueFormatError",
+        "Base.1.15.0.PropertyValueNotInList",
+        "Base.1.15.0.PropertyValueNotInList",
+    ], message_ids(&error));
+
+    let (_, _, collection) = request(
+        &mut service, Method::GET, SUBSCRIPTIONS, None).await;
+    assert_eq!(0, collection["Members@odata.count"]);
+}
+
+#[tokio::test]
+async fn subscriptions_survive_restart() {
+    let directory = tempfile::tempdir().unwrap();
+    let mut service = librebmc::service(&config(&directory)).unwrap();
+    let subscription = subscribe(&mut service, json!({
+        "Destination": "http://192.0.2.1/events", "Protocol": "Redfish",
+    })).await;
+    let (status, _, destination) = request(
+        &mut service, Method::PATCH, &subscription, Some(json!({
+            "Context": "patched", "DeliveryRetryPolicy": "RetryForever",
+        }))).await;
+    assert_eq!(StatusCode::OK, status);
+    assert_eq!("patched", destination["Context"]);
+    drop(service);
+
+    let mut service = librebmc::service(&config(&directory)).unwrap();
+    let (status, _, destination) = request(
+        &mut service, Method::GET, &subscription, None).await;
+    assert_eq!(StatusCode::OK, status);
+    assert_eq!("patched", destination["Context"]);
+    assert_eq!("RetryForever", destination["DeliveryRetryPolicy"]);
+    let (status, _, _) = request(
+        &mut service, Method::DELETE, &subscription, None).await;
+    assert_eq!(StatusCode::NO_CONTENT, status);
+    drop(service);
+
+    let mut service = librebmc::service(&config(&directory)).unwrap();
+    let (status, _, _) = request(
+        &mut service, Method::GET, &subscription, None).await;
+    assert_eq!(StatusCode::NOT_FOUND, status);
+}
+
+#[tokio::test]
+async fn changes_to_resources_are_published() {
+    let directory = tempfile::tempdir().unwrap();
+    let mut service = librebmc::service(&config(&directory)).unwrap();
+    let mut sink = Sink::start(0).await;
+    let subscription = subscribe(&mut service, json!({
+        "Destination": sink.destination(), "Protocol": "Redfish",
+        "RegistryPrefixes": ["ResourceEvent"],
+    })).await;
+    let event = sink.next().await;
+    let record = &event["Events"][0];
+    assert_eq!("ResourceAdded", record["EventType"]);
+    assert_eq!("ResourceEvent.1.3.0.ResourceCreated", record["MessageId"]);
+    assert_eq!(subscription, record["OriginOfCondition"]["@odata.id"]);
+
+    let fan = "/redfish/v1/Chassis/chassis/ThermalSubsystem/Fans/0";
+    let speed = json!({"Oem": {"Librebmc": {"SpeedControlPercent": 60.0}}});
+    let (status, _, _) = request(
+        &mut service, Method::PATCH, fan, Some(speed.clone())).await;
+    assert_eq!(StatusCode::OK, status);
+    let event = sink.next().await;
+    let record = &event["Events"][0];
+    assert_eq!("ResourceUpdated", record["EventType"]);
+    assert_eq!("ResourceEvent.1.3.0.ResourceChanged", record["MessageId"]);
+    assert_eq!(fan, record["OriginOfCondition"]["@odata.id"]);
+
+    // Writing the current value of a property doesn't change anything
+    let (status, _, _) = request(
+        &mut service, Method::PATCH, fan, Some(speed)).await;
+    assert_eq!(StatusCode::OK, status);
+    sink.nothing_delivered().await;
+}
+
+#[tokio::test]
+async fn events_are_streamed() {
+    let directory = tempfile::tempdir().unwrap();
+    let mut service = librebmc::service(&config(&directory)).unwrap();
+    let (_, _, event_service) = request(
+        &mut service, Method::GET, "/redfish/v1/EventService", None).await;
+    assert_eq!(SERVER_SENT_EVENTS, event_service["ServerSentEventUri"]);
+
+    let filter = "?$filter=MessageId%20eq%20%27Base.1.15.0.Success%27\
+                  %20or%20EventType%20eq%20%27Alert%27";
+    let mut stream = EventStream::open(&mut service, filter, None).await;
+    submit(&mut service, json!({"MessageId": "Base.1.15.0.Created"})).await;
+    submit(&mut service, json!({"MessageId": "Base.1.15.0.Success"})).await;
+    submit(&mut service, json!({
+        "MessageId": "Contoso.1.0.Overheat", "EventType": "Alert",
+    })).await;
+
+    let (id, event) = stream.next().await;
+    assert_eq!(id, event["Id"]);
+    assert_eq!("Base.1.15.0.Success", event["Events"][0]["MessageId"]);
+    let (_, event) = stream.next().await;
+    assert_eq!("Contoso.1.0.Overheat", event["Events"][0]["MessageId"]);
+
+    let (status, _, error) = request(
+        &mut service, Method::GET,
+        &format!("{}?$filter=Color%20eq%20%27Blue%27", SERVER_SENT_EVENTS),
+        None).await;
+    assert_eq!(StatusCode::BAD_REQUEST, status);
+    assert_eq!(vec!["Base.1.15.0.QueryParameterValueFormatError"],
+               message_ids(&error));
+}
+
+#[tokio::test]
+async fn stream_resumes_after_last_event_id() {
+    let directory = tempfile::tempdir().unwrap();
+    let mut service = librebmc::service(&config(&directory)).unwrap();
+    let mut stream = EventStream::open(&mut service, "", None).await;
+    submit(&mut service, json!({"MessageId": "Base.1.15.0.Created"})).await;
+    let (last_event_id, _) = stream.next().await;
+    drop(stream);
+
+    // Events published while the client was disconnected are replayed
+    submit(&mut service, json!({"MessageId": "Base.1.15.0.Success"})).await;
+    let mut stream = EventStream::open(
+        &mut service, "", Some(&last_event_id)).await;
+    submit(&mut service, json!({"MessageId": "Base.1.15.0.Accepted"})).await;
+    let (_, event) = stream.next().await;
+    assert_eq!("Base.1.15.0.Success", event["Events"][0]["MessageId"]);
+    let (_, event) = stream.next().await;
+    assert_eq!("Base.1.15.0.Accepted", event["Events"][0]["MessageId"]);
+}
+
+///////////////////////////////////////////////////////////////////////////////
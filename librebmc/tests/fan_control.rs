@@ -0,0 +1,117 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            fan_control.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Exercises fan control through PATCH requests to the Fan
+//                  resource.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use hyper::{Body, Method, Request, StatusCode, service::Service};
+use serde_json::json;
+
+mod common;
+use common::{config, message_ids, request};
+
+const FAN: &'static str =
+    "/redfish/v1/Chassis/chassis/ThermalSubsystem/Fans/0";
+
+#[tokio::test]
+async fn manual_speed_overrides_automatic_control() {
+    let directory = tempfile::tempdir().unwrap();
+    let mut service = librebmc::service(&config(&directory)).unwrap();
+    let (status, fan) = request(&mut service, Method::GET, FAN, None).await;
+    assert_eq!(StatusCode::OK, status);
+    assert_eq!("Automatic", fan["Oem"]["Librebmc"]["FanMode"]);
+
+    let (status, fan) = request(&mut service, Method::PATCH, FAN, Some(json!({
+        "Oem": {"Librebmc": {"SpeedControlPercent": 75.0}}
+    }))).await;
+    assert_eq!(StatusCode::OK, status);
+    assert_eq!("Manual", fan["Oem"]["Librebmc"]["FanMode"]);
+    assert_eq!(75.0, fan["Oem"]["Librebmc"]["SpeedControlPercent"]);
+    assert_eq!(75.0, fan["SpeedPercent"]["Reading"]);
+
+    let (status, fan) = request(&mut service, Method::PATCH, FAN, Some(json!({
+        "Oem": {"Librebmc": {"FanMode": "Automatic"}}
+    }))).await;
+    assert_eq!(StatusCode::OK, status);
+    assert_eq!("Automatic", fan["Oem"]["Librebmc"]["FanMode"]);
+    assert!(fan["Oem"]["Librebmc"].get("SpeedControlPercent").is_none());
+}
+
+#[tokio::test]
+async fn invalid_requests_are_rejected() {
+    let directory = tempfile::tempdir().unwrap();
+    let mut service = librebmc::service(&config(&directory)).unwrap();
+    let patch = json!({
+        "Name": "Renamed",
+        "Color": "Blue",
+        "Oem": {"Librebmc": {"FanMode": "Turbo", "SpeedControlPercent": 101}},
+    });
+    let (status, error) = request(&mut service, Method::PATCH, FAN,
+                                  Some(patch)).await;
+    assert_eq!(StatusCode::BAD_REQUEST, status);
+    let mut ids = message_ids(&error);
+    ids.sort();
+    assert_eq!(vec![
+        "Base.1.15.0.PropertyNotWritable",
+        "Base.1.15.0.PropertyUnknown",
+        "Base.1.15.0.PropertyValueNotInList",
+        "Base.1.15.0.PropertyValueOutOfRange",
+    ], ids);
+
+    // Nothing is applied from a request that failed validation
+    let (_, fan) = request(&mut service, Method::GET, FAN, None).await;
+    assert_eq!("Automatic", fan["Oem"]["Librebmc"]["FanMode"]);
+
+    let patch = json!({
+        "Oem": {"Librebmc": {
+            "FanMode": "Automatic", "SpeedControlPercent": 50,
+        }},
+    });
+    let (status, error) = request(&mut service, Method::PATCH, FAN,
+                                  Some(patch)).await;
+    assert_eq!(StatusCode::BAD_REQUEST, status);
+    assert_eq!(vec!["Base.1.15.0.PropertyValueConflict"], message_ids(&error));
+
+    let request = Request::patch(FAN).body(Body::from("{")).unwrap();
+    let response = service.call(request).await.unwrap();
+    assert_eq!(StatusCode::BAD_REQUEST, response.status());
+}
+
+#[tokio::test]
+async fn read_only_resources_reject_patch() {
+    let directory = tempfile::tempdir().unwrap();
+    let mut service = librebmc::service(&config(&directory)).unwrap();
+    let (status, _) = request(
+        &mut service, Method::PATCH,
+        "/redfish/v1/Chassis/chassis/ThermalSubsystem", Some(json!({}))).await;
+    assert_eq!(StatusCode::METHOD_NOT_ALLOWED, status);
+}
+
+///////////////////////////////////////////////////////////////////////////////
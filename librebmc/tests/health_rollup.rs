@@ -0,0 +1,93 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            health_rollup.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Verifies that the health of a sensor is rolled up into the
+//                  resources that contain it.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::time::{Duration, Instant};
+
+use serde_json::Value;
+
+use librebmc::config::{Config, SensorConfig, SensorProviderConfig};
+use librebmc::models::ServiceRoot;
+use librebmc::service::ResourceService;
+
+mod common;
+use common::{config, fake_sysfs, get, set_temperature};
+
+const SYSTEM: &'static str = "/redfish/v1/Systems/system";
+const CHASSIS: &'static str = "/redfish/v1/Chassis/chassis";
+const THERMAL: &'static str = "/redfish/v1/Chassis/chassis/ThermalSubsystem";
+
+// Wait for the monitor to observe a change in the hardware
+async fn rollup_of(service: &mut ResourceService<ServiceRoot>, uri: &str,
+                   expected: &str) -> Value {
+    let deadline = Instant::now() + Duration::from_secs(5);
+    loop {
+        let (_, resource) = get(service, uri).await;
+        if resource["Status"]["HealthRollup"] == expected
+            || Instant::now() > deadline {
+            return resource["Status"].clone();
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+}
+
+#[tokio::test]
+async fn critical_sensor_is_reflected_in_system() {
+    let sysfs = fake_sysfs();
+    let directory = tempfile::tempdir().unwrap();
+    let config = Config {
+        sensors: SensorConfig {
+            provider: SensorProviderConfig::Hwmon {
+                root: sysfs.path().to_path_buf(),
+            },
+            poll_interval_ms: 10,
+        },
+        ..config(&directory)
+    };
+    let mut service = librebmc::service(&config).unwrap();
+    let status = get(&mut service, SYSTEM).await.1["Status"].clone();
+    assert_eq!("OK", status["Health"]);
+    assert_eq!("OK", status["HealthRollup"]);
+
+    set_temperature(sysfs.path(), 105000);
+    for uri in [THERMAL, CHASSIS, SYSTEM] {
+        let status = rollup_of(&mut service, uri, "Critical").await;
+        assert_eq!("Critical", status["HealthRollup"], "{}", uri);
+        assert_eq!("OK", status["Health"], "{}", uri);
+    }
+
+    set_temperature(sysfs.path(), 45000);
+    let status = rollup_of(&mut service, SYSTEM, "OK").await;
+    assert_eq!("OK", status["HealthRollup"]);
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,262 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            log_service.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Exercises the LogServices of the system and the BMC:
+//                  entries recorded from the event bus, paging, ClearLog and
+//                  persistence.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::time::{Duration, Instant};
+
+use hyper::{Method, StatusCode};
+use serde_json::{json, Value};
+use tempfile::TempDir;
+
+use librebmc::config::{Config, JournalConfig, SensorConfig,
+                       SensorProviderConfig};
+use librebmc::models::ServiceRoot;
+use librebmc::service::ResourceService;
+
+mod common;
+use common::{fake_sysfs, request, set_temperature};
+
+const SEL: &'static str = "/redfish/v1/Systems/system/LogServices/SEL";
+const EVENT_LOG: &'static str =
+    "/redfish/v1/Managers/bmc/LogServices/EventLog";
+const JOURNAL: &'static str = "/redfish/v1/Managers/bmc/LogServices/Journal";
+const SUBSCRIPTIONS: &'static str = "/redfish/v1/EventService/Subscriptions";
+
+///////////////////////////////////////////////////////////////////////////////
+// Helpers
+////
+
+fn config(directory: &TempDir, sysfs: Option<&TempDir>) -> Config {
+    let mut config = common::config(directory);
+    if let Some(sysfs) = sysfs {
+        config.sensors = SensorConfig {
+            provider: SensorProviderConfig::Hwmon {
+                root: sysfs.path().to_path_buf(),
+            },
+            poll_interval_ms: 10,
+        };
+    }
+    config
+}
+
+// Entries are written in the background, so wait for them to appear
+async fn entries(service: &mut ResourceService<ServiceRoot>, log: &str,
+                 count: usize) -> Vec<Value> {
+    let uri = format!("{}/Entries", log);
+    let deadline = Instant::now() + Duration::from_secs(5);
+    loop {
+        let (_, collection) = request(service, Method::GET, &uri, None).await;
+        let members = collection["Members"].as_array().unwrap().clone();
+        if members.len() >= count || Instant::now() > deadline {
+            return members;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+}
+
+async fn subscribe(service: &mut ResourceService<ServiceRoot>) {
+    let subscription = json!({
+        "Destination": "http://192.0.2.1/events", "Protocol": "Redfish",
+        "RegistryPrefixes": ["Base"],
+    });
+    let (status, _) = request(
+        service, Method::POST, SUBSCRIPTIONS, Some(subscription)).await;
+    assert_eq!(StatusCode::CREATED, status);
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Tests
+////
+
+#[tokio::test]
+async fn threshold_crossings_are_recorded_in_the_sel() {
+    let (directory, sysfs) = (tempfile::tempdir().unwrap(), fake_sysfs());
+    let mut service = librebmc::service(&config(&directory, Some(&sysfs)))
+        .unwrap();
+    let (status, log) = request(&mut service, Method::GET, SEL, None).await;
+    assert_eq!(StatusCode::OK, status);
+    assert_eq!("Event", log["LogEntryType"]);
+    assert_eq!(1000, log["MaxNumberOfRecords"]);
+    assert_eq!("WrapsWhenFull", log["OverWritePolicy"]);
+
+    set_temperature(sysfs.path(), 105000);
+    let members = entries(&mut service, SEL, 2).await;
+    let ids: Vec<&Value> = members.iter()
+        .map(|entry| &entry["MessageId"])
+        .collect();
+    assert_eq!(vec!["ResourceEvent.1.3.0.ResourceWarningThresholdExceeded",
+                    "ResourceEvent.1.3.0.ResourceErrorThresholdExceeded"],
+               ids);
+    let entry = &members[1];
+    assert_eq!("Critical", entry["Severity"]);
+    assert_eq!(json!(["Reading", "100"]), entry["MessageArgs"]);
+    let origin = entry["Links"]["OriginOfCondition"]["@odata.id"].as_str()
+        .unwrap();
+    assert!(origin.starts_with("/redfish/v1/Chassis/chassis/Sensors/"));
+
+    let (status, fetched) = request(
+        &mut service, Method::GET, entry["@odata.id"].as_str().unwrap(),
+        None).await;
+    assert_eq!(StatusCode::OK, status);
+    assert_eq!(entry, &fetched);
+
+    // Events from outside of the host aren't recorded in its SEL
+    assert!(entries(&mut service, EVENT_LOG, 0).await.is_empty());
+}
+
+#[tokio::test]
+async fn entries_are_paged_cleared_and_persisted() {
+    let directory = tempfile::tempdir().unwrap();
+    let mut service = librebmc::service(&config(&directory, None)).unwrap();
+    for _ in 0..3 {
+        subscribe(&mut service).await;
+    }
+    let members = entries(&mut service, EVENT_LOG, 3).await;
+    assert_eq!(3, members.len());
+    assert_eq!("ResourceEvent.1.3.0.ResourceCreated", members[0]["MessageId"]);
+
+    let uri = format!("{}/Entries", EVENT_LOG);
+    let (_, page) = request(
+        &mut service, Method::GET, &format!("{}?$top=2", uri), None).await;
+    assert_eq!(3, page["Members@odata.count"]);
+    assert_eq!(&members[..2], page["Members"].as_array().unwrap());
+    let next = page["Members@odata.nextLink"].as_str().unwrap().to_string();
+    assert_eq!(format!("{}?$skip=2&$top=2", uri), next);
+    let (_, page) = request(&mut service, Method::GET, &next, None).await;
+    assert_eq!(&members[2..], page["Members"].as_array().unwrap());
+    assert!(page.get("Members@odata.nextLink").is_none());
+    let (status, _) = request(
+        &mut service, Method::GET, &format!("{}?$top=two", uri), None).await;
+    assert_eq!(StatusCode::BAD_REQUEST, status);
+    drop(service);
+
+    let mut service = librebmc::service(&config(&directory, None)).unwrap();
+    assert_eq!(members, entries(&mut service, EVENT_LOG, 3).await);
+    let (status, _) = request(
+        &mut service, Method::POST,
+        &format!("{}/Actions/LogService.ClearLog", EVENT_LOG), None).await;
+    assert_eq!(StatusCode::NO_CONTENT, status);
+    assert!(entries(&mut service, EVENT_LOG, 0).await.is_empty());
+
+    // Ids aren't reused once the log has been cleared
+    subscribe(&mut service).await;
+    let members = entries(&mut service, EVENT_LOG, 1).await;
+    assert_eq!("4", members[0]["Id"]);
+}
+
+#[tokio::test]
+async fn journal_is_paged_by_cursor() {
+    let directory = tempfile::tempdir().unwrap();
+    let messages = directory.path().join("messages");
+    let lines: String = (0..150)
+        .map(|line| format!(
+            "<{}>1 2026-10-18T14:00:{:02}Z bmc librebmc - - - line {}\n",
+            [30, 28, 27, 26][line % 4], line % 60, line))
+        .collect();
+    fs::write(&messages, lines).unwrap();
+    let mut config = config(&directory, None);
+    config.logs.journal = JournalConfig::Syslog { path: messages };
+    let mut service = librebmc::service(&config).unwrap();
+
+    let (status, log) = request(&mut service, Method::GET, JOURNAL, None)
+        .await;
+    assert_eq!(StatusCode::OK, status);
+    assert_eq!("Event", log["LogEntryType"]);
+    assert!(log.get("MaxNumberOfRecords").is_none());
+    assert!(log.get("Actions").is_none());
+    let (status, _) = request(
+        &mut service, Method::POST,
+        &format!("{}/Actions/LogService.ClearLog", JOURNAL), None).await;
+    assert_eq!(StatusCode::NOT_FOUND, status);
+
+    let uri = format!("{}/Entries", JOURNAL);
+    let (_, page) = request(&mut service, Method::GET, &uri, None).await;
+    assert_eq!(150, page["Members@odata.count"]);
+    let first = page["Members"].as_array().unwrap().clone();
+    assert_eq!(100, first.len());
+    let severities: Vec<&Value> = first[..4].iter()
+        .map(|entry| &entry["Severity"])
+        .collect();
+    assert_eq!(vec!["OK", "Warning", "Warning", "Critical"], severities);
+    assert_eq!("bmc librebmc - - - line 0", first[0]["Message"]);
+    assert_eq!("2026-10-18T14:00:01Z", first[1]["Created"]);
+
+    let next = page["Members@odata.nextLink"].as_str().unwrap().to_string();
+    let (_, page) = request(&mut service, Method::GET, &next, None).await;
+    let rest = page["Members"].as_array().unwrap();
+    assert_eq!(50, rest.len());
+    assert_eq!("bmc librebmc - - - line 100", rest[0]["Message"]);
+    assert!(page.get("Members@odata.nextLink").is_none());
+
+    let entry = &rest[49];
+    let (status, fetched) = request(
+        &mut service, Method::GET, entry["@odata.id"].as_str().unwrap(),
+        None).await;
+    assert_eq!(StatusCode::OK, status);
+    assert_eq!(entry, &fetched);
+    let (status, _) = request(
+        &mut service, Method::GET, &format!("{}/1", uri), None).await;
+    assert_eq!(StatusCode::NOT_FOUND, status);
+}
+
+#[tokio::test]
+async fn journald_is_paged_without_a_count() {
+    // Stands in for journalctl, printing the same entries whatever it's asked
+    let directory = tempfile::tempdir().unwrap();
+    let journalctl = directory.path().join("journalctl");
+    let entries: String = (0..150)
+        .map(|entry| format!(
+            "{{\"__CURSOR\": \"i={}\", \"__REALTIME_TIMESTAMP\": \"0\", \
+             \"MESSAGE\": \"entry {}\"}}\n", entry, entry))
+        .collect();
+    fs::write(&journalctl, format!("#!/bin/sh\ncat <<'EOF'\n{}EOF\n",
+                                   entries)).unwrap();
+    fs::set_permissions(&journalctl, fs::Permissions::from_mode(0o755))
+        .unwrap();
+    let mut config = config(&directory, None);
+    config.logs.journal = JournalConfig::Journald { command: journalctl };
+    let mut service = librebmc::service(&config).unwrap();
+
+    let uri = format!("{}/Entries", JOURNAL);
+    let (status, page) = request(&mut service, Method::GET, &uri, None).await;
+    assert_eq!(StatusCode::OK, status);
+    assert!(page.get("Members@odata.count").is_none());
+    assert_eq!(100, page["Members"].as_array().unwrap().len());
+    assert_eq!("entry 0", page["Members"][0]["Message"]);
+    assert!(page["Members@odata.nextLink"].as_str().unwrap()
+            .contains("$skiptoken=i%3D99"));
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,196 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            network_protocol.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Tests of the ManagerNetworkProtocol, and of the listeners
+//                  it controls.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::net::{Ipv4Addr, TcpListener};
+use std::time::{Duration, Instant};
+
+use hyper::{Body, Client, Method, Request, StatusCode};
+use serde_json::{json, Value};
+use tokio::net::UdpSocket;
+
+use librebmc::config::ProtocolConfig;
+
+mod common;
+use common::{config, message_ids, request};
+
+const NETWORK_PROTOCOL: &'static str =
+    "/redfish/v1/Managers/bmc/NetworkProtocol";
+
+// The same request, made to a listener
+async fn request_port(port: u16, method: Method, body: Option<Value>) ->
+    hyper::Result<(StatusCode, Value)>
+{
+    let body = match body {
+        Some(body) => Body::from(body.to_string()),
+        None => Body::empty(),
+    };
+    let request = Request::builder().method(method)
+        .uri(format!("http://127.0.0.1:{}{}", port, NETWORK_PROTOCOL))
+        .body(body).unwrap();
+    let response = Client::new().request(request).await?;
+    let status = response.status();
+    let body = hyper::body::to_bytes(response.into_body()).await?;
+    Ok((status, serde_json::from_slice(&body).unwrap_or(Value::Null)))
+}
+
+fn free_port() -> u16 {
+    TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap()
+        .local_addr().unwrap().port()
+}
+
+#[tokio::test]
+async fn protocols_are_described() {
+    let directory = tempfile::tempdir().unwrap();
+    let mut config = config(&directory);
+    config.network.ssh = Some(ProtocolConfig { enabled: true, port: 22 });
+    let mut service = librebmc::service(&config).unwrap();
+    let (_, manager) = request(
+        &mut service, Method::GET, "/redfish/v1/Managers/bmc", None).await;
+    assert_eq!(json!({"@odata.id": NETWORK_PROTOCOL}),
+               manager["NetworkProtocol"]);
+
+    let (status, protocols) = request(
+        &mut service, Method::GET, NETWORK_PROTOCOL, None).await;
+    assert_eq!(StatusCode::OK, status);
+    assert_eq!(json!({"ProtocolEnabled": true, "Port": 3000}),
+               protocols["HTTP"]);
+    assert_eq!(json!({"ProtocolEnabled": false, "Port": 1900}),
+               protocols["SSDP"]);
+    assert_eq!(json!({"ProtocolEnabled": true, "Port": 22}),
+               protocols["SSH"]);
+    assert_eq!(Value::Null, protocols["IPMI"]);
+
+    let (status, protocols) = request(
+        &mut service, Method::PATCH, NETWORK_PROTOCOL, Some(json!({
+            "NTP": {"NTPServers": ["10.0.0.123", "time.example.com"]},
+        }))).await;
+    assert_eq!(StatusCode::OK, status);
+    assert_eq!(json!({
+        "ProtocolEnabled": true, "Port": 123,
+        "NTPServers": ["10.0.0.123", "time.example.com"],
+    }), protocols["NTP"]);
+}
+
+#[tokio::test]
+async fn invalid_changes_are_rejected() {
+    let directory = tempfile::tempdir().unwrap();
+    let mut service = librebmc::service(&config(&directory)).unwrap();
+    let (status, error) = request(
+        &mut service, Method::PATCH, NETWORK_PROTOCOL, Some(json!({
+            "HTTP": {"Port": 0},
+            "SSH": {"ProtocolEnabled": false},
+            "NTP": {"NTPServers": ["not a server"], "Port": 124},
+        }))).await;
+    assert_eq!(StatusCode::BAD_REQUEST, status);
+    let mut ids = message_ids(&error);
+    ids.sort();
+    assert_eq!(vec![
+        "Base.1.15.0.PropertyNotWritable", "Base.1.15.0.PropertyNotWritable",
+        "Base.1.15.0.PropertyValueFormatError",
+        "Base.1.15.0.PropertyValueOutOfRange",
+    ], ids);
+
+    // Nothing was changed
+    let (_, protocols) = request(
+        &mut service, Method::GET, NETWORK_PROTOCOL, None).await;
+    assert_eq!(json!({"ProtocolEnabled": true, "Port": 3000}),
+               protocols["HTTP"]);
+    assert_eq!(json!(["pool.ntp.org"]), protocols["NTP"]["NTPServers"]);
+    assert!(!directory.path().join("protocols.json").exists());
+}
+
+#[tokio::test]
+async fn listeners_are_changed_at_runtime() {
+    let directory = tempfile::tempdir().unwrap();
+    let mut config = config(&directory);
+    let (old, new) = (free_port(), free_port());
+    config.network.http.port = old;
+    let (service, protocols) = librebmc::compose(&config).unwrap();
+    protocols.serve(service).unwrap();
+
+    let ssdp_port = std::net::UdpSocket::bind((Ipv4Addr::LOCALHOST, 0))
+        .unwrap().local_addr().unwrap().port();
+    let (status, body) = request_port(old, Method::PATCH, Some(json!({
+        "HTTP": {"Port": new},
+        "SSDP": {"ProtocolEnabled": true, "Port": ssdp_port},
+    }))).await.unwrap();
+    assert_eq!(StatusCode::OK, status);
+    assert_eq!(json!({"ProtocolEnabled": true, "Port": new}), body["HTTP"]);
+
+    // The old listener is closed once its connections are idle
+    let (status, _) = request_port(new, Method::GET, None).await.unwrap();
+    assert_eq!(StatusCode::OK, status);
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while request_port(old, Method::GET, None).await.is_ok() {
+        assert!(Instant::now() < deadline, "port {} is still open", old);
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+
+    // The service is discovered at its new location
+    let socket = UdpSocket::bind((Ipv4Addr::LOCALHOST, 0)).await.unwrap();
+    socket.send_to(
+        b"M-SEARCH * HTTP/1.1\r\n\
+          HOST: 239.255.255.250:1900\r\n\
+          MAN: \"ssdp:discover\"\r\n\
+          MX: 1\r\n\
+          ST: urn:dmtf-org:service:redfish-rest:1\r\n\r\n",
+        (Ipv4Addr::LOCALHOST, ssdp_port)).await.unwrap();
+    let mut buffer = [0; 2048];
+    let length = tokio::time::timeout(
+        Duration::from_secs(5), socket.recv(&mut buffer)).await
+        .expect("no response to M-SEARCH").unwrap();
+    let response = String::from_utf8_lossy(&buffer[..length]);
+    assert!(response.contains(
+        &format!("AL: http://127.0.0.1:{}/redfish/v1/\r\n", new)),
+            "{}", response);
+
+    // Disabling HTTP stops the last listener. The settings are kept for
+    // the next instance.
+    let (status, _) = request_port(new, Method::PATCH, Some(json!({
+        "HTTP": {"ProtocolEnabled": false},
+    }))).await.unwrap();
+    assert_eq!(StatusCode::OK, status);
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while request_port(new, Method::GET, None).await.is_ok() {
+        assert!(Instant::now() < deadline, "port {} is still open", new);
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    let (mut service, _) = librebmc::compose(&config).unwrap();
+    let (_, body) = request(
+        &mut service, Method::GET, NETWORK_PROTOCOL, None).await;
+    assert_eq!(json!({"ProtocolEnabled": false, "Port": new}), body["HTTP"]);
+    assert_eq!(json!({"ProtocolEnabled": true, "Port": ssdp_port}),
+               body["SSDP"]);
+}
+
+///////////////////////////////////////////////////////////////////////////////
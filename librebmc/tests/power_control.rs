@@ -0,0 +1,178 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            power_control.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Exercises the power of the ComputerSystem through its Reset
+//                  action and power settings.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::time::Duration;
+
+use hyper::{Body, Method, Request, StatusCode, service::Service};
+use serde_json::{json, Value};
+use tempfile::TempDir;
+
+use librebmc::models::ServiceRoot;
+use librebmc::service::ResourceService;
+
+mod common;
+use common::{config, message_ids};
+
+const SYSTEM: &'static str = "/redfish/v1/Systems/system";
+const RESET: &'static str =
+    "/redfish/v1/Systems/system/Actions/ComputerSystem.Reset";
+
+struct Fixture {
+    _directory: TempDir,
+    service: ResourceService<ServiceRoot>,
+}
+
+impl Fixture {
+    fn new() -> Self {
+        let directory = tempfile::tempdir().unwrap();
+        let service = librebmc::service(&config(&directory)).unwrap();
+        Fixture { _directory: directory, service }
+    }
+
+    async fn call(&mut self, method: Method, uri: &str, body: Option<Value>)
+        -> (StatusCode, Value)
+    {
+        let body = match body {
+            Some(body) => Body::from(body.to_string()),
+            None => Body::empty(),
+        };
+        let request = Request::builder().method(method).uri(uri).body(body)
+            .unwrap();
+        let response = self.service.call(request).await.unwrap();
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        (status, serde_json::from_slice(&body).unwrap_or(Value::Null))
+    }
+
+    async fn system(&mut self) -> Value {
+        self.call(Method::GET, SYSTEM, None).await.1
+    }
+
+    async fn reset(&mut self, reset_type: &str) -> (StatusCode, Value) {
+        self.call(Method::POST, RESET, Some(json!({"ResetType": reset_type})))
+            .await
+    }
+
+    async fn wait(&mut self, task: &Value) -> (StatusCode, Value) {
+        let monitor = task["TaskMonitor"].as_str().unwrap().to_string();
+        loop {
+            let (status, body) = self.call(Method::GET, &monitor, None).await;
+            if status != StatusCode::ACCEPTED {
+                return (status, body);
+            }
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        }
+    }
+}
+
+#[tokio::test]
+async fn resets_report_transitional_states() {
+    let mut fixture = Fixture::new();
+    let system = fixture.system().await;
+    assert_eq!("Off", system["PowerState"]);
+    let reset_types = &system["Actions"]["#ComputerSystem.Reset"]
+        ["ResetType@Redfish.AllowableValues"];
+    assert!(reset_types.as_array().unwrap().contains(&json!("On")));
+
+    let (status, _) = fixture.call(Method::PATCH, SYSTEM, Some(json!({
+        "PowerOnDelaySeconds": 0.2,
+    }))).await;
+    assert_eq!(StatusCode::OK, status);
+
+    let (status, task) = fixture.reset("On").await;
+    assert_eq!(StatusCode::ACCEPTED, status);
+    let state = loop {
+        let state = fixture.system().await["PowerState"].clone();
+        if state != "Off" {
+            break state;
+        }
+        tokio::time::sleep(Duration::from_millis(1)).await;
+    };
+    assert_eq!("PoweringOn", state);
+    let (status, _) = fixture.wait(&task).await;
+    assert_eq!(StatusCode::NO_CONTENT, status);
+    assert_eq!("On", fixture.system().await["PowerState"]);
+
+    let (status, task) = fixture.reset("GracefulShutdown").await;
+    assert_eq!(StatusCode::ACCEPTED, status);
+    fixture.wait(&task).await;
+    assert_eq!("Off", fixture.system().await["PowerState"]);
+}
+
+#[tokio::test]
+async fn power_settings_are_kept() {
+    let mut fixture = Fixture::new();
+    let system = fixture.system().await;
+    assert_eq!("LastState", system["PowerRestorePolicy"]);
+    assert_eq!(0.0, system["PowerCycleDelaySeconds"]);
+
+    let (status, system) = fixture.call(Method::PATCH, SYSTEM, Some(json!({
+        "PowerRestorePolicy": "AlwaysOn",
+        "PowerCycleDelaySeconds": 5,
+    }))).await;
+    assert_eq!(StatusCode::OK, status);
+    assert_eq!("AlwaysOn", system["PowerRestorePolicy"]);
+    assert_eq!(5.0, system["PowerCycleDelaySeconds"]);
+
+    let (status, error) = fixture.call(Method::PATCH, SYSTEM, Some(json!({
+        "PowerRestorePolicy": "Sometimes",
+        "PowerOnDelaySeconds": -1,
+        "PowerState": "On",
+    }))).await;
+    assert_eq!(StatusCode::BAD_REQUEST, status);
+    let mut ids = message_ids(&error);
+    ids.sort();
+    assert_eq!(vec![
+        "Base.1.15.0.PropertyNotWritable",
+        "Base.1.15.0.PropertyValueNotInList",
+        "Base.1.15.0.PropertyValueOutOfRange",
+    ], ids);
+    assert_eq!("AlwaysOn", fixture.system().await["PowerRestorePolicy"]);
+}
+
+#[tokio::test]
+async fn invalid_resets_are_rejected() {
+    let mut fixture = Fixture::new();
+    let (status, error) = fixture.reset("Suspend").await;
+    assert_eq!(StatusCode::BAD_REQUEST, status);
+    assert_eq!(vec!["Base.1.15.0.ActionParameterValueNotInList"],
+               message_ids(&error));
+
+    let (status, error) = fixture.call(Method::POST, RESET, Some(json!({})))
+        .await;
+    assert_eq!(StatusCode::BAD_REQUEST, status);
+    assert_eq!(vec!["Base.1.15.0.ActionParameterMissing"],
+               message_ids(&error));
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,423 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            schema_conformance.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Crawls the resource tree served by librebmc and validates
+//                  every payload against the vendored DMTF Redfish JSON
+//                  Schemas.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use hyper::{Body, Method, Request, StatusCode, service::Service};
+use jsonschema::{JSONSchema, SchemaResolver, SchemaResolverError,
+                 ValidationError, error::ValidationErrorKind};
+use serde_json::{json, Value};
+use url::Url;
+
+use librebmc::config::JournalConfig;
+use librebmc::models::ServiceRoot;
+use librebmc::service::ResourceService;
+
+mod common;
+use common::get;
+
+const SCHEMA_HOST: &'static str = "redfish.dmtf.org";
+const SCHEMA_BASE: &'static str = "http://redfish.dmtf.org/schemas/v1/";
+const ENTRIES: &'static str =
+    "/redfish/v1/Managers/bmc/LogServices/EventLog/Entries";
+
+// Failures the crawler is known to report for the current tree. Each entry is
+// (URI, JSON pointer, fragment of the error message).
+const KNOWN_FAILURES: &[(&str, &str, &str)] = &[
+    // librebmc does not implement a SessionService yet.
+    ("/redfish/v1", "#", "\"Links\" is a required property"),
+];
+
+fn schema_directory() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/schemas")
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Schema Resolution
+////
+
+// Serves references to redfish.dmtf.org from the vendored schema directory.
+struct VendoredSchemas(PathBuf);
+
+impl SchemaResolver for VendoredSchemas {
+    fn resolve(&self, _root: &Value, url: &Url, _reference: &str) ->
+        Result<Arc<Value>, SchemaResolverError>
+    {
+        let name = url.path_segments()
+            .and_then(|mut segments| segments.next_back())
+            .filter(|_| url.host_str() == Some(SCHEMA_HOST))
+            .ok_or_else(|| io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("not a DMTF schema: {}", url)))?;
+        let schema = fs::read(self.0.join(name))?;
+        Ok(Arc::new(serde_json::from_slice(&schema)?))
+    }
+}
+
+// Maps an @odata.type to the schema file and definition describing it, e.g.
+// #ComputerSystem.v1_16_1.ComputerSystem -> ComputerSystem.v1_16_1.json and
+// ComputerSystem.
+fn schema_for(odata_type: &str) -> Option<(String, String)> {
+    let parts: Vec<&str> = odata_type.strip_prefix('#')?.split('.').collect();
+    match parts.as_slice() {
+        [namespace, definition] =>
+            Some((format!("{}.json", namespace), definition.to_string())),
+        [namespace, version, definition] =>
+            Some((format!("{}.{}.json", namespace, version),
+                  definition.to_string())),
+        _ => None,
+    }
+}
+
+// Unversioned definitions (collections, for example) accept either a full
+// payload or a bare reference. Payloads are always validated against the full
+// form, so that errors point at the offending property instead of at the
+// anyOf.
+fn definition_pointer(file: &Value, definition: &str) -> String {
+    let pointer = format!("#/definitions/{}", definition);
+    let reference = format!("{}odata-v4.json#/definitions/idRef", SCHEMA_BASE);
+    let branches = file["definitions"][definition]["anyOf"].as_array();
+    branches.and_then(|branches| branches.iter().position(
+        |branch| branch.get("$ref").and_then(Value::as_str)
+            != Some(&reference)))
+        .map(|index| format!("{}/anyOf/{}", pointer, index))
+        .unwrap_or(pointer)
+}
+
+// Point at each unexpected property individually, rather than at the object
+// that contains them.
+fn locate(error: ValidationError) -> Vec<(String, String)> {
+    let pointer = format!("#{}", error.instance_path);
+    match &error.kind {
+        ValidationErrorKind::AdditionalProperties { unexpected } =>
+            unexpected.iter().map(|property| (
+                format!("{}/{}", pointer, property),
+                format!("{} is not defined by the schema", property)))
+            .collect(),
+        _ => vec![(pointer, error.to_string())],
+    }
+}
+
+struct Validator {
+    directory: PathBuf,
+    compiled: HashMap<String, JSONSchema>,
+}
+
+impl Validator {
+    fn new(directory: PathBuf) -> Self {
+        Validator { directory, compiled: HashMap::new() }
+    }
+
+    fn compile(&self, odata_type: &str) -> Result<JSONSchema, String> {
+        let (file, definition) = schema_for(odata_type)
+            .ok_or_else(|| format!("malformed @odata.type {}", odata_type))?;
+        let contents = fs::read(self.directory.join(&file))
+            .map_err(|_| format!("no vendored schema {} for {}", file,
+                                 odata_type))?;
+        let contents: Value = serde_json::from_slice(&contents)
+            .map_err(|error| format!("{}: {}", file, error))?;
+        let schema = json!({"$ref": format!(
+            "{}{}{}", SCHEMA_BASE, file,
+            definition_pointer(&contents, &definition))});
+        JSONSchema::options()
+            .with_resolver(VendoredSchemas(self.directory.clone()))
+            .compile(&schema)
+            .map_err(|error| format!("{}: {}", file, error))
+    }
+
+    // Validate a payload, returning (JSON pointer, message) for each error.
+    fn validate(&mut self, odata_type: &str, payload: &Value) ->
+        Vec<(String, String)>
+    {
+        if !self.compiled.contains_key(odata_type) {
+            match self.compile(odata_type) {
+                Ok(schema) => {
+                    self.compiled.insert(odata_type.to_string(), schema);
+                },
+                Err(error) => return vec![("#".to_string(), error)],
+            }
+        }
+
+        match self.compiled[odata_type].validate(payload) {
+            Ok(()) => Vec::new(),
+            Err(errors) => errors.flat_map(locate).collect(),
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Crawler
+////
+
+struct Failure {
+    uri: String,
+    pointer: String,
+    message: String,
+}
+
+impl Failure {
+    fn is_known(&self) -> bool {
+        KNOWN_FAILURES.iter().any(|(uri, pointer, message)| {
+            *uri == self.uri && *pointer == self.pointer
+                && self.message.contains(message)
+        })
+    }
+}
+
+// Create a subscription, which is recorded in the log of the BMC, so that
+// there's a log entry to crawl, and push a firmware update so that there's a
+// task. The update fails, since the image isn't signed.
+async fn populate(service: &mut ResourceService<ServiceRoot>) {
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri("/redfish/v1/UpdateService/update-multipart")
+        .header("Content-Type", "multipart/form-data; boundary=XyZ")
+        .body(Body::from(concat!(
+            "--XyZ\r\n",
+            "Content-Disposition: form-data; name=\"UpdateFile\"; ",
+            "filename=\"bios.bin\"\r\n",
+            "\r\n",
+            "2.0.0\r\n",
+            "--XyZ--\r\n",
+        )))
+        .unwrap();
+    let response = service.call(request).await.unwrap();
+    assert_eq!(StatusCode::ACCEPTED, response.status());
+
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri("/redfish/v1/EventService/Subscriptions")
+        .body(Body::from(json!({
+            "Destination": "http://192.0.2.1/events", "Protocol": "Redfish",
+            "RegistryPrefixes": ["Base"],
+        }).to_string()))
+        .unwrap();
+    let response = service.call(request).await.unwrap();
+    assert_eq!(StatusCode::CREATED, response.status());
+
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while Instant::now() < deadline {
+        let (_, entries) = get(service, ENTRIES).await;
+        if entries["Members@odata.count"] != 0 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+}
+
+async fn crawl(validator: &mut Validator) -> (HashSet<String>, Vec<Failure>) {
+    let directory = tempfile::tempdir().unwrap();
+    let messages = directory.path().join("messages");
+    std::fs::write(&messages, concat!(
+        "<30>Oct 18 14:00:00 bmc systemd[1]: Started librebmc.\n",
+        "<27>Oct 18 14:00:01 bmc librebmc[80]: failed to read sensor\n",
+    )).unwrap();
+    let mut config = common::config(&directory);
+    config.logs.journal = JournalConfig::Syslog { path: messages };
+    let mut service = librebmc::service(&config).unwrap();
+    populate(&mut service).await;
+    let mut visited = HashSet::new();
+    let mut pending = VecDeque::from(vec![librebmc::SERVICE_ROOT.to_string()]);
+    let mut failures = Vec::new();
+
+    while let Some(uri) = pending.pop_front() {
+        if !visited.insert(uri.clone()) {
+            continue;
+        }
+
+        let mut fail = |pointer: &str, message: String| failures.push(Failure {
+            uri: uri.clone(), pointer: pointer.to_string(), message,
+        });
+        let (status, payload) = get(&mut service, &uri).await;
+        if status != StatusCode::OK {
+            fail("#", format!("GET returned {}", status));
+            continue;
+        }
+
+        if payload["@odata.id"] != Value::String(uri.clone()) {
+            fail("#/@odata.id", format!(
+                "{} does not identify the resource", payload["@odata.id"]));
+        }
+
+        match payload["@odata.type"].as_str() {
+            Some(odata_type) => {
+                for (pointer, message) in validator.validate(
+                    odata_type, &payload) {
+                    fail(&pointer, message);
+                }
+            },
+            None => fail("#", "payload has no @odata.type".to_string()),
+        }
+
+        let mut found = Vec::new();
+        odata::links(&payload, &mut found);
+        pending.extend(found);
+    }
+    (visited, failures)
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Tests
+////
+
+#[tokio::test]
+async fn resources_conform_to_schema() {
+    let mut validator = Validator::new(schema_directory());
+    let (visited, failures) = crawl(&mut validator).await;
+    assert!(visited.contains("/redfish/v1/Systems/system"));
+    assert!(visited.contains("/redfish/v1/Systems/system/Processors/0"));
+    assert!(visited.contains("/redfish/v1/Systems/system/Memory/DIMM0"));
+    assert!(visited.contains(
+        "/redfish/v1/Systems/system/Storage/nvme0/Drives/nvme0"));
+    assert!(visited.contains(
+        "/redfish/v1/Systems/system/Storage/nvme0/Volumes/nvme0n1"));
+    assert!(visited.contains(
+        "/redfish/v1/Systems/system/EthernetInterfaces/eno1"));
+    assert!(visited.contains(
+        "/redfish/v1/Managers/bmc/EthernetInterfaces/eth0"));
+    assert!(visited.contains("/redfish/v1/Managers/bmc/NetworkProtocol"));
+    assert!(visited.contains(
+        "/redfish/v1/Chassis/chassis/PowerSubsystem/PowerSupplies/0"));
+    assert!(visited.contains(
+        "/redfish/v1/Chassis/chassis/EnvironmentMetrics"));
+    assert!(visited.contains(
+        "/redfish/v1/Chassis/chassis/ThermalSubsystem/Fans/0"));
+    assert!(visited.contains("/redfish/v1/Chassis/chassis/Sensors/CPU0"));
+    assert!(visited.contains("/redfish/v1/EventService/Subscriptions"));
+    assert!(visited.contains("/redfish/v1/TaskService/Tasks/1"));
+    assert!(visited.contains(
+        "/redfish/v1/UpdateService/FirmwareInventory/BMC"));
+    assert!(visited.contains("/redfish/v1/Systems/system/LogServices/SEL"));
+    assert!(visited.contains(&format!("{}/1", ENTRIES)));
+    assert!(visited.contains(
+        "/redfish/v1/Managers/bmc/LogServices/Journal/Entries/0"));
+
+    let report: Vec<String> = failures.iter()
+        .filter(|failure| !failure.is_known())
+        .map(|failure| format!(
+            "GET {} {}: {}", failure.uri, failure.pointer, failure.message))
+        .collect();
+    assert!(report.is_empty(), "{} non-conformant properties:\n{}",
+            report.len(), report.join("\n"));
+}
+
+#[test]
+fn reports_offending_properties() {
+    let mut validator = Validator::new(schema_directory());
+    let payload = json!({
+        "@odata.id": "/redfish/v1/Systems/system",
+        "@odata_type": "#ComputerSystem.v1_16_1.ComputerSystem",
+        "Id": "system",
+        "Name": "Computer System",
+        "Status": {"State": "Enabled", "Health": "Fine"},
+    });
+    let errors = validator.validate(
+        "#ComputerSystem.v1_16_1.ComputerSystem", &payload);
+    let pointers: Vec<&str> = errors.iter()
+        .map(|(pointer, _)| pointer.as_str())
+        .collect();
+    assert!(pointers.contains(&"#/@odata_type"), "{:?}", errors);
+    assert!(pointers.contains(&"#/Status/Health"), "{:?}", errors);
+}
+
+// The vendored schemas are trimmed copies of DSP8010, which may only lose
+// unused definitions and properties. Deviations belong in KNOWN_FAILURES.
+#[test]
+fn vendored_schemas_are_not_loosened() {
+    const RESOURCE: &[&str] = &["@odata.id", "@odata.type", "Id", "Name"];
+    const COLLECTION: &[&str] = &[
+        "Members", "Members@odata.count", "@odata.id", "@odata.type", "Name",
+    ];
+    // The only objects that DSP8010 leaves open
+    const OPEN: &[(&str, &str)] =
+        &[("Resource.json", "Oem"), ("Resource.json", "OemObject")];
+
+    let mut loosened = Vec::new();
+    for entry in fs::read_dir(schema_directory()).unwrap() {
+        let path = entry.unwrap().path();
+        if path.extension().and_then(|extension| extension.to_str())
+            != Some("json")
+        {
+            continue;
+        }
+
+        let file_name = path.file_name().unwrap().to_str().unwrap();
+        let namespace = file_name.split('.').next().unwrap();
+        let schema: Value = serde_json::from_slice(&fs::read(&path).unwrap())
+            .unwrap();
+        let definitions = schema["definitions"].as_object();
+        for (name, definition) in definitions.into_iter().flatten() {
+            let branches = definition["anyOf"].as_array().cloned()
+                .unwrap_or_else(|| vec![definition.clone()]);
+            for branch in branches.iter().filter(|branch| {
+                branch.get("properties").is_some()
+            }) {
+                if branch["additionalProperties"] != json!(false)
+                    && !OPEN.contains(&(file_name, name.as_str()))
+                {
+                    loosened.push(format!(
+                        "{}#/definitions/{} is open", file_name, name));
+                }
+
+                let required = if name != namespace {
+                    &[][..]
+                } else if name.ends_with("Collection") {
+                    COLLECTION
+                } else if file_name.matches('.').count() == 2 {
+                    RESOURCE
+                } else {
+                    &[][..]
+                };
+                for property in required {
+                    let array = branch["required"].as_array();
+                    if !array.is_some_and(|array| {
+                        array.contains(&json!(property))
+                    }) {
+                        loosened.push(format!(
+                            "{}#/definitions/{} does not require {}",
+                            file_name, name, property));
+                    }
+                }
+            }
+        }
+    }
+    assert!(loosened.is_empty(), "{}", loosened.join("\n"));
+}
+
+///////////////////////////////////////////////////////////////////////////////
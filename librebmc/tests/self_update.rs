@@ -0,0 +1,181 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            self_update.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Integration tests of A/B self-update, with shell scripts
+//                  standing in for the librebmc binary in each slot.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::cell::Cell;
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::sync::mpsc;
+use std::time::Duration;
+
+use tempfile::TempDir;
+
+use librebmc::update::slots::{
+    RESTART_STATUS, Slot, SlotState, SlotUpdater, Slots, Supervisor,
+};
+use librebmc::update::{UpdateError, Updater};
+
+// A script that's being written can't be executed, so tests that run
+// scripts mustn't overlap with tests that write them.
+static SERIAL: Mutex<()> = Mutex::new(());
+
+// Reports the slot it ran from, and confirms that it's healthy
+const HEALTHY: &'static str = concat!(
+    "#!/bin/sh\n",
+    "echo \"$LIBREBMC_SLOT\" >> \"$(dirname \"$0\")/../runs\"\n",
+    "touch \"$LIBREBMC_HEALTHY\"\n",
+);
+
+///////////////////////////////////////////////////////////////////////////////
+// Helpers
+////
+
+struct Fixture {
+    root: TempDir,
+    slots: Slots,
+}
+
+impl Fixture {
+    fn new() -> Self {
+        let root = tempfile::tempdir().unwrap();
+        fs::create_dir(root.path().join("slots")).unwrap();
+        let slots = Slots::new(&root.path().join("slots"));
+        Fixture { root, slots }
+    }
+
+    fn script(&self, name: &str, contents: &str) -> PathBuf {
+        let path = self.root.path().join(name);
+        fs::write(&path, contents).unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755))
+            .unwrap();
+        path
+    }
+
+    // Install a script into the inactive slot, and boot it on trial
+    fn update(&self, contents: &str) {
+        let image = self.script("image", contents);
+        let slot = self.slots.install(&image, "2.0.0", &|_| {}).unwrap();
+        self.slots.switch(slot).unwrap();
+    }
+
+    // Supervise the factory script, which reports that it ran from the
+    // slot, until an instance exits, returning its exit code.
+    fn supervise(&self, factory: &str) -> i32 {
+        let factory = self.script("factory", factory);
+        let supervisor = Supervisor::new(
+            self.slots.clone(), factory, Vec::new(),
+            Duration::from_millis(500));
+        supervisor.run().unwrap().code().unwrap()
+    }
+
+    // The slots that instances ran from, in order
+    fn runs(&self) -> Vec<String> {
+        let runs = self.root.path().join("slots").join("runs");
+        fs::read_to_string(runs).unwrap_or_default().lines()
+            .map(|line| line.to_string())
+            .collect()
+    }
+}
+
+// The factory script is run from the root rather than a slot
+fn factory(script: &str) -> String {
+    script.replace("$(dirname \"$0\")/..", "$(dirname \"$0\")/slots")
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Tests
+////
+
+#[test]
+fn healthy_updates_are_confirmed() {
+    let _serial = SERIAL.lock().unwrap();
+    let fixture = Fixture::new();
+    let (sender, restarted) = mpsc::channel();
+    let updater = SlotUpdater::new(fixture.slots.clone(), move || {
+        sender.send(()).unwrap();
+    });
+    let image = fixture.script("image", HEALTHY);
+
+    // Only one image is installed at a time
+    let concurrent = Cell::new(None);
+    updater.apply("BMC", &image, "2.0.0", &|_| {
+        concurrent.set(Some(updater.apply("BMC", &image, "3.0.0", &|_| {})));
+    }).unwrap();
+    assert!(matches!(concurrent.take(), Some(Err(UpdateError::Rejected(_)))));
+    restarted.recv_timeout(Duration::from_secs(5)).unwrap();
+    assert_eq!(SlotState { boot: Slot::B, fallback: Some(Slot::A) },
+               fixture.slots.state().unwrap());
+
+    // The version of the booted slot is the one it was installed as
+    assert_eq!("2.0.0", updater.components()[0].version);
+
+    // Another update can't replace the fallback while the slot is on trial
+    assert!(matches!(updater.apply("BMC", &image, "3.0.0", &|_| {}),
+                     Err(UpdateError::Rejected(_))));
+
+    assert_eq!(0, fixture.supervise(&factory(HEALTHY)));
+    assert_eq!(vec!["b"], fixture.runs());
+    assert_eq!(SlotState { boot: Slot::B, fallback: None },
+               fixture.slots.state().unwrap());
+}
+
+#[test]
+fn unhealthy_updates_are_rolled_back() {
+    let _serial = SERIAL.lock().unwrap();
+    let fixture = Fixture::new();
+    fixture.update("#!/bin/sh\nexec sleep 10\n");
+    assert_eq!(0, fixture.supervise(&factory(HEALTHY)));
+    assert_eq!(vec!["a"], fixture.runs());
+    assert_eq!(SlotState::default(), fixture.slots.state().unwrap());
+
+    // An update that exits before it's healthy is rolled back too
+    fixture.update("#!/bin/sh\nexit 0\n");
+    assert_eq!(0, fixture.supervise(&factory(HEALTHY)));
+    assert_eq!(vec!["a", "a"], fixture.runs());
+    assert_eq!(SlotState::default(), fixture.slots.state().unwrap());
+}
+
+#[test]
+fn instances_are_restarted_on_request() {
+    let _serial = SERIAL.lock().unwrap();
+    let fixture = Fixture::new();
+    let script = format!(
+        "{}[ \"$(wc -l < \"$(dirname \"$0\")/../runs\")\" -ge 2 ] && \
+         exit 3\nexit {}\n", HEALTHY, RESTART_STATUS);
+    assert_eq!(3, fixture.supervise(&factory(&script)));
+    assert_eq!(vec!["a", "a"], fixture.runs());
+    assert!(!Path::new(&fixture.slots.binary(Slot::A)).exists());
+}
+
+///////////////////////////////////////////////////////////////////////////////
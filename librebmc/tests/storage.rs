@@ -0,0 +1,155 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            storage.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Exercises the Storage, Drives and Volumes of the
+//                  ComputerSystem.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::fs;
+use std::os::unix::fs::symlink;
+use std::time::{Duration, Instant};
+
+use hyper::StatusCode;
+use serde_json::json;
+
+use librebmc::config::StorageProviderConfig;
+
+mod common;
+use common::{config, get};
+
+const SYSTEM: &'static str = "/redfish/v1/Systems/system";
+const STORAGE: &'static str = "/redfish/v1/Systems/system/Storage";
+const SEL_ENTRIES: &'static str =
+    "/redfish/v1/Systems/system/LogServices/SEL/Entries";
+const DISK: &'static str =
+    "devices/pci0000:00/0000:00:17.0/ata1/host0/target0:0:0/0:0:0:0";
+
+// A SATA disk on an AHCI controller
+fn fake_sysfs() -> tempfile::TempDir {
+    let root = tempfile::tempdir().unwrap();
+    let disk = root.path().join(DISK);
+    let block = disk.join("block/sda");
+    fs::create_dir_all(block.join("queue")).unwrap();
+    fs::create_dir_all(root.path().join("block")).unwrap();
+    for (name, value) in [("vendor", "ATA"), ("model", "WDC WD40EFRX-68N"),
+                          ("rev", "0A82"), ("state", "running")] {
+        fs::write(disk.join(name), format!("{}\n", value)).unwrap();
+    }
+    for (name, value) in [("size", "7814037168"), ("removable", "0"),
+                          ("queue/rotational", "1")] {
+        fs::write(block.join(name), format!("{}\n", value)).unwrap();
+    }
+    symlink(&disk, block.join("device")).unwrap();
+    symlink(&block, root.path().join("block/sda")).unwrap();
+    root
+}
+
+#[tokio::test]
+async fn simulated_storage() {
+    let directory = tempfile::tempdir().unwrap();
+    let mut service = librebmc::service(&config(&directory)).unwrap();
+    let (_, system) = get(&mut service, SYSTEM).await;
+    assert_eq!(json!({"@odata.id": STORAGE}), system["Storage"]);
+
+    let (status, collection) = get(&mut service, STORAGE).await;
+    assert_eq!(StatusCode::OK, status);
+    assert_eq!(2, collection["Members@odata.count"]);
+
+    let uri = format!("{}/ahci0", STORAGE);
+    let (status, storage) = get(&mut service, &uri).await;
+    assert_eq!(StatusCode::OK, status);
+    assert_eq!(2, storage["Drives@odata.count"]);
+    assert_eq!(json!({"@odata.id": format!("{}/Volumes", uri)}),
+               storage["Volumes"]);
+    let controller = &storage["StorageControllers"][0];
+    assert_eq!(format!("{}#/StorageControllers/0", uri),
+               controller["@odata.id"]);
+    assert_eq!(json!(["SATA"]), controller["SupportedDeviceProtocols"]);
+
+    let (_, drive) = get(&mut service, &format!("{}/Drives/sdb", uri)).await;
+    assert_eq!("SSD", drive["MediaType"]);
+    assert_eq!("SATA", drive["Protocol"]);
+    let (status, _) = get(&mut service, &format!("{}/Drives/sdc", uri)).await;
+    assert_eq!(StatusCode::NOT_FOUND, status);
+
+    let uri = format!("{}/nvme0", STORAGE);
+    let (_, drive) = get(&mut service, &format!("{}/Drives/nvme0", uri))
+        .await;
+    assert_eq!("NVMe", drive["Protocol"]);
+    assert_eq!(98.0, drive["PredictedMediaLifeLeftPercent"]);
+    let (_, volumes) = get(&mut service, &format!("{}/Volumes", uri)).await;
+    assert_eq!(1, volumes["Members@odata.count"]);
+    let (_, volume) = get(&mut service, &format!("{}/Volumes/nvme0n1", uri))
+        .await;
+    assert_eq!(json!([{"@odata.id": format!("{}/Drives/nvme0", uri)}]),
+               volume["Links"]["Drives"]);
+}
+
+#[tokio::test]
+async fn failed_drive_is_reported() {
+    let (sysfs, directory) = (fake_sysfs(), tempfile::tempdir().unwrap());
+    let mut config = config(&directory);
+    config.sensors.poll_interval_ms = 10;
+    config.host.storage = StorageProviderConfig::Sysfs {
+        root: sysfs.path().join("block"),
+        nvme: sysfs.path().join("nvme"),
+    };
+    let mut service = librebmc::service(&config).unwrap();
+    let uri = format!("{}/0000:00:17.0/Drives/sda", STORAGE);
+    let (status, drive) = get(&mut service, &uri).await;
+    assert_eq!(StatusCode::OK, status);
+    assert_eq!(4000787030016u64, drive["CapacityBytes"]);
+    assert_eq!("HDD", drive["MediaType"]);
+    assert_eq!("OK", drive["Status"]["Health"]);
+
+    fs::write(sysfs.path().join(DISK).join("state"), "offline\n").unwrap();
+    let (_, drive) = get(&mut service, &uri).await;
+    assert_eq!("Critical", drive["Status"]["Health"]);
+
+    // The monitor rolls the failure up to the system, and records it
+    let deadline = Instant::now() + Duration::from_secs(5);
+    let (system, entries) = loop {
+        let (_, system) = get(&mut service, SYSTEM).await;
+        let (_, entries) = get(&mut service, SEL_ENTRIES).await;
+        if (system["Status"]["HealthRollup"] == "Critical"
+            && entries["Members@odata.count"] != 0)
+            || Instant::now() > deadline {
+            break (system, entries);
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    };
+    assert_eq!("Critical", system["Status"]["HealthRollup"]);
+    assert_eq!("OK", system["Status"]["Health"]);
+    let entry = &entries["Members"][0];
+    assert_eq!("ResourceEvent.1.3.0.ResourceStatusChangedCritical",
+               entry["MessageId"]);
+    assert_eq!(uri, entry["Links"]["OriginOfCondition"]["@odata.id"]);
+}
+
+///////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,171 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            system_inventory.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Exercises the inventory of the ComputerSystem and Chassis,
+//                  from each of its sources.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::fs;
+use std::path::Path;
+
+use hyper::StatusCode;
+use serde_json::json;
+
+use librebmc::config::InventoryProviderConfig;
+
+mod common;
+use common::{config, get};
+
+const SYSTEM: &'static str = "/redfish/v1/Systems/system";
+const CHASSIS: &'static str = "/redfish/v1/Chassis/chassis";
+
+#[tokio::test]
+async fn simulated_inventory() {
+    let directory = tempfile::tempdir().unwrap();
+    let mut service = librebmc::service(&config(&directory)).unwrap();
+    let (status, system) = get(&mut service, SYSTEM).await;
+    assert_eq!(StatusCode::OK, status);
+    assert_eq!(json!({"@odata.id": format!("{}/Processors", SYSTEM)}),
+               system["Processors"]);
+    assert_eq!(2, system["ProcessorSummary"]["Count"]);
+    assert_eq!(32, system["ProcessorSummary"]["CoreCount"]);
+    assert_eq!(64, system["ProcessorSummary"]["LogicalProcessorCount"]);
+    assert_eq!(64.0, system["MemorySummary"]["TotalSystemMemoryGiB"]);
+
+    let uri = format!("{}/Processors", SYSTEM);
+    let (status, processors) = get(&mut service, &uri).await;
+    assert_eq!(StatusCode::OK, status);
+    assert_eq!(2, processors["Members@odata.count"]);
+    let (_, processor) = get(&mut service, &format!("{}/1", uri)).await;
+    assert_eq!("CPU1", processor["Name"]);
+    assert_eq!("x86-64", processor["InstructionSet"]);
+
+    let uri = format!("{}/Memory", SYSTEM);
+    let (_, memory) = get(&mut service, &uri).await;
+    assert_eq!(4, memory["Members@odata.count"]);
+    let (_, module) = get(&mut service, &format!("{}/DIMM3", uri)).await;
+    assert_eq!("DDR4", module["MemoryDeviceType"]);
+    assert_eq!(16384, module["CapacityMiB"]);
+
+    let (status, _) = get(&mut service, &format!("{}/DIMM4", uri)).await;
+    assert_eq!(StatusCode::NOT_FOUND, status);
+}
+
+#[tokio::test]
+async fn inventory_file_is_reread() {
+    let directory = tempfile::tempdir().unwrap();
+    let path = directory.path().join("inventory.json");
+    let mut config = config(&directory);
+    config.host.inventory = InventoryProviderConfig::File {
+        path: path.clone(),
+    };
+    let mut service = librebmc::service(&config).unwrap();
+    let (_, system) = get(&mut service, SYSTEM).await;
+    assert_eq!(0, system["ProcessorSummary"]["Count"]);
+    assert_eq!(0.0, system["MemorySummary"]["TotalSystemMemoryGiB"]);
+
+    fs::write(&path, json!({
+        "processors": [
+            {"id": "0", "total-cores": 4, "health": "Warning"},
+            {"id": "1", "total-cores": 4, "state": "Absent"},
+        ],
+        "memory": [
+            {"id": "A0", "capacity-mib": 8192},
+            {"id": "A1", "capacity-mib": 8192, "state": "Absent"},
+        ],
+    }).to_string()).unwrap();
+    let (_, system) = get(&mut service, SYSTEM).await;
+    assert_eq!(json!({
+        "Count": 1,
+        "CoreCount": 4,
+        "Status": {
+            "State": "Enabled", "Health": "Warning", "HealthRollup": "Warning",
+        },
+    }), system["ProcessorSummary"]);
+    assert_eq!(8.0, system["MemorySummary"]["TotalSystemMemoryGiB"]);
+
+    let uri = format!("{}/Processors/1", SYSTEM);
+    let (status, processor) = get(&mut service, &uri).await;
+    assert_eq!(StatusCode::OK, status);
+    assert_eq!("Absent", processor["Status"]["State"]);
+}
+
+#[tokio::test]
+async fn smbios_inventory() {
+    let directory = tempfile::tempdir().unwrap();
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/smbios/two-socket.bin");
+    let mut config = config(&directory);
+    config.host.inventory = InventoryProviderConfig::Smbios { path };
+    let mut service = librebmc::service(&config).unwrap();
+    let (_, system) = get(&mut service, SYSTEM).await;
+    assert_eq!("Supermicro", system["Manufacturer"]);
+    assert_eq!("SYS-6029P-TR", system["Model"]);
+    assert_eq!("S329384X1B12345", system["SerialNumber"]);
+    assert_eq!("091715D9", system["SKU"]);
+    assert_eq!("3.8a", system["BiosVersion"]);
+    assert_eq!(48, system["ProcessorSummary"]["CoreCount"]);
+    assert_eq!(48.0, system["MemorySummary"]["TotalSystemMemoryGiB"]);
+
+    let (_, chassis) = get(&mut service, CHASSIS).await;
+    assert_eq!("C8250LK12AB0123", chassis["SerialNumber"]);
+    assert_eq!("IT-00482", chassis["AssetTag"]);
+    assert_eq!("CSE-825TQC-R740LPB", chassis["SKU"]);
+
+    let uri = format!("{}/Processors/1", SYSTEM);
+    let (_, processor) = get(&mut service, &uri).await;
+    assert_eq!(json!({
+        "@odata.id": uri,
+        "@odata.type": "#Processor.v1_16_0.Processor",
+        "Id": "1",
+        "Name": "CPU2",
+        "ProcessorType": "CPU",
+        "ProcessorArchitecture": "x86",
+        "InstructionSet": "x86-64",
+        "Socket": "CPU2",
+        "Manufacturer": "Intel(R) Corporation",
+        "Model": "Intel(R) Xeon(R) Gold 6248R CPU @ 3.00GHz",
+        "MaxSpeedMHz": 4000,
+        "TotalCores": 24,
+        "TotalThreads": 48,
+        "Status": {"State": "Enabled", "Health": "OK"},
+    }), processor);
+
+    let uri = format!("{}/Memory", SYSTEM);
+    let (_, module) = get(&mut service, &format!("{}/DIMM0", uri)).await;
+    assert_eq!("P1-DIMMA1", module["DeviceLocator"]);
+    assert_eq!(32768, module["CapacityMiB"]);
+    assert_eq!(2666, module["OperatingSpeedMhz"]);
+    assert_eq!("M393A4K40DB2-CVF", module["PartNumber"]);
+    let (_, module) = get(&mut service, &format!("{}/DIMM1", uri)).await;
+    assert_eq!("Absent", module["Status"]["State"]);
+    assert_eq!(None, module.get("CapacityMiB"));
+}
+
+///////////////////////////////////////////////////////////////////////////////
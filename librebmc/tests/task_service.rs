@@ -0,0 +1,238 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            task_service.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Integration tests for tasks started by actions, and their
+//                  monitors.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use hyper::{Body, Method, Request, StatusCode, service::Service};
+use odata::Resource;
+use serde_json::Value;
+use tokio::sync::Notify;
+
+use librebmc::models::TaskService;
+use librebmc::registries::base;
+use librebmc::service::{ActionResult, Dispatch, ODataResource, Parameters,
+                        RedfishError, ResourceService};
+use librebmc::tasks::TaskManager;
+
+const TASK_SERVICE: &'static str = "/redfish/v1/TaskService";
+const OPERATION: &'static str = "/redfish/v1/Operation";
+
+///////////////////////////////////////////////////////////////////////////////
+// Operation
+////
+
+// A resource with an action that runs until it's released
+#[derive(Clone)]
+struct Operation {
+    tasks: Arc<TaskManager>,
+    release: Arc<Notify>,
+}
+
+impl odata::ResourceMetadata for Operation {
+    const ODATA_TYPE: &'static str = "#Operation.v1_0_0.Operation";
+}
+
+impl odata::Serialize for Operation {
+    const CARDINALITY: usize = 2;
+    fn serialize<S>(&self, serializer: &mut S, _me: &Path) ->
+        Result<(), S::Error>
+    where S: serde::ser::SerializeStruct
+    {
+        serializer.serialize_field("Id", "Operation")?;
+        serializer.serialize_field("Name", "Operation")
+    }
+}
+
+impl Dispatch for Operation {
+    type Error = std::convert::Infallible;
+    async fn action(&self, _name: &str, mut parameters: Parameters) ->
+        ActionResult
+    {
+        let fail = parameters.take::<bool>("Fail").unwrap_or(false);
+        parameters.finish()?;
+        let release = self.release.clone();
+        Ok(Some(self.tasks.spawn("Operation", |task| async move {
+            task.set_percent_complete(10);
+            release.notified().await;
+            match fail {
+                true => Err(RedfishError::internal(
+                    vec![base::general_error()])),
+                false => Ok(()),
+            }
+        })))
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Helpers
+////
+
+struct Fixture {
+    tasks: ResourceService<TaskService>,
+    operation: ResourceService<Operation>,
+    release: Arc<Notify>,
+}
+
+impl Fixture {
+    fn new() -> Self {
+        let tasks = TaskManager::new(PathBuf::from(TASK_SERVICE));
+        let release = Arc::new(Notify::new());
+        let operation = Operation { tasks: tasks.clone(),
+                                    release: release.clone() };
+        Fixture {
+            tasks: ODataResource::from(Resource::new(
+                PathBuf::from(TASK_SERVICE), TaskService::new(tasks))).into(),
+            operation: ODataResource::from(Resource::new(
+                PathBuf::from(OPERATION), operation)).into(),
+            release,
+        }
+    }
+
+    async fn start(&mut self, body: &str) -> (StatusCode, String, Value) {
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri(format!("{}/Actions/Operation.Start", OPERATION))
+            .body(Body::from(body.to_string())).unwrap();
+        let response = self.operation.call(request).await.unwrap();
+        let location = response.headers().get("Location")
+            .map(|location| location.to_str().unwrap().to_string())
+            .unwrap_or_default();
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        (status, location, serde_json::from_slice(&body).unwrap())
+    }
+
+    async fn request(&mut self, method: Method, uri: &str) ->
+        (StatusCode, Value)
+    {
+        let request = Request::builder().method(method).uri(uri)
+            .body(Body::empty()).unwrap();
+        let response = self.tasks.call(request).await.unwrap();
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        (status, serde_json::from_slice(&body).unwrap_or(Value::Null))
+    }
+
+    // Poll the monitor until the task is no longer running
+    async fn wait(&mut self, monitor: &str) -> (StatusCode, Value) {
+        loop {
+            let (status, body) = self.request(Method::GET, monitor).await;
+            if status != StatusCode::ACCEPTED {
+                return (status, body);
+            }
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Tests
+////
+
+#[tokio::test]
+async fn actions_are_tracked_by_tasks() {
+    let mut fixture = Fixture::new();
+    let (status, monitor, task) = fixture.start("").await;
+    assert_eq!(StatusCode::ACCEPTED, status);
+    assert_eq!(format!("{}/TaskMonitors/1", TASK_SERVICE), monitor);
+    assert_eq!("Running", task["TaskState"]);
+    assert_eq!(monitor, task["TaskMonitor"]);
+    let task_uri = task["@odata.id"].as_str().unwrap().to_string();
+    assert_eq!(format!("{}/Tasks/1", TASK_SERVICE), task_uri);
+
+    let (status, _) = fixture.request(Method::GET, &monitor).await;
+    assert_eq!(StatusCode::ACCEPTED, status);
+    let (_, tasks) = fixture.request(
+        Method::GET, &format!("{}/Tasks", TASK_SERVICE)).await;
+    assert_eq!(1, tasks["Members@odata.count"]);
+    assert_eq!(task_uri, tasks["Members"][0]["@odata.id"]);
+    loop {
+        let (_, task) = fixture.request(Method::GET, &task_uri).await;
+        if task["PercentComplete"] == 10 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(1)).await;
+    }
+
+    fixture.release.notify_one();
+    let (status, _) = fixture.wait(&monitor).await;
+    assert_eq!(StatusCode::NO_CONTENT, status);
+    let (_, task) = fixture.request(Method::GET, &task_uri).await;
+    assert_eq!("Completed", task["TaskState"]);
+    assert_eq!("OK", task["TaskStatus"]);
+    assert_eq!(100, task["PercentComplete"]);
+    assert!(task["EndTime"].is_string());
+}
+
+#[tokio::test]
+async fn failed_tasks_report_their_error() {
+    let mut fixture = Fixture::new();
+    let (_, monitor, task) = fixture.start(r#"{"Fail": true}"#).await;
+    fixture.release.notify_one();
+    let (status, error) = fixture.wait(&monitor).await;
+    assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, status);
+    let general_error = base::general_error().message_id;
+    assert_eq!(general_error, error["error"]["code"]);
+
+    let (_, task) = fixture.request(
+        Method::GET, task["@odata.id"].as_str().unwrap()).await;
+    assert_eq!("Exception", task["TaskState"]);
+    assert_eq!("Critical", task["TaskStatus"]);
+    assert_eq!(general_error, task["Messages"][0]["MessageId"]);
+}
+
+#[tokio::test]
+async fn tasks_are_cancelled_through_their_monitor() {
+    let mut fixture = Fixture::new();
+    let (_, monitor, task) = fixture.start("").await;
+    let (status, _) = fixture.request(Method::DELETE, &monitor).await;
+    assert_eq!(StatusCode::NO_CONTENT, status);
+    let (status, task) = fixture.request(
+        Method::GET, task["@odata.id"].as_str().unwrap()).await;
+    assert_eq!(StatusCode::OK, status);
+    assert_eq!("Cancelled", task["TaskState"]);
+    let (status, _) = fixture.request(Method::DELETE, &monitor).await;
+    assert_eq!(StatusCode::METHOD_NOT_ALLOWED, status);
+
+    // The operation of a cancelled task never completes
+    fixture.release.notify_one();
+    let (status, _) = fixture.request(Method::GET, &monitor).await;
+    assert_eq!(StatusCode::OK, status);
+    let (status, _) = fixture.request(
+        Method::GET, &format!("{}/TaskMonitors/2", TASK_SERVICE)).await;
+    assert_eq!(StatusCode::NOT_FOUND, status);
+}
+
+///////////////////////////////////////////////////////////////////////////////
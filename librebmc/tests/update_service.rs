@@ -0,0 +1,527 @@
+///////////////////////////////////////////////////////////////////////////////
+// NAME:            update_service.rs
+//
+// AUTHOR:          Ethan D. Twardy <ethan.twardy@gmail.com>
+//
+// DESCRIPTION:     Integration tests of the UpdateService, which apply images
+//                  with a fake flasher.
+//
+// CREATED:         10/18/2026
+//
+// LAST EDITED:     10/18/2026
+//
+// Copyright 2026, Ethan D. Twardy
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to
+// deal in the Software without restriction, including without limitation the
+// rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+// sell copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in
+// all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING
+// FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS
+// IN THE SOFTWARE.
+////
+
+use std::convert::Infallible;
+use std::fs;
+use std::net::{SocketAddr, TcpListener};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use hyper::{Body, Request, Response, Server, StatusCode};
+use hyper::service::{Service, make_service_fn, service_fn};
+use odata::Resource;
+use openssl::asn1::Asn1Time;
+use openssl::cms::{CMSOptions, CmsContentInfo};
+use openssl::ec::{EcGroup, EcKey};
+use openssl::hash::MessageDigest;
+use openssl::nid::Nid;
+use openssl::pkey::{PKey, Private};
+use openssl::sign::Signer;
+use openssl::x509::{X509, X509Builder, X509NameBuilder};
+use serde_json::{json, Value};
+use tempfile::TempDir;
+
+use librebmc::models::{TaskService, UpdateService};
+use librebmc::registries::{base, firmware, update};
+use librebmc::service::{ODataResource, ResourceService};
+use librebmc::tasks::TaskManager;
+use librebmc::update::image::{self, Manifest, Signature, TrustedKeys,
+                              Verifier};
+use librebmc::update::{Component, Staging, UpdateError, Updater};
+
+const UPDATE_SERVICE: &'static str = "/redfish/v1/UpdateService";
+const TASK_SERVICE: &'static str = "/redfish/v1/TaskService";
+const SIMPLE_UPDATE: &'static str =
+    "/redfish/v1/UpdateService/Actions/UpdateService.SimpleUpdate";
+const PUSH: &'static str = "/redfish/v1/UpdateService/update-multipart";
+const INVENTORY: &'static str = "/redfish/v1/UpdateService/FirmwareInventory";
+const MAX_IMAGE_SIZE: u64 = 65536;
+
+///////////////////////////////////////////////////////////////////////////////
+// Flasher
+////
+
+// Records the payloads written to each component, or rejects them all
+struct Flasher {
+    components: Vec<Component>,
+    flashed: Mutex<Vec<(String, Vec<u8>)>>,
+    reject: bool,
+}
+
+impl Flasher {
+    fn new(reject: bool) -> Self {
+        let component = |id: &str, updateable| Component {
+            id: id.to_string(),
+            name: format!("{} Firmware", id),
+            version: "1.0.0".to_string(),
+            updateable,
+        };
+        Flasher {
+            components: vec![component("BMC", true), component("BIOS", true),
+                             component("CPLD", false)],
+            flashed: Mutex::new(Vec::new()),
+            reject,
+        }
+    }
+}
+
+impl Updater for Flasher {
+    fn components(&self) -> Vec<Component> { self.components.clone() }
+
+    fn apply(&self, target: &str, image: &Path, _version: &str,
+             progress: &dyn Fn(u8)) -> Result<(), UpdateError>
+    {
+        if self.reject {
+            return Err(UpdateError::Rejected("bad image".to_string()));
+        }
+        let contents = fs::read(image)?;
+        progress(100);
+        self.flashed.lock().unwrap().push((target.to_string(), contents));
+        Ok(())
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Signing
+////
+
+// Images are signed with an Ed25519 key, or through CMS with a certificate
+struct Signing {
+    key: PKey<Private>,
+    certificate_key: PKey<Private>,
+    certificate: X509,
+}
+
+impl Signing {
+    fn new() -> Self {
+        let group = EcGroup::from_curve_name(Nid::X9_62_PRIME256V1).unwrap();
+        let certificate_key = PKey::from_ec_key(
+            EcKey::generate(&group).unwrap()).unwrap();
+        let mut name = X509NameBuilder::new().unwrap();
+        name.append_entry_by_text("CN", "Firmware Signing").unwrap();
+        let name = name.build();
+        let mut certificate = X509Builder::new().unwrap();
+        certificate.set_version(2).unwrap();
+        certificate.set_subject_name(&name).unwrap();
+        certificate.set_issuer_name(&name).unwrap();
+        certificate.set_pubkey(&certificate_key).unwrap();
+        certificate.set_not_before(&Asn1Time::days_from_now(0).unwrap())
+            .unwrap();
+        certificate.set_not_after(&Asn1Time::days_from_now(1).unwrap())
+            .unwrap();
+        certificate.sign(&certificate_key, MessageDigest::sha256()).unwrap();
+        Signing {
+            key: PKey::generate_ed25519().unwrap(),
+            certificate_key,
+            certificate: certificate.build(),
+        }
+    }
+
+    fn trusted_keys(&self) -> TrustedKeys {
+        let mut keys = TrustedKeys::default();
+        keys.add_pem(&self.key.public_key_to_pem().unwrap()).unwrap();
+        keys.add_pem(&self.certificate.to_pem().unwrap()).unwrap();
+        keys
+    }
+
+    fn ed25519(&self, component: &str, version: &str, payload: &[u8]) ->
+        Vec<u8>
+    {
+        let manifest = Manifest::new(component, version, payload).to_bytes();
+        let signature = Signer::new_without_digest(&self.key).unwrap()
+            .sign_oneshot_to_vec(&manifest).unwrap();
+        image::pack(&manifest, &Signature::Ed25519(signature), payload)
+    }
+
+    fn cms(&self, component: &str, version: &str, payload: &[u8]) -> Vec<u8>
+    {
+        let manifest = Manifest::new(component, version, payload).to_bytes();
+        let signature = CmsContentInfo::sign(
+            Some(&self.certificate), Some(&self.certificate_key), None,
+            Some(&manifest), CMSOptions::DETACHED | CMSOptions::BINARY)
+            .unwrap().to_der().unwrap();
+        image::pack(&manifest, &Signature::Cms(signature), payload)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Helpers
+////
+
+struct Fixture {
+    updates: ResourceService<UpdateService>,
+    tasks: ResourceService<TaskService>,
+    flasher: Arc<Flasher>,
+    signing: Signing,
+    staging: TempDir,
+}
+
+impl Fixture {
+    fn new(reject: bool) -> Self {
+        let tasks = TaskManager::new(PathBuf::from(TASK_SERVICE));
+        let flasher = Arc::new(Flasher::new(reject));
+        let signing = Signing::new();
+        let staging = tempfile::tempdir().unwrap();
+        let service = UpdateService::new(
+            PathBuf::from(UPDATE_SERVICE), flasher.clone(),
+            Verifier::new(signing.trusted_keys(), false),
+            Staging::new(staging.path(), MAX_IMAGE_SIZE), tasks.clone());
+        Fixture {
+            updates: ODataResource::from(Resource::new(
+                PathBuf::from(UPDATE_SERVICE), service)).into(),
+            tasks: ODataResource::from(Resource::new(
+                PathBuf::from(TASK_SERVICE), TaskService::new(tasks))).into(),
+            flasher,
+            signing,
+            staging,
+        }
+    }
+
+    async fn call(&mut self, request: Request<Body>) -> (StatusCode, Value) {
+        let response = match request.uri().path().starts_with(TASK_SERVICE) {
+            true => self.tasks.call(request).await.unwrap(),
+            false => self.updates.call(request).await.unwrap(),
+        };
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        (status, serde_json::from_slice(&body).unwrap_or(Value::Null))
+    }
+
+    async fn get(&mut self, uri: &str) -> (StatusCode, Value) {
+        self.call(Request::get(uri).body(Body::empty()).unwrap()).await
+    }
+
+    async fn simple_update(&mut self, parameters: Value) ->
+        (StatusCode, Value)
+    {
+        self.call(Request::post(SIMPLE_UPDATE)
+                  .body(Body::from(parameters.to_string())).unwrap()).await
+    }
+
+    async fn push(&mut self, parameters: Option<Value>, image: &[u8]) ->
+        (StatusCode, Value)
+    {
+        let mut body = Vec::new();
+        if let Some(parameters) = parameters {
+            body.extend_from_slice(b"--XyZ\r\n");
+            body.extend_from_slice(b"Content-Disposition: form-data; ");
+            body.extend_from_slice(b"name=\"UpdateParameters\"\r\n\r\n");
+            body.extend_from_slice(format!("{}\r\n", parameters).as_bytes());
+        }
+        body.extend_from_slice(b"--XyZ\r\nContent-Disposition: form-data; ");
+        body.extend_from_slice(
+            b"name=\"UpdateFile\"; filename=\"image.bin\"\r\n\r\n");
+        body.extend_from_slice(image);
+        body.extend_from_slice(b"\r\n--XyZ--\r\n");
+        self.call(Request::post(PUSH)
+                  .header("Content-Type", "multipart/form-data; boundary=XyZ")
+                  .body(Body::from(body)).unwrap()).await
+    }
+
+    // Poll the monitor of the task until it's no longer running
+    async fn wait(&mut self, task: &Value) -> (StatusCode, Value) {
+        let monitor = task["TaskMonitor"].as_str().unwrap().to_string();
+        loop {
+            let (status, body) = self.get(&monitor).await;
+            if status != StatusCode::ACCEPTED {
+                return (status, body);
+            }
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        }
+    }
+
+    // Push an image that's refused, returning the id of the error's message
+    async fn refused(&mut self, parameters: Option<Value>, image: &[u8]) ->
+        String
+    {
+        let (status, task) = self.push(parameters, image).await;
+        assert_eq!(StatusCode::ACCEPTED, status);
+        let (status, error) = self.wait(&task).await;
+        assert_eq!(StatusCode::BAD_REQUEST, status);
+        error["error"]["code"].as_str().unwrap().to_string()
+    }
+
+    fn flashed(&self) -> Vec<(String, Vec<u8>)> {
+        self.flasher.flashed.lock().unwrap().clone()
+    }
+}
+
+// Serve an image at /image.bin to clients presenting the authorization
+fn image_server(image: Vec<u8>, authorization: &'static str) -> SocketAddr {
+    let make_service = make_service_fn(move |_| {
+        let image = image.clone();
+        async move {
+            Ok::<_, Infallible>(service_fn(move |request: Request<Body>| {
+                let authorized = request.headers().get("Authorization")
+                    .is_some_and(|value| value == authorization);
+                let status = match (request.uri().path(), authorized) {
+                    ("/image.bin", true) => StatusCode::OK,
+                    ("/image.bin", false) => StatusCode::UNAUTHORIZED,
+                    _ => StatusCode::NOT_FOUND,
+                };
+                let image = image.clone();
+                async move {
+                    Ok::<_, Infallible>(Response::builder().status(status)
+                                        .body(Body::from(image)).unwrap())
+                }
+            }))
+        }
+    });
+    let server = Server::bind(&"127.0.0.1:0".parse().unwrap())
+        .serve(make_service);
+    let address = server.local_addr();
+    tokio::spawn(server);
+    address
+}
+
+fn message_ids(task: &Value) -> Vec<String> {
+    task["Messages"].as_array().unwrap().iter()
+        .map(|message| message["MessageId"].as_str().unwrap().to_string())
+        .collect()
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Tests
+////
+
+#[tokio::test]
+async fn firmware_inventory_lists_components() {
+    let mut fixture = Fixture::new(false);
+    let (_, service) = fixture.get(UPDATE_SERVICE).await;
+    assert_eq!(INVENTORY, service["FirmwareInventory"]["@odata.id"]);
+    assert_eq!(PUSH, service["MultipartHttpPushUri"]);
+    assert_eq!(json!(["HTTP"]), service["Actions"]
+               ["#UpdateService.SimpleUpdate"]
+               ["TransferProtocol@Redfish.AllowableValues"]);
+
+    let (_, inventory) = fixture.get(INVENTORY).await;
+    assert_eq!(3, inventory["Members@odata.count"]);
+    let (status, cpld) = fixture.get(&format!("{}/CPLD", INVENTORY)).await;
+    assert_eq!(StatusCode::OK, status);
+    assert_eq!("1.0.0", cpld["Version"]);
+    assert_eq!(false, cpld["Updateable"]);
+}
+
+#[tokio::test]
+async fn pushed_images_are_applied_to_their_targets() {
+    let mut fixture = Fixture::new(false);
+    let image = fixture.signing.ed25519("BIOS", "2.0.0", b"bios image");
+    let (status, task) = fixture.push(Some(json!({
+        "Targets": [format!("{}/BIOS", INVENTORY)],
+    })), &image).await;
+    assert_eq!(StatusCode::ACCEPTED, status);
+    let (status, _) = fixture.wait(&task).await;
+    assert_eq!(StatusCode::NO_CONTENT, status);
+    assert_eq!(vec![("BIOS".to_string(), b"bios image".to_vec())],
+               fixture.flashed());
+
+    let (_, task) = fixture.get(task["@odata.id"].as_str().unwrap()).await;
+    assert_eq!("Completed", task["TaskState"]);
+    assert_eq!(vec![
+        firmware::image_verified("", "", "").message_id,
+        update::installing_on_component("", "").message_id,
+        update::update_successful("", "").message_id,
+    ], message_ids(&task));
+
+    // Staged images are removed once they're applied
+    assert_eq!(0, fs::read_dir(fixture.staging.path()).unwrap().count());
+}
+
+#[tokio::test]
+async fn simple_update_downloads_the_image() {
+    let mut fixture = Fixture::new(false);
+    let image = fixture.signing.cms("BMC", "1.1.0", b"bmc image");
+    let address = image_server(image, "Basic cm9vdDowcGVuQm1j");
+    let (status, task) = fixture.simple_update(json!({
+        "ImageURI": format!("{}/image.bin", address),
+        "TransferProtocol": "HTTP",
+        "Username": "root",
+        "Password": "0penBmc",
+    })).await;
+    assert_eq!(StatusCode::ACCEPTED, status);
+    let (status, _) = fixture.wait(&task).await;
+    assert_eq!(StatusCode::NO_CONTENT, status);
+
+    // The manifest of the image determines the target
+    assert_eq!(vec![("BMC".to_string(), b"bmc image".to_vec())],
+               fixture.flashed());
+    let (_, task) = fixture.get(task["@odata.id"].as_str().unwrap()).await;
+    assert_eq!(100, task["PercentComplete"]);
+    assert_eq!(update::transferring_to_component("", "").message_id,
+               message_ids(&task)[0]);
+}
+
+#[tokio::test]
+async fn untrusted_images_are_refused() {
+    let mut fixture = Fixture::new(false);
+    assert_eq!(firmware::image_malformed("").message_id,
+               fixture.refused(None, b"unsigned image").await);
+
+    let other = Signing::new();
+    let image = other.ed25519("BIOS", "2.0.0", b"bios image");
+    assert_eq!(firmware::signature_untrusted("").message_id,
+               fixture.refused(None, &image).await);
+    let image = other.cms("BIOS", "2.0.0", b"bios image");
+    assert_eq!(firmware::signature_untrusted("").message_id,
+               fixture.refused(None, &image).await);
+
+    // The payload can't be replaced once the manifest is signed
+    let mut image = fixture.signing.ed25519("BIOS", "2.0.0", b"bios image");
+    *image.last_mut().unwrap() ^= 1;
+    assert_eq!(firmware::digest_mismatch("").message_id,
+               fixture.refused(None, &image).await);
+    assert!(fixture.flashed().is_empty());
+}
+
+#[tokio::test]
+async fn images_must_suit_their_targets() {
+    let mut fixture = Fixture::new(false);
+    let image = fixture.signing.ed25519("BIOS", "0.9.0", b"bios image");
+    assert_eq!(firmware::downgrade_rejected("", "", "").message_id,
+               fixture.refused(None, &image).await);
+
+    let image = fixture.signing.ed25519("BIOS", "2.0.0", b"bios image");
+    let targets = json!({"Targets": [format!("{}/BMC", INVENTORY)]});
+    assert_eq!(firmware::component_mismatch("", "", "").message_id,
+               fixture.refused(Some(targets), &image).await);
+
+    let image = fixture.signing.ed25519("CPLD", "2.0.0", b"cpld image");
+    assert_eq!(update::no_targets_determined("").message_id,
+               fixture.refused(None, &image).await);
+    assert!(fixture.flashed().is_empty());
+}
+
+#[tokio::test]
+async fn invalid_parameters_are_rejected() {
+    let mut fixture = Fixture::new(false);
+    let (status, error) = fixture.simple_update(json!({
+        "ImageURI": "ftp://192.0.2.1/image.bin",
+        "Targets": [format!("{}/CPLD", INVENTORY)],
+    })).await;
+    assert_eq!(StatusCode::BAD_REQUEST, status);
+    let ids: Vec<&str> = error["error"]["@Message.ExtendedInfo"].as_array()
+        .unwrap().iter()
+        .map(|message| message["MessageId"].as_str().unwrap())
+        .collect();
+    assert!(ids.contains(&base::action_parameter_value_format_error(
+        "", "", "").message_id.as_str()), "{:?}", ids);
+    assert!(ids.contains(&base::action_parameter_value_not_in_list(
+        "", "", "").message_id.as_str()), "{:?}", ids);
+
+    let (status, _) = fixture.push(Some(json!({
+        "Targets": ["/redfish/v1/Systems/system"],
+    })), b"image").await;
+    assert_eq!(StatusCode::BAD_REQUEST, status);
+    let (status, _) = fixture.call(
+        Request::post(PUSH).header("Content-Type", "application/json")
+            .body(Body::from("{}")).unwrap()).await;
+    assert_eq!(StatusCode::UNSUPPORTED_MEDIA_TYPE, status);
+    assert!(fixture.flashed().is_empty());
+}
+
+#[tokio::test]
+async fn oversized_images_are_refused() {
+    let mut fixture = Fixture::new(false);
+    let (_, service) = fixture.get(UPDATE_SERVICE).await;
+    assert_eq!(MAX_IMAGE_SIZE, service["MaxImageSizeBytes"]);
+
+    let image = vec![0; MAX_IMAGE_SIZE as usize + 1];
+    let (status, _) = fixture.push(None, &image).await;
+    assert_eq!(StatusCode::PAYLOAD_TOO_LARGE, status);
+    assert_eq!(0, fs::read_dir(fixture.staging.path()).unwrap().count());
+
+    let address = image_server(image, "Basic cm9vdDowcGVuQm1j");
+    let (_, task) = fixture.simple_update(json!({
+        "ImageURI": format!("http://{}/image.bin", address),
+        "Username": "root",
+        "Password": "0penBmc",
+    })).await;
+    let (status, error) = fixture.wait(&task).await;
+    assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, status);
+    assert_eq!(update::transfer_failed("", "").message_id,
+               error["error"]["code"]);
+    assert_eq!(0, fs::read_dir(fixture.staging.path()).unwrap().count());
+    assert!(fixture.flashed().is_empty());
+}
+
+#[tokio::test]
+async fn components_are_updated_by_one_task_at_a_time() {
+    let mut fixture = Fixture::new(false);
+
+    // The download from a server that never responds doesn't complete
+    let server = TcpListener::bind("127.0.0.1:0").unwrap();
+    let (status, _) = fixture.simple_update(json!({
+        "ImageURI": format!("http://{}/image.bin",
+                            server.local_addr().unwrap()),
+        "Targets": [format!("{}/BIOS", INVENTORY)],
+    })).await;
+    assert_eq!(StatusCode::ACCEPTED, status);
+
+    let image = fixture.signing.ed25519("BIOS", "2.0.0", b"bios image");
+    let (status, error) = fixture.push(Some(json!({
+        "Targets": [format!("{}/BIOS", INVENTORY)],
+    })), &image).await;
+    assert_eq!(StatusCode::CONFLICT, status);
+    assert_eq!(base::resource_in_use().message_id, error["error"]["code"]);
+
+    // Other components may be updated, and again once they have been
+    let image = fixture.signing.ed25519("BMC", "2.0.0", b"bmc image");
+    for _ in 0..2 {
+        let (status, task) = fixture.push(None, &image).await;
+        assert_eq!(StatusCode::ACCEPTED, status);
+        let (status, _) = fixture.wait(&task).await;
+        assert_eq!(StatusCode::NO_CONTENT, status);
+    }
+    assert_eq!(2, fixture.flashed().len());
+}
+
+#[tokio::test]
+async fn failed_updates_report_their_error() {
+    let mut fixture = Fixture::new(true);
+    let image = fixture.signing.ed25519("BMC", "2.0.0", b"bmc image");
+    assert_eq!(update::verification_failed("", "").message_id,
+               fixture.refused(None, &image).await);
+
+    // The image is never transferred from a server that refuses it
+    let address = image_server(image, "Basic c2VjcmV0");
+    let (_, task) = fixture.simple_update(json!({
+        "ImageURI": format!("http://{}/image.bin", address),
+    })).await;
+    let (status, error) = fixture.wait(&task).await;
+    assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, status);
+    assert_eq!(update::transfer_failed("", "").message_id,
+               error["error"]["code"]);
+}
+
+///////////////////////////////////////////////////////////////////////////////
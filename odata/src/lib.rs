@@ -8,7 +8,7 @@
 //
 // CREATED:         04/01/2022
 //
-// LAST EDITED:     04/09/2022
+// LAST EDITED:     10/18/2026
 //
 // Copyright 2022, Ethan D. Twardy
 //
@@ -31,7 +31,6 @@
 // IN THE SOFTWARE.
 ////
 
-use core::convert::Infallible;
 use std::path::{Path, PathBuf};
 use serde::{self, ser::SerializeStruct};
 
@@ -53,7 +52,7 @@ pub struct Link(PathBuf);
 impl Link {
     pub fn resolve(&self, root: &Path) -> Self {
         root.to_owned().join(
-            self.0.strip_prefix("/").or::<Infallible>(Ok(&self.0)).unwrap()
+            self.0.strip_prefix("/").unwrap_or(&self.0)
         ).into()
     }
 }
@@ -72,13 +71,48 @@ impl serde::Serialize for Link {
     }
 }
 
+impl<'de> serde::Deserialize<'de> for Link {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) ->
+        Result<Self, D::Error>
+    {
+        #[derive(serde::Deserialize)]
+        struct Reference {
+            #[serde(rename = "@odata.id")]
+            odata_id: PathBuf,
+        }
+
+        let reference = Reference::deserialize(deserializer)?;
+        Ok(Link(reference.odata_id))
+    }
+}
+
 impl From<PathBuf> for Link {
     fn from(value: PathBuf) -> Self { Link(value) }
 }
 
-impl Into<PathBuf> for Link {
-    fn into(self) -> PathBuf {
-        self.0
+impl From<Link> for PathBuf {
+    fn from(value: Link) -> Self {
+        value.0
+    }
+}
+
+// Collect every resource referenced by @odata.id anywhere in a payload.
+pub fn links(value: &serde_json::Value, found: &mut Vec<String>) {
+    use serde_json::Value;
+    match value {
+        Value::Object(properties) => {
+            for (name, value) in properties {
+                match (name.as_str(), value) {
+                    ("@odata.id", Value::String(uri)) if !uri.contains('#') =>
+                        found.push(uri.clone()),
+                    _ => links(value, found),
+                }
+            }
+        },
+        Value::Array(items) => {
+            items.iter().for_each(|item| links(item, found))
+        },
+        _ => {},
     }
 }
 
@@ -125,8 +159,8 @@ impl<T: Serialize + ResourceMetadata> serde::Serialize for Resource<T> {
 
 #[cfg(test)]
 mod tests {
-    use std::path::PathBuf;
-    use crate::{Resource, ResourceMetadata, Serialize};
+    use std::path::{Path, PathBuf};
+    use crate::{Link, Resource, ResourceMetadata, Serialize};
     use serde::ser::SerializeStruct;
     use serde_json;
 
@@ -137,8 +171,8 @@ mod tests {
 
     impl Serialize for Person {
         const CARDINALITY: usize = 1;
-        fn serialize<S: SerializeStruct>(&self, serializer: &mut S) ->
-            Result<(), S::Error>
+        fn serialize<S: SerializeStruct>(&self, serializer: &mut S, _me: &Path)
+            -> Result<(), S::Error>
         { serializer.serialize_field("Name", &self.0) }
     }
 
@@ -155,6 +189,15 @@ mod tests {
             result.unwrap()
         )
     }
+
+    #[test]
+    fn link_round_trip() {
+        let link: Link = PathBuf::from("/redfish/v1/Systems").into();
+        let json = serde_json::to_string(&link).unwrap();
+        assert_eq!("{\"@odata.id\":\"/redfish/v1/Systems\"}", json);
+        let link: Link = serde_json::from_str(&json).unwrap();
+        assert_eq!(Path::new("/redfish/v1/Systems"), link.as_ref());
+    }
 }
 
 ///////////////////////////////////////////////////////////////////////////////